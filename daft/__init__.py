@@ -66,7 +66,7 @@ from daft.convert import (
 from daft.daft import ImageFormat, ImageMode, ResourceRequest
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
-from daft.expressions import Expression, col, lit
+from daft.expressions import Expression, Window, col, interval, lit
 from daft.io import from_glob_path, read_csv, read_iceberg, read_json, read_parquet
 from daft.series import Series
 from daft.udf import udf
@@ -86,10 +86,12 @@ __all__ = [
     "read_iceberg",
     "DataFrame",
     "Expression",
+    "Window",
     "col",
     "DataType",
     "ImageMode",
     "ImageFormat",
+    "interval",
     "lit",
     "Series",
     "TimeUnit",
@@ -138,6 +138,107 @@ class CountMode(Enum):
         """
         ...
 
+class ClosedInterval(Enum):
+    """
+    Which bounds of a range check are inclusive.
+
+    | Both    - lower <= x <= upper.
+    | Left    - lower <= x < upper.
+    | Right   - lower < x <= upper.
+    | Neither - lower < x < upper.
+    """
+
+    Both: int
+    Left: int
+    Right: int
+    Neither: int
+
+    @staticmethod
+    def from_closed_interval_str(closed: str) -> ClosedInterval:
+        """
+        Create a ClosedInterval from its string representation.
+
+        Args:
+            closed: String representation of the closed interval, e.g. "both", "left", "right", or "neither".
+        """
+        ...
+
+class RoundMode(Enum):
+    """
+    How values exactly halfway between two rounding candidates are rounded.
+
+    | HalfEven         - Round to the even candidate, e.g. 2.5 -> 2 and 3.5 -> 4.
+    | HalfAwayFromZero - Round to the candidate further from zero, e.g. 2.5 -> 3 and -2.5 -> -3.
+    """
+
+    HalfEven: int
+    HalfAwayFromZero: int
+
+    @staticmethod
+    def from_round_mode_str(mode: str) -> RoundMode:
+        """
+        Create a RoundMode from its string representation.
+
+        Args:
+            mode: String representation of the round mode, e.g. "half_even" or "half_away_from_zero".
+        """
+        ...
+
+class DstResolution(Enum):
+    """
+    How local times that are ambiguous or nonexistent in a timezone because of a daylight saving time transition
+    are resolved to instants.
+
+    | Raise    - Raise an error.
+    | Null     - Resolve the local time to null.
+    | Earliest - Resolve an ambiguous local time to its earlier instant, and a nonexistent local time to the
+    |            instant of the transition.
+    | Latest   - Resolve an ambiguous local time to its later instant, and a nonexistent local time to the
+    |            instant of the transition.
+    """
+
+    Raise: int
+    Null: int
+    Earliest: int
+    Latest: int
+
+    @staticmethod
+    def from_dst_resolution_str(resolution: str) -> DstResolution:
+        """
+        Create a DstResolution from its string representation.
+
+        Args:
+            resolution: String representation of the resolution, e.g. "raise", "null", "earliest" or "latest".
+        """
+        ...
+
+class FillNullStrategy(Enum):
+    """
+    How nulls are filled when they aren't filled with a given value.
+
+    | Forward  - Fill each null with the last non-null value before it.
+    | Backward - Fill each null with the first non-null value after it.
+    | Min      - Fill nulls with the minimum of the non-null values.
+    | Max      - Fill nulls with the maximum of the non-null values.
+    | Mean     - Fill nulls with the mean of the non-null values.
+    """
+
+    Forward: int
+    Backward: int
+    Min: int
+    Max: int
+    Mean: int
+
+    @staticmethod
+    def from_fill_null_strategy_str(strategy: str) -> FillNullStrategy:
+        """
+        Create a FillNullStrategy from its string representation.
+
+        Args:
+            strategy: String representation of the strategy, e.g. "forward", "backward", "min", "max" or "mean".
+        """
+        ...
+
 class PartitionScheme(Enum):
     """
     Partition scheme for Daft DataFrame.
@@ -804,6 +905,8 @@ class PyDataType:
     @staticmethod
     def string() -> PyDataType: ...
     @staticmethod
+    def categorical() -> PyDataType: ...
+    @staticmethod
     def decimal128(precision: int, size: int) -> PyDataType: ...
     @staticmethod
     def date() -> PyDataType: ...
@@ -814,12 +917,16 @@ class PyDataType:
     @staticmethod
     def duration(time_unit: PyTimeUnit) -> PyDataType: ...
     @staticmethod
+    def interval() -> PyDataType: ...
+    @staticmethod
     def list(data_type: PyDataType) -> PyDataType: ...
     @staticmethod
     def fixed_size_list(data_type: PyDataType, size: int) -> PyDataType: ...
     @staticmethod
     def struct(fields: dict[str, PyDataType]) -> PyDataType: ...
     @staticmethod
+    def map(key_type: PyDataType, value_type: PyDataType) -> PyDataType: ...
+    @staticmethod
     def extension(name: str, storage_data_type: PyDataType, metadata: str | None = None) -> PyDataType: ...
     @staticmethod
     def embedding(data_type: PyDataType, size: int) -> PyDataType: ...
@@ -832,6 +939,7 @@ class PyDataType:
     def to_arrow(self, cast_tensor_type_for_ray: builtins.bool | None = None) -> pyarrow.DataType: ...
     def is_image(self) -> builtins.bool: ...
     def is_fixed_shape_image(self) -> builtins.bool: ...
+    def is_embedding(self) -> builtins.bool: ...
     def is_tensor(self) -> builtins.bool: ...
     def is_fixed_shape_tensor(self) -> builtins.bool: ...
     def is_logical(self) -> builtins.bool: ...
@@ -872,6 +980,24 @@ class PyExpr:
     def cast(self, dtype: PyDataType) -> PyExpr: ...
     def ceil(self) -> PyExpr: ...
     def floor(self) -> PyExpr: ...
+    def round(self, decimals: int, mode: RoundMode) -> PyExpr: ...
+    def sqrt(self) -> PyExpr: ...
+    def exp(self) -> PyExpr: ...
+    def ln(self) -> PyExpr: ...
+    def sin(self) -> PyExpr: ...
+    def cos(self) -> PyExpr: ...
+    def tan(self) -> PyExpr: ...
+    def pow(self, other: PyExpr) -> PyExpr: ...
+    def log(self, other: PyExpr) -> PyExpr: ...
+    def atan2(self, other: PyExpr) -> PyExpr: ...
+    def sign(self) -> PyExpr: ...
+    def clip(self, lower: PyExpr, upper: PyExpr) -> PyExpr: ...
+    def cumsum(self) -> PyExpr: ...
+    def cummax(self) -> PyExpr: ...
+    def cummin(self) -> PyExpr: ...
+    def cumcount(self) -> PyExpr: ...
+    def lead(self, n: int, default: PyExpr) -> PyExpr: ...
+    def lag(self, n: int, default: PyExpr) -> PyExpr: ...
     def if_else(self, if_true: PyExpr, if_false: PyExpr) -> PyExpr: ...
     def count(self, mode: CountMode) -> PyExpr: ...
     def sum(self) -> PyExpr: ...
@@ -879,8 +1005,17 @@ class PyExpr:
     def min(self) -> PyExpr: ...
     def max(self) -> PyExpr: ...
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
+    def first(self) -> PyExpr: ...
+    def last(self) -> PyExpr: ...
+    def approx_count_distinct(self) -> PyExpr: ...
+    def count_distinct(self) -> PyExpr: ...
+    def approx_percentile(self, percentile: float) -> PyExpr: ...
+    def median(self) -> PyExpr: ...
+    def str_join(self, separator: str) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def agg_filter(self, predicate: PyExpr) -> PyExpr: ...
+    def over(self, partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
     def explode(self) -> PyExpr: ...
     def __abs__(self) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
@@ -892,6 +1027,11 @@ class PyExpr:
     def __and__(self, other: PyExpr) -> PyExpr: ...
     def __or__(self, other: PyExpr) -> PyExpr: ...
     def __xor__(self, other: PyExpr) -> PyExpr: ...
+    def bitwise_and(self, other: PyExpr) -> PyExpr: ...
+    def bitwise_or(self, other: PyExpr) -> PyExpr: ...
+    def bitwise_xor(self, other: PyExpr) -> PyExpr: ...
+    def __lshift__(self, other: PyExpr) -> PyExpr: ...
+    def __rshift__(self, other: PyExpr) -> PyExpr: ...
     def __invert__(self) -> PyExpr: ...
     def __lt__(self, other: PyExpr) -> PyExpr: ...
     def __le__(self, other: PyExpr) -> PyExpr: ...
@@ -899,31 +1039,47 @@ class PyExpr:
     def __ge__(self, other: PyExpr) -> PyExpr: ...
     def __eq__(self, other: PyExpr) -> PyExpr: ...  # type: ignore[override]
     def __ne__(self, other: PyExpr) -> PyExpr: ...  # type: ignore[override]
+    def eq_null_safe(self, other: PyExpr) -> PyExpr: ...
     def is_null(self) -> PyExpr: ...
     def not_null(self) -> PyExpr: ...
     def is_in(self, other: PyExpr) -> PyExpr: ...
+    def between(self, lower: PyExpr, upper: PyExpr, closed: ClosedInterval) -> PyExpr: ...
     def name(self) -> str: ...
     def to_field(self, schema: PySchema) -> PyField: ...
     def __repr__(self) -> str: ...
     def __hash__(self) -> int: ...
     def __reduce__(self) -> tuple: ...
     def is_nan(self) -> PyExpr: ...
+    def is_inf(self) -> PyExpr: ...
+    def not_nan(self) -> PyExpr: ...
+    def fill_nan(self, fill_value: PyExpr) -> PyExpr: ...
+    def hash(self, seed: int | None = None) -> PyExpr: ...
+    def fill_null(self, fill_value: PyExpr) -> PyExpr: ...
     def dt_date(self) -> PyExpr: ...
     def dt_day(self) -> PyExpr: ...
     def dt_hour(self) -> PyExpr: ...
+    def dt_minute(self) -> PyExpr: ...
     def dt_month(self) -> PyExpr: ...
     def dt_year(self) -> PyExpr: ...
     def dt_day_of_week(self) -> PyExpr: ...
+    def dt_tz_localize(self, tz: str, ambiguous: DstResolution, nonexistent: DstResolution) -> PyExpr: ...
+    def dt_tz_convert(self, tz: str) -> PyExpr: ...
     def utf8_endswith(self, pattern: PyExpr) -> PyExpr: ...
     def utf8_startswith(self, pattern: PyExpr) -> PyExpr: ...
     def utf8_contains(self, pattern: PyExpr) -> PyExpr: ...
-    def utf8_split(self, pattern: PyExpr) -> PyExpr: ...
+    def utf8_split(self, pattern: PyExpr, regex: bool) -> PyExpr: ...
+    def utf8_match(self, pattern: PyExpr) -> PyExpr: ...
+    def utf8_extract(self, pattern: PyExpr, index: int) -> PyExpr: ...
+    def utf8_replace(self, pattern: PyExpr, replacement: PyExpr) -> PyExpr: ...
     def utf8_length(self) -> PyExpr: ...
+    def utf8_length_chars(self) -> PyExpr: ...
     def utf8_lower(self) -> PyExpr: ...
     def utf8_upper(self) -> PyExpr: ...
+    def utf8_capitalize(self) -> PyExpr: ...
     def utf8_lstrip(self) -> PyExpr: ...
     def utf8_rstrip(self) -> PyExpr: ...
     def utf8_reverse(self) -> PyExpr: ...
+    def utf8_substr(self, start: int, length: int | None) -> PyExpr: ...
     def image_decode(self) -> PyExpr: ...
     def image_encode(self, image_format: ImageFormat) -> PyExpr: ...
     def image_resize(self, w: int, h: int) -> PyExpr: ...
@@ -931,7 +1087,13 @@ class PyExpr:
     def list_join(self, delimiter: PyExpr) -> PyExpr: ...
     def list_lengths(self) -> PyExpr: ...
     def list_get(self, idx: PyExpr, default: PyExpr) -> PyExpr: ...
+    def list_slice(self, start: PyExpr, end: PyExpr) -> PyExpr: ...
+    def list_contains(self, item: PyExpr) -> PyExpr: ...
     def struct_get(self, name: str) -> PyExpr: ...
+    def map_get(self, key: PyExpr) -> PyExpr: ...
+    def embedding_dot(self, other: PyExpr) -> PyExpr: ...
+    def embedding_cosine_similarity(self, other: PyExpr) -> PyExpr: ...
+    def json_query(self, query: str) -> PyExpr: ...
     def url_download(
         self, max_connections: int, raise_error_on_failure: bool, multi_thread: bool, config: IOConfig
     ) -> PyExpr: ...
@@ -943,11 +1105,21 @@ class PyExpr:
     def partitioning_iceberg_truncate(self, w: int) -> PyExpr: ...
 
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
+def coalesce(exprs: list[PyExpr]) -> PyExpr: ...
+def row_number() -> PyExpr: ...
+def rank() -> PyExpr: ...
+def dense_rank() -> PyExpr: ...
+def rand(seed: int | None) -> PyExpr: ...
+def uuid4() -> PyExpr: ...
+def monotonically_increasing_id() -> PyExpr: ...
 def col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
 def date_lit(item: int) -> PyExpr: ...
 def time_lit(item: int, tu: PyTimeUnit) -> PyExpr: ...
+def duration_lit(item: int, tu: PyTimeUnit) -> PyExpr: ...
+def interval_lit(months: int, days: int, nanoseconds: int) -> PyExpr: ...
 def timestamp_lit(item: int, tu: PyTimeUnit, tz: str | None) -> PyExpr: ...
+def decimal_lit(val: int, precision: int, scale: int) -> PyExpr: ...
 def series_lit(item: PySeries) -> PyExpr: ...
 def udf(func: Callable, expressions: list[PyExpr], return_dtype: PyDataType) -> PyExpr: ...
 
@@ -973,11 +1145,12 @@ class PySeries:
     def __ge__(self, other: PySeries) -> PySeries: ...
     def __eq__(self, other: PySeries) -> PySeries: ...  # type: ignore[override]
     def __ne__(self, other: PySeries) -> PySeries: ...  # type: ignore[override]
+    def eq_null_safe(self, other: PySeries) -> PySeries: ...
     def take(self, idx: PySeries) -> PySeries: ...
     def slice(self, start: int, end: int) -> PySeries: ...
     def filter(self, mask: PySeries) -> PySeries: ...
-    def sort(self, descending: bool) -> PySeries: ...
-    def argsort(self, descending: bool) -> PySeries: ...
+    def sort(self, descending: bool, nulls_first: bool) -> PySeries: ...
+    def argsort(self, descending: bool, nulls_first: bool) -> PySeries: ...
     def hash(self, seed: PySeries | None = None) -> PySeries: ...
     def __invert__(self) -> PySeries: ...
     def _count(self, mode: CountMode) -> PySeries: ...
@@ -987,32 +1160,55 @@ class PySeries:
     def _max(self) -> PySeries: ...
     def _agg_list(self) -> PySeries: ...
     def cast(self, dtype: PyDataType) -> PySeries: ...
+    def strict_cast(self, dtype: PyDataType) -> PySeries: ...
     def ceil(self) -> PySeries: ...
     def floor(self) -> PySeries: ...
+    def round(self, decimals: int, mode: RoundMode) -> PySeries: ...
+    def sqrt(self) -> PySeries: ...
+    def exp(self) -> PySeries: ...
+    def ln(self) -> PySeries: ...
+    def sin(self) -> PySeries: ...
+    def cos(self) -> PySeries: ...
+    def tan(self) -> PySeries: ...
+    def pow(self, other: PySeries) -> PySeries: ...
+    def log(self, other: PySeries) -> PySeries: ...
+    def atan2(self, other: PySeries) -> PySeries: ...
+    def sign(self) -> PySeries: ...
+    def clip(self, lower: PySeries, upper: PySeries) -> PySeries: ...
     @staticmethod
     def concat(series: list[PySeries]) -> PySeries: ...
     def __len__(self) -> int: ...
     def size_bytes(self) -> int: ...
+    def null_count(self) -> int: ...
     def name(self) -> str: ...
     def rename(self, name: str) -> PySeries: ...
     def data_type(self) -> PyDataType: ...
     def utf8_endswith(self, pattern: PySeries) -> PySeries: ...
     def utf8_startswith(self, pattern: PySeries) -> PySeries: ...
     def utf8_contains(self, pattern: PySeries) -> PySeries: ...
-    def utf8_split(self, pattern: PySeries) -> PySeries: ...
+    def utf8_split(self, pattern: PySeries, regex: bool) -> PySeries: ...
     def utf8_length(self) -> PySeries: ...
+    def utf8_length_chars(self) -> PySeries: ...
     def utf8_lower(self) -> PySeries: ...
     def utf8_upper(self) -> PySeries: ...
+    def utf8_capitalize(self) -> PySeries: ...
     def utf8_lstrip(self) -> PySeries: ...
     def utf8_rstrip(self) -> PySeries: ...
     def utf8_reverse(self) -> PySeries: ...
+    def utf8_substr(self, start: int, length: int | None) -> PySeries: ...
     def is_nan(self) -> PySeries: ...
+    def is_inf(self) -> PySeries: ...
+    def not_nan(self) -> PySeries: ...
+    def fill_nan(self, fill_value: PySeries) -> PySeries: ...
     def dt_date(self) -> PySeries: ...
     def dt_day(self) -> PySeries: ...
     def dt_hour(self) -> PySeries: ...
+    def dt_minute(self) -> PySeries: ...
     def dt_month(self) -> PySeries: ...
     def dt_year(self) -> PySeries: ...
     def dt_day_of_week(self) -> PySeries: ...
+    def dt_tz_localize(self, tz: str, ambiguous: DstResolution, nonexistent: DstResolution) -> PySeries: ...
+    def dt_tz_convert(self, tz: str) -> PySeries: ...
     def partitioning_days(self) -> PySeries: ...
     def partitioning_hours(self) -> PySeries: ...
     def partitioning_months(self) -> PySeries: ...
@@ -1021,12 +1217,17 @@ class PySeries:
     def partitioning_iceberg_truncate(self, w: int) -> PySeries: ...
     def list_lengths(self) -> PySeries: ...
     def list_get(self, idx: PySeries, default: PySeries) -> PySeries: ...
+    def list_slice(self, start: PySeries, end: PySeries) -> PySeries: ...
+    def list_contains(self, item: PySeries) -> PySeries: ...
     def image_decode(self) -> PySeries: ...
     def image_encode(self, image_format: ImageFormat) -> PySeries: ...
     def image_resize(self, w: int, h: int) -> PySeries: ...
     def if_else(self, other: PySeries, predicate: PySeries) -> PySeries: ...
     def is_null(self) -> PySeries: ...
     def not_null(self) -> PySeries: ...
+    def unique(self) -> PySeries: ...
+    def fill_null(self, fill_value: PySeries) -> PySeries: ...
+    def fill_null_with_strategy(self, strategy: FillNullStrategy) -> PySeries: ...
     def murmur3_32(self) -> PySeries: ...
     def to_str_values(self) -> PySeries: ...
     def _debug_bincode_serialize(self) -> bytes: ...
@@ -1067,6 +1268,8 @@ class PyTable:
     def get_column_by_index(self, idx: int) -> PySeries: ...
     @staticmethod
     def concat(tables: list[PyTable]) -> PyTable: ...
+    @staticmethod
+    def value_counts(series: PySeries) -> PyTable: ...
     def slice(self, start: int, end: int) -> PyTable: ...
     @staticmethod
     def from_arrow_record_batches(record_batches: list[pyarrow.RecordBatch], schema: PySchema) -> PyTable: ...
@@ -942,15 +942,22 @@ class DataFrame:
         return DataFrame(builder)
 
     def _agg(
-        self, to_agg: List[Tuple[ColumnInputType, str]], group_by: Optional[ExpressionsProjection] = None
+        self,
+        to_agg: List[Union[Tuple[ColumnInputType, str], Tuple[ColumnInputType, str, Expression]]],
+        group_by: Optional[ExpressionsProjection] = None,
     ) -> "DataFrame":
-        exprs_to_agg: List[Tuple[Expression, str]] = list(
-            zip(self.__column_input_to_expression([c for c, _ in to_agg]), [op for _, op in to_agg])
-        )
+        exprs_to_agg: List[Tuple[Expression, str, Optional[Expression]]] = [
+            (expr, agg[1], agg[2] if len(agg) > 2 else None)
+            for expr, agg in zip(self.__column_input_to_expression([agg[0] for agg in to_agg]), to_agg)
+        ]
 
         builder = self._builder.agg(exprs_to_agg, list(group_by) if group_by is not None else None)
         return DataFrame(builder)
 
+    def _agg_exprs(self, exprs: List[Expression], group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
+        builder = self._builder.agg_exprs(exprs, list(group_by) if group_by is not None else None)
+        return DataFrame(builder)
+
     def _map_groups(self, udf: Expression, group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
         builder = self._builder.map_groups(udf, list(group_by) if group_by is not None else None)
         return DataFrame(builder)
@@ -1053,12 +1060,14 @@ class DataFrame:
 
     @DataframePublicAPI
     def agg_concat(self, *cols: ColumnInputType) -> "DataFrame":
-        """Performs a global list concatenation agg on the DataFrame
+        """Performs a global concatenation agg on the DataFrame
+
+        Lists are concatenated into a single list and strings into a single string, skipping nulls.
 
         Args:
-            *cols (Union[str, Expression]): columns that are lists to concatenate
+            *cols (Union[str, Expression]): columns of lists or strings to concatenate
         Returns:
-            DataFrame: Globally aggregated list. Should be a single row.
+            DataFrame: Globally aggregated lists or strings. Should be a single row.
         """
         if len(cols) == 0:
             warnings.warn(
@@ -1068,7 +1077,29 @@ class DataFrame:
         return self._agg([(c, "concat") for c in cols])
 
     @DataframePublicAPI
-    def agg(self, to_agg: List[Tuple[ColumnInputType, str]]) -> "DataFrame":
+    def agg_str_join(self, separator: str, *cols: ColumnInputType) -> "DataFrame":
+        """Performs a global string join agg on the DataFrame
+
+        Strings are joined into a single string with ``separator`` between them, skipping nulls.
+        See :meth:`Expression.str_join`.
+
+        Args:
+            separator (str): string to put between values
+            *cols (Union[str, Expression]): columns of strings to join
+        Returns:
+            DataFrame: Globally aggregated strings. Should be a single row.
+        """
+        if len(cols) == 0:
+            warnings.warn(
+                "No columns specified; performing agg_str_join on all columns. Specify columns using df.agg_str_join(separator, 'col1', 'col2', ...)."
+            )
+            cols = tuple(self.columns)
+        return self._agg_exprs([expr.str_join(separator) for expr in self.__column_input_to_expression(cols)])
+
+    @DataframePublicAPI
+    def agg(
+        self, to_agg: List[Union[Tuple[ColumnInputType, str], Tuple[ColumnInputType, str, Expression]]]
+    ) -> "DataFrame":
         """Perform aggregations on this DataFrame. Allows for mixed aggregations for multiple columns
         Will return a single row that aggregated the entire DataFrame.
 
@@ -1079,10 +1110,12 @@ class DataFrame:
             >>>     ('y', 'min'),
             >>>     ('y', 'max'),
             >>>     (col('x') + col('y'), 'max'),
+            >>>     (col('x').alias('x_if_y_positive'), 'sum', col('y') > 0),
             >>> ])
 
         Args:
-            to_agg (List[Tuple[ColumnInputType, str]]): list of (column, agg_type)
+            to_agg (List[Tuple[ColumnInputType, str]]): list of (column, agg_type), or of (column, agg_type, predicate)
+                to only aggregate the rows for which the predicate is true (see :meth:`Expression.filter`)
 
         Returns:
             DataFrame: DataFrame with aggregated results
@@ -1558,6 +1591,35 @@ class GroupedDataFrame:
         """
         return self.df._agg([(c, "any_value") for c in cols], group_by=self.group_by)
 
+    def first(self, *cols: ColumnInputType) -> "DataFrame":
+        """Returns the first value of each group on this GroupedDataFrame, which may be null.
+
+        Rows are taken in the order of the DataFrame, so sort it before grouping to pick a specific row.
+
+        Example:
+            >>> df.sort("timestamp").groupby("user").first("event")
+
+        Args:
+            *cols (Union[str, Expression]): columns to get
+
+        Returns:
+            DataFrame: DataFrame with the first values.
+        """
+        return self.df._agg([(c, "first") for c in cols], group_by=self.group_by)
+
+    def last(self, *cols: ColumnInputType) -> "DataFrame":
+        """Returns the last value of each group on this GroupedDataFrame, which may be null.
+
+        Rows are taken in the order of the DataFrame, so sort it before grouping to pick a specific row.
+
+        Args:
+            *cols (Union[str, Expression]): columns to get
+
+        Returns:
+            DataFrame: DataFrame with the last values.
+        """
+        return self.df._agg([(c, "last") for c in cols], group_by=self.group_by)
+
     def count(self) -> "DataFrame":
         """Performs grouped count on this GroupedDataFrame.
 
@@ -1583,15 +1645,37 @@ class GroupedDataFrame:
     def agg_concat(self) -> "DataFrame":
         """Performs grouped concat on this GroupedDataFrame.
 
+        Lists are concatenated into a single list and strings into a single string, skipping nulls.
+
         Returns:
-            DataFrame: DataFrame with grouped concatenated list per column.
+            DataFrame: DataFrame with grouped concatenated list or string per column.
         """
         groupby_name_set = self.group_by.to_name_set()
         return self.df._agg(
             [(c, "concat") for c in self.df.column_names if c not in groupby_name_set], group_by=self.group_by
         )
 
-    def agg(self, to_agg: List[Tuple[ColumnInputType, str]]) -> "DataFrame":
+    def agg_str_join(self, separator: str) -> "DataFrame":
+        """Performs grouped string join on this GroupedDataFrame.
+
+        Strings are joined into a single string with ``separator`` between them, skipping nulls.
+        See :meth:`Expression.str_join`.
+
+        Args:
+            separator (str): string to put between values
+
+        Returns:
+            DataFrame: DataFrame with grouped joined string per column.
+        """
+        groupby_name_set = self.group_by.to_name_set()
+        return self.df._agg_exprs(
+            [col(c).str_join(separator) for c in self.df.column_names if c not in groupby_name_set],
+            group_by=self.group_by,
+        )
+
+    def agg(
+        self, to_agg: List[Union[Tuple[ColumnInputType, str], Tuple[ColumnInputType, str, Expression]]]
+    ) -> "DataFrame":
         """Perform aggregations on this GroupedDataFrame. Allows for mixed aggregations.
 
         Example:
@@ -1600,10 +1684,12 @@ class GroupedDataFrame:
             >>>     ('x', 'mean'),
             >>>     ('y', 'min'),
             >>>     ('y', 'max'),
+            >>>     (col('y').alias('num_positive_y'), 'count', col('y') > 0),
             >>> ])
 
         Args:
-            to_agg (List[Tuple[ColumnInputType, str]]): list of (column, agg_type)
+            to_agg (List[Tuple[ColumnInputType, str]]): list of (column, agg_type), or of (column, agg_type, predicate)
+                to only aggregate the rows for which the predicate is true (see :meth:`Expression.filter`)
 
         Returns:
             DataFrame: DataFrame with grouped aggregations
@@ -168,6 +168,13 @@ class DataType:
         """Create a String DataType: A string of UTF8 characters"""
         return cls._from_pydatatype(PyDataType.string())
 
+    @classmethod
+    def categorical(cls) -> DataType:
+        """Create a Categorical DataType: A string of UTF8 characters that is dictionary-encoded, which is
+        more compact and faster to group, compare and join for columns with few distinct values
+        """
+        return cls._from_pydatatype(PyDataType.categorical())
+
     @classmethod
     def bool(cls) -> DataType:
         """Create the Boolean DataType: Either ``True`` or ``False``"""
@@ -214,6 +221,15 @@ class DataType:
             timeunit = TimeUnit.from_str(timeunit)
         return cls._from_pydatatype(PyDataType.duration(timeunit._timeunit))
 
+    @classmethod
+    def interval(cls) -> DataType:
+        """Interval DataType: a calendar-aware offset of months, days and nanoseconds.
+
+        Unlike durations, adding an interval of 1 month to a date or timestamp moves it to the same
+        day of the next month (clamped to the end of the month), whatever the length of the month.
+        """
+        return cls._from_pydatatype(PyDataType.interval())
+
     @classmethod
     def list(cls, dtype: DataType) -> DataType:
         """Create a List DataType: Variable-length list, where each element in the list has type ``dtype``
@@ -245,6 +261,16 @@ class DataType:
         """
         return cls._from_pydatatype(PyDataType.struct({name: datatype._dtype for name, datatype in fields.items()}))
 
+    @classmethod
+    def map(cls, key_type: DataType, value_type: DataType) -> DataType:
+        """Create a Map DataType: a nested type which maps keys of type ``key_type`` to values of type ``value_type``
+
+        Args:
+            key_type: DataType of the keys of the map
+            value_type: DataType of the values of the map
+        """
+        return cls._from_pydatatype(PyDataType.map(key_type._dtype, value_type._dtype))
+
     @classmethod
     def extension(cls, name: str, storage_dtype: DataType, metadata: str | None = None) -> DataType:
         return cls._from_pydatatype(PyDataType.extension(name, storage_dtype._dtype, metadata))
@@ -346,7 +372,8 @@ class DataType:
             return cls.uint32()
         elif pa.types.is_uint64(arrow_type):
             return cls.uint64()
-        elif pa.types.is_float32(arrow_type):
+        elif pa.types.is_float16(arrow_type) or pa.types.is_float32(arrow_type):
+            # Half-precision floats are widened to single-precision floats.
             return cls.float32()
         elif pa.types.is_float64(arrow_type):
             return cls.float64()
@@ -366,6 +393,11 @@ class DataType:
             return cls.decimal128(arrow_type.precision, arrow_type.scale)
         elif pa.types.is_date32(arrow_type):
             return cls.date()
+        elif pa.types.is_date64(arrow_type):
+            return cls.timestamp(TimeUnit.ms())
+        elif pa.types.is_time32(arrow_type):
+            # 32-bit times are converted to 64-bit times in microseconds.
+            return cls.time(TimeUnit.us())
         elif pa.types.is_time64(arrow_type):
             timeunit = TimeUnit.from_str(pa.type_for_alias(str(arrow_type)).unit)
             return cls.time(timeunit)
@@ -375,6 +407,17 @@ class DataType:
         elif pa.types.is_duration(arrow_type):
             timeunit = TimeUnit.from_str(arrow_type.unit)
             return cls.duration(timeunit=timeunit)
+        elif pa.types.is_interval(arrow_type):
+            return cls.interval()
+        elif pa.types.is_dictionary(arrow_type):
+            assert isinstance(arrow_type, pa.DictionaryType)
+            if pa.types.is_string(arrow_type.value_type) or pa.types.is_large_string(arrow_type.value_type):
+                return cls.categorical()
+            # Dictionaries of other types are decoded.
+            return cls.from_arrow_type(arrow_type.value_type)
+        elif pa.types.is_map(arrow_type):
+            assert isinstance(arrow_type, pa.MapType)
+            return cls.map(cls.from_arrow_type(arrow_type.key_type), cls.from_arrow_type(arrow_type.item_type))
         elif pa.types.is_list(arrow_type) or pa.types.is_large_list(arrow_type):
             assert isinstance(arrow_type, (pa.ListType, pa.LargeListType))
             field = arrow_type.value_field
@@ -452,6 +495,9 @@ class DataType:
 
         return self == DataType.python()
 
+    def _is_embedding_type(self) -> builtins.bool:
+        return self._dtype.is_embedding()
+
     def _is_tensor_type(self) -> builtins.bool:
         return self._dtype.is_tensor()
 
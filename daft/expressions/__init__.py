@@ -1,5 +1,34 @@
 from __future__ import annotations
 
-from .expressions import Expression, ExpressionsProjection, col, lit
+from .expressions import (
+    Expression,
+    ExpressionsProjection,
+    coalesce,
+    col,
+    dense_rank,
+    interval,
+    lit,
+    monotonically_increasing_id,
+    rand,
+    rank,
+    row_number,
+    uuid4,
+)
+from .window import Window, WindowSpec
 
-__all__ = ["Expression", "ExpressionsProjection", "col", "lit"]
+__all__ = [
+    "Expression",
+    "ExpressionsProjection",
+    "Window",
+    "WindowSpec",
+    "coalesce",
+    "col",
+    "dense_rank",
+    "interval",
+    "lit",
+    "monotonically_increasing_id",
+    "rand",
+    "rank",
+    "row_number",
+    "uuid4",
+]
@@ -1,23 +1,34 @@
 from __future__ import annotations
 
 import builtins
+import decimal
 import os
 import sys
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta
 from typing import TYPE_CHECKING, Any, Callable, Iterable, Iterator, TypeVar, overload
 
 import pyarrow as pa
 
 from daft import context
-from daft.daft import CountMode, ImageFormat
+from daft.daft import ClosedInterval, CountMode, DstResolution, ImageFormat, RoundMode
 from daft.daft import PyExpr as _PyExpr
+from daft.daft import coalesce as _coalesce
 from daft.daft import col as _col
 from daft.daft import date_lit as _date_lit
+from daft.daft import decimal_lit as _decimal_lit
+from daft.daft import dense_rank as _dense_rank
+from daft.daft import duration_lit as _duration_lit
+from daft.daft import interval_lit as _interval_lit
 from daft.daft import lit as _lit
+from daft.daft import monotonically_increasing_id as _monotonically_increasing_id
+from daft.daft import rand as _rand
+from daft.daft import rank as _rank
+from daft.daft import row_number as _row_number
 from daft.daft import series_lit as _series_lit
 from daft.daft import time_lit as _time_lit
 from daft.daft import timestamp_lit as _timestamp_lit
 from daft.daft import udf as _udf
+from daft.daft import uuid4 as _uuid4
 from daft.datatype import DataType, TimeUnit
 from daft.expressions.testing import expr_structurally_equal
 from daft.logical.schema import Field, Schema
@@ -29,6 +40,7 @@ else:
     from typing import Literal
 
 if TYPE_CHECKING:
+    from daft.expressions.window import WindowSpec
     from daft.io import IOConfig
 
 
@@ -87,6 +99,21 @@ def lit(value: object) -> Expression:
         i64_value = pa_time.cast(pa.int64()).as_py()
         time_unit = TimeUnit.from_str(pa.type_for_alias(str(pa_time.type)).unit)._timeunit
         lit_value = _time_lit(i64_value, time_unit)
+    elif isinstance(value, timedelta):
+        # pyo3 timedelta (PyDelta) is not available when running in abi3 mode, workaround
+        pa_duration = pa.scalar(value)
+        i64_value = pa_duration.cast(pa.int64()).as_py()
+        time_unit = TimeUnit.from_str(pa_duration.type.unit)._timeunit
+        lit_value = _duration_lit(i64_value, time_unit)
+    elif isinstance(value, decimal.Decimal):
+        sign, digits, exponent = value.as_tuple()
+        assert isinstance(exponent, int)
+        unscaled = int("".join(str(d) for d in digits)) * (-1 if sign else 1)
+        scale = max(-exponent, 0)
+        if exponent > 0:
+            unscaled *= 10**exponent
+        precision = max(len(digits) + max(exponent, 0), scale)
+        lit_value = _decimal_lit(unscaled, precision, scale)
     elif isinstance(value, Series):
         lit_value = _series_lit(value._series)
     else:
@@ -94,6 +121,42 @@ def lit(value: object) -> Expression:
     return Expression._from_pyexpr(lit_value)
 
 
+def interval(
+    years: int = 0,
+    months: int = 0,
+    weeks: int = 0,
+    days: int = 0,
+    hours: int = 0,
+    minutes: int = 0,
+    seconds: int = 0,
+    milliseconds: int = 0,
+    microseconds: int = 0,
+    nanoseconds: int = 0,
+) -> Expression:
+    """Creates an Expression representing a calendar-aware interval, which can be added to or subtracted
+    from dates and timestamps
+
+    Years and months are applied first, clamping to the end of the month (e.g. January 31st + 1 month is the
+    last day of February), and then weeks and days. These are applied to the local date and time of timestamps
+    with a timezone, while the remaining units are added as an exact amount of time.
+
+    Example:
+        >>> col("date") + interval(months=1, days=2)
+
+    Returns:
+        Expression: Expression representing the interval
+    """
+    total_months = years * 12 + months
+    total_days = weeks * 7 + days
+    total_nanoseconds = (
+        ((hours * 60 + minutes) * 60 + seconds) * 1_000_000_000
+        + milliseconds * 1_000_000
+        + microseconds * 1_000
+        + nanoseconds
+    )
+    return Expression._from_pyexpr(_interval_lit(total_months, total_days, total_nanoseconds))
+
+
 def col(name: str) -> Expression:
     """Creates an Expression referring to the column with the provided name
 
@@ -109,6 +172,110 @@ def col(name: str) -> Expression:
     return Expression._from_pyexpr(_col(name))
 
 
+def coalesce(*exprs: object) -> Expression:
+    """Creates an Expression that picks the first non-null value of the provided expressions for each row
+
+    Example:
+        >>> # x: [1, None, None], y: [None, 2, None] -> [1, 2, 0]
+        >>> coalesce(col("x"), col("y"), 0)
+
+    Args:
+        exprs: Expressions or literals to pick from, in order of priority
+
+    Returns:
+        Expression: Expression whose type is the supertype of all the provided expressions
+    """
+    return Expression._from_pyexpr(_coalesce([Expression._to_expression(e)._expr for e in exprs]))
+
+
+def row_number() -> Expression:
+    """Creates an Expression that numbers the rows of each window partition in order, starting from 1
+
+    Must be evaluated over a window with :meth:`Expression.over`. Rows with equal ordering values are numbered in
+    an arbitrary order.
+
+    Example:
+        >>> # k = ["a", "a", "b"], t = [2, 1, 3] -> [2, 1, 1]
+        >>> row_number().over(Window.partition_by("k").order_by("t"))
+
+    Returns:
+        Expression: a UInt64 Expression named ``row_number``
+    """
+    return Expression._from_pyexpr(_row_number())
+
+
+def rank() -> Expression:
+    """Creates an Expression that ranks the rows of each window partition in order, starting from 1
+
+    Must be evaluated over a window with :meth:`Expression.over`. Like SQL's ``RANK``, rows with equal ordering
+    values get the same rank, and the ranks after them are skipped, e.g. ``[1, 1, 3]``.
+
+    Returns:
+        Expression: a UInt64 Expression named ``rank``
+    """
+    return Expression._from_pyexpr(_rank())
+
+
+def dense_rank() -> Expression:
+    """Creates an Expression that ranks the rows of each window partition in order, without gaps
+
+    Must be evaluated over a window with :meth:`Expression.over`. Like SQL's ``DENSE_RANK``, rows with equal
+    ordering values get the same rank, and the next rank follows immediately, e.g. ``[1, 1, 2]``.
+
+    Returns:
+        Expression: a UInt64 Expression named ``dense_rank``
+    """
+    return Expression._from_pyexpr(_dense_rank())
+
+
+def rand(seed: int | None = None) -> Expression:
+    """Creates an Expression that generates a random value for every row, sampled uniformly from ``[0, 1)``
+
+    Example:
+        >>> df.with_column("sample", rand(seed=42) < 0.1)
+
+    Args:
+        seed: Seed of the random number generator. With a seed, the value of each row is derived from the seed and
+            the position of the row in the DataFrame, so the values are the same each time the DataFrame is evaluated,
+            but are not reproducible if the partitioning changes.
+
+    Returns:
+        Expression: a Float64 Expression named ``rand``
+    """
+    if seed is not None and seed < 0:
+        raise ValueError(f"Expected seed to be a non-negative integer, but received {seed}")
+    return Expression._from_pyexpr(_rand(seed))
+
+
+def uuid4() -> Expression:
+    """Creates an Expression that generates a random (version 4) UUID for every row
+
+    Example:
+        >>> df.with_column("id", uuid4())
+
+    Returns:
+        Expression: a String Expression named ``uuid4``, of UUIDs formatted like ``"a7b5c5d2-8f2e-4f1a-9c3e-1d2b3c4d5e6f"``
+    """
+    return Expression._from_pyexpr(_uuid4())
+
+
+def monotonically_increasing_id() -> Expression:
+    """Creates an Expression that generates a unique, monotonically increasing id for every row
+
+    The ids are not consecutive: like :meth:`DataFrame._add_monotonically_increasing_id`, the upper 28 bits hold the
+    partition number and the lower 36 bits hold the row number within the partition. Can only be used when selecting
+    columns, e.g. with :meth:`DataFrame.select` or :meth:`DataFrame.with_column`.
+
+    Example:
+        >>> # a = [1, 2, 3, 4] in 2 partitions -> [0, 1, 68719476736, 68719476737]
+        >>> df.with_column("id", monotonically_increasing_id())
+
+    Returns:
+        Expression: a UInt64 Expression named ``monotonically_increasing_id``
+    """
+    return Expression._from_pyexpr(_monotonically_increasing_id())
+
+
 class Expression:
     _expr: _PyExpr = None  # type: ignore
 
@@ -145,6 +312,21 @@ class Expression:
         """Access methods that work on columns of structs"""
         return ExpressionStructNamespace.from_expression(self)
 
+    @accessor_namespace_property
+    def map(self) -> ExpressionMapNamespace:
+        """Access methods that work on columns of maps"""
+        return ExpressionMapNamespace.from_expression(self)
+
+    @accessor_namespace_property
+    def embedding(self) -> ExpressionEmbeddingNamespace:
+        """Access methods that work on columns of embeddings"""
+        return ExpressionEmbeddingNamespace.from_expression(self)
+
+    @accessor_namespace_property
+    def json(self) -> ExpressionJsonNamespace:
+        """Access methods that work on columns of JSON strings"""
+        return ExpressionJsonNamespace.from_expression(self)
+
     @accessor_namespace_property
     def image(self) -> ExpressionImageNamespace:
         """Access methods that work on columns of images"""
@@ -183,9 +365,132 @@ class Expression:
         return self.abs()
 
     def abs(self) -> Expression:
-        """Absolute of a numeric expression (``expr.abs()``)"""
+        """Absolute of a numeric expression (``expr.abs()``)
+
+        Raises an error for the minimum value of a signed integer type, which has no positive counterpart.
+        """
         return Expression._from_pyexpr(abs(self._expr))
 
+    def sign(self) -> Expression:
+        """The sign of a numeric expression (``expr.sign()``)
+
+        Returns -1 for negative values, 1 for positive values, and the value itself for zeros and NaN.
+        """
+        return Expression._from_pyexpr(self._expr.sign())
+
+    def clip(self, lower: object = None, upper: object = None) -> Expression:
+        """Clips a numeric expression to the given bounds (``expr.clip(lower, upper)``)
+
+        Fractional bounds of integer expressions are rounded inwards, and bounds that don't fit in the expression's
+        type raise an error.
+
+        Example:
+            >>> # [1, 5, 10, None] -> [2, 5, 8, None]
+            >>> col("x").clip(2, 8)
+            >>> # [1, 5, 10, None] -> [1, 5, 8, None]
+            >>> col("x").clip(upper=8)
+
+        Args:
+            lower: lower bound, either a number or a numeric expression; values are left unbounded below if the
+                bound is null
+            upper: upper bound, either a number or a numeric expression; values are left unbounded above if the
+                bound is null
+
+        Returns:
+            Expression: an Expression of the same type, with values lower bounded first and then upper bounded
+        """
+        lower_expr = Expression._to_expression(lower)
+        upper_expr = Expression._to_expression(upper)
+        return Expression._from_pyexpr(self._expr.clip(lower_expr._expr, upper_expr._expr))
+
+    def cumsum(self) -> Expression:
+        """Running sum of a numeric expression (``expr.cumsum()``)
+
+        Cumulative expressions depend on the order of rows: on their own they run over all rows in their current
+        order, while over a window they restart in every window partition and follow the window's ordering.
+
+        Example:
+            >>> # [1, None, 2, 3] -> [1, None, 3, 6]
+            >>> col("x").cumsum()
+            >>> # Running sum per key, in order of timestamp
+            >>> col("x").cumsum().over(Window.partition_by("k").order_by("ts"))
+
+        Returns:
+            Expression: an Expression with the type of ``sum``, where nulls stay null and are skipped over
+        """
+        return Expression._from_pyexpr(self._expr.cumsum())
+
+    def cummax(self) -> Expression:
+        """Running maximum of a numeric expression (``expr.cummax()``), see :meth:`Expression.cumsum`
+
+        Returns:
+            Expression: an Expression of the same type, where nulls stay null and are skipped over
+        """
+        return Expression._from_pyexpr(self._expr.cummax())
+
+    def cummin(self) -> Expression:
+        """Running minimum of a numeric expression (``expr.cummin()``), see :meth:`Expression.cumsum`
+
+        Returns:
+            Expression: an Expression of the same type, where nulls stay null and are skipped over
+        """
+        return Expression._from_pyexpr(self._expr.cummin())
+
+    def cumcount(self) -> Expression:
+        """Running count of the non-null values of an expression (``expr.cumcount()``), see :meth:`Expression.cumsum`
+
+        Returns:
+            Expression: a UInt64 Expression
+        """
+        return Expression._from_pyexpr(self._expr.cumcount())
+
+    def lead(self, n: int = 1, default: object = None) -> Expression:
+        """The value of the row ``n`` rows after each row (``expr.lead(n, default)``)
+
+        Like cumulative expressions, this depends on the order of rows: on its own it runs over all rows in their
+        current order, while over a window it runs over every window partition in the window's ordering.
+
+        Example:
+            >>> # [1, 2, 4] -> [2, 4, None]
+            >>> col("x").lead()
+            >>> # Change to the next value per key, in order of timestamp
+            >>> col("x").lead().over(Window.partition_by("k").order_by("ts")) - col("x")
+
+        Args:
+            n: number of rows to look ahead, defaults to 1
+            default: value for rows without a row ``n`` rows after them, either a literal or an expression that
+                is cast to the type of this expression; null if not provided
+
+        Returns:
+            Expression: an Expression of the same type
+        """
+        if n < 0:
+            raise ValueError(f"Expected n of lead to be non-negative, but received {n}")
+        default_expr = Expression._to_expression(default)
+        return Expression._from_pyexpr(self._expr.lead(n, default_expr._expr))
+
+    def lag(self, n: int = 1, default: object = None) -> Expression:
+        """The value of the row ``n`` rows before each row (``expr.lag(n, default)``), see :meth:`Expression.lead`
+
+        Example:
+            >>> # [1, 2, 4] -> [None, 1, 2]
+            >>> col("x").lag()
+            >>> # [1, 2, 4] -> [1, 1, 2]
+            >>> col("x").lag(default=col("x"))
+
+        Args:
+            n: number of rows to look back, defaults to 1
+            default: value for rows without a row ``n`` rows before them, either a literal or an expression that
+                is cast to the type of this expression; null if not provided
+
+        Returns:
+            Expression: an Expression of the same type
+        """
+        if n < 0:
+            raise ValueError(f"Expected n of lag to be non-negative, but received {n}")
+        default_expr = Expression._to_expression(default)
+        return Expression._from_pyexpr(self._expr.lag(n, default_expr._expr))
+
     def __add__(self, other: object) -> Expression:
         """Adds two numeric expressions or concatenates two string expressions (``e1 + e2``)"""
         expr = Expression._to_expression(other)
@@ -214,7 +519,7 @@ class Expression:
         return Expression._from_pyexpr(expr._expr * self._expr)
 
     def __truediv__(self, other: object) -> Expression:
-        """True divides two numeric expressions (``e1 / e2``)"""
+        """True divides two numeric expressions (``e1 / e2``), where division by zero yields null"""
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr / expr._expr)
 
@@ -223,7 +528,7 @@ class Expression:
         return Expression._from_pyexpr(expr._expr / self._expr)
 
     def __mod__(self, other: Expression) -> Expression:
-        """Takes the mod of two numeric expressions (``e1 % e2``)"""
+        """Takes the mod of two numeric expressions (``e1 % e2``), where modulo by zero yields null"""
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr % expr._expr)
 
@@ -252,6 +557,62 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(expr._expr | self._expr)
 
+    def __xor__(self, other: Expression) -> Expression:
+        """Takes the logical XOR of two boolean expressions (``e1 ^ e2``)"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr ^ expr._expr)
+
+    def __rxor__(self, other: Expression) -> Expression:
+        """Takes the logical reverse XOR of two boolean expressions (``e1 ^ e2``)"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr ^ self._expr)
+
+    def bitwise_and(self, other: Expression) -> Expression:
+        """Takes the bitwise AND of two integer expressions
+
+        Example:
+            >>> # [0b1100, 0b1010] -> [0b1000, 0b1000]
+            >>> col("x").bitwise_and(0b1000)
+        """
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.bitwise_and(expr._expr))
+
+    def bitwise_or(self, other: Expression) -> Expression:
+        """Takes the bitwise OR of two integer expressions"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.bitwise_or(expr._expr))
+
+    def bitwise_xor(self, other: Expression) -> Expression:
+        """Takes the bitwise XOR of two integer expressions"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.bitwise_xor(expr._expr))
+
+    def __lshift__(self, other: Expression) -> Expression:
+        """Shifts the bits of an integer expression left by an integer expression (``e1 << e2``)"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr << expr._expr)
+
+    def __rlshift__(self, other: Expression) -> Expression:
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr << self._expr)
+
+    def __rshift__(self, other: Expression) -> Expression:
+        """Shifts the bits of an integer expression right by an integer expression, sign-extending signed integers (``e1 >> e2``)"""
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr >> expr._expr)
+
+    def __rrshift__(self, other: Expression) -> Expression:
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(expr._expr >> self._expr)
+
+    def __pow__(self, other: object) -> Expression:
+        """Raises a numeric expression to the power of another, as a float (``e1 ** e2``)"""
+        return self.pow(other)
+
+    def __rpow__(self, other: object) -> Expression:
+        expr = Expression._to_expression(other)
+        return expr.pow(self)
+
     def __lt__(self, other: Expression) -> Expression:
         """Compares if an expression is less than another (``e1 < e2``)"""
         expr = Expression._to_expression(other)
@@ -272,6 +633,21 @@ class Expression:
         expr = Expression._to_expression(other)
         return Expression._from_pyexpr(self._expr != expr._expr)
 
+    def eq_null_safe(self, other: object) -> Expression:
+        """Compares if an expression is equal to another, treating nulls as equal (``e1 <=> e2``)
+
+        Unlike ``==``, two nulls are equal and a null is not equal to any non-null value, so the result is never null.
+
+        Example:
+            >>> # [1, None, None] <=> [1, None, 2] -> [True, True, False]
+            >>> col("x").eq_null_safe(col("y"))
+
+        Returns:
+            Expression: a Boolean Expression without nulls
+        """
+        expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.eq_null_safe(expr._expr))
+
     def __gt__(self, other: Expression) -> Expression:
         """Compares if an expression is greater than another (``e1 > e2``)"""
         expr = Expression._to_expression(other)
@@ -332,6 +708,89 @@ class Expression:
         expr = self._expr.floor()
         return Expression._from_pyexpr(expr)
 
+    def round(self, decimals: int = 0, mode: builtins.str = "half_away_from_zero") -> Expression:
+        """Rounds a numeric expression to the given number of decimal places (``expr.round(decimals)``)
+
+        Example:
+            >>> # [1.25, 2.5, -2.5] -> [1.3, 2.5, -2.5]
+            >>> col("x").round(1)
+            >>> # [1.25, 2.5, -2.5] -> [1.2, 2.5, -2.5]
+            >>> col("x").round(1, mode="half_even")
+            >>> # [1.25, 2.5, -2.5] -> [1.0, 3.0, -3.0]
+            >>> col("x").round()
+
+        Args:
+            decimals: number of decimal places to keep; negative values round to the left of the decimal point,
+                e.g. ``-1`` rounds to the nearest ten
+            mode: how to round values exactly halfway between two candidates, either "half_away_from_zero" or
+                "half_even"
+
+        Returns:
+            Expression: an Expression of the same type, with rounded values
+        """
+        round_mode = RoundMode.from_round_mode_str(mode)
+        expr = self._expr.round(decimals, round_mode)
+        return Expression._from_pyexpr(expr)
+
+    def sqrt(self) -> Expression:
+        """The square root of a numeric expression (``expr.sqrt()``)"""
+        expr = self._expr.sqrt()
+        return Expression._from_pyexpr(expr)
+
+    def exp(self) -> Expression:
+        """e raised to the power of a numeric expression (``expr.exp()``)"""
+        expr = self._expr.exp()
+        return Expression._from_pyexpr(expr)
+
+    def ln(self) -> Expression:
+        """The natural logarithm of a numeric expression (``expr.ln()``)"""
+        expr = self._expr.ln()
+        return Expression._from_pyexpr(expr)
+
+    def log(self, base: object) -> Expression:
+        """The logarithm of a numeric expression in the given base (``expr.log(base)``)
+
+        Example:
+            >>> # [1, 10, 100] -> [0.0, 1.0, 2.0]
+            >>> col("x").log(10)
+
+        Args:
+            base: base of the logarithm, either a number or a numeric expression
+
+        Returns:
+            Expression: a floating point Expression
+        """
+        base_expr = Expression._to_expression(base)
+        expr = self._expr.log(base_expr._expr)
+        return Expression._from_pyexpr(expr)
+
+    def pow(self, exponent: object) -> Expression:
+        """Raises a numeric expression to the power of another, as a float (``expr.pow(exponent)``)"""
+        exponent_expr = Expression._to_expression(exponent)
+        expr = self._expr.pow(exponent_expr._expr)
+        return Expression._from_pyexpr(expr)
+
+    def sin(self) -> Expression:
+        """The sine of a numeric expression in radians (``expr.sin()``)"""
+        expr = self._expr.sin()
+        return Expression._from_pyexpr(expr)
+
+    def cos(self) -> Expression:
+        """The cosine of a numeric expression in radians (``expr.cos()``)"""
+        expr = self._expr.cos()
+        return Expression._from_pyexpr(expr)
+
+    def tan(self) -> Expression:
+        """The tangent of a numeric expression in radians (``expr.tan()``)"""
+        expr = self._expr.tan()
+        return Expression._from_pyexpr(expr)
+
+    def atan2(self, x: object) -> Expression:
+        """The four-quadrant arctangent of this expression divided by ``x``, in radians (``y.atan2(x)``)"""
+        x_expr = Expression._to_expression(x)
+        expr = self._expr.atan2(x_expr._expr)
+        return Expression._from_pyexpr(expr)
+
     def _count(self, mode: CountMode = CountMode.Valid) -> Expression:
         expr = self._expr.count(mode)
         return Expression._from_pyexpr(expr)
@@ -356,6 +815,30 @@ class Expression:
         expr = self._expr.any_value(ignore_nulls)
         return Expression._from_pyexpr(expr)
 
+    def _first(self) -> Expression:
+        expr = self._expr.first()
+        return Expression._from_pyexpr(expr)
+
+    def _last(self) -> Expression:
+        expr = self._expr.last()
+        return Expression._from_pyexpr(expr)
+
+    def _approx_count_distinct(self) -> Expression:
+        expr = self._expr.approx_count_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def _count_distinct(self) -> Expression:
+        expr = self._expr.count_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def _approx_percentile(self, percentile: float) -> Expression:
+        expr = self._expr.approx_percentile(percentile)
+        return Expression._from_pyexpr(expr)
+
+    def _median(self) -> Expression:
+        expr = self._expr.median()
+        return Expression._from_pyexpr(expr)
+
     def _agg_list(self) -> Expression:
         expr = self._expr.agg_list()
         return Expression._from_pyexpr(expr)
@@ -364,10 +847,75 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def str_join(self, separator: str) -> Expression:
+        """Aggregates a string expression by joining its values with ``separator``, skipping nulls
+
+        A group that is all null results in null. Use :meth:`DataFrame.agg_str_join` or
+        :meth:`GroupedDataFrame.agg_str_join` to run this aggregation on a DataFrame.
+
+        Example:
+            >>> # x = ["a", None, "b"] -> "a, b"
+            >>> col("x").str_join(", ")
+
+        Args:
+            separator (str): String to put between values
+
+        Returns:
+            Expression: Utf8 expression with the joined values
+        """
+        expr = self._expr.str_join(separator)
+        return Expression._from_pyexpr(expr)
+
     def _explode(self) -> Expression:
         expr = self._expr.explode()
         return Expression._from_pyexpr(expr)
 
+    def filter(self, predicate: Expression) -> Expression:
+        """Only aggregates the rows for which ``predicate`` is true, like SQL's ``FILTER (WHERE ...)``
+
+        Rows are excluded by masking them out of the aggregation's input as nulls, so this is supported for
+        aggregations that skip nulls, and for counts, which only count the included rows. Filtering ``first``,
+        ``last``, ``list`` and ``any_value`` aggregations (which keep nulls) is not supported.
+
+        Example:
+            >>> # x = [1, 2, 3], y = [-1, 1, 1] -> 5
+            >>> col("x")._sum().filter(col("y") > 0)
+
+        Args:
+            predicate (Expression): Boolean expression of the rows to aggregate
+
+        Returns:
+            Expression: Expression with the same name and type as the aggregation
+        """
+        predicate = Expression._to_expression(predicate)
+        expr = self._expr.agg_filter(predicate._expr)
+        return Expression._from_pyexpr(expr)
+
+    def over(self, window: WindowSpec) -> Expression:
+        """Evaluates the current aggregation or cumulative expression over a window, producing one value per row
+
+        Without an ordering, every row receives the aggregate of its window partition. With an ordering,
+        every row receives the aggregate of the rows in its partition up to and including itself, as well as
+        any following rows that tie with it in the ordering, as in SQL. Cumulative expressions such as
+        :meth:`Expression.cumsum` run over each partition in the window's ordering.
+
+        Example:
+            >>> # k = ["a", "b", "a"], x = [1, 2, 3] -> [4, 2, 4]
+            >>> col("x")._sum().over(Window.partition_by("k"))
+
+        Args:
+            window (WindowSpec): Window to evaluate the aggregation over
+
+        Returns:
+            Expression: Expression with the same name and type as the aggregation
+        """
+        expr = self._expr.over(
+            [e._expr for e in window._partition_by],
+            [e._expr for e in window._order_by],
+            window._descending,
+        )
+        return Expression._from_pyexpr(expr)
+
     def if_else(self, if_true: Expression, if_false: Expression) -> Expression:
         """Conditionally choose values between two expressions using the current boolean expression as a condition
 
@@ -444,6 +992,49 @@ class Expression:
         expr = self._expr.not_null()
         return Expression._from_pyexpr(expr)
 
+    def hash(self, seed: int | None = None) -> Expression:
+        """Hashes the values in the Expression into UInt64 values with XXH64
+
+        Equal values always hash to the same value for a given seed, which makes this useful for
+        bucketing and deterministic sampling.
+
+        Example:
+            >>> # Assign each row to one of 8 buckets
+            >>> col("x").hash() % 8
+
+        Args:
+            seed: Optional seed to hash with
+
+        Returns:
+            Expression: UInt64 Expression of hashes
+        """
+        expr = self._expr.hash(seed)
+        return Expression._from_pyexpr(expr)
+
+    def fill_null(self, fill_value: object = None, strategy: builtins.str | None = None) -> Expression:
+        """Replaces Null values in the Expression with ``fill_value``
+
+        Example:
+            >>> # [1, None, 3] -> [1, 0, 3]
+            >>> col("x").fill_null(0)
+
+        Args:
+            fill_value: Expression or literal to use in place of Null values
+            strategy: not supported on Expressions, since strategies need statistics or ordering across the whole
+                DataFrame rather than a single partition; use ``Series.fill_null(strategy=...)`` instead
+
+        Returns:
+            Expression: Expression with Null values replaced
+        """
+        if strategy is not None:
+            raise ValueError(
+                f"fill_null with strategy {strategy!r} is only supported on Series, not on Expressions, "
+                "since strategies need statistics or ordering across all partitions"
+            )
+        fill_value_expr = Expression._to_expression(fill_value)
+        expr = self._expr.fill_null(fill_value_expr._expr)
+        return Expression._from_pyexpr(expr)
+
     def is_in(self, other: Any) -> Expression:
         """Checks if values in the Expression are in the provided list
 
@@ -462,6 +1053,29 @@ class Expression:
         expr = self._expr.is_in(other._expr)
         return Expression._from_pyexpr(expr)
 
+    def between(self, lower: Any, upper: Any, closed: builtins.str = "both") -> Expression:
+        """Checks if values in the Expression are between ``lower`` and ``upper``
+
+        Example:
+            >>> # [1, 2, 3, 4] -> [False, True, True, False]
+            >>> col("x").between(2, 3)
+            >>> # [1, 2, 3, 4] -> [False, True, False, False]
+            >>> col("x").between(2, 3, closed="left")
+
+        Args:
+            lower: lower bound of the range
+            upper: upper bound of the range
+            closed: which bounds are inclusive, one of "both", "left", "right" or "neither"
+
+        Returns:
+            Expression: Boolean Expression indicating whether values are within the bounds
+        """
+        lower_expr = Expression._to_expression(lower)
+        upper_expr = Expression._to_expression(upper)
+        closed_interval = ClosedInterval.from_closed_interval_str(closed)
+        expr = self._expr.between(lower_expr._expr, upper_expr._expr, closed_interval)
+        return Expression._from_pyexpr(expr)
+
     def name(self) -> builtins.str:
         return self._expr.name()
 
@@ -538,14 +1152,11 @@ class ExpressionUrlNamespace(ExpressionNamespace):
         Returns:
             Expression: a Binary expression which is the bytes contents of the URL, or None if an error occured during download
         """
+        if on_error not in ("raise", "null"):
+            raise ValueError(f"Unimplemented on_error option: {on_error}, expected one of: raise, null")
+
         if use_native_downloader:
-            raise_on_error = False
-            if on_error == "raise":
-                raise_on_error = True
-            elif on_error == "null":
-                raise_on_error = False
-            else:
-                raise NotImplemented(f"Unimplemented on_error option: {on_error}.")
+            raise_on_error = on_error == "raise"
 
             if not (isinstance(max_connections, int) and max_connections > 0):
                 raise ValueError(f"Invalid value for `max_connections`: {max_connections}")
@@ -587,6 +1198,52 @@ class ExpressionFloatNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.is_nan())
 
+    def is_inf(self) -> Expression:
+        """Checks if values are positive or negative infinity
+
+        .. NOTE::
+            Nulls will be propagated! I.e. this operation will return a null for null values.
+
+        Example:
+            >>> # [1., None, inf, -inf, NaN] -> [False, None, True, True, False]
+            >>> col("x").float.is_inf()
+
+        Returns:
+            Expression: Boolean Expression indicating whether values are infinite.
+        """
+        return Expression._from_pyexpr(self._expr.is_inf())
+
+    def not_nan(self) -> Expression:
+        """Checks if values are not NaN (a special float value indicating not-a-number)
+
+        .. NOTE::
+            Nulls will be propagated! I.e. this operation will return a null for null values.
+
+        Example:
+            >>> # [1., None, NaN] -> [True, None, False]
+            >>> col("x").float.not_nan()
+
+        Returns:
+            Expression: Boolean Expression indicating whether values are not NaN.
+        """
+        return Expression._from_pyexpr(self._expr.not_nan())
+
+    def fill_nan(self, fill_value: Expression) -> Expression:
+        """Fills NaN values in the Expression with the provided fill_value
+
+        .. NOTE::
+            Nulls are not NaNs, and are kept as nulls. Use :meth:`Expression.fill_null` to fill them.
+
+        Example:
+            >>> # [1., None, NaN] -> [1., None, 0.]
+            >>> col("x").float.fill_nan(0.0)
+
+        Returns:
+            Expression: Expression with NaN values filled with the provided fill_value
+        """
+        fill_value = Expression._to_expression(fill_value)
+        return Expression._from_pyexpr(self._expr.fill_nan(fill_value._expr))
+
 
 class ExpressionDatetimeNamespace(ExpressionNamespace):
     def date(self) -> Expression:
@@ -622,6 +1279,17 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.dt_hour())
 
+    def minute(self) -> Expression:
+        """Retrieves the minute for a datetime column
+
+        Example:
+            >>> col("x").dt.minute()
+
+        Returns:
+            Expression: a UInt32 expression with just the minute extracted from a datetime column
+        """
+        return Expression._from_pyexpr(self._expr.dt_minute())
+
     def month(self) -> Expression:
         """Retrieves the month for a datetime column
 
@@ -655,6 +1323,55 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.dt_day_of_week())
 
+    def tz_localize(self, tz: str, ambiguous: str = "raise", nonexistent: str = "raise") -> Expression:
+        """Interprets each timestamp of a timestamp column without a timezone as a local time in ``tz``
+
+        Local times around daylight saving time transitions are either ambiguous, because clocks are turned back
+        and the local time occurs twice, or nonexistent, because clocks are turned forward and the local time is
+        skipped. These are resolved according to ``ambiguous`` and ``nonexistent``, which are one of:
+
+        * ``"raise"``: raise an error
+        * ``"null"``: resolve the local time to null
+        * ``"earliest"``: resolve an ambiguous local time to its earlier instant, and a nonexistent local time to the
+          instant of the transition
+        * ``"latest"``: resolve an ambiguous local time to its later instant, and a nonexistent local time to the
+          instant of the transition
+
+        Example:
+            >>> col("x").dt.tz_localize("Europe/Berlin", ambiguous="earliest")
+
+        Args:
+            tz: timezone as a fixed offset like "+08:00", or as a name in the tz database like "Europe/Berlin"
+            ambiguous: how to resolve ambiguous local times, defaults to "raise"
+            nonexistent: how to resolve nonexistent local times, defaults to "raise"
+
+        Returns:
+            Expression: a Timestamp expression with the timezone ``tz``
+        """
+        return Expression._from_pyexpr(
+            self._expr.dt_tz_localize(
+                tz,
+                DstResolution.from_dst_resolution_str(ambiguous),
+                DstResolution.from_dst_resolution_str(nonexistent),
+            )
+        )
+
+    def tz_convert(self, tz: str) -> Expression:
+        """Converts each timestamp of a timestamp column with a timezone to the same instant in ``tz``
+
+        This normalizes timestamps with different timezones, e.g. before joining on them.
+
+        Example:
+            >>> col("x").dt.tz_convert("UTC")
+
+        Args:
+            tz: timezone as a fixed offset like "+08:00", or as a name in the tz database like "Europe/Berlin"
+
+        Returns:
+            Expression: a Timestamp expression with the timezone ``tz``
+        """
+        return Expression._from_pyexpr(self._expr.dt_tz_convert(tz))
+
 
 class ExpressionStringNamespace(ExpressionNamespace):
     def contains(self, substr: str | Expression) -> Expression:
@@ -702,21 +1419,79 @@ class ExpressionStringNamespace(ExpressionNamespace):
         prefix_expr = Expression._to_expression(prefix)
         return Expression._from_pyexpr(self._expr.utf8_startswith(prefix_expr._expr))
 
-    def split(self, pattern: str | Expression) -> Expression:
+    def split(self, pattern: str | Expression, regex: bool = False) -> Expression:
         """Splits each string on the given pattern, into one or more strings.
 
         Example:
             >>> col("x").str.split(",")
             >>> col("x").str.split(col("pattern"))
+            >>> col("x").str.split(r"\\s+", regex=True)
 
         Args:
             pattern: The pattern on which each string should be split, or a column to pick such patterns from.
+            regex: Whether the pattern is a regular expression. Defaults to False.
 
         Returns:
             Expression: A List[Utf8] expression containing the string splits for each string in the column.
         """
         pattern_expr = Expression._to_expression(pattern)
-        return Expression._from_pyexpr(self._expr.utf8_split(pattern_expr._expr))
+        return Expression._from_pyexpr(self._expr.utf8_split(pattern_expr._expr, regex))
+
+    def match(self, pattern: str | Expression) -> Expression:
+        """Checks whether each string contains a match of the given regular expression
+
+        Example:
+            >>> col("x").str.match(r"\\d+")
+
+        Args:
+            pattern: Regular expression to search for, or a column to pick such patterns from.
+
+        Returns:
+            Expression: a Boolean expression indicating whether each value matches the provided pattern
+        """
+        pattern_expr = Expression._to_expression(pattern)
+        return Expression._from_pyexpr(self._expr.utf8_match(pattern_expr._expr))
+
+    def extract(self, pattern: str | Expression, index: int = 0) -> Expression:
+        """Extracts a capture group of the first match of the given regular expression from each string
+
+        Strings without a match produce a null.
+
+        Example:
+            >>> # ["GET /index.html", "POST /login"] -> ["/index.html", "/login"]
+            >>> col("x").str.extract(r"^(\\w+) (\\S+)$", index=2)
+
+        Args:
+            pattern: Regular expression to match, or a column to pick such patterns from.
+            index: Index of the capture group to extract, where 0 is the entire match. Defaults to 0.
+
+        Returns:
+            Expression: a String expression with the extracted values
+        """
+        if index < 0:
+            raise ValueError(f"Capture group index must be non-negative, but received {index}")
+        pattern_expr = Expression._to_expression(pattern)
+        return Expression._from_pyexpr(self._expr.utf8_extract(pattern_expr._expr, index))
+
+    def replace(self, pattern: str | Expression, replacement: str | Expression) -> Expression:
+        """Replaces all matches of the given regular expression in each string
+
+        The replacement may refer to capture groups of the pattern as ``$1`` or ``${name}``.
+
+        Example:
+            >>> # ["a-1", "b-2"] -> ["1:a", "2:b"]
+            >>> col("x").str.replace(r"(\\w)-(\\d)", "$2:$1")
+
+        Args:
+            pattern: Regular expression to replace, or a column to pick such patterns from.
+            replacement: Replacement string, or a column to pick such replacements from.
+
+        Returns:
+            Expression: a String expression with the replaced values
+        """
+        pattern_expr = Expression._to_expression(pattern)
+        replacement_expr = Expression._to_expression(replacement)
+        return Expression._from_pyexpr(self._expr.utf8_replace(pattern_expr._expr, replacement_expr._expr))
 
     def concat(self, other: str) -> Expression:
         """Concatenates two string expressions together
@@ -748,6 +1523,18 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.utf8_length())
 
+    def length_chars(self) -> Expression:
+        """Retrieves the length for a UTF-8 string column, in characters (Unicode code points)
+
+        Example:
+            >>> # ["foo", "héllo"] -> [3, 5]
+            >>> col("x").str.length_chars()
+
+        Returns:
+            Expression: an UInt64 expression with the number of characters of each string
+        """
+        return Expression._from_pyexpr(self._expr.utf8_length_chars())
+
     def lower(self) -> Expression:
         """Convert UTF-8 string to all lowercase
 
@@ -770,6 +1557,18 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.utf8_upper())
 
+    def capitalize(self) -> Expression:
+        """Convert the first character of a UTF-8 string to title case and the rest to lower case
+
+        Example:
+            >>> # ["hello WORLD", "éclair"] -> ["Hello world", "Éclair"]
+            >>> col("x").str.capitalize()
+
+        Returns:
+            Expression: a String expression which is `self` capitalized
+        """
+        return Expression._from_pyexpr(self._expr.utf8_capitalize())
+
     def lstrip(self) -> Expression:
         """Strip whitespace from the left side of a UTF-8 string
 
@@ -803,6 +1602,27 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.utf8_reverse())
 
+    def substr(self, start: int, length: int | None = None) -> Expression:
+        """Takes a substring of each UTF-8 string, in characters
+
+        Example:
+            >>> # ["hello", "daft"] -> ["ell", "aft"]
+            >>> col("x").str.substr(1, 3)
+            >>> # ["hello", "daft"] -> ["lo", "ft"]
+            >>> col("x").str.substr(-2)
+
+        Args:
+            start: Index of the first character of the substring, counted from the end of the string if negative.
+            length: Maximum number of characters in the substring. Defaults to None, which takes every character
+                up to the end of the string.
+
+        Returns:
+            Expression: a String expression with the substrings, which are empty when `start` is past the end
+        """
+        if length is not None and length < 0:
+            raise ValueError(f"Substring length must be non-negative, but received {length}")
+        return Expression._from_pyexpr(self._expr.utf8_substr(start, length))
+
 
 class ExpressionListNamespace(ExpressionNamespace):
     def join(self, delimiter: str | Expression) -> Expression:
@@ -839,6 +1659,38 @@ class ExpressionListNamespace(ExpressionNamespace):
         default_expr = lit(default)
         return Expression._from_pyexpr(self._expr.list_get(idx_expr._expr, default_expr._expr))
 
+    def slice(self, start: int | Expression, end: int | Expression | None = None) -> Expression:
+        """Gets a sublist of each list, from index ``start`` (inclusive) to ``end`` (exclusive)
+
+        Negative indices count from the end of each list, and out of bounds indices are clamped to the list.
+
+        Example:
+            >>> col("x").list.slice(1, -1)
+
+        Args:
+            start: index or indices to start each sublist at
+            end: index or indices to end each sublist at, or None to slice until the end of each list
+
+        Returns:
+            Expression: a List expression with the same element type as the input lists
+        """
+        start_expr = Expression._to_expression(start)
+        end_expr = Expression._to_expression(end)
+        return Expression._from_pyexpr(self._expr.list_slice(start_expr._expr, end_expr._expr))
+
+    def contains(self, item: object) -> Expression:
+        """Checks whether each list contains the specified item
+
+        Args:
+            item: the item or items to look for in each list
+
+        Returns:
+            Expression: a Boolean expression which is True if the list contains the item, and null if the list
+                or item is null
+        """
+        item_expr = Expression._to_expression(item)
+        return Expression._from_pyexpr(self._expr.list_contains(item_expr._expr))
+
 
 class ExpressionStructNamespace(ExpressionNamespace):
     def get(self, name: str) -> Expression:
@@ -853,6 +1705,83 @@ class ExpressionStructNamespace(ExpressionNamespace):
         return Expression._from_pyexpr(self._expr.struct_get(name))
 
 
+class ExpressionMapNamespace(ExpressionNamespace):
+    def get(self, key: Expression) -> Expression:
+        """Retrieves the value of a key in each map. If a key occurs more than once in a map, its last
+        value is returned, and maps without the key produce a null.
+
+        Example:
+            >>> # [{"a": 1, "b": 2}, {"b": 3}] -> [1, None]
+            >>> col("m").map.get("a")
+
+        Args:
+            key: the key to retrieve, either a single key for all maps or an expression with one key per map
+
+        Returns:
+            Expression: an expression with the values of the key
+        """
+        key_expr = Expression._to_expression(key)
+        return Expression._from_pyexpr(self._expr.map_get(key_expr._expr))
+
+
+class ExpressionEmbeddingNamespace(ExpressionNamespace):
+    def dot(self, other: Expression) -> Expression:
+        """Computes the dot product of each embedding with another embedding. The result is null where
+        either embedding is null.
+
+        Example:
+            >>> col("a").embedding.dot(col("b"))
+
+        Args:
+            other: the embeddings to multiply by, either a single embedding for all rows or an expression with
+                one embedding per row, which is cast to the embedding type of this expression
+
+        Returns:
+            Expression: a Float64 expression with the dot products
+        """
+        other_expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.embedding_dot(other_expr._expr))
+
+    def cosine_similarity(self, other: Expression) -> Expression:
+        """Computes the cosine similarity of each embedding with another embedding. The result is null where
+        either embedding is null, and NaN where either embedding is all zeros.
+
+        Example:
+            >>> col("a").embedding.cosine_similarity(col("b"))
+
+        Args:
+            other: the embeddings to compare to, either a single embedding for all rows or an expression with
+                one embedding per row, which is cast to the embedding type of this expression
+
+        Returns:
+            Expression: a Float64 expression with the cosine similarities
+        """
+        other_expr = Expression._to_expression(other)
+        return Expression._from_pyexpr(self._expr.embedding_cosine_similarity(other_expr._expr))
+
+
+class ExpressionJsonNamespace(ExpressionNamespace):
+    def query(self, query: str) -> Expression:
+        """Queries each JSON string with a JSONPath-like selector
+
+        The selector starts at the root ``$``, and selects object fields with ``.key`` or ``["key"]`` and
+        array elements with ``[i]``, where negative indices count from the end of the array. Selected
+        strings are returned as-is, and other selected values are returned as JSON strings. Invalid JSON,
+        missing paths and JSON nulls produce a null.
+
+        Example:
+            >>> # ['{"a": {"b": [1, 2]}}', '{"a": {}}'] -> ["1", None]
+            >>> col("x").json.query("$.a.b[0]")
+
+        Args:
+            query: JSONPath-like selector to evaluate
+
+        Returns:
+            Expression: a String expression with the selected values
+        """
+        return Expression._from_pyexpr(self._expr.json_query(query))
+
+
 class ExpressionsProjection(Iterable[Expression]):
     """A collection of Expressions that can be projected onto a Table to produce another Table
 
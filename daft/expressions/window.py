@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+from daft.expressions.expressions import Expression, col
+
+
+def _to_expressions(columns: tuple[str | Expression, ...]) -> list[Expression]:
+    return [col(c) if isinstance(c, str) else c for c in columns]
+
+
+class WindowSpec:
+    """Describes the rows that an aggregation is evaluated over when used with :meth:`Expression.over`
+
+    Build one with :meth:`Window.partition_by` or :meth:`Window.order_by`.
+    """
+
+    def __init__(
+        self,
+        partition_by: list[Expression],
+        order_by: list[Expression],
+        descending: list[bool],
+    ) -> None:
+        self._partition_by = partition_by
+        self._order_by = order_by
+        self._descending = descending
+
+    def partition_by(self, *columns: str | Expression) -> WindowSpec:
+        """Splits rows into window partitions by the provided columns
+
+        Args:
+            columns: Columns to partition by
+
+        Returns:
+            WindowSpec: New window spec with the provided partition columns
+        """
+        return WindowSpec(_to_expressions(columns), self._order_by, self._descending)
+
+    def order_by(self, *columns: str | Expression, desc: bool | list[bool] = False) -> WindowSpec:
+        """Orders rows within each window partition by the provided columns, so that aggregations
+        are computed over the rows up to and including the current row and its peers, i.e. the rows
+        with the same values in these columns
+
+        Args:
+            columns: Columns to order by
+            desc: Whether to sort in descending order, either for all columns or one flag per column
+
+        Returns:
+            WindowSpec: New window spec with the provided ordering
+        """
+        descending = desc if isinstance(desc, list) else [desc] * len(columns)
+        return WindowSpec(self._partition_by, _to_expressions(columns), descending)
+
+
+class Window:
+    """Entrypoint for building a :class:`WindowSpec`
+
+    Example:
+        >>> # Mean of each partition broadcast to every row
+        >>> col("x")._mean().over(Window.partition_by("k"))
+        >>> # Running sum in order of "t" within each partition
+        >>> col("x")._sum().over(Window.partition_by("k").order_by("t"))
+    """
+
+    @staticmethod
+    def partition_by(*columns: str | Expression) -> WindowSpec:
+        return WindowSpec([], [], []).partition_by(*columns)
+
+    @staticmethod
+    def order_by(*columns: str | Expression, desc: bool | list[bool] = False) -> WindowSpec:
+        return WindowSpec([], [], []).order_by(*columns, desc=desc)
@@ -135,11 +135,11 @@ class LogicalPlanBuilder:
 
     def agg(
         self,
-        to_agg: list[tuple[Expression, str]],
+        to_agg: list[tuple[Expression, str, Expression | None]],
         group_by: list[Expression] | None,
     ) -> LogicalPlanBuilder:
         exprs = []
-        for expr, op in to_agg:
+        for expr, op, predicate in to_agg:
             if op == "sum":
                 exprs.append(expr._sum())
             elif op == "count":
@@ -152,13 +152,28 @@ class LogicalPlanBuilder:
                 exprs.append(expr._mean())
             elif op == "any_value":
                 exprs.append(expr._any_value())
+            elif op == "first":
+                exprs.append(expr._first())
+            elif op == "last":
+                exprs.append(expr._last())
+            elif op == "approx_count_distinct":
+                exprs.append(expr._approx_count_distinct())
+            elif op == "count_distinct":
+                exprs.append(expr._count_distinct())
+            elif op == "median":
+                exprs.append(expr._median())
             elif op == "list":
                 exprs.append(expr._agg_list())
             elif op == "concat":
                 exprs.append(expr._agg_concat())
             else:
                 raise NotImplementedError(f"Aggregation {op} is not implemented.")
+            if predicate is not None:
+                exprs[-1] = exprs[-1].filter(predicate)
 
+        return self.agg_exprs(exprs, group_by)
+
+    def agg_exprs(self, exprs: list[Expression], group_by: list[Expression] | None) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
         builder = self._builder.aggregate([expr._expr for expr in exprs], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
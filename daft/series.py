@@ -1,14 +1,17 @@
 from __future__ import annotations
 
-from typing import Any, TypeVar
+from typing import TYPE_CHECKING, Any, TypeVar
 
 import pyarrow as pa
 
 from daft.arrow_utils import ensure_array, ensure_chunked_array
-from daft.daft import CountMode, ImageFormat, PySeries
+from daft.daft import CountMode, DstResolution, FillNullStrategy, ImageFormat, PySeries, PyTable, RoundMode
 from daft.datatype import DataType
 from daft.utils import pyarrow_supports_fixed_shape_tensor
 
+if TYPE_CHECKING:
+    from daft.table.table import Table
+
 _RAY_DATA_EXTENSIONS_AVAILABLE = True
 try:
     from ray.data.extensions import (
@@ -192,7 +195,22 @@ class Series:
         # a Pandas scalar object will be returned.
         return cls.from_pylist(data.to_list(), name=name, pyobj="force")
 
-    def cast(self, dtype: DataType) -> Series:
+    def cast(self, dtype: DataType, strict: bool = False) -> Series:
+        """Casts the Series to the given datatype.
+
+        Args:
+            dtype (DataType): Datatype to cast to
+            strict (bool): Whether to raise an error when a value cannot be cast, e.g. when a string
+                fails to parse or a number overflows the target type. Otherwise, values that fail to parse
+                become null, and overflowing numbers are cast without checks. Defaults to False.
+
+        Returns:
+            Series: Series with the given datatype
+        """
+        if not isinstance(strict, bool):
+            raise TypeError(f"expected a bool for strict but got {type(strict)}")
+        if strict:
+            return Series._from_pyseries(self._series.strict_cast(dtype._dtype))
         return Series._from_pyseries(self._series.cast(dtype._dtype))
 
     def _cast_to_python(self) -> Series:
@@ -273,6 +291,21 @@ class Series:
         else:
             return self._series.to_arrow()
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert this Embedding Series to a 2-D NumPy array with one row per embedding.
+
+        The array is a zero-copy view of the Series' data, so the Series can't have nulls.
+        """
+        dtype = self.datatype()
+        if not dtype._is_embedding_type():
+            raise ValueError(f"to_numpy is only supported for Embedding Series, but got {dtype}")
+        storage = self._series.to_arrow().storage
+        if storage.null_count > 0:
+            raise ValueError("Cannot convert an Embedding Series with nulls to a NumPy array without copying")
+        values = storage.flatten().to_numpy(zero_copy_only=True)
+        return values.reshape(len(storage), storage.type.list_size)
+
     def to_pylist(self) -> list:
         """
         Convert this Series to a Python list.
@@ -302,17 +335,37 @@ class Series:
 
         return Series._from_pyseries(self._series.slice(start, end))
 
-    def argsort(self, descending: bool = False) -> Series:
+    def argsort(self, descending: bool = False, nulls_first: bool | None = None) -> Series:
+        """Returns the indices that stably sort the Series
+
+        Args:
+            descending: whether to sort in descending order, defaults to False
+            nulls_first: whether to place nulls first, defaults to ``descending``
+        """
         if not isinstance(descending, bool):
             raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
+        if nulls_first is None:
+            nulls_first = descending
+        elif not isinstance(nulls_first, bool):
+            raise TypeError(f"expected `nulls_first` to be bool, got {type(nulls_first)}")
 
-        return Series._from_pyseries(self._series.argsort(descending))
+        return Series._from_pyseries(self._series.argsort(descending, nulls_first))
 
-    def sort(self, descending: bool = False) -> Series:
+    def sort(self, descending: bool = False, nulls_first: bool | None = None) -> Series:
+        """Sorts the Series
+
+        Args:
+            descending: whether to sort in descending order, defaults to False
+            nulls_first: whether to place nulls first, defaults to ``descending``
+        """
         if not isinstance(descending, bool):
             raise TypeError(f"expected `descending` to be bool, got {type(descending)}")
+        if nulls_first is None:
+            nulls_first = descending
+        elif not isinstance(nulls_first, bool):
+            raise TypeError(f"expected `nulls_first` to be bool, got {type(nulls_first)}")
 
-        return Series._from_pyseries(self._series.sort(descending))
+        return Series._from_pyseries(self._series.sort(descending, nulls_first))
 
     def hash(self, seed: Series | None = None) -> Series:
         if not isinstance(seed, Series) and seed is not None:
@@ -346,15 +399,71 @@ class Series:
         """
         return self._series.size_bytes()
 
+    def null_count(self) -> int:
+        """Returns the number of nulls in this Series, which is read from its validity without scanning its values."""
+        return self._series.null_count()
+
     def __abs__(self) -> Series:
         return Series._from_pyseries(abs(self._series))
 
+    def abs(self) -> Series:
+        return Series._from_pyseries(abs(self._series))
+
+    def sign(self) -> Series:
+        return Series._from_pyseries(self._series.sign())
+
+    def clip(self, lower: Series, upper: Series) -> Series:
+        if not isinstance(lower, Series) or not isinstance(upper, Series):
+            raise TypeError(f"expected Series bounds but got {type(lower)} and {type(upper)}")
+        return Series._from_pyseries(self._series.clip(lower._series, upper._series))
+
     def ceil(self) -> Series:
         return Series._from_pyseries(self._series.ceil())
 
     def floor(self) -> Series:
         return Series._from_pyseries(self._series.floor())
 
+    def round(self, decimals: int = 0, mode: str = "half_away_from_zero") -> Series:
+        return Series._from_pyseries(self._series.round(decimals, RoundMode.from_round_mode_str(mode)))
+
+    def sqrt(self) -> Series:
+        return Series._from_pyseries(self._series.sqrt())
+
+    def exp(self) -> Series:
+        return Series._from_pyseries(self._series.exp())
+
+    def ln(self) -> Series:
+        return Series._from_pyseries(self._series.ln())
+
+    def sin(self) -> Series:
+        return Series._from_pyseries(self._series.sin())
+
+    def cos(self) -> Series:
+        return Series._from_pyseries(self._series.cos())
+
+    def tan(self) -> Series:
+        return Series._from_pyseries(self._series.tan())
+
+    def pow(self, other: Series) -> Series:
+        if not isinstance(other, Series):
+            raise TypeError(f"expected another Series but got {type(other)}")
+        return Series._from_pyseries(self._series.pow(other._series))
+
+    def log(self, other: Series) -> Series:
+        if not isinstance(other, Series):
+            raise TypeError(f"expected another Series but got {type(other)}")
+        return Series._from_pyseries(self._series.log(other._series))
+
+    def atan2(self, other: Series) -> Series:
+        if not isinstance(other, Series):
+            raise TypeError(f"expected another Series but got {type(other)}")
+        return Series._from_pyseries(self._series.atan2(other._series))
+
+    def __pow__(self, other: object) -> Series:
+        if not isinstance(other, Series):
+            raise TypeError(f"expected another Series but got {type(other)}")
+        return self.pow(other)
+
     def __add__(self, other: object) -> Series:
         if not isinstance(other, Series):
             raise TypeError(f"expected another Series but got {type(other)}")
@@ -397,6 +506,12 @@ class Series:
         assert self._series is not None and other._series is not None
         return Series._from_pyseries(self._series != other._series)
 
+    def eq_null_safe(self, other: object) -> Series:
+        if not isinstance(other, Series):
+            raise TypeError(f"expected another Series but got {type(other)}")
+        assert self._series is not None and other._series is not None
+        return Series._from_pyseries(self._series.eq_null_safe(other._series))
+
     def __gt__(self, other: object) -> Series:
         if not isinstance(other, Series):
             raise TypeError(f"expected another Series but got {type(other)}")
@@ -480,6 +595,32 @@ class Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.not_null())
 
+    def unique(self) -> Series:
+        """The distinct values of the Series in order of first occurrence, with a single null if it has any nulls"""
+        assert self._series is not None
+        return Series._from_pyseries(self._series.unique())
+
+    def value_counts(self) -> Table:
+        """Counts the occurrences of each distinct value, returning a Table of the values and a ``count`` column
+        sorted by descending count. Nulls are counted as a single value. If the Series is itself named ``count``,
+        the count column is named ``count_count`` instead.
+        """
+        from daft.table.table import Table
+
+        assert self._series is not None
+        return Table._from_pytable(PyTable.value_counts(self._series))
+
+    def fill_null(self, fill_value: object = None, strategy: str | None = None) -> Series:
+        if strategy is not None:
+            if fill_value is not None:
+                raise ValueError("fill_null expects either a fill_value or a strategy, but not both")
+            return Series._from_pyseries(
+                self._series.fill_null_with_strategy(FillNullStrategy.from_fill_null_strategy_str(strategy))
+            )
+        if not isinstance(fill_value, Series):
+            fill_value = Series.from_pylist([fill_value])
+        return Series._from_pyseries(self._series.fill_null(fill_value._series))
+
     def _to_str_values(self) -> Series:
         return Series._from_pyseries(self._series.to_str_values())
 
@@ -557,6 +698,18 @@ class SeriesFloatNamespace(SeriesNamespace):
     def is_nan(self) -> Series:
         return Series._from_pyseries(self._series.is_nan())
 
+    def is_inf(self) -> Series:
+        return Series._from_pyseries(self._series.is_inf())
+
+    def not_nan(self) -> Series:
+        return Series._from_pyseries(self._series.not_nan())
+
+    def fill_nan(self, fill_value: Series) -> Series:
+        if not isinstance(fill_value, Series):
+            raise ValueError(f"expected another Series but got {type(fill_value)}")
+        assert self._series is not None and fill_value._series is not None
+        return Series._from_pyseries(self._series.fill_nan(fill_value._series))
+
 
 class SeriesStringNamespace(SeriesNamespace):
     def endswith(self, suffix: Series) -> Series:
@@ -577,11 +730,11 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None and pattern._series is not None
         return Series._from_pyseries(self._series.utf8_contains(pattern._series))
 
-    def split(self, pattern: Series) -> Series:
+    def split(self, pattern: Series, regex: bool = False) -> Series:
         if not isinstance(pattern, Series):
             raise ValueError(f"expected another Series but got {type(pattern)}")
         assert self._series is not None and pattern._series is not None
-        return Series._from_pyseries(self._series.utf8_split(pattern._series))
+        return Series._from_pyseries(self._series.utf8_split(pattern._series, regex))
 
     def concat(self, other: Series) -> Series:
         if not isinstance(other, Series):
@@ -593,6 +746,10 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_length())
 
+    def length_chars(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_length_chars())
+
     def lower(self) -> Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_lower())
@@ -601,6 +758,10 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_upper())
 
+    def capitalize(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_capitalize())
+
     def lstrip(self) -> Series:
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_lstrip())
@@ -613,6 +774,12 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_reverse())
 
+    def substr(self, start: int, length: int | None = None) -> Series:
+        if length is not None and length < 0:
+            raise ValueError(f"Substring length must be non-negative, but received {length}")
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_substr(start, length))
+
 
 class SeriesDateNamespace(SeriesNamespace):
     def date(self) -> Series:
@@ -624,6 +791,9 @@ class SeriesDateNamespace(SeriesNamespace):
     def hour(self) -> Series:
         return Series._from_pyseries(self._series.dt_hour())
 
+    def minute(self) -> Series:
+        return Series._from_pyseries(self._series.dt_minute())
+
     def month(self) -> Series:
         return Series._from_pyseries(self._series.dt_month())
 
@@ -633,6 +803,18 @@ class SeriesDateNamespace(SeriesNamespace):
     def day_of_week(self) -> Series:
         return Series._from_pyseries(self._series.dt_day_of_week())
 
+    def tz_localize(self, tz: str, ambiguous: str = "raise", nonexistent: str = "raise") -> Series:
+        return Series._from_pyseries(
+            self._series.dt_tz_localize(
+                tz,
+                DstResolution.from_dst_resolution_str(ambiguous),
+                DstResolution.from_dst_resolution_str(nonexistent),
+            )
+        )
+
+    def tz_convert(self, tz: str) -> Series:
+        return Series._from_pyseries(self._series.dt_tz_convert(tz))
+
 
 class SeriesPartitioningNamespace(SeriesNamespace):
     def days(self) -> Series:
@@ -661,6 +843,12 @@ class SeriesListNamespace(SeriesNamespace):
     def get(self, idx: Series, default: Series) -> Series:
         return Series._from_pyseries(self._series.list_get(idx._series, default._series))
 
+    def slice(self, start: Series, end: Series) -> Series:
+        return Series._from_pyseries(self._series.list_slice(start._series, end._series))
+
+    def contains(self, item: Series) -> Series:
+        return Series._from_pyseries(self._series.list_contains(item._series))
+
 
 class SeriesImageNamespace(SeriesNamespace):
     def decode(self) -> Series:
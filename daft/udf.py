@@ -33,6 +33,29 @@ class PartialUDF:
         return {key: val for key, val in self.bound_args.arguments.items() if isinstance(val, Expression)}
 
     def __call__(self, evaluated_expressions: list[Series]) -> PySeries:
+        # NOTE: We currently initialize the function once for every invocation of the PartialUDF.
+        # This is not ideal and we should cache initializations across calls for the same process.
+        func = self.udf.get_initialized_func()
+
+        # HACK: Series have names and the logic for naming fields/series in a UDF is to take the first
+        # Expression's name. Note that this logic is tied to the `to_field` implementation of the Rust PythonUDF
+        # and is quite error prone! If our Series naming logic here is wrong, things will break when the UDF is run on a table.
+        name = evaluated_expressions[0].name()
+
+        batch_size = self.udf.batch_size
+        num_rows = len(evaluated_expressions[0])
+        if batch_size is None or num_rows <= batch_size:
+            return self._call_batch(func, evaluated_expressions, name)._series
+
+        # Run the function on consecutive slices of the inputs, reusing the initialized function and the
+        # GIL acquired for this invocation across all batches.
+        batches = [
+            self._call_batch(func, [s.slice(start, start + batch_size) for s in evaluated_expressions], name)
+            for start in range(0, num_rows, batch_size)
+        ]
+        return Series.concat(batches)._series
+
+    def _call_batch(self, func: Callable, evaluated_expressions: list[Series], result_name: str) -> Series:
         kwarg_keys = list(self.bound_args.kwargs.keys())
         arg_keys = [k for k in self.bound_args.arguments.keys() if k not in self.bound_args.kwargs.keys()]
         pyvalues = {key: val for key, val in self.bound_args.arguments.items() if not isinstance(val, Expression)}
@@ -62,27 +85,18 @@ class PartialUDF:
             else:
                 kwargs[name] = evaluated_expressions[function_parameter_name_to_index[name]]
 
-        # NOTE: We currently initialize the function once for every invocation of the PartialUDF.
-        # This is not ideal and we should cache initializations across calls for the same process.
-        func = self.udf.get_initialized_func()
-
         result = func(*args, **kwargs)
 
-        # HACK: Series have names and the logic for naming fields/series in a UDF is to take the first
-        # Expression's name. Note that this logic is tied to the `to_field` implementation of the Rust PythonUDF
-        # and is quite error prone! If our Series naming logic here is wrong, things will break when the UDF is run on a table.
-        name = evaluated_expressions[0].name()
-
         # Post-processing of results into a Series of the appropriate dtype
         if isinstance(result, Series):
-            return result.rename(name).cast(self.udf.return_dtype)._series
+            return result.rename(result_name).cast(self.udf.return_dtype)
         elif isinstance(result, list):
             if self.udf.return_dtype == DataType.python():
-                return Series.from_pylist(result, name=name, pyobj="force")._series
+                return Series.from_pylist(result, name=result_name, pyobj="force")
             else:
-                return Series.from_pylist(result, name=name, pyobj="allow").cast(self.udf.return_dtype)._series
+                return Series.from_pylist(result, name=result_name, pyobj="allow").cast(self.udf.return_dtype)
         elif _NUMPY_AVAILABLE and isinstance(result, np.ndarray):
-            return Series.from_numpy(result, name=name).cast(self.udf.return_dtype)._series
+            return Series.from_numpy(result, name=result_name).cast(self.udf.return_dtype)
         else:
             raise NotImplementedError(f"Return type not supported for UDF: {type(result)}")
 
@@ -99,6 +113,7 @@ class PartialUDF:
 class UDF:
     func: UserProvidedPythonFunction
     return_dtype: DataType
+    batch_size: int | None = None
 
     def __post_init__(self):
         """Analagous to the @functools.wraps(self.func) pattern
@@ -144,12 +159,13 @@ class UDF:
         raise NotImplementedError(f"UDF type not supported: {type(self.func)}")
 
     def __hash__(self) -> int:
-        return hash((self.func, self.return_dtype))
+        return hash((self.func, self.return_dtype, self.batch_size))
 
 
 def udf(
     *,
     return_dtype: DataType,
+    batch_size: int | None = None,
 ) -> Callable[[UserProvidedPythonFunction], UDF]:
     """Decorator to convert a Python function into a UDF
 
@@ -179,15 +195,20 @@ def udf(
 
     Args:
         return_dtype (DataType): Returned type of the UDF
+        batch_size (int | None): If provided, the UDF is called on batches of at most this many rows at a time, and
+            the results are concatenated. Defaults to None, which calls the UDF once on all the rows of a partition.
 
     Returns:
         Callable[[UserProvidedPythonFunction], UDF]: UDF decorator - converts a user-provided Python function as a UDF that can be called on Expressions
     """
 
     def _udf(f: UserProvidedPythonFunction) -> UDF:
+        if batch_size is not None and batch_size < 1:
+            raise ValueError(f"UDF batch_size must be a positive integer, but received {batch_size}")
         return UDF(
             func=f,
             return_dtype=return_dtype,
+            batch_size=batch_size,
         )
 
     return _udf
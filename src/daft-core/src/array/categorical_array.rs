@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use indexmap::IndexMap;
+
+use crate::array::growable::{Growable, GrowableArray};
+use crate::array::ops::as_arrow::AsArrow;
+use crate::datatypes::{DaftArrayType, Field, UInt32Array, Utf8Array};
+use crate::DataType;
+
+/// A dictionary-encoded array of strings, where each element is a code into a dictionary of unique
+/// non-null strings. Nulls are represented by the validity of the codes.
+#[derive(Clone, Debug)]
+pub struct CategoricalArray {
+    pub field: Arc<Field>,
+    codes: UInt32Array,
+    dictionary: Utf8Array,
+}
+
+impl DaftArrayType for CategoricalArray {}
+
+impl CategoricalArray {
+    pub fn new<F: Into<Arc<Field>>>(field: F, codes: UInt32Array, dictionary: Utf8Array) -> Self {
+        let field: Arc<Field> = field.into();
+        if field.dtype != DataType::Categorical {
+            panic!(
+                "CategoricalArray::new expected Categorical datatype, but received field: {}",
+                field
+            )
+        }
+        if dictionary.data().null_count() > 0 {
+            panic!("CategoricalArray::new expects a dictionary without nulls")
+        }
+        if let Some(max_code) = codes.as_arrow().iter().flatten().max() {
+            if *max_code as usize >= dictionary.len() {
+                panic!(
+                    "CategoricalArray::new received code {} for a dictionary of length {}",
+                    max_code,
+                    dictionary.len()
+                )
+            }
+        }
+        CategoricalArray {
+            codes: codes.rename(&field.name),
+            dictionary: dictionary.rename("dictionary"),
+            field,
+        }
+    }
+
+    /// Dictionary-encodes an array of strings, with the dictionary in order of first occurrence.
+    pub fn encode(array: &Utf8Array) -> Self {
+        let mut dictionary = IndexMap::<&str, u32>::new();
+        let codes = array
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    let next_code = dictionary.len() as u32;
+                    *dictionary.entry(v).or_insert(next_code)
+                })
+            })
+            .collect::<Vec<_>>();
+        let dictionary = dictionary.into_keys().map(Some).collect::<Vec<_>>();
+        Self::new(
+            Field::new(array.name(), DataType::Categorical),
+            UInt32Array::from_iter(array.name(), codes.into_iter()),
+            Utf8Array::from_iter("dictionary", dictionary.into_iter()),
+        )
+    }
+
+    /// Decodes this array into an array of strings.
+    pub fn decode(&self) -> DaftResult<Utf8Array> {
+        Ok(self.dictionary.take(&self.codes)?.rename(self.name()))
+    }
+
+    pub fn codes(&self) -> &UInt32Array {
+        &self.codes
+    }
+
+    pub fn dictionary(&self) -> &Utf8Array {
+        &self.dictionary
+    }
+
+    pub fn validity(&self) -> Option<&arrow2::bitmap::Bitmap> {
+        self.codes.validity()
+    }
+
+    pub fn concat(arrays: &[&Self]) -> DaftResult<Self> {
+        if arrays.is_empty() {
+            return Err(DaftError::ValueError(
+                "Need at least 1 CategoricalArray to concat".to_string(),
+            ));
+        }
+
+        let first_array = arrays.get(0).unwrap();
+        let mut growable = <Self as GrowableArray>::make_growable(
+            first_array.name(),
+            first_array.data_type(),
+            arrays.to_vec(),
+            arrays
+                .iter()
+                .map(|a| a.validity().map_or(0usize, |v| v.unset_bits()))
+                .sum::<usize>()
+                > 0,
+            arrays.iter().map(|a| a.len()).sum(),
+        );
+
+        for (i, arr) in arrays.iter().enumerate() {
+            growable.extend(i, 0, arr.len());
+        }
+
+        growable
+            .build()
+            .map(|s| s.downcast::<CategoricalArray>().unwrap().clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn name(&self) -> &str {
+        &self.field.name
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.field.dtype
+    }
+
+    pub fn rename(&self, name: &str) -> Self {
+        Self {
+            field: Arc::new(Field::new(name, self.data_type().clone())),
+            codes: self.codes.rename(name),
+            dictionary: self.dictionary.clone(),
+        }
+    }
+
+    pub fn slice(&self, start: usize, end: usize) -> DaftResult<Self> {
+        Ok(self.with_codes(self.codes.slice(start, end)?))
+    }
+
+    pub fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        Box::new(
+            arrow2::array::DictionaryArray::<u32>::try_new(
+                self.data_type().to_arrow().unwrap(),
+                self.codes.as_arrow().clone(),
+                self.dictionary.data().to_boxed(),
+            )
+            .unwrap(),
+        )
+    }
+
+    pub fn with_validity(&self, validity: Option<arrow2::bitmap::Bitmap>) -> DaftResult<Self> {
+        Ok(self.with_codes(self.codes.with_validity(validity)?))
+    }
+
+    /// An array with the same dictionary as this one, but the given codes.
+    pub(crate) fn with_codes(&self, codes: UInt32Array) -> Self {
+        Self {
+            field: self.field.clone(),
+            codes,
+            dictionary: self.dictionary.clone(),
+        }
+    }
+
+    /// For each element, the rank of its string among the strings of the dictionary, so that
+    /// comparing ranks is the same as comparing strings.
+    pub fn ranks(&self) -> DaftResult<UInt32Array> {
+        let sorted = self
+            .dictionary
+            .argsort::<crate::datatypes::UInt32Type>(false)?;
+        let mut rank_of_code = vec![0u32; self.dictionary.len()];
+        for (rank, code) in sorted.as_arrow().values_iter().enumerate() {
+            rank_of_code[*code as usize] = rank as u32;
+        }
+        let ranks = self
+            .codes
+            .as_arrow()
+            .iter()
+            .map(|code| code.map(|code| rank_of_code[*code as usize]))
+            .collect::<Vec<_>>();
+        Ok(UInt32Array::from_iter(self.name(), ranks.into_iter()))
+    }
+}
+
+/// Merges the dictionaries of `arrays` into a single dictionary, returning it together with, for each
+/// array, the mapping from its codes to codes into the merged dictionary.
+pub(crate) fn merge_dictionaries(arrays: &[&CategoricalArray]) -> (Utf8Array, Vec<Vec<u32>>) {
+    let mut merged = IndexMap::<&str, u32>::new();
+    let code_mappings = arrays
+        .iter()
+        .map(|array| {
+            array
+                .dictionary
+                .as_arrow()
+                .values_iter()
+                .map(|v| {
+                    let next_code = merged.len() as u32;
+                    *merged.entry(v).or_insert(next_code)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let merged = merged.into_keys().map(Some).collect::<Vec<_>>();
+    (
+        Utf8Array::from_iter("dictionary", merged.into_iter()),
+        code_mappings,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::CategoricalArray;
+    use crate::{array::ops::DaftCompare, datatypes::Utf8Array};
+
+    fn categorical(values: &[Option<&str>]) -> CategoricalArray {
+        CategoricalArray::encode(&Utf8Array::from_iter("c", values.iter().copied()))
+    }
+
+    #[test]
+    fn encode_and_decode() -> DaftResult<()> {
+        let array = categorical(&[Some("b"), Some("a"), None, Some("b")]);
+        assert_eq!(array.dictionary().len(), 2);
+        assert_eq!(
+            (0..4).map(|i| array.codes().get(i)).collect::<Vec<_>>(),
+            vec![Some(0), Some(1), None, Some(0)]
+        );
+        let decoded = array.decode()?;
+        assert_eq!(decoded.name(), "c");
+        assert_eq!(
+            (0..4).map(|i| decoded.get(i)).collect::<Vec<_>>(),
+            vec![Some("b"), Some("a"), None, Some("b")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn compare_and_concat_across_dictionaries() -> DaftResult<()> {
+        let lhs = categorical(&[Some("b"), Some("a"), Some("c"), None]);
+        let rhs = categorical(&[Some("a"), Some("a"), Some("b"), Some("a")]);
+        let eq = lhs.equal(&rhs)?;
+        assert_eq!(
+            (0..4).map(|i| eq.get(i)).collect::<Vec<_>>(),
+            vec![Some(false), Some(true), Some(false), None]
+        );
+        // Ordered by the strings, not by the codes.
+        let gt = lhs.gt(&rhs)?;
+        assert_eq!(
+            (0..4).map(|i| gt.get(i)).collect::<Vec<_>>(),
+            vec![Some(true), Some(false), Some(true), None]
+        );
+
+        let concatted = CategoricalArray::concat(&[&lhs, &rhs])?;
+        assert_eq!(concatted.dictionary().len(), 3);
+        assert_eq!(
+            (0..8).map(|i| concatted.get(i)).collect::<Vec<_>>(),
+            vec![
+                Some("b"),
+                Some("a"),
+                Some("c"),
+                None,
+                Some("a"),
+                Some("a"),
+                Some("b"),
+                Some("a")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ranks_follow_string_order() -> DaftResult<()> {
+        let ranks = categorical(&[Some("c"), Some("a"), None, Some("b")]).ranks()?;
+        assert_eq!(
+            (0..4).map(|i| ranks.get(i)).collect::<Vec<_>>(),
+            vec![Some(2), Some(0), None, Some(1)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use common_error::DaftResult;
+
+use crate::{
+    array::{merge_dictionaries, ops::as_arrow::AsArrow, CategoricalArray},
+    datatypes::{Field, UInt32Array, Utf8Array},
+    DataType, IntoSeries, Series,
+};
+
+use super::Growable;
+
+/// Grows a [`CategoricalArray`] over the merged dictionary of all of its input arrays, remapping the
+/// codes of each input into that dictionary.
+pub struct CategoricalGrowable<'a> {
+    name: String,
+    dtype: DataType,
+    arrays: Vec<&'a CategoricalArray>,
+    dictionary: Utf8Array,
+    code_mappings: Vec<Vec<u32>>,
+    codes: Vec<Option<u32>>,
+}
+
+impl<'a> CategoricalGrowable<'a> {
+    pub fn new(
+        name: &str,
+        dtype: &DataType,
+        arrays: Vec<&'a CategoricalArray>,
+        _use_validity: bool,
+        capacity: usize,
+    ) -> Self {
+        match dtype {
+            DataType::Categorical => {
+                let (dictionary, code_mappings) = merge_dictionaries(&arrays);
+                Self {
+                    name: name.to_string(),
+                    dtype: dtype.clone(),
+                    arrays,
+                    dictionary,
+                    code_mappings,
+                    codes: Vec::with_capacity(capacity),
+                }
+            }
+            _ => panic!("Cannot create CategoricalGrowable from dtype: {}", dtype),
+        }
+    }
+}
+
+impl<'a> Growable for CategoricalGrowable<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let code_mapping = &self.code_mappings[index];
+        self.codes.extend(
+            self.arrays[index]
+                .codes()
+                .as_arrow()
+                .iter()
+                .skip(start)
+                .take(len)
+                .map(|code| code.map(|code| code_mapping[*code as usize])),
+        );
+    }
+
+    fn add_nulls(&mut self, additional: usize) {
+        self.codes.extend(std::iter::repeat(None).take(additional));
+    }
+
+    fn build(&mut self) -> DaftResult<Series> {
+        let codes = std::mem::take(&mut self.codes);
+        Ok(CategoricalArray::new(
+            Field::new(self.name.clone(), self.dtype.clone()),
+            UInt32Array::from_iter(self.name.as_str(), codes.into_iter()),
+            self.dictionary.clone(),
+        )
+        .into_series())
+    }
+}
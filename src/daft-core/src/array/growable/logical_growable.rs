@@ -6,7 +6,7 @@ use crate::{
     datatypes::{
         logical::LogicalArray, DaftDataType, DaftLogicalType, DateType, Decimal128Type,
         DurationType, EmbeddingType, Field, FixedShapeImageType, FixedShapeTensorType, ImageType,
-        TensorType, TimeType, TimestampType,
+        IntervalType, MapType, TensorType, TimeType, TimestampType,
     },
     DataType, IntoSeries, Series,
 };
@@ -76,6 +76,8 @@ macro_rules! impl_logical_growable {
 
 impl_logical_growable!(LogicalTimestampGrowable, TimestampType);
 impl_logical_growable!(LogicalDurationGrowable, DurationType);
+impl_logical_growable!(LogicalIntervalGrowable, IntervalType);
+impl_logical_growable!(LogicalMapGrowable, MapType);
 impl_logical_growable!(LogicalDateGrowable, DateType);
 impl_logical_growable!(LogicalTimeGrowable, TimeType);
 impl_logical_growable!(LogicalEmbeddingGrowable, EmbeddingType);
@@ -1,11 +1,12 @@
 use common_error::DaftResult;
 
 use crate::{
-    array::{FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int128Array,
         Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
@@ -16,6 +17,7 @@ use crate::{
 
 mod arrow_growable;
 mod bitmap_growable;
+mod categorical_growable;
 mod fixed_size_list_growable;
 mod list_growable;
 mod logical_growable;
@@ -186,11 +188,17 @@ impl_growable_array!(
     fixed_size_list_growable::FixedSizeListGrowable<'a>
 );
 impl_growable_array!(StructArray, struct_growable::StructGrowable<'a>);
+impl_growable_array!(
+    CategoricalArray,
+    categorical_growable::CategoricalGrowable<'a>
+);
 impl_growable_array!(
     TimestampArray,
     logical_growable::LogicalTimestampGrowable<'a>
 );
 impl_growable_array!(DurationArray, logical_growable::LogicalDurationGrowable<'a>);
+impl_growable_array!(IntervalArray, logical_growable::LogicalIntervalGrowable<'a>);
+impl_growable_array!(MapArray, logical_growable::LogicalMapGrowable<'a>);
 impl_growable_array!(DateArray, logical_growable::LogicalDateGrowable<'a>);
 impl_growable_array!(TimeArray, logical_growable::LogicalTimeGrowable<'a>);
 impl_growable_array!(
@@ -1,3 +1,4 @@
+mod categorical_array;
 mod fixed_size_list_array;
 pub mod from;
 pub mod growable;
@@ -5,9 +6,11 @@ pub mod iterator;
 mod list_array;
 pub mod ops;
 pub mod pseudo_arrow;
-mod serdes;
+pub(crate) mod serdes;
 mod struct_array;
 use arrow2::bitmap::Bitmap;
+pub(crate) use categorical_array::merge_dictionaries;
+pub use categorical_array::CategoricalArray;
 pub use fixed_size_list_array::FixedSizeListArray;
 pub use list_array::ListArray;
 
@@ -1,8 +1,13 @@
-use num_traits::Signed;
+use num_traits::{Bounded, Signed};
 
-use crate::{array::DataArray, datatypes::DaftNumericType};
+use crate::{
+    array::DataArray,
+    datatypes::{DaftIntegerType, DaftNumericType},
+};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
 
 impl<T: DaftNumericType> DataArray<T>
 where
@@ -12,3 +17,21 @@ where
         self.apply(|v| v.abs())
     }
 }
+
+impl<T: DaftIntegerType> DataArray<T>
+where
+    T::Native: Ord + Signed + Bounded,
+{
+    /// Like [`DataArray::abs`], but errors on the minimum value of the type instead of overflowing,
+    /// since it has no positive counterpart.
+    pub fn checked_abs(&self) -> DaftResult<Self> {
+        let min = T::Native::min_value();
+        if self.as_arrow().iter().flatten().any(|v| *v == min) {
+            return Err(DaftError::ComputeError(format!(
+                "abs overflowed on {min}, which has no positive counterpart in {}",
+                self.data_type()
+            )));
+        }
+        self.abs()
+    }
+}
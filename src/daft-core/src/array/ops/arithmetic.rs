@@ -74,6 +74,25 @@ where
     }
 }
 
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Nulls out the zeros of a divisor, so that division and remainder by zero yield null.
+    fn with_zeros_as_nulls(&self) -> DaftResult<Self> {
+        let arrow_array = self.as_arrow();
+        let zero = T::Native::default();
+        if !arrow_array.values_iter().any(|v| *v == zero) {
+            return Ok(self.clone());
+        }
+        let validity = arrow_array
+            .iter()
+            .map(|v| v.is_some_and(|v| *v != zero))
+            .collect::<arrow2::bitmap::Bitmap>();
+        self.with_validity(Some(validity))
+    }
+}
+
 impl<T> Add for &DataArray<T>
 where
     T: DaftNumericType,
@@ -117,6 +136,7 @@ where
 impl Div for &Float64Array {
     type Output = DaftResult<Float64Array>;
     fn div(self, rhs: Self) -> Self::Output {
+        let rhs = &rhs.with_zeros_as_nulls()?;
         arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
     }
 }
@@ -124,7 +144,42 @@ impl Div for &Float64Array {
 impl Div for &Int64Array {
     type Output = DaftResult<Int64Array>;
     fn div(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
+        let rhs = &rhs.with_zeros_as_nulls()?;
+        if rhs.data().null_count() == 0 {
+            return arithmetic_helper(self, rhs, basic::div, |l, r| l / r);
+        }
+        // The values under nulls may be zeros, so only divide by valid values.
+        match (self.len(), rhs.len()) {
+            (a, b) if a == b => Ok(DataArray::from((
+                self.name(),
+                Box::new(binary_with_nulls(
+                    self.as_arrow(),
+                    rhs.as_arrow(),
+                    |a, b| a / b,
+                )),
+            ))),
+            // broadcast right path, where the only value is null
+            (_, 1) => Ok(DataArray::full_null(
+                self.name(),
+                self.data_type(),
+                self.len(),
+            )),
+            (1, _) => {
+                let opt_lhs = self.get(0);
+                Ok(match opt_lhs {
+                    None => DataArray::full_null(rhs.name(), rhs.data_type(), rhs.len()),
+                    Some(lhs) => {
+                        let values_iter = rhs.as_arrow().iter().map(|v| v.map(|v| lhs / *v));
+                        let arrow_array =
+                            unsafe { PrimitiveArray::from_trusted_len_iter_unchecked(values_iter) };
+                        DataArray::from((self.name(), Box::new(arrow_array)))
+                    }
+                })
+            }
+            (a, b) => Err(DaftError::ValueError(format!(
+                "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+            ))),
+        }
     }
 }
 
@@ -162,6 +217,7 @@ where
 {
     type Output = DaftResult<DataArray<T>>;
     fn rem(self, rhs: Self) -> Self::Output {
+        let rhs = &rhs.with_zeros_as_nulls()?;
         if rhs.data().null_count() == 0 {
             arithmetic_helper(self, rhs, basic::rem, |l, r| l % r)
         } else {
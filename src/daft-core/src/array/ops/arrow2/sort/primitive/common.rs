@@ -1,6 +1,7 @@
 use arrow2::array::ord::DynComparator;
 use arrow2::{array::PrimitiveArray, bitmap::Bitmap, types::Index};
 
+/// Stable sort of indices, where nulls are placed last, or first if descending.
 pub fn idx_sort<I, F>(
     validity: Option<&Bitmap>,
     cmp: F,
@@ -16,9 +17,9 @@ where
     let indices_slice = &mut indices.as_mut_slice()[start_idx..end_idx];
 
     if !descending {
-        indices_slice.sort_unstable_by(|a, b| cmp(a, b));
+        indices_slice.sort_by(|a, b| cmp(a, b));
     } else {
-        indices_slice.sort_unstable_by(|a, b| cmp(b, a));
+        indices_slice.sort_by(|a, b| cmp(b, a));
     }
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::new(data_type, indices.into(), None)
@@ -39,14 +40,14 @@ where
         generate_initial_indices::<I>(first_col_validity, length, first_col_desc);
     let indices_slice = &mut indices.as_mut_slice()[start_idx..end_idx];
 
-    indices_slice.sort_unstable_by(|a, b| overall_cmp(a, b));
+    indices_slice.sort_by(|a, b| overall_cmp(a, b));
     if start_idx > 0 {
         let preslice_indices = &mut indices.as_mut_slice()[..start_idx];
-        preslice_indices.sort_unstable_by(|a, b| others_cmp(a.to_usize(), b.to_usize()));
+        preslice_indices.sort_by(|a, b| others_cmp(a.to_usize(), b.to_usize()));
     }
     if end_idx < length {
         let postslice_indices = &mut indices.as_mut_slice()[end_idx..];
-        postslice_indices.sort_unstable_by(|a, b| others_cmp(a.to_usize(), b.to_usize()));
+        postslice_indices.sort_by(|a, b| others_cmp(a.to_usize(), b.to_usize()));
     }
 
     let data_type = I::PRIMITIVE.into();
@@ -5,8 +5,8 @@ use arrow2::{
 
 use super::common;
 
-/// Unstable sort of indices.
-pub fn indices_sorted_unstable_by<I, T, F>(
+/// Stable sort of indices.
+pub fn indices_sorted_by<I, T, F>(
     array: &PrimitiveArray<T>,
     cmp: F,
     descending: bool,
@@ -0,0 +1,197 @@
+use crate::{
+    array::DataArray,
+    closed_interval::ClosedInterval,
+    datatypes::{BinaryArray, BooleanArray, DaftNumericType, NullArray, Utf8Array},
+    DataType,
+};
+
+use super::as_arrow::AsArrow;
+use super::{full::FullNull, DaftBetween};
+use common_error::{DaftError, DaftResult};
+
+#[inline]
+fn in_range<V: PartialOrd>(value: V, lower: V, upper: V, closed: ClosedInterval) -> bool {
+    let above_lower = if closed.lower_inclusive() {
+        value >= lower
+    } else {
+        value > lower
+    };
+    let below_upper = if closed.upper_inclusive() {
+        value <= upper
+    } else {
+        value < upper
+    };
+    above_lower && below_upper
+}
+
+fn check_bound_len(name: &str, bound: &str, bound_len: usize, len: usize) -> DaftResult<()> {
+    if bound_len != 1 && bound_len != len {
+        return Err(DaftError::ValueError(format!(
+            "trying to check {name} of length {len} between {bound} bound of length {bound_len}, bounds must either be of length 1 or match length of array"
+        )));
+    }
+    Ok(())
+}
+
+macro_rules! check_between {
+    ($self:expr, $lower:expr, $upper:expr, $closed:expr) => {{
+        let len = $self.len();
+        check_bound_len($self.name(), "lower", $lower.len(), len)?;
+        check_bound_len($self.name(), "upper", $upper.len(), len)?;
+
+        // Bounds are materialized once so that length-1 bounds, the common case for literals,
+        // can be broadcast by index without re-scanning the arrow arrays.
+        let lower = $lower.as_arrow().iter().collect::<Vec<_>>();
+        let upper = $upper.as_arrow().iter().collect::<Vec<_>>();
+        let lower_idx = |i: usize| if lower.len() == 1 { 0 } else { i };
+        let upper_idx = |i: usize| if upper.len() == 1 { 0 } else { i };
+
+        let result = $self
+            .as_arrow()
+            .iter()
+            .enumerate()
+            .map(
+                |(i, value)| match (value, lower[lower_idx(i)], upper[upper_idx(i)]) {
+                    (Some(value), Some(lower), Some(upper)) => {
+                        Some(in_range(value, lower, upper, $closed))
+                    }
+                    _ => None,
+                },
+            )
+            .collect::<Vec<_>>();
+        Ok(BooleanArray::from(($self.name(), result.as_slice())))
+    }};
+}
+
+impl<T> DaftBetween<&DataArray<T>> for DataArray<T>
+where
+    T: DaftNumericType,
+{
+    type Output = DaftResult<BooleanArray>;
+
+    fn between(
+        &self,
+        lower: &DataArray<T>,
+        upper: &DataArray<T>,
+        closed: ClosedInterval,
+    ) -> Self::Output {
+        check_between!(self, lower, upper, closed)
+    }
+}
+
+macro_rules! impl_between_non_numeric_array {
+    ($arr:ident) => {
+        impl DaftBetween<&$arr> for $arr {
+            type Output = DaftResult<BooleanArray>;
+
+            fn between(&self, lower: &$arr, upper: &$arr, closed: ClosedInterval) -> Self::Output {
+                check_between!(self, lower, upper, closed)
+            }
+        }
+    };
+}
+impl_between_non_numeric_array!(BooleanArray);
+impl_between_non_numeric_array!(Utf8Array);
+impl_between_non_numeric_array!(BinaryArray);
+
+impl DaftBetween<&NullArray> for NullArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn between(
+        &self,
+        _lower: &NullArray,
+        _upper: &NullArray,
+        _closed: ClosedInterval,
+    ) -> Self::Output {
+        Ok(BooleanArray::full_null(
+            self.name(),
+            &DataType::Boolean,
+            self.len(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftBetween},
+        closed_interval::ClosedInterval,
+        datatypes::{Int64Array, Utf8Array},
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn between_int64_array_with_scalar_bounds() -> DaftResult<()> {
+        let array = Int64Array::arange("a", 0, 5, 1)?;
+        let lower = Int64Array::from(("lower", vec![1].as_slice()));
+        let upper = Int64Array::from(("upper", vec![3].as_slice()));
+
+        let result: Vec<_> = array
+            .between(&lower, &upper, ClosedInterval::Both)?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(
+            result[..],
+            [Some(false), Some(true), Some(true), Some(true), Some(false)]
+        );
+
+        let result: Vec<_> = array
+            .between(&lower, &upper, ClosedInterval::Left)?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(
+            result[..],
+            [
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false)
+            ]
+        );
+
+        let result: Vec<_> = array
+            .between(&lower, &upper, ClosedInterval::Neither)?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(
+            result[..],
+            [
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(false)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn between_utf8_array_with_array_bounds() -> DaftResult<()> {
+        let array = Utf8Array::from(("a", ["b", "b", "z"].as_slice()));
+        let lower = Utf8Array::from(("lower", ["a", "b", "a"].as_slice()));
+        let upper = Utf8Array::from(("upper", ["c", "c", "y"].as_slice()))
+            .with_validity_slice(&[true, true, false])?;
+
+        let result: Vec<_> = array
+            .between(&lower, &upper, ClosedInterval::Right)?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(result[..], [Some(true), Some(false), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn between_rejects_mismatched_bounds() -> DaftResult<()> {
+        let array = Int64Array::arange("a", 0, 5, 1)?;
+        let lower = Int64Array::arange("lower", 0, 2, 1)?;
+        let upper = Int64Array::from(("upper", vec![3].as_slice()));
+        assert!(array.between(&lower, &upper, ClosedInterval::Both).is_err());
+        Ok(())
+    }
+}
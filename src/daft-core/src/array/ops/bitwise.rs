@@ -0,0 +1,132 @@
+use arrow2::array::PrimitiveArray;
+use num_traits::PrimInt;
+
+use crate::{
+    array::DataArray,
+    datatypes::{DaftIntegerType, Int64Array},
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
+
+fn bitwise_helper<T, R, F>(
+    lhs: &DataArray<T>,
+    rhs: &DataArray<R>,
+    operation: F,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftIntegerType,
+    T::Native: Ord,
+    R: DaftIntegerType,
+    R::Native: Ord,
+    F: Fn(T::Native, R::Native) -> T::Native,
+{
+    if lhs.len() != rhs.len() {
+        return Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {} vs {}",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    let result = lhs
+        .as_arrow()
+        .iter()
+        .zip(rhs.as_arrow().iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => Some(operation(*l, *r)),
+            _ => None,
+        })
+        .collect::<PrimitiveArray<T::Native>>();
+    Ok(DataArray::from((lhs.name(), Box::new(result))))
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftIntegerType,
+    T::Native: Ord + PrimInt,
+{
+    pub fn bitwise_and(&self, rhs: &Self) -> DaftResult<Self> {
+        bitwise_helper(self, rhs, |l, r| l & r)
+    }
+
+    pub fn bitwise_or(&self, rhs: &Self) -> DaftResult<Self> {
+        bitwise_helper(self, rhs, |l, r| l | r)
+    }
+
+    pub fn bitwise_xor(&self, rhs: &Self) -> DaftResult<Self> {
+        bitwise_helper(self, rhs, |l, r| l ^ r)
+    }
+
+    /// Checks that every shift amount is within `0..bit_width`, where shifts are well defined.
+    fn check_shift_amounts(amounts: &Int64Array) -> DaftResult<()> {
+        let bit_width = (std::mem::size_of::<T::Native>() * 8) as i64;
+        match amounts
+            .as_arrow()
+            .iter()
+            .flatten()
+            .find(|amount| !(0..bit_width).contains(*amount))
+        {
+            Some(amount) => Err(DaftError::ValueError(format!(
+                "Shift amount must be between 0 and {}, but got {amount}",
+                bit_width - 1
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn shift_left(&self, amounts: &Int64Array) -> DaftResult<Self> {
+        Self::check_shift_amounts(amounts)?;
+        bitwise_helper(self, amounts, |l, r| l << r as usize)
+    }
+
+    /// Shifts right, sign-extending signed integers.
+    pub fn shift_right(&self, amounts: &Int64Array) -> DaftResult<Self> {
+        Self::check_shift_amounts(amounts)?;
+        bitwise_helper(self, amounts, |l, r| l >> r as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Int64Array, Int8Array, UInt8Array},
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn check_bitwise_kernels() -> DaftResult<()> {
+        let a = UInt8Array::from(("a", vec![0b1100u8, 0b1010, 0xff]));
+        let b = UInt8Array::from(("b", vec![0b1010u8, 0b0110, 0x0f]));
+        assert_eq!(
+            a.bitwise_and(&b)?.as_arrow().values().as_slice(),
+            &[0b1000, 0b0010, 0x0f]
+        );
+        assert_eq!(
+            a.bitwise_or(&b)?.as_arrow().values().as_slice(),
+            &[0b1110, 0b1110, 0xff]
+        );
+        assert_eq!(
+            a.bitwise_xor(&b)?.as_arrow().values().as_slice(),
+            &[0b0110, 0b1100, 0xf0]
+        );
+
+        let amounts = Int64Array::from(("amounts", vec![1, 2, 7]));
+        assert_eq!(
+            a.shift_left(&amounts)?.as_arrow().values().as_slice(),
+            &[0b11000, 0b101000, 0x80]
+        );
+        let signed = Int8Array::from(("signed", vec![-8i8, 64, -1]));
+        assert_eq!(
+            signed.shift_right(&amounts)?.as_arrow().values().as_slice(),
+            &[-4, 16, -1]
+        );
+
+        let too_far = Int64Array::from(("amounts", vec![1, 8, 0]));
+        assert!(a.shift_left(&too_far).is_err());
+        let negative = Int64Array::from(("amounts", vec![1, -1, 0]));
+        assert!(a.shift_right(&negative).is_err());
+        Ok(())
+    }
+}
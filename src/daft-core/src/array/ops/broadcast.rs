@@ -1,7 +1,7 @@
 use crate::{
     array::{
         growable::{Growable, GrowableArray},
-        DataArray, FixedSizeListArray, ListArray, StructArray,
+        CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{DaftArrayType, DaftPhysicalType, DataType},
 };
@@ -108,3 +108,9 @@ impl Broadcastable for StructArray {
         }
     }
 }
+
+impl Broadcastable for CategoricalArray {
+    fn broadcast(&self, num: usize) -> DaftResult<Self> {
+        Ok(self.with_codes(self.codes().broadcast(num)?))
+    }
+}
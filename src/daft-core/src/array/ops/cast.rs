@@ -5,19 +5,19 @@ use crate::{
     array::{
         growable::make_growable,
         ops::{from_arrow::FromArrow, full::FullNull, image::ImageArraySidecarData},
-        DataArray, FixedSizeListArray, ListArray, StructArray,
+        CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, LogicalArray, LogicalArrayImpl, TensorArray,
-            TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, LogicalArray, LogicalArrayImpl,
+            MapArray, TensorArray, TimeArray, TimestampArray,
         },
-        DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageMode, Int64Array, TimeUnit,
-        UInt64Array, Utf8Array,
+        BinaryArray, DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageFormat, ImageMode,
+        Int64Array, TimeUnit, UInt64Array, Utf8Array,
     },
     series::{IntoSeries, Series},
-    utils::display_table::display_time64,
+    utils::display_table::{display_date32, display_interval, display_time64},
     with_match_daft_logical_primitive_types,
 };
 use common_error::{DaftError, DaftResult};
@@ -25,10 +25,7 @@ use common_error::{DaftError, DaftResult};
 use arrow2::{
     array::Array,
     bitmap::utils::SlicesIterator,
-    compute::{
-        self,
-        cast::{can_cast_types, cast, CastOptions},
-    },
+    compute::cast::{can_cast_types, cast, CastOptions},
     offset::Offsets,
 };
 use indexmap::IndexMap;
@@ -248,32 +245,79 @@ where
                 })?;
                 Ok(new_pyseries.into())
             }
+            // Arrow can't parse strings as times, so parse them ourselves.
+            DataType::Time(timeunit) if self.data_type() == &DataType::Utf8 => {
+                let utf8_array = self
+                    .as_any()
+                    .downcast_ref::<Utf8Array>()
+                    .expect("DataArray with Utf8 dtype must be a Utf8Array");
+                Ok(utf8_array.to_time(timeunit)?.into_series())
+            }
+            // Arrow can't parse strings as decimals either.
+            DataType::Decimal128(precision, scale) if self.data_type() == &DataType::Utf8 => {
+                let utf8_array = self
+                    .as_any()
+                    .downcast_ref::<Utf8Array>()
+                    .expect("DataArray with Utf8 dtype must be a Utf8Array");
+                let values = utf8_array
+                    .as_arrow()
+                    .iter()
+                    .map(|val| str_to_decimal128(val?, *precision, *scale))
+                    .collect::<arrow2::array::PrimitiveArray<i128>>();
+                Ok(Decimal128Array::new(
+                    Field::new(self.name(), dtype.clone()),
+                    DataArray::from((self.name(), Box::new(values))),
+                )
+                .into_series())
+            }
+            DataType::Categorical if self.data_type() == &DataType::Utf8 => {
+                let utf8_array = self
+                    .as_any()
+                    .downcast_ref::<Utf8Array>()
+                    .expect("DataArray with Utf8 dtype must be a Utf8Array");
+                Ok(CategoricalArray::encode(utf8_array).into_series())
+            }
+            // Encoded images are decoded into the mode of the image type, if it has one.
+            DataType::Image(mode) if self.data_type() == &DataType::Binary => {
+                let binary_array = self
+                    .as_any()
+                    .downcast_ref::<BinaryArray>()
+                    .expect("DataArray with Binary dtype must be a BinaryArray");
+                Ok(binary_array.image_decode_to_mode(*mode)?.into_series())
+            }
+            DataType::FixedShapeImage(mode, ..) if self.data_type() == &DataType::Binary => {
+                let binary_array = self
+                    .as_any()
+                    .downcast_ref::<BinaryArray>()
+                    .expect("DataArray with Binary dtype must be a BinaryArray");
+                binary_array.image_decode_to_mode(Some(*mode))?.cast(dtype)
+            }
             _ => arrow_cast(self, dtype),
         }
     }
 }
 
+impl CategoricalArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Categorical => Ok(self.clone().into_series()),
+            DataType::Utf8 => Ok(self.decode()?.into_series()),
+            _ => self.decode()?.cast(dtype),
+        }
+    }
+}
+
 impl DateArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
-        let date_array = self
-            .as_arrow()
-            .clone()
-            .to(arrow2::datatypes::DataType::Date32);
         match dtype {
             DataType::Date => Ok(self.clone().into_series()),
             DataType::Utf8 => {
-                // TODO: we should move this into our own strftime kernel
-                let year_array = compute::temporal::year(&date_array)?;
-                let month_array = compute::temporal::month(&date_array)?;
-                let day_array = compute::temporal::day(&date_array)?;
-                let date_str: arrow2::array::Utf8Array<i64> = year_array
+                // Format as ISO 8601 (%Y-%m-%d), which is also what casting from Utf8 parses.
+                let date_str: arrow2::array::Utf8Array<i64> = self
+                    .physical
+                    .as_arrow()
                     .iter()
-                    .zip(month_array.iter())
-                    .zip(day_array.iter())
-                    .map(|((y, m), d)| match (y, m, d) {
-                        (None, _, _) | (_, None, _) | (_, _, None) => None,
-                        (Some(y), Some(m), Some(d)) => Some(format!("{y}-{m}-{d}")),
-                    })
+                    .map(|val| val.map(|val| display_date32(*val)))
                     .collect();
                 Ok(Utf8Array::from((self.name(), Box::new(date_str))).into_series())
             }
@@ -302,9 +346,14 @@ impl DateArray {
     }
 }
 
-pub(super) fn decimal128_to_str(val: i128, _precision: u8, scale: i8) -> String {
+pub(crate) fn decimal128_to_str(val: i128, _precision: u8, scale: i8) -> String {
     if scale < 0 {
-        unimplemented!();
+        // A negative scale shifts the unscaled value left, i.e. val * 10^-scale.
+        if val == 0 {
+            "0".to_string()
+        } else {
+            format!("{}{}", val, "0".repeat(scale.unsigned_abs() as usize))
+        }
     } else {
         let modulus = i128::pow(10, scale as u32);
         let integral = val / modulus;
@@ -320,6 +369,42 @@ pub(super) fn decimal128_to_str(val: i128, _precision: u8, scale: i8) -> String
     }
 }
 
+/// Parses a decimal string such as "-12.345" into an unscaled value of the given scale, rounding
+/// half away from zero if it has more fractional digits. Returns None for strings that aren't
+/// decimals or that don't fit in the precision.
+///
+/// This is the single parser for decimal strings, shared by Utf8 casts and literal parsing.
+pub fn str_to_decimal128(val: &str, precision: usize, scale: usize) -> Option<i128> {
+    let val = val.trim();
+    let (negative, digits) = match val.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, val.strip_prefix('+').unwrap_or(val)),
+    };
+    let (integral, fractional) = digits.split_once('.').unwrap_or((digits, ""));
+    if (integral.is_empty() && fractional.is_empty())
+        || !integral
+            .bytes()
+            .chain(fractional.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut unscaled: i128 = 0;
+    let fractional = fractional.bytes().chain(std::iter::repeat(b'0'));
+    for digit in integral.bytes().chain(fractional.clone().take(scale)) {
+        unscaled = unscaled
+            .checked_mul(10)?
+            .checked_add((digit - b'0') as i128)?;
+    }
+    if fractional.clone().nth(scale)? >= b'5' {
+        unscaled = unscaled.checked_add(1)?;
+    }
+    if unscaled >= 10i128.checked_pow(precision as u32)? {
+        return None;
+    }
+    Some(if negative { -unscaled } else { unscaled })
+}
+
 pub(crate) fn timestamp_to_str_naive(val: i64, unit: &TimeUnit) -> String {
     let chrono_ts =
         { arrow2::temporal_conversions::timestamp_to_naive_datetime(val, unit.to_arrow()) };
@@ -441,6 +526,8 @@ impl DurationArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
             DataType::Duration(..) => arrow_logical_cast(self, dtype),
+            // Integer counts of the duration's time unit.
+            dtype if dtype.is_integer() => self.physical.cast(dtype),
             DataType::Float32 => self.cast(&DataType::Int64)?.cast(&DataType::Float32),
             DataType::Float64 => self.cast(&DataType::Int64)?.cast(&DataType::Float64),
             #[cfg(feature = "python")]
@@ -450,9 +537,90 @@ impl DurationArray {
     }
 }
 
+impl IntervalArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            DataType::Utf8 => {
+                let interval_str = (0..self.len())
+                    .map(|i| {
+                        self.get(i)
+                            .map(|(months, days, ns)| display_interval(months, days, ns))
+                    })
+                    .collect::<arrow2::array::Utf8Array<i64>>();
+                Ok(Utf8Array::from((self.name(), Box::new(interval_str))).into_series())
+            }
+            #[cfg(feature = "python")]
+            DataType::Python => Python::with_gil(|py| {
+                let arrow_array = self.to_arrow().with_validity(None);
+                let pyarrow = py.import("pyarrow")?;
+                let py_array: Vec<PyObject> = ffi::to_py_array(arrow_array, py, pyarrow)?
+                    .call_method0(py, pyo3::intern!(py, "to_pylist"))?
+                    .extract(py)?;
+                let values_array =
+                    PseudoArrowArray::new(py_array.into(), self.physical.validity().cloned());
+                Ok(PythonArray::new(
+                    Field::new(self.name(), dtype.clone()).into(),
+                    values_array.to_boxed(),
+                )?
+                .into_series())
+            }),
+            _ => Err(DaftError::TypeError(format!(
+                "Cannot cast Interval to {dtype}"
+            ))),
+        }
+    }
+}
+
+impl MapArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Map(..) => {
+                let physical = self.physical.cast(&dtype.to_physical())?;
+                Ok(MapArray::new(
+                    Field::new(self.name(), dtype.clone()),
+                    physical.list()?.clone(),
+                )
+                .into_series())
+            }
+            DataType::List(..) => self.physical.cast(dtype),
+            #[cfg(feature = "python")]
+            DataType::Python => Python::with_gil(|py| {
+                let arrow_array = self.to_arrow().with_validity(None);
+                let pyarrow = py.import("pyarrow")?;
+                let py_array: Vec<PyObject> = ffi::to_py_array(arrow_array, py, pyarrow)?
+                    .call_method0(py, pyo3::intern!(py, "to_pylist"))?
+                    .extract(py)?;
+                let values_array =
+                    PseudoArrowArray::new(py_array.into(), self.physical.validity().cloned());
+                Ok(PythonArray::new(
+                    Field::new(self.name(), dtype.clone()).into(),
+                    values_array.to_boxed(),
+                )?
+                .into_series())
+            }),
+            _ => Err(DaftError::TypeError(format!("Cannot cast Map to {dtype}"))),
+        }
+    }
+}
+
 impl Decimal128Array {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
+            DataType::Utf8 => {
+                let DataType::Decimal128(precision, scale) = self.data_type() else {
+                    panic!("Wrong dtype for Decimal128Array: {}", self.data_type())
+                };
+                let decimal_str = self
+                    .physical
+                    .as_arrow()
+                    .iter()
+                    .map(|val| {
+                        val.map(|val| decimal128_to_str(*val, *precision as u8, *scale as i8))
+                    })
+                    .collect::<arrow2::array::Utf8Array<i64>>();
+                Ok(Utf8Array::from((self.name(), Box::new(decimal_str))).into_series())
+            }
             #[cfg(feature = "python")]
             DataType::Python => cast_logical_to_python_array(self, dtype),
             _ => arrow_logical_cast(self, dtype),
@@ -1261,6 +1429,8 @@ impl ImageArray {
                 let tensor_array = tensor_array.downcast::<TensorArray>()?;
                 tensor_array.cast(dtype)
             }
+            // Images are encoded losslessly.
+            DataType::Binary => Ok(self.encode(ImageFormat::PNG)?.into_series()),
             _ => self.physical.cast(dtype),
         }
     }
@@ -1269,6 +1439,7 @@ impl ImageArray {
 impl FixedShapeImageArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match (dtype, self.data_type()) {
+            (DataType::Binary, _) => Ok(self.encode(ImageFormat::PNG)?.into_series()),
             #[cfg(feature = "python")]
             (DataType::Python, DataType::FixedShapeImage(mode, height, width)) => {
                 let physical_arrow = self.physical.flat_child.to_arrow();
@@ -1744,6 +1915,14 @@ impl ListArray {
                     }
                 }
             }
+            DataType::Map(..) => {
+                let physical = self.cast(&dtype.to_physical())?;
+                Ok(MapArray::new(
+                    Field::new(self.name(), dtype.clone()),
+                    physical.list()?.clone(),
+                )
+                .into_series())
+            }
             _ => unimplemented!("List casting not implemented for dtype: {}", dtype),
         }
     }
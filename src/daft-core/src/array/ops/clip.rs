@@ -0,0 +1,43 @@
+use arrow2::array::PrimitiveArray;
+
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
+
+impl<T: DaftNumericType> DataArray<T>
+where
+    T::Native: PartialOrd,
+{
+    /// Clips each value to be at least `lower` and then at most `upper`.
+    ///
+    /// Null bounds leave values unbounded on that side, and null values stay null.
+    pub fn clip(&self, lower: &Self, upper: &Self) -> DaftResult<Self> {
+        if self.len() != lower.len() || self.len() != upper.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected clip bounds to have the same length as the input ({}), got {} and {}",
+                self.len(),
+                lower.len(),
+                upper.len()
+            )));
+        }
+        let result = self
+            .as_arrow()
+            .iter()
+            .zip(lower.as_arrow().iter())
+            .zip(upper.as_arrow().iter())
+            .map(|((v, lower), upper)| {
+                let mut v = *v?;
+                if let Some(lower) = lower && v < *lower {
+                    v = *lower;
+                }
+                if let Some(upper) = upper && v > *upper {
+                    v = *upper;
+                }
+                Some(v)
+            })
+            .collect::<PrimitiveArray<T::Native>>();
+        Ok(DataArray::from((self.name(), Box::new(result))))
+    }
+}
@@ -1,10 +1,10 @@
 use num_traits::{NumCast, ToPrimitive};
 
 use crate::{
-    array::DataArray,
+    array::{merge_dictionaries, CategoricalArray, DataArray},
     datatypes::{
         BinaryArray, BooleanArray, DaftArrowBackedType, DaftNumericType, DataType, NullArray,
-        Utf8Array,
+        UInt32Array, Utf8Array,
     },
     utils::arrow::arrow_bitmap_and_helper,
 };
@@ -870,6 +870,69 @@ impl DaftLogical<bool> for BooleanArray {
     }
 }
 
+impl CategoricalArray {
+    /// Codes of both arrays into their merged dictionary, so that codes are equal exactly when their
+    /// strings are. If `ordered`, codes are also ordered like their strings.
+    fn merged_codes(&self, rhs: &Self, ordered: bool) -> DaftResult<(UInt32Array, UInt32Array)> {
+        let (dictionary, mut code_mappings) = merge_dictionaries(&[self, rhs]);
+        if ordered {
+            let ranks = CategoricalArray::encode(&dictionary).ranks()?;
+            for mapping in code_mappings.iter_mut() {
+                for code in mapping.iter_mut() {
+                    *code = ranks.get(*code as usize).unwrap();
+                }
+            }
+        }
+        let recode = |array: &Self, mapping: &[u32]| {
+            let codes = array
+                .codes()
+                .as_arrow()
+                .iter()
+                .map(|code| code.map(|code| mapping[*code as usize]))
+                .collect::<Vec<_>>();
+            UInt32Array::from_iter(array.name(), codes.into_iter())
+        };
+        Ok((
+            recode(self, &code_mappings[0]),
+            recode(rhs, &code_mappings[1]),
+        ))
+    }
+}
+
+impl DaftCompare<&CategoricalArray> for CategoricalArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn equal(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, false)?;
+        lhs.equal(&rhs)
+    }
+
+    fn not_equal(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, false)?;
+        lhs.not_equal(&rhs)
+    }
+
+    fn lt(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, true)?;
+        lhs.lt(&rhs)
+    }
+
+    fn lte(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, true)?;
+        lhs.lte(&rhs)
+    }
+
+    fn gt(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, true)?;
+        lhs.gt(&rhs)
+    }
+
+    fn gte(&self, rhs: &CategoricalArray) -> Self::Output {
+        let (lhs, rhs) = self.merged_codes(rhs, true)?;
+        lhs.gte(&rhs)
+    }
+}
+
 impl DaftCompare<&Utf8Array> for Utf8Array {
     type Output = DaftResult<BooleanArray>;
 
@@ -1,6 +1,9 @@
-use crate::array::{
-    growable::{make_growable, Growable},
-    ListArray,
+use crate::{
+    array::{
+        growable::{make_growable, Growable},
+        ListArray,
+    },
+    datatypes::Utf8Array,
 };
 use arrow2::{bitmap::utils::SlicesIterator, offset::OffsetsBuffer, types::Index};
 use common_error::DaftResult;
@@ -56,6 +59,44 @@ impl DaftConcatAggable for crate::datatypes::PythonArray {
     }
 }
 
+impl DaftConcatAggable for Utf8Array {
+    type Output = DaftResult<Self>;
+    fn concat(&self) -> Self::Output {
+        self.grouped_concat(&vec![(0..self.len() as u64).collect()])
+    }
+
+    fn grouped_concat(&self, groups: &super::GroupIndices) -> Self::Output {
+        self.grouped_str_join(groups, "")
+    }
+}
+
+impl Utf8Array {
+    /// Joins the strings of each group with `separator`, in the order of the group's indices.
+    pub fn grouped_str_join(
+        &self,
+        groups: &super::GroupIndices,
+        separator: &str,
+    ) -> DaftResult<Self> {
+        let arrow_array = self.as_arrow();
+        // Like the list concat, nulls are skipped and only groups without any valid strings are null.
+        let joined = groups
+            .iter()
+            .map(|group| {
+                let mut valid = group
+                    .iter()
+                    .filter_map(|idx| arrow_array.get(idx.to_usize()));
+                let mut joined = valid.next()?.to_string();
+                for value in valid {
+                    joined.push_str(separator);
+                    joined.push_str(value);
+                }
+                Some(joined)
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(joined))))
+    }
+}
+
 impl DaftConcatAggable for ListArray {
     type Output = DaftResult<Self>;
     fn concat(&self) -> Self::Output {
@@ -153,8 +194,11 @@ mod test {
     use common_error::DaftResult;
 
     use crate::{
-        array::{ops::DaftConcatAggable, ListArray},
-        datatypes::{Field, Int64Array},
+        array::{
+            ops::{as_arrow::AsArrow, DaftConcatAggable},
+            ListArray,
+        },
+        datatypes::{Field, Int64Array, Utf8Array},
         DataType, IntoSeries,
     };
 
@@ -216,6 +260,31 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_grouped_utf8_concat_agg() -> DaftResult<()> {
+        let utf8_array = Utf8Array::from((
+            "foo",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a"),
+                None,
+                Some("b"),
+                None,
+                Some(""),
+            ])),
+        ));
+
+        let concatted = utf8_array.grouped_concat(&vec![vec![0, 1, 2], vec![3], vec![4]])?;
+        assert_eq!(
+            concatted.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("ab"), None, Some("")]
+        );
+        assert_eq!(
+            utf8_array.concat()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("ab")]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_grouped_list_concat_agg() -> DaftResult<()> {
         // [[0], [0, 0], [1, None], [None], [2, None], None, None, None]
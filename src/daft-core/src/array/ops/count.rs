@@ -3,7 +3,7 @@ use std::{iter::repeat, sync::Arc};
 use arrow2;
 
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     count_mode::CountMode,
     datatypes::*,
 };
@@ -128,3 +128,4 @@ macro_rules! impl_daft_count_aggable_nested_array {
 impl_daft_count_aggable_nested_array!(FixedSizeListArray);
 impl_daft_count_aggable_nested_array!(ListArray);
 impl_daft_count_aggable_nested_array!(StructArray);
+impl_daft_count_aggable_nested_array!(CategoricalArray);
@@ -0,0 +1,82 @@
+use arrow2::array::PrimitiveArray;
+
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+use common_error::DaftResult;
+
+use super::as_arrow::AsArrow;
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Folds the values in order, with `combine(state, value)` producing the value for each row.
+    /// Nulls stay null and are skipped over, so they don't affect the values of later rows.
+    fn running<F>(&self, combine: F) -> DaftResult<Self>
+    where
+        F: Fn(T::Native, T::Native) -> T::Native,
+    {
+        let mut state: Option<T::Native> = None;
+        let result = self
+            .as_arrow()
+            .iter()
+            .map(|v| {
+                let v = *v?;
+                let next = match state {
+                    Some(state) => combine(state, v),
+                    None => v,
+                };
+                state = Some(next);
+                Some(next)
+            })
+            .collect::<PrimitiveArray<T::Native>>();
+        Ok(DataArray::from((self.name(), Box::new(result))))
+    }
+
+    pub fn cumsum(&self) -> DaftResult<Self> {
+        self.running(|acc, v| acc + v)
+    }
+
+    pub fn cummax(&self) -> DaftResult<Self> {
+        self.running(|acc, v| if v > acc { v } else { acc })
+    }
+
+    pub fn cummin(&self) -> DaftResult<Self> {
+        self.running(|acc, v| if v < acc { v } else { acc })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{array::ops::as_arrow::AsArrow, datatypes::Int64Array};
+
+    #[test]
+    fn running_ops_skip_nulls() -> DaftResult<()> {
+        let array = Int64Array::from((
+            "a",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                None,
+                Some(3),
+                Some(1),
+                None,
+                Some(4),
+            ])),
+        ));
+        let values = |a: Int64Array| a.as_arrow().iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            values(array.cumsum()?),
+            [None, Some(3), Some(4), None, Some(8)]
+        );
+        assert_eq!(
+            values(array.cummax()?),
+            [None, Some(3), Some(3), None, Some(4)]
+        );
+        assert_eq!(
+            values(array.cummin()?),
+            [None, Some(3), Some(1), None, Some(1)]
+        );
+        Ok(())
+    }
+}
@@ -1,12 +1,12 @@
 use crate::{
     datatypes::{
         logical::{DateArray, TimeArray, TimestampArray},
-        Field, Int32Array, Int64Array, TimeUnit, UInt32Array,
+        Field, Int32Array, Int64Array, TimeUnit, Timezone, UInt32Array, Utf8Array,
     },
-    DataType,
+    DataType, DstResolution,
 };
 use arrow2::compute::arithmetics::ArraySub;
-use chrono::{NaiveDate, NaiveTime, Timelike};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
@@ -53,55 +53,52 @@ impl DateArray {
     }
 }
 
+/// The timestamp in `unit` since the epoch of the UTC datetime `dt`.
+fn naive_datetime_to_timestamp(dt: &NaiveDateTime, unit: &TimeUnit) -> i64 {
+    let (per_second, subsec) = match unit {
+        TimeUnit::Seconds => (1, 0),
+        TimeUnit::Milliseconds => (1_000, dt.timestamp_subsec_millis()),
+        TimeUnit::Microseconds => (1_000_000, dt.timestamp_subsec_micros()),
+        TimeUnit::Nanoseconds => (1_000_000_000, dt.timestamp_subsec_nanos()),
+    };
+    dt.timestamp() * per_second + subsec as i64
+}
+
 impl TimestampArray {
-    pub fn date(&self) -> DaftResult<DateArray> {
-        let physical = self.physical.as_arrow();
+    fn timeunit_and_timezone(&self) -> DaftResult<(&TimeUnit, Option<Timezone>)> {
         let DataType::Timestamp(timeunit, tz) = self.data_type() else {
             unreachable!("Timestamp array must have Timestamp datatype")
         };
+        Ok((timeunit, tz.as_deref().map(Timezone::parse).transpose()?))
+    }
+
+    /// The local datetime of each timestamp, in the timezone of the array if it has one.
+    fn local_datetimes(&self) -> DaftResult<Vec<Option<NaiveDateTime>>> {
+        let (timeunit, tz) = self.timeunit_and_timezone()?;
         let tu = timeunit.to_arrow();
+        Ok(self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| {
+                    let dt = arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu);
+                    match &tz {
+                        Some(tz) => tz.to_local(&dt),
+                        None => dt,
+                    }
+                })
+            })
+            .collect())
+    }
+
+    pub fn date(&self) -> DaftResult<DateArray> {
         let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-        let date_arrow = match tz {
-            Some(tz) => {
-                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
-                    Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                        physical.iter().map(|ts| {
-                            ts.map(|ts| {
-                                (arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz)
-                                    .date_naive()
-                                    - epoch_date)
-                                    .num_days() as i32
-                            })
-                        }),
-                    ))
-                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
-                    Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                        physical.iter().map(|ts| {
-                            ts.map(|ts| {
-                                (arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz)
-                                    .date_naive()
-                                    - epoch_date)
-                                    .num_days() as i32
-                            })
-                        }),
-                    ))
-                } else {
-                    Err(DaftError::TypeError(format!(
-                        "Cannot parse timezone in Timestamp datatype: {}",
-                        tz
-                    )))
-                }
-            }
-            None => Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                physical.iter().map(|ts| {
-                    ts.map(|ts| {
-                        (arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu).date()
-                            - epoch_date)
-                            .num_days() as i32
-                    })
-                }),
-            )),
-        }?;
+        let date_arrow = arrow2::array::PrimitiveArray::<i32>::from_iter(
+            self.local_datetimes()?
+                .into_iter()
+                .map(|dt| dt.map(|dt| (dt.date() - epoch_date).num_days() as i32)),
+        );
         Ok(DateArray::new(
             Field::new(self.name(), DataType::Date),
             Int32Array::from((self.name(), Box::new(date_arrow))),
@@ -109,52 +106,25 @@ impl TimestampArray {
     }
 
     pub fn time(&self, timeunit_for_cast: &TimeUnit) -> DaftResult<TimeArray> {
-        let physical = self.physical.as_arrow();
-        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
-            unreachable!("Timestamp array must have Timestamp datatype")
-        };
-        let tu = timeunit.to_arrow();
         if !matches!(
             timeunit_for_cast,
             TimeUnit::Microseconds | TimeUnit::Nanoseconds
         ) {
             return Err(DaftError::ValueError(format!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}")));
         }
-        let time_arrow = match tz {
-            Some(tz) => match arrow2::temporal_conversions::parse_offset(tz) {
-                Ok(tz) => Ok(arrow2::array::PrimitiveArray::<i64>::from_iter(
-                    physical.iter().map(|ts| {
-                        ts.map(|ts| {
-                            let dt =
-                                arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz);
-                                let time_delta = dt.time() - NaiveTime::from_hms_opt(0,0,0).unwrap();
-                                match timeunit_for_cast {
-                                    TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
-                                    TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
-                                    _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
-                                }
-                        })
-                    }),
-                )),
-                Err(e) => Err(DaftError::TypeError(format!(
-                    "Cannot parse timezone in Timestamp datatype: {}, error: {}",
-                    tz, e
-                ))),
-            },
-            None => Ok(arrow2::array::PrimitiveArray::<i64>::from_iter(
-                physical.iter().map(|ts| {
-                    ts.map(|ts| {
-                        let dt = arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu);
-                        let time_delta = dt.time() - NaiveTime::from_hms_opt(0,0,0).unwrap();
-                        match timeunit_for_cast {
-                            TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
-                            TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
-                            _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
-                        }
-                    })
-                }),
-            )),
-        }?;
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        let time_arrow = arrow2::array::PrimitiveArray::<i64>::from_iter(
+            self.local_datetimes()?.into_iter().map(|dt| {
+                dt.map(|dt| {
+                    let time_delta = dt.time() - midnight;
+                    match timeunit_for_cast {
+                        TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
+                        TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
+                        _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
+                    }
+                })
+            }),
+        );
         Ok(TimeArray::new(
             Field::new(self.name(), DataType::Time(*timeunit_for_cast)),
             Int64Array::from((self.name(), Box::new(time_arrow))),
@@ -162,37 +132,159 @@ impl TimestampArray {
     }
 
     pub fn hour(&self) -> DaftResult<UInt32Array> {
-        let physical = self.physical.as_arrow();
-        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
-            unreachable!("Timestamp array must have Timestamp datatype")
-        };
-        let tu = timeunit.to_arrow();
-        let date_arrow = match tz {
-            Some(tz) => match arrow2::temporal_conversions::parse_offset(tz) {
-                Ok(tz) => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                    |ts| {
-                        ts.map(|ts| {
-                            arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz).hour()
-                        })
-                    },
-                ))),
-                Err(e) => Err(DaftError::TypeError(format!(
-                    "Cannot parse timezone in Timestamp datatype: {}, error: {}",
-                    tz, e
-                ))),
-            },
-            None => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                |ts| {
-                    ts.map(|ts| {
-                        arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu).hour()
-                    })
-                },
-            ))),
-        }?;
+        let hour_arrow = arrow2::array::UInt32Array::from_iter(
+            self.local_datetimes()?
+                .into_iter()
+                .map(|dt| dt.map(|dt| dt.hour())),
+        );
+        UInt32Array::new(
+            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
+            Box::new(hour_arrow),
+        )
+    }
 
+    pub fn minute(&self) -> DaftResult<UInt32Array> {
+        let minute_arrow = arrow2::array::UInt32Array::from_iter(
+            self.local_datetimes()?
+                .into_iter()
+                .map(|dt| dt.map(|dt| dt.minute())),
+        );
         UInt32Array::new(
             std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
-            Box::new(date_arrow),
+            Box::new(minute_arrow),
         )
     }
+
+    /// Interprets each timestamp of a timestamp array without a timezone as a local time in `tz`,
+    /// resolving local times that are ambiguous or nonexistent because of daylight saving time
+    /// transitions with `ambiguous` and `nonexistent` respectively.
+    pub fn tz_localize(
+        &self,
+        tz: &str,
+        ambiguous: DstResolution,
+        nonexistent: DstResolution,
+    ) -> DaftResult<Self> {
+        let (timeunit, None) = self.timeunit_and_timezone()? else {
+            return Err(DaftError::TypeError(format!(
+                "Can only localize timestamps without a timezone, but received {}; use tz_convert to convert timestamps to another timezone",
+                self.data_type()
+            )));
+        };
+        let timezone = Timezone::parse(tz)?;
+        let timestamps = self
+            .local_datetimes()?
+            .into_iter()
+            .map(|dt| match dt {
+                Some(dt) => Ok(timezone
+                    .to_utc(&dt, ambiguous, nonexistent)?
+                    .map(|utc| naive_datetime_to_timestamp(&utc, timeunit))),
+                None => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(tz.to_string())),
+            ),
+            Int64Array::from_iter(self.name(), timestamps.into_iter()),
+        ))
+    }
+
+    /// Converts each timestamp of a timestamp array with a timezone to the same instant in `tz`.
+    pub fn tz_convert(&self, tz: &str) -> DaftResult<Self> {
+        let (timeunit, Some(_)) = self.timeunit_and_timezone()? else {
+            return Err(DaftError::TypeError(format!(
+                "Can only convert timestamps with a timezone, but received {}; use tz_localize to assign a timezone to timestamps",
+                self.data_type()
+            )));
+        };
+        Timezone::parse(tz)?;
+        // Timestamps with a timezone are stored as instants since the epoch in UTC.
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(tz.to_string())),
+            ),
+            self.physical.clone(),
+        ))
+    }
+}
+
+impl Utf8Array {
+    /// Parses each string as a time of day, e.g. `09:30`, `09:30:00` or `23:59:59.5`.
+    ///
+    /// Strings that aren't valid times are parsed as nulls, like when casting strings to dates.
+    pub fn to_time(&self, timeunit: &TimeUnit) -> DaftResult<TimeArray> {
+        if !matches!(timeunit, TimeUnit::Microseconds | TimeUnit::Nanoseconds) {
+            return Err(DaftError::ValueError(format!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit}")));
+        }
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        let time_arrow =
+            arrow2::array::PrimitiveArray::<i64>::from_iter(self.as_arrow().iter().map(|val| {
+                let val = val?.trim();
+                let time = NaiveTime::parse_from_str(val, "%H:%M:%S%.f")
+                    .or_else(|_| NaiveTime::parse_from_str(val, "%H:%M"))
+                    .ok()?;
+                let time_delta = time - midnight;
+                match timeunit {
+                    TimeUnit::Microseconds => time_delta.num_microseconds(),
+                    TimeUnit::Nanoseconds => time_delta.num_nanoseconds(),
+                    _ => unreachable!(),
+                }
+            }));
+        Ok(TimeArray::new(
+            Field::new(self.name(), DataType::Time(*timeunit)),
+            Int64Array::from((self.name(), Box::new(time_arrow))),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_tz_localize_and_convert() -> DaftResult<()> {
+        // 2023-11-05T01:30:00, which is ambiguous in America/New_York, and 2023-11-05T12:00:00.
+        let naive = TimestampArray::new(
+            Field::new("t", DataType::Timestamp(TimeUnit::Seconds, None)),
+            Int64Array::from(("t", vec![1_699_147_800, 1_699_185_600])),
+        );
+        assert!(naive
+            .tz_localize(
+                "America/New_York",
+                DstResolution::Raise,
+                DstResolution::Raise
+            )
+            .is_err());
+        let localized = naive.tz_localize(
+            "America/New_York",
+            DstResolution::Latest,
+            DstResolution::Raise,
+        )?;
+        assert_eq!(
+            localized.physical.as_arrow().values().as_slice(),
+            &[1_699_147_800 + 5 * 3600, 1_699_185_600 + 5 * 3600]
+        );
+        assert_eq!(localized.hour()?.as_arrow().values().as_slice(), &[1, 12]);
+
+        let converted = localized.tz_convert("Asia/Singapore")?;
+        assert_eq!(converted.physical.as_arrow(), localized.physical.as_arrow());
+        assert_eq!(converted.hour()?.as_arrow().values().as_slice(), &[14, 1]);
+        assert!(naive.tz_convert("UTC").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn utf8_to_time() -> DaftResult<()> {
+        let strings = Utf8Array::from(("t", &["09:30:00", "00:00:01.5", "25:00:00"][..]));
+        let times = strings.to_time(&TimeUnit::Microseconds)?;
+        assert_eq!(times.data_type(), &DataType::Time(TimeUnit::Microseconds));
+        assert_eq!(
+            times.physical.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(&34_200_000_000), Some(&1_500_000), None]
+        );
+        assert!(strings.to_time(&TimeUnit::Milliseconds).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,123 @@
+use arrow2;
+
+use crate::{
+    datatypes::{BinaryArray, Float64Array},
+    kernels::ddsketch::DDSketch,
+};
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftDDSketchAggable, DaftDDSketchMergeAggable, GroupIndices};
+
+fn sketches_to_binary_array(name: &str, sketches: &[DDSketch]) -> DaftResult<BinaryArray> {
+    let serialized = sketches
+        .iter()
+        .map(|sketch| sketch.to_bytes())
+        .collect::<DaftResult<Vec<_>>>()?;
+    let arrow_array = arrow2::array::BinaryArray::<i64>::from_iter_values(serialized.iter());
+    Ok(BinaryArray::from((name, Box::new(arrow_array))))
+}
+
+impl DaftDDSketchAggable for Float64Array {
+    type Output = DaftResult<BinaryArray>;
+
+    fn dd_sketch(&self) -> Self::Output {
+        let mut sketch = DDSketch::new();
+        for value in self.as_arrow().iter().flatten() {
+            sketch.add(*value);
+        }
+        sketches_to_binary_array(self.name(), &[sketch])
+    }
+
+    fn grouped_dd_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| {
+                let mut sketch = DDSketch::new();
+                for idx in g {
+                    if let Some(value) = arrow_array.get(*idx as usize) {
+                        sketch.add(value);
+                    }
+                }
+                sketch
+            })
+            .collect::<Vec<_>>();
+        sketches_to_binary_array(self.name(), &sketches)
+    }
+}
+
+impl DaftDDSketchMergeAggable for BinaryArray {
+    type Output = DaftResult<BinaryArray>;
+
+    fn dd_merge(&self) -> Self::Output {
+        let mut merged = DDSketch::new();
+        for bytes in self.as_arrow().iter().flatten() {
+            merged.merge(&DDSketch::from_bytes(bytes)?);
+        }
+        sketches_to_binary_array(self.name(), &[merged])
+    }
+
+    fn grouped_dd_merge(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| {
+                let mut merged = DDSketch::new();
+                for idx in g {
+                    if let Some(bytes) = arrow_array.get(*idx as usize) {
+                        merged.merge(&DDSketch::from_bytes(bytes)?);
+                    }
+                }
+                Ok(merged)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        sketches_to_binary_array(self.name(), &sketches)
+    }
+}
+
+impl BinaryArray {
+    /// Estimates the value at quantile `q` of each serialized DDSketch.
+    pub fn dd_sketch_quantile(&self, q: f64) -> DaftResult<Float64Array> {
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|bytes| match bytes {
+                Some(bytes) => DDSketch::from_bytes(bytes)?.quantile(q),
+                None => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_array = arrow2::array::PrimitiveArray::<f64>::from(values);
+        Ok(Float64Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftDDSketchAggable, DaftDDSketchMergeAggable},
+        datatypes::Float64Array,
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn sketch_and_merge_values() -> DaftResult<()> {
+        let values = Float64Array::from(("a", vec![1.0, 5.0, 2.0, 4.0, 3.0, 100.0]))
+            .with_validity_slice(&[true, true, true, true, true, false])?;
+        let sketch = values.dd_sketch()?;
+        let result: Vec<_> = sketch.dd_sketch_quantile(1.0)?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&5.0)]);
+
+        let sketches = values.grouped_dd_sketch(&vec![vec![0, 1], vec![2, 3, 4, 5], vec![]])?;
+        let result: Vec<_> = sketches
+            .dd_sketch_quantile(0.0)?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(result[..], [Some(&1.0), Some(&2.0), None]);
+
+        let merged = sketches.dd_merge()?;
+        let result: Vec<_> = merged.dd_sketch_quantile(0.0)?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&1.0)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{logical::EmbeddingArray, DataType, Float64Array},
+};
+
+impl EmbeddingArray {
+    /// Applies `op` to the values of each pair of embeddings, where an array of length 1 is
+    /// broadcast. The result is null where either embedding or any of their values is null.
+    fn pairwise_embedding_op(
+        &self,
+        rhs: &EmbeddingArray,
+        op: impl Fn(&[f64], &[f64]) -> f64,
+    ) -> DaftResult<Float64Array> {
+        if self.data_type() != rhs.data_type() {
+            return Err(DaftError::TypeError(format!(
+                "Expected embeddings of the same type, but received {} and {}",
+                self.data_type(),
+                rhs.data_type()
+            )));
+        }
+        let len = match (self.len(), rhs.len()) {
+            (a, b) if a == b => a,
+            (1, b) => b,
+            (a, 1) => a,
+            (a, b) => {
+                return Err(DaftError::ValueError(format!(
+                    "Cannot apply operation on embedding arrays of different lengths: {a} vs {b}"
+                )))
+            }
+        };
+        let size = self.physical.fixed_element_len();
+        let values = |array: &EmbeddingArray| -> DaftResult<Vec<Option<f64>>> {
+            let child = array.physical.flat_child.cast(&DataType::Float64)?;
+            Ok(child.f64()?.as_arrow().iter().map(|v| v.copied()).collect())
+        };
+        let (lhs_values, rhs_values) = (values(self)?, values(rhs)?);
+        let embedding = |array: &EmbeddingArray, values: &[Option<f64>], i: usize| {
+            let i = if array.len() == 1 { 0 } else { i };
+            if !array.physical.validity().map_or(true, |v| v.get_bit(i)) {
+                return None;
+            }
+            values[i * size..(i + 1) * size]
+                .iter()
+                .copied()
+                .collect::<Option<Vec<_>>>()
+        };
+        let result = (0..len)
+            .map(|i| {
+                let lhs = embedding(self, &lhs_values, i)?;
+                let rhs = embedding(rhs, &rhs_values, i)?;
+                Some(op(&lhs, &rhs))
+            })
+            .collect::<Vec<_>>();
+        Ok(Float64Array::from_iter(self.name(), result.into_iter()))
+    }
+
+    /// The dot product of each pair of embeddings.
+    pub fn dot(&self, rhs: &EmbeddingArray) -> DaftResult<Float64Array> {
+        self.pairwise_embedding_op(rhs, dot)
+    }
+
+    /// The cosine similarity of each pair of embeddings, which is NaN if either is all zeros.
+    pub fn cosine_similarity(&self, rhs: &EmbeddingArray) -> DaftResult<Float64Array> {
+        self.pairwise_embedding_op(rhs, |lhs, rhs| {
+            dot(lhs, rhs) / (dot(lhs, lhs).sqrt() * dot(rhs, rhs).sqrt())
+        })
+    }
+}
+
+fn dot(lhs: &[f64], rhs: &[f64]) -> f64 {
+    lhs.iter().zip(rhs).map(|(l, r)| l * r).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::FixedSizeListArray,
+        datatypes::{Field, Float32Array},
+        IntoSeries,
+    };
+
+    fn embeddings(name: &str, values: Vec<f32>, validity: Option<Vec<bool>>) -> EmbeddingArray {
+        let dtype = DataType::Embedding(Box::new(DataType::Float32), 2);
+        let physical = FixedSizeListArray::new(
+            Field::new(name, dtype.to_physical()),
+            Float32Array::from(("item", values)).into_series(),
+            validity.map(|v| v.into_iter().collect::<arrow2::bitmap::Bitmap>()),
+        );
+        EmbeddingArray::new(Field::new(name, dtype), physical)
+    }
+
+    #[test]
+    fn dot_and_cosine_similarity() -> DaftResult<()> {
+        let lhs = embeddings(
+            "a",
+            vec![1., 0., 3., 4., 1., 1., 0., 0.],
+            Some(vec![true, true, false, true]),
+        );
+        let rhs = embeddings("b", vec![2., 0.], None);
+
+        let dot = lhs.dot(&rhs)?;
+        assert_eq!(dot.name(), "a");
+        assert_eq!(
+            (0..4).map(|i| dot.get(i)).collect::<Vec<_>>(),
+            vec![Some(2.), Some(6.), None, Some(0.)]
+        );
+
+        let similarity = lhs.cosine_similarity(&rhs)?;
+        assert_eq!(similarity.get(0), Some(1.));
+        assert_eq!(similarity.get(1), Some(0.6));
+        assert_eq!(similarity.get(2), None);
+        assert!(similarity.get(3).unwrap().is_nan());
+
+        let other_size = EmbeddingArray::new(
+            Field::new("c", DataType::Embedding(Box::new(DataType::Float32), 1)),
+            FixedSizeListArray::new(
+                Field::new("c", DataType::FixedSizeList(Box::new(DataType::Float32), 1)),
+                Float32Array::from(("item", vec![1.])).into_series(),
+                None,
+            ),
+        );
+        assert!(lhs.dot(&other_size).is_err());
+        Ok(())
+    }
+}
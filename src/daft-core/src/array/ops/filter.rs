@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use crate::{
     array::{
         growable::{Growable, GrowableArray},
-        DataArray, FixedSizeListArray, ListArray, StructArray,
+        CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{BooleanArray, DaftArrayType, DaftArrowBackedType},
     DataType,
@@ -121,6 +121,12 @@ impl FixedSizeListArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        Ok(self.with_codes(self.codes().filter(mask)?))
+    }
+}
+
 impl StructArray {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
         generic_filter(self, mask, self.name(), self.data_type())
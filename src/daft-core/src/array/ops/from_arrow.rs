@@ -3,9 +3,10 @@ use std::sync::Arc;
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::LogicalArray, DaftDataType, DaftLogicalType, DaftPhysicalType, Field, FieldRef,
+        UInt32Array, Utf8Array,
     },
     DataType, Series,
 };
@@ -30,7 +31,17 @@ where
 {
     fn from_arrow(field: FieldRef, arrow_arr: Box<dyn arrow2::array::Array>) -> DaftResult<Self> {
         let data_array_field = Arc::new(Field::new(field.name.clone(), field.dtype.to_physical()));
-        let physical_arrow_arr = arrow_arr.to_type(data_array_field.dtype.to_arrow()?);
+        let physical_arrow_arr = match (&field.dtype, arrow_arr.data_type()) {
+            // Arrow intervals have a native layout, which is stored as a struct in Daft.
+            (DataType::Interval, arrow2::datatypes::DataType::Interval(_)) => {
+                crate::array::ops::interval::interval_to_physical_arrow(arrow_arr.as_ref())?
+            }
+            // Arrow maps have a native layout, which is stored as a list of structs in Daft.
+            (DataType::Map(..), arrow2::datatypes::DataType::Map(..)) => {
+                crate::array::ops::map::map_to_physical_arrow(arrow_arr.as_ref())?
+            }
+            _ => arrow_arr.to_type(data_array_field.dtype.to_arrow()?),
+        };
         let physical = <L::PhysicalType as DaftDataType>::ArrayType::from_arrow(
             data_array_field,
             physical_arrow_arr,
@@ -108,3 +119,35 @@ impl FromArrow for StructArray {
         }
     }
 }
+
+impl FromArrow for CategoricalArray {
+    fn from_arrow(field: FieldRef, arrow_arr: Box<dyn arrow2::array::Array>) -> DaftResult<Self> {
+        match (&field.dtype, arrow_arr.data_type()) {
+            (DataType::Categorical, arrow2::datatypes::DataType::Utf8 | arrow2::datatypes::DataType::LargeUtf8) => {
+                let arrow_arr = arrow2::compute::cast::cast(arrow_arr.as_ref(), &arrow2::datatypes::DataType::LargeUtf8, arrow2::compute::cast::CastOptions { wrapped: true, partial: false })?;
+                let utf8_array = Utf8Array::from_arrow(Arc::new(Field::new(field.name.clone(), DataType::Utf8)), arrow_arr)?;
+                Ok(CategoricalArray::encode(&utf8_array))
+            }
+            (DataType::Categorical, arrow2::datatypes::DataType::Dictionary(..)) => {
+                let arrow_arr = arrow2::compute::cast::cast(arrow_arr.as_ref(), &field.dtype.to_arrow()?, arrow2::compute::cast::CastOptions { wrapped: true, partial: false })?;
+                let arrow_arr = arrow_arr.as_any().downcast_ref::<arrow2::array::DictionaryArray<u32>>().unwrap();
+                let values = arrow_arr.values().as_any().downcast_ref::<arrow2::array::Utf8Array<i64>>().unwrap();
+
+                // Arrow dictionaries may contain nulls and duplicates, so map their codes to a dictionary of unique strings.
+                let mut dictionary = indexmap::IndexMap::<&str, u32>::new();
+                let code_mapping = values.iter().map(|v| v.map(|v| {
+                    let next_code = dictionary.len() as u32;
+                    *dictionary.entry(v).or_insert(next_code)
+                })).collect::<Vec<_>>();
+                let codes = arrow_arr.keys().iter().map(|code| code.and_then(|code| code_mapping[*code as usize])).collect::<Vec<_>>();
+                let dictionary = dictionary.into_keys().map(Some).collect::<Vec<_>>();
+                Ok(CategoricalArray::new(
+                    field.clone(),
+                    UInt32Array::from_iter(field.name.as_str(), codes.into_iter()),
+                    Utf8Array::from_iter("dictionary", dictionary.into_iter()),
+                ))
+            }
+            (d, a) => Err(DaftError::TypeError(format!("Attempting to create Daft CategoricalArray with type {} from arrow array with type {:?}", d, a)))
+        }
+    }
+}
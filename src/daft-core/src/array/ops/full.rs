@@ -6,10 +6,12 @@ use pyo3::Python;
 
 use crate::{
     array::{
-        pseudo_arrow::PseudoArrowArray, DataArray, FixedSizeListArray, ListArray, StructArray,
+        pseudo_arrow::PseudoArrowArray, CategoricalArray, DataArray, FixedSizeListArray, ListArray,
+        StructArray,
     },
     datatypes::{
         logical::LogicalArray, DaftDataType, DaftLogicalType, DaftPhysicalType, DataType, Field,
+        UInt32Array, Utf8Array,
     },
     Series,
 };
@@ -184,6 +186,33 @@ impl FullNull for StructArray {
     }
 }
 
+impl FullNull for CategoricalArray {
+    fn full_null(name: &str, dtype: &DataType, length: usize) -> Self {
+        match dtype {
+            DataType::Categorical => Self::new(
+                Field::new(name, dtype.clone()),
+                UInt32Array::full_null(name, &DataType::UInt32, length),
+                Utf8Array::empty("dictionary", &DataType::Utf8),
+            ),
+            _ => panic!(
+                "Cannot create CategoricalArray::full_null from datatype: {}",
+                dtype
+            ),
+        }
+    }
+
+    fn empty(name: &str, dtype: &DataType) -> Self {
+        match dtype {
+            DataType::Categorical => Self::new(
+                Field::new(name, dtype.clone()),
+                UInt32Array::empty(name, &DataType::UInt32),
+                Utf8Array::empty("dictionary", &DataType::Utf8),
+            ),
+            _ => panic!("Cannot create empty CategoricalArray with dtype: {}", dtype),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_error::DaftResult;
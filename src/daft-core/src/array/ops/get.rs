@@ -1,8 +1,9 @@
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray},
     datatypes::{
         logical::{
-            DateArray, Decimal128Array, DurationArray, LogicalArrayImpl, TimeArray, TimestampArray,
+            DateArray, Decimal128Array, DurationArray, IntervalArray, LogicalArrayImpl, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftLogicalType, DaftNumericType, ExtensionArray, NullArray,
         Utf8Array,
@@ -63,6 +64,15 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, FixedSizeListArray> {
     }
 }
 
+impl CategoricalArray {
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        self.codes()
+            .get(idx)
+            .map(|code| self.dictionary().get(code as usize).unwrap())
+    }
+}
+
 impl_array_arrow_get!(Utf8Array, &str);
 impl_array_arrow_get!(BooleanArray, bool);
 impl_array_arrow_get!(BinaryArray, &[u8]);
@@ -72,6 +82,29 @@ impl_array_arrow_get!(TimeArray, i64);
 impl_array_arrow_get!(DurationArray, i64);
 impl_array_arrow_get!(TimestampArray, i64);
 
+impl IntervalArray {
+    /// Gets the (months, days, nanoseconds) of the interval at `idx`.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<(i32, i32, i64)> {
+        if idx >= self.len() {
+            panic!("Out of bounds: {} vs len: {}", idx, self.len())
+        }
+        let is_valid = self
+            .physical
+            .validity()
+            .map_or(true, |validity| validity.get_bit(idx));
+        if !is_valid {
+            return None;
+        }
+        let children = &self.physical.children;
+        Some((
+            children[0].i32().ok()?.get(idx)?,
+            children[1].i32().ok()?.get(idx)?,
+            children[2].i64().ok()?.get(idx)?,
+        ))
+    }
+}
+
 impl NullArray {
     #[inline]
     pub fn get(&self, idx: usize) -> Option<()> {
@@ -4,7 +4,7 @@ use arrow2::array::Array;
 use fnv::FnvHashMap;
 
 use crate::{
-    array::DataArray,
+    array::{CategoricalArray, DataArray},
     datatypes::{
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, Float32Array, Float64Array,
         NullArray, Utf8Array,
@@ -38,15 +38,10 @@ where
             }
         }
     }
-    let mut s_indices = Vec::with_capacity(tbl.len());
-    let mut g_indices = Vec::with_capacity(tbl.len());
-
-    for (s_idx, g_idx) in tbl.into_values() {
-        s_indices.push(s_idx);
-        g_indices.push(g_idx);
-    }
-
-    Ok((s_indices, g_indices))
+    // Groups are returned in order of first occurrence, so that results are deterministic.
+    let mut groups = tbl.into_values().collect::<Vec<_>>();
+    groups.sort_unstable_by_key(|(s_idx, _)| *s_idx);
+    Ok(groups.into_iter().unzip())
 }
 
 impl<T> IntoGroups for DataArray<T>
@@ -159,3 +154,9 @@ impl IntoGroups for NullArray {
         Ok((vec![0], vec![v]))
     }
 }
+
+impl IntoGroups for CategoricalArray {
+    fn make_groups(&self) -> DaftResult<super::GroupIndicesPair> {
+        self.codes().make_groups()
+    }
+}
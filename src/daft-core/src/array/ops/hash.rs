@@ -1,16 +1,19 @@
 use crate::{
-    array::DataArray,
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{DateArray, Decimal128Array, TimeArray, TimestampArray},
         BinaryArray, BooleanArray, DaftNumericType, Int16Array, Int32Array, Int64Array, Int8Array,
         NullArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array, Utf8Array,
     },
     kernels,
+    series::Series,
+    DataType,
 };
 
 use common_error::DaftResult;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
 
-use super::as_arrow::AsArrow;
+use super::{as_arrow::AsArrow, full::FullNull};
 
 impl<T> DataArray<T>
 where
@@ -218,3 +221,106 @@ fn murmur3_32_hash_from_iter_no_nulls<B: AsRef<[u8]>>(
         .collect::<Vec<_>>();
     Ok(Int32Array::from((name, hashes)))
 }
+
+impl CategoricalArray {
+    /// Hashes the strings of the elements, so that hashes match those of the decoded strings.
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        if seed.is_some() || self.codes().data().null_count() > 0 {
+            return self.decode()?.hash(seed);
+        }
+        // Without nulls or seeds, each string of the dictionary only needs to be hashed once.
+        Ok(self
+            .dictionary()
+            .hash(None)?
+            .take(self.codes())?
+            .rename(self.name()))
+    }
+}
+
+impl StructArray {
+    /// Hashes the fields of each struct in order, chaining each field's hash into the next as its
+    /// seed. Null structs hash like nulls of other types.
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let hashes = match self.children.split_first() {
+            Some((first, rest)) => {
+                let mut hashes = first.hash(seed)?;
+                for child in rest {
+                    hashes = child.hash(Some(&hashes))?;
+                }
+                hashes
+            }
+            None => NullArray::full_null(self.name(), &DataType::Null, self.len()).hash(seed)?,
+        };
+        let hashes = match self.validity() {
+            Some(validity) => hashes
+                .as_arrow()
+                .values_iter()
+                .zip(validity.iter())
+                .enumerate()
+                .map(
+                    |(i, (h, valid))| {
+                        if valid {
+                            *h
+                        } else {
+                            null_hash(seed, i)
+                        }
+                    },
+                )
+                .collect::<Vec<_>>(),
+            None => hashes.as_arrow().values().to_vec(),
+        };
+        Ok(UInt64Array::from((self.name(), hashes)))
+    }
+}
+
+impl ListArray {
+    /// Hashes the hashes of the elements of each list, so that equal lists hash equally.
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let ranges = (0..self.len()).map(|i| self.offsets().start_end(i));
+        hash_sublists(self.name(), &self.flat_child, ranges, self.validity(), seed)
+    }
+}
+
+impl FixedSizeListArray {
+    /// Hashes the hashes of the elements of each list, so that equal lists hash equally.
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        let size = self.fixed_element_len();
+        let ranges = (0..self.len()).map(|i| (i * size, (i + 1) * size));
+        hash_sublists(self.name(), &self.flat_child, ranges, self.validity(), seed)
+    }
+}
+
+/// The hash of a null, which is the hash of no bytes.
+fn null_hash(seed: Option<&UInt64Array>, idx: usize) -> u64 {
+    let seed = seed.map_or(0, |s| s.as_arrow().value(idx));
+    xxh3_64_with_seed(b"", seed)
+}
+
+/// Hashes each `[start, end)` range of `flat_child` by hashing the number of elements followed by
+/// the hashes of the elements, so that empty lists don't hash like nulls.
+fn hash_sublists(
+    name: &str,
+    flat_child: &Series,
+    ranges: impl Iterator<Item = (usize, usize)>,
+    validity: Option<&arrow2::bitmap::Bitmap>,
+    seed: Option<&UInt64Array>,
+) -> DaftResult<UInt64Array> {
+    let child_hashes = flat_child.hash(None)?;
+    let child_hashes = child_hashes.as_arrow().values();
+    let hashes = ranges
+        .enumerate()
+        .map(|(i, (start, end))| {
+            if validity.is_some_and(|v| !v.get_bit(i)) {
+                return null_hash(seed, i);
+            }
+            let mut bytes = Vec::with_capacity((end - start + 1) * 8);
+            bytes.extend_from_slice(&((end - start) as u64).to_le_bytes());
+            for h in &child_hashes[start..end] {
+                bytes.extend_from_slice(&h.to_le_bytes());
+            }
+            let seed = seed.map_or(0, |s| s.as_arrow().value(i));
+            xxh3_64_with_seed(&bytes, seed)
+        })
+        .collect::<Vec<_>>();
+    Ok(UInt64Array::from((name, hashes)))
+}
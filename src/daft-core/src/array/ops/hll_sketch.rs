@@ -0,0 +1,119 @@
+use arrow2;
+
+use crate::{
+    datatypes::{BinaryArray, UInt64Array},
+    kernels::hyperloglog::HyperLogLog,
+};
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftHllMergeAggable, DaftHllSketchAggable, GroupIndices};
+
+fn sketches_to_binary_array(name: &str, sketches: &[HyperLogLog]) -> BinaryArray {
+    let arrow_array = arrow2::array::BinaryArray::<i64>::from_iter_values(
+        sketches.iter().map(|sketch| sketch.as_bytes()),
+    );
+    BinaryArray::from((name, Box::new(arrow_array)))
+}
+
+impl DaftHllSketchAggable for UInt64Array {
+    type Output = DaftResult<BinaryArray>;
+
+    fn hll_sketch(&self) -> Self::Output {
+        let mut sketch = HyperLogLog::new();
+        for hash in self.as_arrow().iter().flatten() {
+            sketch.add_hash(*hash);
+        }
+        Ok(sketches_to_binary_array(self.name(), &[sketch]))
+    }
+
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| {
+                let mut sketch = HyperLogLog::new();
+                for idx in g {
+                    if let Some(hash) = arrow_array.get(*idx as usize) {
+                        sketch.add_hash(hash);
+                    }
+                }
+                sketch
+            })
+            .collect::<Vec<_>>();
+        Ok(sketches_to_binary_array(self.name(), &sketches))
+    }
+}
+
+impl DaftHllMergeAggable for BinaryArray {
+    type Output = DaftResult<BinaryArray>;
+
+    fn hll_merge(&self) -> Self::Output {
+        let mut merged = HyperLogLog::new();
+        for bytes in self.as_arrow().iter().flatten() {
+            merged.merge(&HyperLogLog::from_bytes(bytes)?);
+        }
+        Ok(sketches_to_binary_array(self.name(), &[merged]))
+    }
+
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| {
+                let mut merged = HyperLogLog::new();
+                for idx in g {
+                    if let Some(bytes) = arrow_array.get(*idx as usize) {
+                        merged.merge(&HyperLogLog::from_bytes(bytes)?);
+                    }
+                }
+                Ok(merged)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(sketches_to_binary_array(self.name(), &sketches))
+    }
+}
+
+impl BinaryArray {
+    /// Estimates the number of distinct values summarized by each serialized HyperLogLog sketch.
+    pub fn hll_cardinality(&self) -> DaftResult<UInt64Array> {
+        let counts = self
+            .as_arrow()
+            .iter()
+            .map(|bytes| {
+                bytes
+                    .map(|bytes| Ok(HyperLogLog::from_bytes(bytes)?.count()))
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_array = arrow2::array::PrimitiveArray::<u64>::from(counts);
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftHllMergeAggable, DaftHllSketchAggable},
+        datatypes::UInt64Array,
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn sketch_and_merge_hashes() -> DaftResult<()> {
+        let hashes =
+            UInt64Array::from(("a", vec![1u64 << 63, 1 << 62, 1 << 63, 1 << 61, 1 << 62, 3]))
+                .with_validity_slice(&[true, true, true, true, true, false])?;
+        let sketch = hashes.hll_sketch()?;
+        let result: Vec<_> = sketch.hll_cardinality()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&3)]);
+
+        let sketches = hashes.grouped_hll_sketch(&vec![vec![0, 1, 5], vec![2, 3, 4]])?;
+        let result: Vec<_> = sketches.hll_cardinality()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&2), Some(&3)]);
+
+        let merged = sketches.hll_merge()?;
+        let result: Vec<_> = merged.hll_cardinality()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&3)]);
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
 use crate::array::growable::{Growable, GrowableArray};
 use crate::array::ops::full::FullNull;
-use crate::array::{DataArray, FixedSizeListArray, ListArray, StructArray};
+use crate::array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray};
 use crate::datatypes::{BooleanArray, DaftPhysicalType};
 use crate::{DataType, IntoSeries, Series};
 use arrow2::array::Array;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
 
@@ -17,18 +17,33 @@ fn generic_if_else<T: GrowableArray + FullNull + Clone + IntoSeries>(
     lhs_len: usize,
     rhs_len: usize,
 ) -> DaftResult<Series> {
+    // Each of the predicate, lhs and rhs is either broadcast from length 1 or of the output length.
+    let lens = [predicate.len(), lhs_len, rhs_len];
+    let output_len = lens.into_iter().find(|len| *len != 1).unwrap_or(1);
+    if lens.iter().any(|len| *len != 1 && *len != output_len) {
+        return Err(DaftError::ValueError(format!(
+            "if_else expects the predicate, if_true and if_false to be of length 1 or of the same length, but received {} vs {} vs {}",
+            predicate.len(),
+            lhs_len,
+            rhs_len
+        )));
+    }
+
     // CASE 1: Broadcast predicate
     if predicate.len() == 1 {
-        return match predicate.get(0) {
-            None => Ok(T::full_null(name, dtype, lhs_len).into_series()),
-            Some(predicate_scalar_value) => {
-                if predicate_scalar_value {
-                    Ok(lhs.clone().into_series())
-                } else {
-                    Ok(rhs.clone().into_series())
-                }
-            }
+        let (array, array_idx, array_len) = match predicate.get(0) {
+            None => return Ok(T::full_null(name, dtype, output_len).into_series()),
+            Some(true) => (lhs, 0, lhs_len),
+            Some(false) => (rhs, 1, rhs_len),
         };
+        if array_len == output_len {
+            return Ok(array.clone().into_series());
+        }
+        let mut growable = T::make_growable(name, dtype, vec![lhs, rhs], false, output_len);
+        for _ in 0..output_len {
+            growable.extend(array_idx, 0, 1);
+        }
+        return growable.build();
     }
 
     // Build the result using a Growable
@@ -159,3 +174,4 @@ macro_rules! impl_if_else_nested_array {
 impl_if_else_nested_array!(ListArray);
 impl_if_else_nested_array!(FixedSizeListArray);
 impl_if_else_nested_array!(StructArray);
+impl_if_else_nested_array!(CategoricalArray);
@@ -163,9 +163,25 @@ impl<'a> DaftImageBuffer<'a> {
     }
 
     pub fn decode(bytes: &[u8]) -> DaftResult<Self> {
-        image::load_from_memory(bytes)
-            .map(|v| v.into())
-            .map_err(|e| DaftError::ValueError(format!("Decoding image from bytes failed: {}", e)))
+        Self::decode_to_mode(bytes, None)
+    }
+
+    /// Decodes an image from bytes, converting it to `mode` if given.
+    pub fn decode_to_mode(bytes: &[u8], mode: Option<ImageMode>) -> DaftResult<Self> {
+        let image = image::load_from_memory(bytes).map_err(|e| {
+            DaftError::ValueError(format!("Decoding image from bytes failed: {}", e))
+        })?;
+        let image = match mode {
+            None => image,
+            Some(ImageMode::L) => DynamicImage::ImageLuma8(image.to_luma8()),
+            Some(ImageMode::LA) => DynamicImage::ImageLumaA8(image.to_luma_alpha8()),
+            Some(ImageMode::RGB) => DynamicImage::ImageRgb8(image.to_rgb8()),
+            Some(ImageMode::RGBA) => DynamicImage::ImageRgba8(image.to_rgba8()),
+            Some(mode) => return Err(DaftError::ValueError(format!(
+                "Decoding images into mode {mode} is not supported, only 8-bit modes are supported"
+            ))),
+        };
+        Ok(image.into())
     }
 
     pub fn encode<W>(&self, image_format: ImageFormat, writer: &mut W) -> DaftResult<()>
@@ -792,6 +808,11 @@ where
 
 impl BinaryArray {
     pub fn image_decode(&self) -> DaftResult<ImageArray> {
+        self.image_decode_to_mode(None)
+    }
+
+    /// Decodes images from their encoded bytes, converting them all to `mode` if given.
+    pub fn image_decode_to_mode(&self, mode: Option<ImageMode>) -> DaftResult<ImageArray> {
         let arrow_array = self
             .data()
             .as_any()
@@ -802,7 +823,9 @@ impl BinaryArray {
         // Load images from binary buffers.
         // Confirm that all images have the same value dtype.
         for row in arrow_array.iter() {
-            let img_buf = row.map(DaftImageBuffer::decode).transpose()?;
+            let img_buf = row
+                .map(|bytes| DaftImageBuffer::decode_to_mode(bytes, mode))
+                .transpose()?;
             let dtype = img_buf.as_ref().map(|im| im.mode().get_dtype());
             match (dtype.as_ref(), cached_dtype.as_ref()) {
                 (Some(t1), Some(t2)) => {
@@ -820,7 +843,7 @@ impl BinaryArray {
         // Fall back to UInt8 dtype if series is all nulls.
         let cached_dtype = cached_dtype.unwrap_or(DataType::UInt8);
         match cached_dtype {
-            DataType::UInt8 => Ok(ImageArray::from_daft_image_buffers(self.name(), img_bufs.as_slice(), &None)?),
+            DataType::UInt8 => Ok(ImageArray::from_daft_image_buffers(self.name(), img_bufs.as_slice(), &mode)?),
             _ => unimplemented!("Decoding images of dtype {cached_dtype:?} is not supported, only uint8 images are supported."),
         }
     }
@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, PrimitiveArray},
+    datatypes::{DataType as ArrowType, IntervalUnit},
+    types::months_days_ns,
+};
+use chrono::{Duration, LocalResult, Months, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::from_arrow::FromArrow,
+    datatypes::{
+        logical::{DateArray, IntervalArray, TimestampArray},
+        Field, Int32Array, Int64Array, TimeUnit,
+    },
+    DataType,
+};
+
+const NANOSECONDS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Converts an arrow2 month/day/nanosecond interval array into the struct layout of the physical
+/// Interval type.
+pub(crate) fn interval_to_physical_arrow(arrow_arr: &dyn Array) -> DaftResult<Box<dyn Array>> {
+    let intervals = arrow_arr
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .ok_or_else(|| {
+            DaftError::TypeError(format!(
+                "Expected arrow interval array, got {:?}",
+                arrow_arr.data_type()
+            ))
+        })?;
+    let months = intervals
+        .values_iter()
+        .map(|v| v.months())
+        .collect::<Vec<_>>();
+    let days = intervals
+        .values_iter()
+        .map(|v| v.days())
+        .collect::<Vec<_>>();
+    let ns = intervals.values_iter().map(|v| v.ns()).collect::<Vec<_>>();
+    Ok(arrow2::array::StructArray::new(
+        DataType::Interval.to_physical().to_arrow()?,
+        vec![
+            PrimitiveArray::from_vec(months).boxed(),
+            PrimitiveArray::from_vec(days).boxed(),
+            PrimitiveArray::from_vec(ns).boxed(),
+        ],
+        intervals.validity().cloned(),
+    )
+    .boxed())
+}
+
+/// Converts the struct layout of the physical Interval type into an arrow2 month/day/nanosecond
+/// interval array.
+pub(crate) fn physical_arrow_to_interval(arrow_arr: &dyn Array) -> Box<dyn Array> {
+    let physical = arrow_arr
+        .as_any()
+        .downcast_ref::<arrow2::array::StructArray>()
+        .unwrap();
+    let child = |i: usize| physical.values()[i].as_any();
+    let months = child(0).downcast_ref::<PrimitiveArray<i32>>().unwrap();
+    let days = child(1).downcast_ref::<PrimitiveArray<i32>>().unwrap();
+    let ns = child(2).downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    let values = months
+        .values_iter()
+        .zip(days.values_iter())
+        .zip(ns.values_iter())
+        .map(|((m, d), ns)| months_days_ns::new(*m, *d, *ns))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        ArrowType::Interval(IntervalUnit::MonthDayNano),
+        values.into(),
+        physical.validity().cloned(),
+    )
+    .boxed()
+}
+
+impl IntervalArray {
+    /// Creates an interval array from (months, days, nanoseconds) triples.
+    pub fn from_values(
+        name: &str,
+        values: impl Iterator<Item = Option<(i32, i32, i64)>>,
+    ) -> DaftResult<Self> {
+        let arrow_arr = values
+            .map(|v| v.map(|(months, days, ns)| months_days_ns::new(months, days, ns)))
+            .collect::<PrimitiveArray<months_days_ns>>()
+            .to(ArrowType::Interval(IntervalUnit::MonthDayNano));
+        Self::from_arrow(
+            Arc::new(Field::new(name, DataType::Interval)),
+            arrow_arr.boxed(),
+        )
+    }
+}
+
+/// Length of the result of a binary operation between arrays of these lengths, where arrays of
+/// length 1 are broadcast.
+fn broadcast_len(lhs_len: usize, rhs_len: usize) -> DaftResult<usize> {
+    match (lhs_len, rhs_len) {
+        (a, b) if a == b => Ok(a),
+        (1, b) => Ok(b),
+        (a, 1) => Ok(a),
+        (a, b) => Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+        ))),
+    }
+}
+
+/// Adds months and then days to a wall-clock datetime. Adding months clamps to the last day of the
+/// resulting month, e.g. Jan 31 + 1 month is the last day of February.
+fn add_calendar(datetime: NaiveDateTime, months: i64, days: i64) -> Option<NaiveDateTime> {
+    let datetime = if months >= 0 {
+        datetime.checked_add_months(Months::new(u32::try_from(months).ok()?))?
+    } else {
+        datetime.checked_sub_months(Months::new(u32::try_from(-months).ok()?))?
+    };
+    datetime.checked_add_signed(Duration::days(days))
+}
+
+/// Adds an interval (or subtracts it, if `negate`) to a timestamp in the given timezone. Months and
+/// days are applied to the wall-clock time in the timezone, and nanoseconds as an absolute duration.
+fn add_interval_in_tz<Tz: TimeZone>(
+    timestamp: i64,
+    unit: &TimeUnit,
+    tz: &Tz,
+    (months, days, ns): (i32, i32, i64),
+    negate: bool,
+) -> Option<i64> {
+    let sign = if negate { -1 } else { 1 };
+    let datetime =
+        arrow2::temporal_conversions::timestamp_to_datetime(timestamp, unit.to_arrow(), tz);
+    let local = add_calendar(
+        datetime.naive_local(),
+        sign * months as i64,
+        sign * days as i64,
+    )?;
+    let datetime = match tz.from_local_datetime(&local) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+        // The wall-clock time falls into a gap (e.g. a DST transition), so keep the original offset.
+        LocalResult::None => {
+            let offset = datetime.offset().fix().local_minus_utc() as i64;
+            tz.from_utc_datetime(&(local - Duration::seconds(offset)))
+        }
+    };
+    let datetime = datetime.checked_add_signed(Duration::nanoseconds(ns.checked_mul(sign)?))?;
+    match unit {
+        TimeUnit::Seconds => Some(datetime.timestamp()),
+        TimeUnit::Milliseconds => Some(datetime.timestamp_millis()),
+        TimeUnit::Microseconds => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanoseconds => datetime.timestamp_nanos_opt(),
+    }
+}
+
+impl DateArray {
+    /// Adds an interval (or subtracts it, if `negate`) to each date. Months are applied before days,
+    /// and nanoseconds are truncated to whole days. Dates that overflow are null.
+    pub fn add_interval(&self, interval: &IntervalArray, negate: bool) -> DaftResult<DateArray> {
+        let len = broadcast_len(self.len(), interval.len())?;
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let sign = if negate { -1 } else { 1 };
+        let result = (0..len)
+            .map(|i| {
+                let date = self.physical.get(if self.len() == 1 { 0 } else { i })?;
+                let (months, days, ns) = interval.get(if interval.len() == 1 { 0 } else { i })?;
+                let days = days as i64 + ns / NANOSECONDS_PER_DAY;
+                let datetime = epoch.checked_add_signed(Duration::days(date as i64))?;
+                let datetime = add_calendar(datetime, sign * months as i64, sign * days)?;
+                i32::try_from((datetime - epoch).num_days()).ok()
+            })
+            .collect::<PrimitiveArray<i32>>();
+        Ok(DateArray::new(
+            Field::new(self.name(), DataType::Date),
+            Int32Array::from((self.name(), Box::new(result))),
+        ))
+    }
+}
+
+impl TimestampArray {
+    /// Adds an interval (or subtracts it, if `negate`) to each timestamp. Months and days are
+    /// calendar-aware in the timestamp's timezone, e.g. adding a day across a DST transition keeps the
+    /// wall-clock time. Timestamps that overflow are null.
+    pub fn add_interval(
+        &self,
+        interval: &IntervalArray,
+        negate: bool,
+    ) -> DaftResult<TimestampArray> {
+        let DataType::Timestamp(unit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let len = broadcast_len(self.len(), interval.len())?;
+        let apply = |f: &dyn Fn(i64, (i32, i32, i64)) -> Option<i64>| {
+            (0..len)
+                .map(|i| {
+                    let timestamp = self.physical.get(if self.len() == 1 { 0 } else { i })?;
+                    let interval = interval.get(if interval.len() == 1 { 0 } else { i })?;
+                    f(timestamp, interval)
+                })
+                .collect::<PrimitiveArray<i64>>()
+        };
+        let result = match tz {
+            None => {
+                apply(&|ts, interval| add_interval_in_tz(ts, unit, &chrono::Utc, interval, negate))
+            }
+            Some(tz) => {
+                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
+                    apply(&|ts, interval| add_interval_in_tz(ts, unit, &tz, interval, negate))
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+                    apply(&|ts, interval| add_interval_in_tz(ts, unit, &tz, interval, negate))
+                } else {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot parse timezone in Timestamp datatype: {}",
+                        tz
+                    )));
+                }
+            }
+        };
+        Ok(TimestampArray::new(
+            self.field.clone(),
+            Int64Array::from((self.name(), Box::new(result))),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(months: i32, days: i32, ns: i64) -> DaftResult<IntervalArray> {
+        IntervalArray::from_values("interval", std::iter::once(Some((months, days, ns))))
+    }
+
+    #[test]
+    fn date_add_interval() -> DaftResult<()> {
+        // 2024-01-31, 2023-01-31 and 2024-03-31.
+        let dates = DateArray::new(
+            Field::new("date", DataType::Date),
+            Int32Array::from(("date", vec![19753, 19388, 19813])),
+        );
+        let result = dates.add_interval(&interval(1, 0, 0)?, false)?;
+        // 2024-02-29, 2023-02-28 and 2024-04-30.
+        assert_eq!(
+            (0..3).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(19782), Some(19416), Some(19843)]
+        );
+        let result = dates.add_interval(&interval(1, 1, NANOSECONDS_PER_DAY)?, true)?;
+        // 2023-12-29, 2022-12-29 and 2024-02-27.
+        assert_eq!(
+            (0..3).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(19720), Some(19355), Some(19780)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_add_interval_across_dst() -> DaftResult<()> {
+        // 2024-03-09T12:00:00 in America/New_York, the day before DST starts.
+        let timestamps = TimestampArray::new(
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Seconds, Some("America/New_York".to_string())),
+            ),
+            Int64Array::from(("ts", vec![1_710_003_600])),
+        );
+        // A calendar day keeps the wall-clock time, so it is only 23 hours long here.
+        let result = timestamps.add_interval(&interval(0, 1, 0)?, false)?;
+        assert_eq!(result.get(0), Some(1_710_003_600 + 23 * 3600));
+        let result = timestamps.add_interval(&interval(0, 0, NANOSECONDS_PER_DAY)?, false)?;
+        assert_eq!(result.get(0), Some(1_710_003_600 + 24 * 3600));
+        Ok(())
+    }
+}
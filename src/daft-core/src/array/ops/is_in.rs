@@ -11,7 +11,7 @@ use super::as_arrow::AsArrow;
 use super::{full::FullNull, DaftIsIn};
 use crate::utils::hashable_float_wrapper::FloatWrapper;
 use common_error::DaftResult;
-use std::collections::{BTreeSet, HashSet};
+use std::collections::HashSet;
 
 macro_rules! collect_to_set_and_check_membership {
     ($self:expr, $rhs:expr) => {{
@@ -31,7 +31,6 @@ macro_rules! collect_to_set_and_check_membership {
 impl<T> DaftIsIn<&DataArray<T>> for DataArray<T>
 where
     T: DaftIntegerType,
-    <T as DaftNumericType>::Native: Ord,
     <T as DaftNumericType>::Native: std::hash::Hash,
     <T as DaftNumericType>::Native: std::cmp::Eq,
 {
@@ -51,8 +50,13 @@ macro_rules! impl_is_in_floating_array {
                 let set = rhs
                     .as_arrow()
                     .iter()
-                    .filter_map(|item| item.map(|value| FloatWrapper(*value)))
-                    .collect::<BTreeSet<FloatWrapper<$T>>>();
+                    // FloatWrapper considers NaNs equal, as hash keys should, but membership follows
+                    // IEEE equality where NaN matches nothing, so NaNs are kept out of the set.
+                    .filter_map(|item| {
+                        item.filter(|value| !value.is_nan())
+                            .map(|value| FloatWrapper(*value))
+                    })
+                    .collect::<HashSet<FloatWrapper<$T>>>();
                 let result = self.as_arrow().iter().map(|option| {
                     option.and_then(|value| Some(set.contains(&FloatWrapper(*value))))
                 });
@@ -91,3 +95,46 @@ impl DaftIsIn<&NullArray> for NullArray {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftIsIn},
+        datatypes::{Float64Array, Int64Array, Utf8Array},
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn is_in_int64_array() -> DaftResult<()> {
+        let array = Int64Array::arange("a", 0, 5, 1)?
+            .with_validity_slice(&[true, true, false, true, true])?;
+        let items = Int64Array::from(("items", vec![1, 2, 4].as_slice()));
+        let result: Vec<_> = array.is_in(&items)?.as_arrow().iter().collect();
+        assert_eq!(
+            result[..],
+            [Some(false), Some(true), None, Some(false), Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn is_in_float64_array_canonicalizes() -> DaftResult<()> {
+        let array = Float64Array::from(("a", vec![f64::NAN, -0.0, 1.5, 2.5].as_slice()));
+        let items = Float64Array::from(("items", vec![0.0, f64::NAN, 2.5].as_slice()));
+        let result: Vec<_> = array.is_in(&items)?.as_arrow().iter().collect();
+        assert_eq!(
+            result[..],
+            [Some(true), Some(true), Some(false), Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn is_in_utf8_array() -> DaftResult<()> {
+        let array = Utf8Array::from(("a", ["x", "y", "z"].as_slice()));
+        let items = Utf8Array::from(("items", ["z", "x"].as_slice()));
+        let result: Vec<_> = array.is_in(&items)?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), Some(true)]);
+        Ok(())
+    }
+}
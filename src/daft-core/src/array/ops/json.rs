@@ -0,0 +1,174 @@
+use common_error::{DaftError, DaftResult};
+use serde_json::Value;
+
+use super::as_arrow::AsArrow;
+use crate::datatypes::Utf8Array;
+
+/// A single step of a parsed JSON query.
+#[derive(Debug, PartialEq)]
+enum JsonQueryStep {
+    /// Field of an object, from `.key` or `["key"]`.
+    Key(String),
+    /// Element of an array, from `[i]`. Negative indices count from the end of the array.
+    Index(i64),
+}
+
+/// Parses a JSONPath-like query such as `$.a.b[0]` or `$["a key"][-1]` into its steps.
+fn parse_json_query(query: &str) -> DaftResult<Vec<JsonQueryStep>> {
+    let invalid =
+        |reason: &str| DaftError::ValueError(format!("Invalid JSON query \"{query}\": {reason}"));
+    let chars = query.trim().chars().collect::<Vec<_>>();
+    if chars.first() != Some(&'$') {
+        return Err(invalid("expected query to start with $"));
+    }
+    let mut steps = vec![];
+    let mut i = 1;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                let start = i + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|c| *c == '.' || *c == '[')
+                    .map_or(chars.len(), |p| start + p);
+                if end == start {
+                    return Err(invalid("expected a key after ."));
+                }
+                steps.push(JsonQueryStep::Key(chars[start..end].iter().collect()));
+                i = end;
+            }
+            '[' => match chars.get(i + 1) {
+                Some(quote @ ('"' | '\'')) => {
+                    let start = i + 2;
+                    let end = chars[start..]
+                        .iter()
+                        .position(|c| c == quote)
+                        .map(|p| start + p)
+                        .ok_or_else(|| invalid("unterminated quoted key"))?;
+                    if chars.get(end + 1) != Some(&']') {
+                        return Err(invalid("expected ] after quoted key"));
+                    }
+                    steps.push(JsonQueryStep::Key(chars[start..end].iter().collect()));
+                    i = end + 2;
+                }
+                _ => {
+                    let start = i + 1;
+                    let end = chars[start..]
+                        .iter()
+                        .position(|c| *c == ']')
+                        .map(|p| start + p)
+                        .ok_or_else(|| invalid("unterminated ["))?;
+                    let index = chars[start..end]
+                        .iter()
+                        .collect::<String>()
+                        .trim()
+                        .parse()
+                        .map_err(|_| invalid("expected an integer index or a quoted key in []"))?;
+                    steps.push(JsonQueryStep::Index(index));
+                    i = end + 1;
+                }
+            },
+            c => return Err(invalid(&format!("unexpected character '{c}'"))),
+        }
+    }
+    Ok(steps)
+}
+
+fn query_json_value<'a>(value: &'a Value, steps: &[JsonQueryStep]) -> Option<&'a Value> {
+    steps
+        .iter()
+        .try_fold(value, |value, step| match (step, value) {
+            (JsonQueryStep::Key(key), Value::Object(map)) => map.get(key),
+            (JsonQueryStep::Index(index), Value::Array(items)) => {
+                let index = if *index < 0 {
+                    items.len() as i64 + index
+                } else {
+                    *index
+                };
+                usize::try_from(index).ok().and_then(|i| items.get(i))
+            }
+            _ => None,
+        })
+}
+
+impl Utf8Array {
+    /// Evaluates a JSONPath-like query (e.g. `$.a.b[0]`) over each JSON string.
+    ///
+    /// Selected strings are returned as-is, while other selected values are returned as JSON.
+    /// Invalid JSON, paths that don't exist and JSON nulls produce a null.
+    pub fn json_query(&self, query: &str) -> DaftResult<Utf8Array> {
+        let steps = parse_json_query(query)?;
+        let arrow_result = self
+            .as_arrow()
+            .iter()
+            .map(|val| {
+                let value: Value = serde_json::from_str(val?).ok()?;
+                match query_json_value(&value, &steps)? {
+                    Value::Null => None,
+                    Value::String(s) => Some(s.clone()),
+                    other => Some(other.to_string()),
+                }
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_json_query() -> DaftResult<()> {
+        assert_eq!(parse_json_query("$")?, vec![]);
+        assert_eq!(
+            parse_json_query("$.a.b[0]['c d'][-1]")?,
+            vec![
+                JsonQueryStep::Key("a".to_string()),
+                JsonQueryStep::Key("b".to_string()),
+                JsonQueryStep::Index(0),
+                JsonQueryStep::Key("c d".to_string()),
+                JsonQueryStep::Index(-1),
+            ]
+        );
+        for invalid in ["a.b", "$.", "$[0", "$[x]", "$[\"a\"", "$a"] {
+            assert!(parse_json_query(invalid).is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn check_json_query() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some(r#"{"a": {"b": [1, 2]}, "c": "x"}"#),
+                Some(r#"{"a": {"b": [{"d": true}]}, "c": null}"#),
+                Some(r#"{"a": 1}"#),
+                Some("not json"),
+                None,
+            ])),
+        ));
+        let query = |q: &str| -> DaftResult<Vec<Option<String>>> {
+            Ok(data
+                .json_query(q)?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect())
+        };
+        let s = |v: &str| Some(v.to_string());
+        assert_eq!(
+            query("$.a.b[0]")?,
+            [s("1"), s(r#"{"d":true}"#), None, None, None]
+        );
+        assert_eq!(
+            query("$.a.b[-1]")?,
+            [s("2"), s(r#"{"d":true}"#), None, None, None]
+        );
+        assert_eq!(query("$.c")?, [s("x"), None, None, None, None]);
+        assert_eq!(query("$['a']")?[2], s("1"));
+        assert!(data.json_query("a").is_err());
+        Ok(())
+    }
+}
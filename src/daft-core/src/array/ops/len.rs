@@ -1,5 +1,5 @@
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::DaftArrowBackedType,
 };
 use common_error::DaftResult;
@@ -74,3 +74,9 @@ impl StructArray {
         Ok(children_size_bytes + validity_size(self.validity()))
     }
 }
+
+impl CategoricalArray {
+    pub fn size_bytes(&self) -> DaftResult<usize> {
+        Ok(self.codes().size_bytes()? + self.dictionary().size_bytes()?)
+    }
+}
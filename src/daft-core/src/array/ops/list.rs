@@ -4,16 +4,16 @@ use crate::array::{
     growable::{make_growable, Growable},
     FixedSizeListArray, ListArray,
 };
-use crate::datatypes::{Int64Array, UInt64Array, Utf8Array};
+use crate::datatypes::{BooleanArray, Field, Int64Array, UInt64Array, Utf8Array};
 use crate::DataType;
 
-use crate::series::Series;
+use crate::series::{IntoSeries, Series};
 
 use arrow2;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
-use super::as_arrow::AsArrow;
+use super::{as_arrow::AsArrow, DaftCompare};
 
 fn join_arrow_list_of_utf8s(
     list_element: Option<&dyn arrow2::array::Array>,
@@ -41,7 +41,172 @@ fn join_arrow_list_of_utf8s(
         })
 }
 
+fn broadcast_i64_iter(
+    arr: &Int64Array,
+    len: usize,
+) -> DaftResult<Box<dyn Iterator<Item = Option<i64>> + '_>> {
+    match arr.len() {
+        1 => Ok(Box::new(repeat(arr.get(0)).take(len))),
+        l if l == len => Ok(Box::new(arr.as_arrow().iter().map(|v| v.copied()))),
+        l => Err(DaftError::ValueError(format!(
+            "Expected slice bounds of length 1 or {len}, but received length {l}"
+        ))),
+    }
+}
+
+/// Resolves Python-style `[start, end)` slice bounds against a list of length `len`, where negative
+/// bounds count from the end of the list and missing bounds extend to the respective end of the list.
+fn resolve_slice_bounds(start: Option<i64>, end: Option<i64>, len: i64) -> (i64, i64) {
+    let resolve = |idx: i64| {
+        if idx < 0 {
+            (len + idx).max(0)
+        } else {
+            idx.min(len)
+        }
+    };
+    let start = start.map_or(0, resolve);
+    let end = end.map_or(len, resolve);
+    (start, end.max(start))
+}
+
+/// Slices every row of a list, given the `(start, end)` range of each valid row into `flat_child`.
+fn slice_list_rows(
+    name: &str,
+    flat_child: &Series,
+    row_ranges: impl Iterator<Item = Option<(usize, usize)>>,
+    len: usize,
+    start: &Int64Array,
+    end: &Int64Array,
+) -> DaftResult<ListArray> {
+    let mut growable = make_growable(
+        name,
+        flat_child.data_type(),
+        vec![flat_child],
+        true,
+        flat_child.len(),
+    );
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(len);
+
+    let bounds = broadcast_i64_iter(start, len)?.zip(broadcast_i64_iter(end, len)?);
+    for (row_range, (start, end)) in row_ranges.zip(bounds) {
+        let last_offset = *offsets.last().unwrap();
+        match row_range {
+            Some((row_start, row_end)) => {
+                let (start, end) = resolve_slice_bounds(start, end, (row_end - row_start) as i64);
+                growable.extend(0, row_start + start as usize, (end - start) as usize);
+                offsets.push(last_offset + end - start);
+                validity.push(true);
+            }
+            None => {
+                offsets.push(last_offset);
+                validity.push(false);
+            }
+        }
+    }
+
+    let validity: Option<arrow2::bitmap::Bitmap> = match validity.unset_bits() {
+        0 => None,
+        _ => Some(validity.into()),
+    };
+    Ok(ListArray::new(
+        Field::new(
+            name,
+            DataType::List(Box::new(flat_child.data_type().clone())),
+        ),
+        growable.build()?,
+        arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+        validity,
+    ))
+}
+
+/// Checks whether every row of a list contains `item`, given the `(start, end)` range of each valid
+/// row into `flat_child`. `item` is either a single value or one value per row.
+fn list_rows_contain(
+    name: &str,
+    flat_child: &Series,
+    row_ranges: impl Iterator<Item = Option<(usize, usize)>>,
+    len: usize,
+    item: &Series,
+) -> DaftResult<BooleanArray> {
+    if item.len() != 1 && item.len() != len {
+        return Err(DaftError::ValueError(format!(
+            "Expected item of length 1 or {len}, but received length {}",
+            item.len()
+        )));
+    }
+    let row_ranges = row_ranges.collect::<Vec<_>>();
+    if item.data_type() == &DataType::Null {
+        let result = vec![None; len];
+        return Ok(BooleanArray::from((name, result.as_slice())));
+    }
+
+    let item_matches = if item.len() == 1 {
+        flat_child.equal(item)?
+    } else {
+        // Repeat each row's item for every element of that row, so that it lines up with the flat child.
+        let mut take_idx = vec![0u64; flat_child.len()];
+        for (i, row_range) in row_ranges.iter().enumerate() {
+            if let Some((row_start, row_end)) = row_range {
+                take_idx[*row_start..*row_end].fill(i as u64);
+            }
+        }
+        let take_idx = UInt64Array::from(("idx", take_idx)).into_series();
+        flat_child.equal(&item.take(&take_idx)?)?
+    };
+    let item_matches = item_matches.as_arrow();
+    let item_valid = item.not_null()?;
+    let item_valid = item_valid.bool()?;
+
+    let result = row_ranges
+        .iter()
+        .enumerate()
+        .map(|(i, row_range)| {
+            let item_idx = if item.len() == 1 { 0 } else { i };
+            match row_range {
+                Some((row_start, row_end)) if item_valid.get(item_idx) == Some(true) => Some(
+                    (*row_start..*row_end)
+                        .any(|j| item_matches.is_valid(j) && item_matches.value(j)),
+                ),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(BooleanArray::from((name, result.as_slice())))
+}
+
 impl ListArray {
+    fn row_ranges(&self) -> impl Iterator<Item = Option<(usize, usize)>> + '_ {
+        let offsets = self.offsets();
+        (0..self.len()).map(move |i| {
+            let start = *offsets.get(i).unwrap() as usize;
+            let end = *offsets.get(i + 1).unwrap() as usize;
+            self.is_valid(i).then_some((start, end))
+        })
+    }
+
+    pub fn slice_children(&self, start: &Int64Array, end: &Int64Array) -> DaftResult<ListArray> {
+        slice_list_rows(
+            self.name(),
+            &self.flat_child,
+            self.row_ranges(),
+            self.len(),
+            start,
+            end,
+        )
+    }
+
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        list_rows_contain(
+            self.name(),
+            &self.flat_child,
+            self.row_ranges(),
+            self.len(),
+            item,
+        )
+    }
+
     pub fn lengths(&self) -> DaftResult<UInt64Array> {
         let lengths = self.offsets().lengths().map(|l| Some(l as u64));
         let array = Box::new(
@@ -172,6 +337,32 @@ impl ListArray {
 }
 
 impl FixedSizeListArray {
+    fn row_ranges(&self) -> impl Iterator<Item = Option<(usize, usize)>> + '_ {
+        let size = self.fixed_element_len();
+        (0..self.len()).map(move |i| self.is_valid(i).then_some((i * size, (i + 1) * size)))
+    }
+
+    pub fn slice_children(&self, start: &Int64Array, end: &Int64Array) -> DaftResult<ListArray> {
+        slice_list_rows(
+            self.name(),
+            &self.flat_child,
+            self.row_ranges(),
+            self.len(),
+            start,
+            end,
+        )
+    }
+
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        list_rows_contain(
+            self.name(),
+            &self.flat_child,
+            self.row_ranges(),
+            self.len(),
+            item,
+        )
+    }
+
     pub fn lengths(&self) -> DaftResult<UInt64Array> {
         let size = self.fixed_element_len();
         match self.validity() {
@@ -303,3 +494,100 @@ impl FixedSizeListArray {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::{ops::as_arrow::AsArrow, ListArray},
+        datatypes::{BooleanArray, DataType, Field, Int64Array, UInt64Array},
+        series::{IntoSeries, Series},
+    };
+    use common_error::DaftResult;
+
+    // [[0, 1, 2], None, [3, 4], []]
+    fn make_list_array() -> DaftResult<ListArray> {
+        let flat_child = Int64Array::arange("a", 0, 5, 1)?.into_series();
+        let offsets = arrow2::offset::OffsetsBuffer::try_from(vec![0i64, 3, 3, 5, 5])?;
+        let validity = Some(arrow2::bitmap::Bitmap::from([true, false, true, true]));
+        Ok(ListArray::new(
+            Field::new("a", DataType::List(Box::new(DataType::Int64))),
+            flat_child,
+            offsets,
+            validity,
+        ))
+    }
+
+    #[test]
+    fn slice_list_children() -> DaftResult<()> {
+        let array = make_list_array()?;
+        let start = Int64Array::from(("start", vec![-2]));
+        let end = Int64Array::from(("end", vec![10]));
+        let result = array.slice_children(&start, &end)?;
+        assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 4, 4]);
+        assert_eq!(result.validity(), array.validity());
+        let values: Vec<_> = result.flat_child.i64()?.as_arrow().iter().collect();
+        assert_eq!(values[..], [Some(&1), Some(&2), Some(&3), Some(&4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn list_contains_item() -> DaftResult<()> {
+        let array = make_list_array()?;
+        let item = Int64Array::from(("item", vec![3])).into_series();
+        let result: Vec<_> = array.contains(&item)?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(false), None, Some(true), Some(false)]);
+
+        let item = Int64Array::from(("item", vec![2, 0, 0, 0])).into_series();
+        let result: Vec<_> = array.contains(&item)?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(true), None, Some(false), Some(false)]);
+        Ok(())
+    }
+
+    fn child_values(array: &ListArray) -> DaftResult<Vec<i64>> {
+        Ok(array
+            .flat_child
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
+
+    #[test]
+    fn take_filter_concat_lists() -> DaftResult<()> {
+        let array = make_list_array()?;
+
+        let taken = array.take(&UInt64Array::from(("idx", vec![2, 1, 0])))?;
+        assert_eq!(taken.offsets().as_slice(), &[0, 2, 2, 5]);
+        assert_eq!(
+            taken.validity(),
+            Some(&arrow2::bitmap::Bitmap::from([true, false, true]))
+        );
+        assert_eq!(child_values(&taken)?, [3, 4, 0, 1, 2]);
+
+        let filtered = array.filter(&BooleanArray::from((
+            "mask",
+            &[false, true, true, true][..],
+        )))?;
+        assert_eq!(filtered.offsets().as_slice(), &[0, 0, 2, 2]);
+        assert_eq!(child_values(&filtered)?, [3, 4]);
+
+        let concatted = ListArray::concat(&[&array, &taken])?;
+        assert_eq!(concatted.len(), 7);
+        assert_eq!(concatted.offsets().as_slice(), &[0, 3, 3, 5, 5, 7, 7, 10]);
+        assert_eq!(child_values(&concatted)?, [0, 1, 2, 3, 4, 3, 4, 0, 1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn list_arrow_roundtrip() -> DaftResult<()> {
+        let array = make_list_array()?;
+        let roundtripped = Series::try_from(("a", array.to_arrow()))?;
+        assert_eq!(roundtripped.data_type(), array.data_type());
+        let roundtripped = roundtripped.list()?;
+        assert_eq!(roundtripped.offsets(), array.offsets());
+        assert_eq!(roundtripped.validity(), array.validity());
+        assert_eq!(child_values(roundtripped)?, child_values(&array)?);
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     array::{
         growable::{Growable, GrowableArray},
-        DataArray, FixedSizeListArray, ListArray, StructArray,
+        CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::DaftArrowBackedType,
     IntoSeries,
@@ -137,3 +137,36 @@ impl DaftListAggable for StructArray {
         todo!("Requires new ListArrays for implementation")
     }
 }
+
+impl DaftListAggable for CategoricalArray {
+    type Output = DaftResult<ListArray>;
+
+    fn list(&self) -> Self::Output {
+        let child_series = self.clone().into_series();
+        let offsets = arrow2::offset::OffsetsBuffer::try_from(vec![0, child_series.len() as i64])?;
+        let list_field = self.field.to_list_field()?;
+        Ok(ListArray::new(list_field, child_series, offsets, None))
+    }
+
+    fn grouped_list(&self, groups: &GroupIndices) -> Self::Output {
+        let mut offsets = Vec::with_capacity(groups.len() + 1);
+
+        offsets.push(0);
+        for g in groups {
+            offsets.push(offsets.last().unwrap() + g.len() as i64);
+        }
+
+        let indices = groups.iter().flatten().copied().collect::<Vec<_>>();
+        let child_series = self
+            .take(&crate::datatypes::UInt64Array::from(("", indices)))?
+            .into_series();
+        let list_field = self.field.to_list_field()?;
+
+        Ok(ListArray::new(
+            list_field,
+            child_series,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            None,
+        ))
+    }
+}
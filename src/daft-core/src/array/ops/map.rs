@@ -0,0 +1,185 @@
+use arrow2::{
+    array::Array,
+    datatypes::{DataType as ArrowType, Field as ArrowField},
+    offset::OffsetsBuffer,
+};
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::DaftCompare,
+    datatypes::{logical::MapArray, UInt64Array},
+    DataType, IntoSeries, Series,
+};
+
+/// Converts an arrow2 map array into the list-of-structs layout of the physical Map type.
+pub(crate) fn map_to_physical_arrow(arrow_arr: &dyn Array) -> DaftResult<Box<dyn Array>> {
+    let map = arrow_arr
+        .as_any()
+        .downcast_ref::<arrow2::array::MapArray>()
+        .ok_or_else(|| {
+            DaftError::TypeError(format!(
+                "Expected arrow map array, got {:?}",
+                arrow_arr.data_type()
+            ))
+        })?;
+    let entries = map.field().clone();
+    Ok(arrow2::array::ListArray::<i64>::new(
+        ArrowType::LargeList(Box::new(ArrowField::new(
+            "item",
+            entries.data_type().clone(),
+            true,
+        ))),
+        OffsetsBuffer::<i64>::from(map.offsets()),
+        entries,
+        map.validity().cloned(),
+    )
+    .boxed())
+}
+
+/// Converts the list-of-structs layout of the physical Map type into an arrow2 map array.
+pub(crate) fn physical_arrow_to_map(
+    arrow_arr: &dyn Array,
+    map_type: ArrowType,
+) -> DaftResult<Box<dyn Array>> {
+    let list = arrow_arr
+        .as_any()
+        .downcast_ref::<arrow2::array::ListArray<i64>>()
+        .unwrap();
+    let offsets = OffsetsBuffer::<i32>::try_from(list.offsets()).map_err(|_| {
+        DaftError::ValueError("Map array has too many entries to convert to arrow".to_string())
+    })?;
+    let mut entries = list.values().clone();
+    if let ArrowType::Map(entries_field, _) = &map_type {
+        entries.change_type(entries_field.data_type().clone());
+    }
+    Ok(Box::new(arrow2::array::MapArray::new(
+        map_type,
+        offsets,
+        entries,
+        list.validity().cloned(),
+    )))
+}
+
+impl MapArray {
+    fn key_and_value_types(&self) -> (&DataType, &DataType) {
+        match self.data_type() {
+            DataType::Map(key, value) => (key.as_ref(), value.as_ref()),
+            _ => unreachable!("Map array must have Map datatype"),
+        }
+    }
+
+    /// Looks up `key` in each map, where `key` is either a single key for all maps or one key per
+    /// map. If a key occurs more than once in a map, its last value is returned. The result is null
+    /// for null maps and for maps without the key.
+    pub fn map_get(&self, key: &Series) -> DaftResult<Series> {
+        let (key_type, _) = self.key_and_value_types();
+        if key.len() != 1 && key.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} keys to look up in map of length {}, but received {}",
+                if self.len() == 1 {
+                    "1"
+                } else {
+                    "1 or the same number of"
+                },
+                self.len(),
+                key.len()
+            )));
+        }
+        let key = key.cast(key_type)?;
+        let entries = self.physical.flat_child.struct_()?;
+        let offsets = self.physical.offsets();
+        let start = *offsets.first() as usize;
+        let end = *offsets.last() as usize;
+
+        // Compare every entry against the key of its row.
+        let entry_rows = offsets
+            .as_slice()
+            .windows(2)
+            .enumerate()
+            .flat_map(|(i, w)| {
+                let row = if key.len() == 1 { 0 } else { i as u64 };
+                std::iter::repeat(Some(row)).take((w[1] - w[0]) as usize)
+            })
+            .collect::<Vec<_>>();
+        let entry_rows = UInt64Array::from_iter("", entry_rows.into_iter());
+        let entry_keys = entries.children[0].slice(start, end)?;
+        let matches = entry_keys.equal(&key.take(&entry_rows.into_series())?)?;
+
+        let indices = offsets
+            .as_slice()
+            .windows(2)
+            .enumerate()
+            .map(|(i, w)| {
+                if !self.physical.validity().map_or(true, |v| v.get_bit(i)) {
+                    return None;
+                }
+                (w[0]..w[1])
+                    .rev()
+                    .find(|j| matches.get(*j as usize - start) == Some(true))
+                    .map(|j| j as u64)
+            })
+            .collect::<Vec<_>>();
+        let indices = UInt64Array::from_iter("", indices.into_iter());
+        let values = entries.children[1].take(&indices.into_series())?;
+        Ok(values.rename(self.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        array::{ops::from_arrow::FromArrow, ListArray, StructArray},
+        datatypes::{Field, Int64Array, Utf8Array},
+    };
+
+    #[test]
+    fn map_get() -> DaftResult<()> {
+        // [{a: 1, b: 2}, None, {}, {a: 3, a: 4}]
+        let dtype = DataType::Map(Box::new(DataType::Utf8), Box::new(DataType::Int64));
+        let entry_keys = Utf8Array::from_iter("key", ["a", "b", "a", "a"].iter().map(Some));
+        let entry_values = Int64Array::from(("value", vec![1, 2, 3, 4]));
+        let entries = StructArray::new(
+            Field::new(
+                "item",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8),
+                    Field::new("value", DataType::Int64),
+                ]),
+            ),
+            vec![entry_keys.into_series(), entry_values.into_series()],
+            None,
+        );
+        let physical = ListArray::new(
+            Field::new("m", dtype.to_physical()),
+            entries.into_series(),
+            arrow2::offset::OffsetsBuffer::try_from(vec![0i64, 2, 2, 2, 4])?,
+            Some(arrow2::bitmap::Bitmap::from([true, false, true, true])),
+        );
+        let map = MapArray::new(Field::new("m", dtype), physical);
+
+        let key = Utf8Array::from_iter("", vec![Some("a")].into_iter());
+        let result = map.map_get(&key.into_series())?;
+        assert_eq!(result.name(), "m");
+        let result = result.i64()?;
+        assert_eq!(
+            (0..4).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(1), None, None, Some(4)]
+        );
+
+        let keys = Utf8Array::from_iter("", ["b", "a", "a", "b"].iter().map(Some));
+        let result = map.map_get(&keys.into_series())?;
+        let result = result.i64()?;
+        assert_eq!(
+            (0..4).map(|i| result.get(i)).collect::<Vec<_>>(),
+            vec![Some(2), None, None, None]
+        );
+
+        // Round-trips through arrow's native map layout.
+        let roundtrip = MapArray::from_arrow(Arc::new(map.field().clone()), map.to_arrow())?;
+        assert_eq!(roundtrip.physical.offsets(), map.physical.offsets());
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use arrow2::array::PrimitiveArray;
+use num_traits::Float;
+
+use crate::{
+    array::DataArray,
+    datatypes::{DaftFloatType, DaftNumericType},
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::as_arrow::AsArrow;
+
+macro_rules! impl_unary_math_op {
+    ($($(#[$doc:meta])* $fname:ident => $op:expr),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $fname(&self) -> DaftResult<Self> {
+                self.apply($op)
+            }
+        )*
+    };
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftFloatType,
+    <T as DaftNumericType>::Native: Float,
+{
+    impl_unary_math_op!(
+        sqrt => |v| v.sqrt(),
+        exp => |v| v.exp(),
+        /// Natural logarithm.
+        ln => |v| v.ln(),
+        sin => |v| v.sin(),
+        cos => |v| v.cos(),
+        tan => |v| v.tan(),
+    );
+
+    fn binary_math_helper<F>(&self, rhs: &Self, operation: F) -> DaftResult<Self>
+    where
+        F: Fn(T::Native, T::Native) -> T::Native,
+    {
+        if self.len() != rhs.len() {
+            return Err(DaftError::ValueError(format!(
+                "Cannot apply operation on arrays of different lengths: {} vs {}",
+                self.len(),
+                rhs.len()
+            )));
+        }
+        let result = self
+            .as_arrow()
+            .iter()
+            .zip(rhs.as_arrow().iter())
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(operation(*l, *r)),
+                _ => None,
+            })
+            .collect::<PrimitiveArray<T::Native>>();
+        Ok(DataArray::from((self.name(), Box::new(result))))
+    }
+
+    pub fn pow(&self, exponent: &Self) -> DaftResult<Self> {
+        self.binary_math_helper(exponent, |v, e| v.powf(e))
+    }
+
+    /// Logarithm of each value in the given base.
+    pub fn log(&self, base: &Self) -> DaftResult<Self> {
+        self.binary_math_helper(base, |v, b| v.log(b))
+    }
+
+    /// Four-quadrant arctangent of `self / x`, in radians.
+    pub fn atan2(&self, x: &Self) -> DaftResult<Self> {
+        self.binary_math_helper(x, |y, x| y.atan2(x))
+    }
+}
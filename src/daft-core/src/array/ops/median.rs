@@ -0,0 +1,66 @@
+use arrow2;
+
+use crate::datatypes::Float64Array;
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftMedianAggable, GroupIndices};
+
+/// Computes the exact median of the given values, ignoring nulls and NaNs.
+fn median_of(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    let mut values = values.flatten().filter(|v| !v.is_nan()).collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        Some((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        Some(values[mid])
+    }
+}
+
+impl DaftMedianAggable for Float64Array {
+    type Output = DaftResult<Float64Array>;
+
+    fn median(&self) -> Self::Output {
+        let result = median_of(self.as_arrow().iter().map(|v| v.copied()));
+        let arrow_array = arrow2::array::PrimitiveArray::<f64>::from([result]);
+        Ok(Float64Array::from((self.name(), Box::new(arrow_array))))
+    }
+
+    fn grouped_median(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let medians = groups
+            .iter()
+            .map(|g| median_of(g.iter().map(|idx| arrow_array.get(*idx as usize))))
+            .collect::<Vec<_>>();
+        let arrow_array = arrow2::array::PrimitiveArray::<f64>::from(medians);
+        Ok(Float64Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftMedianAggable},
+        datatypes::Float64Array,
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn exact_median() -> DaftResult<()> {
+        let values = Float64Array::from(("a", vec![3.0, 1.0, f64::NAN, 4.0, 2.0, 0.0]))
+            .with_validity_slice(&[true, true, true, true, true, false])?;
+        let result: Vec<_> = values.median()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(&2.5)]);
+
+        let result: Vec<_> = values
+            .grouped_median(&vec![vec![0, 1, 4], vec![2, 5]])?
+            .as_arrow()
+            .iter()
+            .collect();
+        assert_eq!(result[..], [Some(&2.0), None]);
+        Ok(())
+    }
+}
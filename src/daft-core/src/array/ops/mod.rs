@@ -4,15 +4,21 @@ mod arange;
 mod arithmetic;
 pub mod arrow2;
 pub mod as_arrow;
+mod between;
+mod bitwise;
 pub(crate) mod broadcast;
 pub(crate) mod cast;
 mod ceil;
+mod clip;
 mod compare_agg;
 mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod cumulative;
 mod date;
+mod ddsketch;
+mod embedding;
 mod filter;
 mod float;
 mod floor;
@@ -21,17 +27,25 @@ pub mod full;
 mod get;
 pub(crate) mod groups;
 mod hash;
+mod hll_sketch;
 mod if_else;
 pub(crate) mod image;
+pub(crate) mod interval;
 mod is_in;
+mod json;
 mod len;
 mod list;
 mod list_agg;
+pub(crate) mod map;
+mod math;
 mod mean;
+mod median;
 mod null;
 mod pairwise;
 mod repr;
+mod round;
 mod search_sorted;
+mod sign;
 mod sort;
 mod struct_;
 mod sum;
@@ -40,11 +54,12 @@ pub(crate) mod tensor;
 mod truncate;
 mod utf8;
 
+pub use cast::str_to_decimal128;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
 
 use common_error::DaftResult;
 
-use crate::count_mode::CountMode;
+use crate::{closed_interval::ClosedInterval, count_mode::CountMode};
 
 pub trait DaftCompare<Rhs> {
     type Output;
@@ -86,6 +101,11 @@ pub trait DaftIsIn<Rhs> {
     fn is_in(&self, rhs: Rhs) -> Self::Output;
 }
 
+pub trait DaftBetween<Rhs> {
+    type Output;
+    fn between(&self, lower: Rhs, upper: Rhs, closed: ClosedInterval) -> Self::Output;
+}
+
 pub trait DaftIsNull {
     type Output;
     fn is_null(&self) -> Self::Output;
@@ -101,6 +121,16 @@ pub trait DaftIsNan {
     fn is_nan(&self) -> Self::Output;
 }
 
+pub trait DaftIsInf {
+    type Output;
+    fn is_inf(&self) -> Self::Output;
+}
+
+pub trait DaftNotNan {
+    type Output;
+    fn not_nan(&self) -> Self::Output;
+}
+
 pub type VecIndices = Vec<u64>;
 pub type GroupIndices = Vec<VecIndices>;
 pub type GroupIndicesPair = (VecIndices, GroupIndices);
@@ -135,6 +165,36 @@ pub trait DaftCompareAggable {
     fn grouped_max(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftMedianAggable {
+    type Output;
+    fn median(&self) -> Self::Output;
+    fn grouped_median(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftDDSketchAggable {
+    type Output;
+    fn dd_sketch(&self) -> Self::Output;
+    fn grouped_dd_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftDDSketchMergeAggable {
+    type Output;
+    fn dd_merge(&self) -> Self::Output;
+    fn grouped_dd_merge(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllSketchAggable {
+    type Output;
+    fn hll_sketch(&self) -> Self::Output;
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllMergeAggable {
+    type Output;
+    fn hll_merge(&self) -> Self::Output;
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftListAggable {
     type Output;
     fn list(&self) -> Self::Output;
@@ -3,7 +3,7 @@ use std::{iter::repeat, sync::Arc};
 use arrow2;
 
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::*,
 };
 use common_error::DaftResult;
@@ -112,10 +112,12 @@ macro_rules! impl_not_null_nested_array {
 impl_is_null_nested_array!(ListArray);
 impl_is_null_nested_array!(FixedSizeListArray);
 impl_is_null_nested_array!(StructArray);
+impl_is_null_nested_array!(CategoricalArray);
 
 impl_not_null_nested_array!(ListArray);
 impl_not_null_nested_array!(FixedSizeListArray);
 impl_not_null_nested_array!(StructArray);
+impl_not_null_nested_array!(CategoricalArray);
 
 impl<T> DataArray<T>
 where
@@ -156,3 +158,43 @@ impl StructArray {
         }
     }
 }
+
+impl CategoricalArray {
+    #[inline]
+    pub fn is_valid(&self, idx: usize) -> bool {
+        self.codes().is_valid(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::{as_arrow::AsArrow, full::FullNull, DaftIsNull, DaftNotNull},
+        datatypes::{DataType, Int64Array, NullArray},
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn nullity_of_array_with_validity() -> DaftResult<()> {
+        let array = Int64Array::arange("a", 0, 3, 1)?.with_validity_slice(&[true, false, true])?;
+        let result: Vec<_> = array.is_null()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(false), Some(true), Some(false)]);
+        let result: Vec<_> = array.not_null()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(true), Some(false), Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn nullity_of_array_without_validity() -> DaftResult<()> {
+        let array = Int64Array::arange("a", 0, 2, 1)?;
+        let result: Vec<_> = array.is_null()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(false), Some(false)]);
+
+        let array = NullArray::full_null("a", &DataType::Null, 2);
+        let result: Vec<_> = array.is_null()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(true), Some(true)]);
+        let result: Vec<_> = array.not_null()?.as_arrow().iter().collect();
+        assert_eq!(result[..], [Some(false), Some(false)]);
+        Ok(())
+    }
+}
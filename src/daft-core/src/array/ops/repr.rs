@@ -1,16 +1,17 @@
 use base64::Engine;
 
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftNumericType, ExtensionArray, ImageFormat, NullArray,
         UInt64Array, Utf8Array,
     },
-    utils::display_table::{display_date32, display_time64, display_timestamp},
+    utils::display_table::{display_date32, display_interval, display_time64, display_timestamp},
     with_match_daft_types, DataType, Series,
 };
 use common_error::DaftResult;
@@ -33,9 +34,20 @@ macro_rules! impl_array_str_value {
 }
 
 impl_array_str_value!(BooleanArray, "{}");
-impl_array_str_value!(ExtensionArray, "{:?}");
 impl_array_str_value!(DurationArray, "{}");
 
+impl ExtensionArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        if let Some(extension) = self.extension_type()? {
+            return extension.str_value(self, idx);
+        }
+        match self.get(idx) {
+            None => Ok("None".to_string()),
+            Some(v) => Ok(format!("{:?}", v)),
+        }
+    }
+}
+
 fn pretty_print_bytes(bytes: &[u8], max_len: usize) -> DaftResult<String> {
     /// influenced by pythons bytes repr
     /// https://github.com/python/cpython/blob/main/Objects/bytesobject.c#L1336
@@ -103,6 +115,16 @@ impl Utf8Array {
         }
     }
 }
+impl CategoricalArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        let val = self.get(idx);
+        match val {
+            None => Ok("None".to_string()),
+            Some(v) => Ok(v.to_string()),
+        }
+    }
+}
+
 impl NullArray {
     pub fn str_value(&self, idx: usize) -> DaftResult<String> {
         if idx >= self.len() {
@@ -149,6 +171,30 @@ impl DateArray {
     }
 }
 
+impl IntervalArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        let val = self.get(idx);
+        match val {
+            None => Ok("None".to_string()),
+            Some((months, days, ns)) => Ok(display_interval(months, days, ns)),
+        }
+    }
+}
+
+impl MapArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        let Some(entries) = self.physical.get(idx) else {
+            return Ok("None".to_string());
+        };
+        let entries = entries.struct_()?;
+        let (keys, values) = (&entries.children[0], &entries.children[1]);
+        let items = (0..entries.len())
+            .map(|i| Ok(format!("{}: {}", keys.str_value(i)?, values.str_value(i)?)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(format!("{{{}}}", items.join(", ")))
+    }
+}
+
 impl TimeArray {
     pub fn str_value(&self, idx: usize) -> DaftResult<String> {
         let res = self.get(idx).map_or_else(
@@ -340,11 +386,14 @@ impl_array_html_value!(BinaryArray);
 impl_array_html_value!(ListArray);
 impl_array_html_value!(FixedSizeListArray);
 impl_array_html_value!(StructArray);
+impl_array_html_value!(CategoricalArray);
 impl_array_html_value!(ExtensionArray);
 impl_array_html_value!(Decimal128Array);
 impl_array_html_value!(DateArray);
 impl_array_html_value!(TimeArray);
 impl_array_html_value!(DurationArray);
+impl_array_html_value!(IntervalArray);
+impl_array_html_value!(MapArray);
 impl_array_html_value!(TimestampArray);
 impl_array_html_value!(EmbeddingArray);
 
@@ -0,0 +1,221 @@
+use arrow2::array::PrimitiveArray;
+use num_traits::{Float, NumCast, Zero};
+
+use crate::{
+    array::DataArray,
+    datatypes::{logical::Decimal128Array, DaftFloatType, DaftNumericType, DataType, Int128Array},
+    RoundMode,
+};
+
+use super::as_arrow::AsArrow;
+
+use common_error::DaftResult;
+
+fn round_float<F: Float>(v: F, mode: RoundMode) -> F {
+    let half = F::from(0.5).unwrap();
+    match mode {
+        RoundMode::HalfEven if (v - v.trunc()).abs() == half => {
+            let two = F::from(2.0).unwrap();
+            (v / two).round() * two
+        }
+        // `Float::round` rounds halfway cases away from zero.
+        _ => v.round(),
+    }
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftFloatType + DaftNumericType,
+    T::Native: Float,
+{
+    /// Rounds to the given number of decimal places; negative `decimals` round to the left of the
+    /// decimal point, e.g. `round(-1)` rounds to the nearest ten.
+    pub fn round(&self, decimals: i32, mode: RoundMode) -> DaftResult<Self> {
+        // Scale by a power of ten that is exactly representable, i.e. divide by 10 rather than
+        // multiply by 0.1 for negative decimals.
+        let factor = <T::Native as NumCast>::from(10.0)
+            .unwrap()
+            .powi(decimals.saturating_abs());
+        if factor.is_infinite() {
+            return if decimals > 0 {
+                // All finite values already have fewer decimal places than requested.
+                Ok(self.clone())
+            } else {
+                self.apply(|v| v * T::Native::zero())
+            };
+        }
+        if decimals >= 0 {
+            self.apply(|v| round_float(v * factor, mode) / factor)
+        } else {
+            self.apply(|v| round_float(v / factor, mode) * factor)
+        }
+    }
+}
+
+/// Rounds a decimal's unscaled value to a multiple of `factor`, where `factor` is a power of ten.
+fn round_unscaled(v: i128, factor: i128, mode: RoundMode) -> i128 {
+    let (quotient, remainder) = (v / factor, v % factor);
+    let away_from_zero = match (2 * remainder.abs()).cmp(&factor) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => match mode {
+            RoundMode::HalfAwayFromZero => true,
+            RoundMode::HalfEven => quotient % 2 != 0,
+        },
+    };
+    let quotient = if away_from_zero {
+        quotient + v.signum()
+    } else {
+        quotient
+    };
+    quotient * factor
+}
+
+impl Decimal128Array {
+    fn precision_and_scale(&self) -> (usize, usize) {
+        let DataType::Decimal128(precision, scale) = self.data_type() else {
+            panic!("Wrong dtype for Decimal128Array: {}", self.data_type())
+        };
+        (*precision, *scale)
+    }
+
+    fn scale(&self) -> usize {
+        self.precision_and_scale().1
+    }
+
+    /// Applies `func` to the unscaled values with the power of ten that separates the kept digits
+    /// from the dropped ones, or zeroes everything if no digits are kept.
+    ///
+    /// Rounding away from zero can carry into a new digit, e.g. ceil(99.99) = 100.00, which doesn't
+    /// fit in the precision of Decimal128(4, 2); such values become null.
+    fn apply_unscaled<F>(&self, dropped_digits: i64, func: F) -> DaftResult<Self>
+    where
+        F: Fn(i128, i128) -> i128 + Copy,
+    {
+        if dropped_digits <= 0 {
+            return Ok(self.clone());
+        }
+        let physical = match u32::try_from(dropped_digits)
+            .ok()
+            .and_then(|d| 10i128.checked_pow(d))
+        {
+            Some(factor) => self.physical.apply(|v| func(v, factor))?,
+            // Decimals have at most 38 digits, so they all round to zero.
+            None => self.physical.apply(|_| 0)?,
+        };
+        let (precision, _) = self.precision_and_scale();
+        let physical = match 10i128.checked_pow(precision as u32) {
+            Some(limit) => {
+                let values = physical
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.copied().filter(|v| v.abs() < limit))
+                    .collect::<PrimitiveArray<i128>>();
+                Int128Array::from((self.name(), Box::new(values)))
+            }
+            None => physical,
+        };
+        Ok(Self::new(self.field.clone(), physical))
+    }
+
+    pub fn round(&self, decimals: i32, mode: RoundMode) -> DaftResult<Self> {
+        self.apply_unscaled(self.scale() as i64 - decimals as i64, |v, factor| {
+            round_unscaled(v, factor, mode)
+        })
+    }
+
+    pub fn ceil(&self) -> DaftResult<Self> {
+        self.apply_unscaled(self.scale() as i64, |v, factor| {
+            let quotient = v.div_ceil(factor);
+            quotient * factor
+        })
+    }
+
+    pub fn floor(&self) -> DaftResult<Self> {
+        self.apply_unscaled(self.scale() as i64, |v, factor| {
+            let quotient = v.div_floor(factor);
+            quotient * factor
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{logical::Decimal128Array, Field, Float64Array, Int128Array},
+        DataType, RoundMode,
+    };
+    use common_error::DaftResult;
+
+    #[test]
+    fn check_float_round() -> DaftResult<()> {
+        let a = Float64Array::from(("a", vec![2.5, 3.5, -2.5, 1.234, 15.0, f64::NAN]));
+        let round = |decimals, mode| -> DaftResult<Vec<f64>> {
+            Ok(a.round(decimals, mode)?.as_arrow().values().to_vec())
+        };
+        let even = round(0, RoundMode::HalfEven)?;
+        assert_eq!(even[..5], [2.0, 4.0, -2.0, 1.0, 15.0]);
+        assert!(even[5].is_nan());
+        let away = round(0, RoundMode::HalfAwayFromZero)?;
+        assert_eq!(away[..5], [3.0, 4.0, -3.0, 1.0, 15.0]);
+        assert_eq!(round(2, RoundMode::HalfEven)?[3], 1.23);
+        assert_eq!(round(-1, RoundMode::HalfEven)?[4], 20.0);
+        assert_eq!(round(-1, RoundMode::HalfAwayFromZero)?[4], 20.0);
+        assert_eq!(round(400, RoundMode::HalfEven)?[3], 1.234);
+        Ok(())
+    }
+
+    #[test]
+    fn check_decimal_round() -> DaftResult<()> {
+        // 1.25, 1.35, -1.25, 1.21, -1.21 at scale 2.
+        let a = Decimal128Array::new(
+            Field::new("a", DataType::Decimal128(10, 2)),
+            Int128Array::from(("a", vec![125, 135, -125, 121, -121])),
+        );
+        let values = |a: Decimal128Array| a.as_arrow().values().to_vec();
+        assert_eq!(
+            values(a.round(1, RoundMode::HalfEven)?),
+            [120, 140, -120, 120, -120]
+        );
+        assert_eq!(
+            values(a.round(1, RoundMode::HalfAwayFromZero)?),
+            [130, 140, -130, 120, -120]
+        );
+        assert_eq!(values(a.round(3, RoundMode::HalfEven)?), values(a.clone()));
+        assert_eq!(values(a.round(-40, RoundMode::HalfEven)?), [0, 0, 0, 0, 0]);
+        assert_eq!(values(a.ceil()?), [200, 200, -100, 200, -100]);
+        assert_eq!(values(a.floor()?), [100, 100, -200, 100, -200]);
+        Ok(())
+    }
+
+    #[test]
+    fn check_decimal_round_overflow_is_null() -> DaftResult<()> {
+        // 99.99, -99.99, 99.50, 12.34 at Decimal128(4, 2).
+        let a = Decimal128Array::new(
+            Field::new("a", DataType::Decimal128(4, 2)),
+            Int128Array::from(("a", vec![9999, -9999, 9950, 1234])),
+        );
+        let values = |a: Decimal128Array| {
+            a.physical
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(a.ceil()?), [None, Some(-9900), None, Some(1300)]);
+        assert_eq!(
+            values(a.floor()?),
+            [Some(9900), None, Some(9900), Some(1200)]
+        );
+        assert_eq!(
+            values(a.round(0, RoundMode::HalfAwayFromZero)?),
+            [None, None, None, Some(1200)]
+        );
+        assert_eq!(
+            values(a.round(1, RoundMode::HalfEven)?),
+            [None, None, Some(9950), Some(1230)]
+        );
+        Ok(())
+    }
+}
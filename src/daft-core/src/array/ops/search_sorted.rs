@@ -1,7 +1,7 @@
 use crate::{
     array::DataArray,
     datatypes::{DaftArrowBackedType, UInt64Array},
-    kernels::search_sorted,
+    kernels::search_sorted::{self, SearchSortedSide},
 };
 use common_error::DaftResult;
 
@@ -9,9 +9,14 @@ impl<T> DataArray<T>
 where
     T: DaftArrowBackedType + 'static,
 {
-    pub fn search_sorted(&self, keys: &Self, descending: bool) -> DaftResult<UInt64Array> {
+    pub fn search_sorted(
+        &self,
+        keys: &Self,
+        descending: bool,
+        side: SearchSortedSide,
+    ) -> DaftResult<UInt64Array> {
         let array =
-            search_sorted::search_sorted(self.data.as_ref(), keys.data.as_ref(), descending)?;
+            search_sorted::search_sorted(self.data.as_ref(), keys.data.as_ref(), descending, side)?;
 
         Ok(DataArray::from((self.name(), Box::new(array))))
     }
@@ -0,0 +1,26 @@
+use num_traits::Num;
+
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+use common_error::DaftResult;
+
+impl<T: DaftNumericType> DataArray<T>
+where
+    T::Native: Num + PartialOrd,
+{
+    /// -1 for negative values, 1 for positive values and the value itself otherwise, i.e. for zeros
+    /// (including -0.0) and NaN.
+    pub fn sign(&self) -> DaftResult<Self> {
+        let zero = T::Native::zero();
+        let one = T::Native::one();
+        self.apply(|v| {
+            if v > zero {
+                one
+            } else if v < zero {
+                zero - one
+            } else {
+                v
+            }
+        })
+    }
+}
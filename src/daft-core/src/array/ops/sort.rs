@@ -1,17 +1,18 @@
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, Float32Array,
-        Float64Array, NullArray, Utf8Array,
+        Float64Array, NullArray, UInt64Type, Utf8Array,
     },
     kernels::search_sorted::{build_compare_with_nulls, cmp_float},
-    series::Series,
+    series::{IntoSeries, Series},
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 #[cfg(feature = "python")]
 use crate::datatypes::PythonArray;
@@ -21,7 +22,7 @@ use arrow2::{
     types::Index,
 };
 
-use super::arrow2::sort::primitive::common::multi_column_idx_sort;
+use super::arrow2::sort::primitive::common::{idx_sort, multi_column_idx_sort};
 
 use super::as_arrow::AsArrow;
 
@@ -71,12 +72,11 @@ where
     {
         let arrow_array = self.as_arrow();
 
-        let result =
-            crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_unstable_by::<
-                I::Native,
-                T::Native,
-                _,
-            >(arrow_array, ord::total_cmp, descending);
+        let result = crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_by::<
+            I::Native,
+            T::Native,
+            _,
+        >(arrow_array, ord::total_cmp, descending);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -163,12 +163,11 @@ impl Float32Array {
     {
         let arrow_array = self.as_arrow();
 
-        let result =
-            crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_unstable_by::<
-                I::Native,
-                f32,
-                _,
-            >(arrow_array, cmp_float::<f32>, descending);
+        let result = crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_by::<
+            I::Native,
+            f32,
+            _,
+        >(arrow_array, cmp_float::<f32>, descending);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -255,12 +254,11 @@ impl Float64Array {
     {
         let arrow_array = self.as_arrow();
 
-        let result =
-            crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_unstable_by::<
-                I::Native,
-                f64,
-                _,
-            >(arrow_array, cmp_float::<f64>, descending);
+        let result = crate::array::ops::arrow2::sort::primitive::indices::indices_sorted_by::<
+            I::Native,
+            f64,
+            _,
+        >(arrow_array, cmp_float::<f64>, descending);
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -387,13 +385,17 @@ impl BooleanArray {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        let options = arrow2::compute::sort::SortOptions {
+        let values = self.as_arrow().values();
+        let result = idx_sort(
+            self.data().validity(),
+            |a: &I::Native, b: &I::Native| unsafe {
+                values
+                    .get_bit_unchecked(a.to_usize())
+                    .cmp(&values.get_bit_unchecked(b.to_usize()))
+            },
+            self.len(),
             descending,
-            nulls_first: descending,
-        };
-
-        let result =
-            arrow2::compute::sort::sort_to_indices::<I::Native>(self.data(), &options, None)?;
+        );
 
         Ok(DataArray::<I>::from((self.name(), Box::new(result))))
     }
@@ -477,16 +479,17 @@ macro_rules! impl_binary_like_sort {
                 I: DaftIntegerType,
                 <I as DaftNumericType>::Native: arrow2::types::Index,
             {
-                let options = arrow2::compute::sort::SortOptions {
+                let values = self.as_arrow();
+                let result = idx_sort(
+                    self.data().validity(),
+                    |a: &I::Native, b: &I::Native| unsafe {
+                        values
+                            .value_unchecked(a.to_usize())
+                            .cmp(values.value_unchecked(b.to_usize()))
+                    },
+                    self.len(),
                     descending,
-                    nulls_first: descending,
-                };
-
-                let result = arrow2::compute::sort::sort_to_indices::<I::Native>(
-                    self.data(),
-                    &options,
-                    None,
-                )?;
+                );
 
                 Ok(DataArray::<I>::from((self.name(), Box::new(result))))
             }
@@ -575,14 +578,44 @@ impl ListArray {
 }
 
 impl StructArray {
-    pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
-        todo!("impl sort for StructArray")
+    /// Sorts structs lexicographically by their fields, in the order the fields are defined. Null
+    /// structs are placed last, or first if descending, like nulls of primitive arrays.
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        let indices = self.clone().into_series().argsort(descending, descending)?;
+        self.take(indices.u64()?)
+    }
+}
+
+impl CategoricalArray {
+    pub fn argsort<I>(&self, descending: bool) -> DaftResult<DataArray<I>>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        self.ranks()?.argsort::<I>(descending)
+    }
+
+    pub fn argsort_multikey<I>(
+        &self,
+        others: &[Series],
+        descending: &[bool],
+    ) -> DaftResult<DataArray<I>>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        self.ranks()?.argsort_multikey::<I>(others, descending)
+    }
+
+    /// Sorts by the strings of the elements, keeping the dictionary as-is.
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        self.take(&self.argsort::<UInt64Type>(descending)?)
     }
 }
 
 impl ExtensionArray {
-    pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
-        todo!("impl sort for ExtensionArray")
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        self.take(&self.argsort(descending)?)
     }
 }
 
@@ -628,6 +661,21 @@ impl TimestampArray {
     }
 }
 
+impl IntervalArray {
+    pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
+        // Intervals aren't totally ordered, e.g. 1 month vs 30 days depends on the start date.
+        Err(DaftError::TypeError(
+            "Cannot sort Interval arrays".to_string(),
+        ))
+    }
+}
+
+impl MapArray {
+    pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
+        Err(DaftError::TypeError("Cannot sort Map arrays".to_string()))
+    }
+}
+
 impl EmbeddingArray {
     pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
         todo!("impl sort for EmbeddingArray")
@@ -1,12 +1,13 @@
 use crate::{
     array::{
         growable::{Growable, GrowableArray},
-        DataArray, FixedSizeListArray, ListArray, StructArray,
+        CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
     },
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, NullArray,
         Utf8Array,
@@ -72,6 +73,8 @@ impl_logicalarray_take!(Decimal128Array);
 impl_logicalarray_take!(DateArray);
 impl_logicalarray_take!(TimeArray);
 impl_logicalarray_take!(DurationArray);
+impl_logicalarray_take!(IntervalArray);
+impl_logicalarray_take!(MapArray);
 impl_logicalarray_take!(TimestampArray);
 impl_logicalarray_take!(EmbeddingArray);
 impl_logicalarray_take!(ImageArray);
@@ -213,6 +216,16 @@ impl ListArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn take<I>(&self, idx: &DataArray<I>) -> DaftResult<Self>
+    where
+        I: DaftIntegerType,
+        <I as DaftNumericType>::Native: arrow2::types::Index,
+    {
+        Ok(self.with_codes(self.codes().take(idx)?))
+    }
+}
+
 impl StructArray {
     pub fn take<I>(&self, idx: &DataArray<I>) -> DaftResult<Self>
     where
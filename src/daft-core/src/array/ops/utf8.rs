@@ -1,40 +1,41 @@
+use std::collections::HashMap;
+
 use crate::{
     array::ListArray,
     datatypes::{BooleanArray, Field, UInt64Array, Utf8Array},
     DataType, Series,
 };
 use arrow2;
+use regex::Regex;
 
 use common_error::{DaftError, DaftResult};
 
 use super::{as_arrow::AsArrow, full::FullNull};
 
-fn split_array_on_patterns<'a, T, U>(
+/// Splits each of the `arr_len` strings of `arr_iter` on the corresponding pattern, where
+/// `split_on` pushes the pieces of a string onto the child array and returns how many it pushed.
+fn split_array_on_patterns<'a, T, U, F>(
     arr_iter: T,
     pattern_iter: U,
+    arr_len: usize,
     buffer_len: usize,
     name: &str,
+    mut split_on: F,
 ) -> DaftResult<ListArray>
 where
-    T: arrow2::trusted_len::TrustedLen + Iterator<Item = Option<&'a str>>,
+    T: Iterator<Item = Option<&'a str>>,
     U: Iterator<Item = Option<&'a str>>,
+    F: FnMut(&'a str, &'a str, &mut arrow2::array::MutableUtf8Array<i64>) -> DaftResult<i64>,
 {
     // This will overallocate by pattern_len * N_i, where N_i is the number of pattern occurences in the ith string in arr_iter.
     let mut splits = arrow2::array::MutableUtf8Array::with_capacity(buffer_len);
-    // arr_iter implements TrustedLen, so we can always use size_hint().1 as the exact length of the iterator. The only
-    // time this would fail is if the length of the iterator exceeds usize::MAX, which should never happen for an i64
-    // offset array, since the array length can't exceed i64::MAX on 64-bit machines.
-    let arr_len = arr_iter.size_hint().1.unwrap();
     let mut offsets = arrow2::offset::Offsets::new();
     let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(arr_len);
     for (val, pat) in arr_iter.zip(pattern_iter) {
         let mut num_splits = 0i64;
         match (val, pat) {
             (Some(val), Some(pat)) => {
-                for split in val.split(pat) {
-                    splits.push(Some(split));
-                    num_splits += 1;
-                }
+                num_splits = split_on(val, pat, &mut splits)?;
                 validity.push(true);
             }
             (_, _) => {
@@ -61,6 +62,154 @@ where
     ))
 }
 
+/// Range of the substring of a sequence of `len` elements that starts at `start`, counted from the
+/// end if negative, and spans at most `length` elements, clamped to the bounds of the sequence.
+fn substr_bounds(len: usize, start: i64, length: Option<usize>) -> (usize, usize) {
+    let start = if start < 0 {
+        len.saturating_sub(start.unsigned_abs() as usize)
+    } else {
+        (start as usize).min(len)
+    };
+    let end = length.map_or(len, |length| start.saturating_add(length).min(len));
+    (start, end)
+}
+
+/// Byte offset of the character at `char_idx` in `val`, or the length of `val` if it has fewer
+/// characters.
+fn char_to_byte_offset(val: &str, char_idx: usize) -> usize {
+    val.char_indices()
+        .nth(char_idx)
+        .map_or(val.len(), |(idx, _)| idx)
+}
+
+fn push_literal_splits<'a>(
+    val: &'a str,
+    pat: &'a str,
+    splits: &mut arrow2::array::MutableUtf8Array<i64>,
+) -> DaftResult<i64> {
+    let mut num_splits = 0i64;
+    for split in val.split(pat) {
+        splits.push(Some(split));
+        num_splits += 1;
+    }
+    Ok(num_splits)
+}
+
+/// Compiles each distinct pattern once, so that a pattern shared by many rows of a batch is only
+/// compiled a single time.
+#[derive(Default)]
+struct RegexCache<'a> {
+    cache: HashMap<&'a str, Regex>,
+}
+
+impl<'a> RegexCache<'a> {
+    fn get(&mut self, pattern: &'a str) -> DaftResult<&Regex> {
+        if !self.cache.contains_key(pattern) {
+            let regex = Regex::new(pattern).map_err(|e| {
+                DaftError::ValueError(format!("Invalid regex pattern \"{pattern}\": {e}"))
+            })?;
+            self.cache.insert(pattern, regex);
+        }
+        Ok(&self.cache[pattern])
+    }
+}
+
+/// Length of the result of a kernel over the provided arrays, where arrays of length 1 are
+/// broadcast to the length of the others.
+fn broadcasted_len(arrays: &[&Utf8Array]) -> DaftResult<usize> {
+    let len = arrays
+        .iter()
+        .map(|arr| arr.len())
+        .find(|len| *len != 1)
+        .unwrap_or(1);
+    match arrays.iter().find(|arr| arr.len() != len && arr.len() != 1) {
+        Some(arr) => Err(DaftError::ComputeError(format!(
+            "Inputs have different length arrays: {} vs {len}",
+            arr.len()
+        ))),
+        None => Ok(len),
+    }
+}
+
+fn broadcasted_iter<'a>(
+    arr: &'a Utf8Array,
+    len: usize,
+) -> Box<dyn Iterator<Item = Option<&'a str>> + 'a> {
+    if arr.len() == len {
+        Box::new(arr.as_arrow().iter())
+    } else {
+        Box::new(std::iter::repeat(arr.get(0)).take(len))
+    }
+}
+
+/// Writes the lowercase of `s` into `buffer`. Only a capital sigma lowercases differently depending
+/// on its position in a word, so other strings are lowercased character by character.
+fn push_lowercase(s: &str, buffer: &mut String) {
+    if s.contains('\u{3a3}') {
+        buffer.push_str(&s.to_lowercase());
+    } else {
+        buffer.extend(s.chars().flat_map(char::to_lowercase));
+    }
+}
+
+/// Writes the titlecase of `c` into `buffer`, as defined by Unicode's case mappings. It only
+/// differs from the uppercase of digraphs and ligatures, whose titlecase capitalizes just their
+/// first letter, and of Georgian and Greek letters without a distinct titlecase form.
+fn push_titlecase(c: char, buffer: &mut String) {
+    let titlecase = match c {
+        '\u{df}' => "Ss",
+        '\u{1c4}'..='\u{1c6}' => "\u{1c5}",
+        '\u{1c7}'..='\u{1c9}' => "\u{1c8}",
+        '\u{1ca}'..='\u{1cc}' => "\u{1cb}",
+        '\u{1f1}'..='\u{1f3}' => "\u{1f2}",
+        '\u{587}' => "\u{535}\u{582}",
+        '\u{1fb2}' => "\u{1fba}\u{345}",
+        '\u{1fb3}' => "\u{1fbc}",
+        '\u{1fb4}' => "\u{386}\u{345}",
+        '\u{1fb7}' => "\u{391}\u{342}\u{345}",
+        '\u{1fc2}' => "\u{1fca}\u{345}",
+        '\u{1fc3}' => "\u{1fcc}",
+        '\u{1fc4}' => "\u{389}\u{345}",
+        '\u{1fc7}' => "\u{397}\u{342}\u{345}",
+        '\u{1ff2}' => "\u{1ffa}\u{345}",
+        '\u{1ff3}' => "\u{1ffc}",
+        '\u{1ff4}' => "\u{38f}\u{345}",
+        '\u{1ff7}' => "\u{3a9}\u{342}\u{345}",
+        '\u{fb00}' => "Ff",
+        '\u{fb01}' => "Fi",
+        '\u{fb02}' => "Fl",
+        '\u{fb03}' => "Ffi",
+        '\u{fb04}' => "Ffl",
+        '\u{fb05}' | '\u{fb06}' => "St",
+        '\u{fb13}' => "\u{544}\u{576}",
+        '\u{fb14}' => "\u{544}\u{565}",
+        '\u{fb15}' => "\u{544}\u{56b}",
+        '\u{fb16}' => "\u{54e}\u{576}",
+        '\u{fb17}' => "\u{544}\u{56d}",
+        // Greek letters with a ypogegrammeni titlecase to the capital letter with a prosgegrammeni.
+        '\u{1f80}'..='\u{1f87}' | '\u{1f90}'..='\u{1f97}' | '\u{1fa0}'..='\u{1fa7}' => {
+            buffer.push(char::from_u32(c as u32 + 8).unwrap());
+            return;
+        }
+        '\u{10d0}'..='\u{10fa}'
+        | '\u{10fd}'..='\u{10ff}'
+        | '\u{1f88}'..='\u{1f8f}'
+        | '\u{1f98}'..='\u{1f9f}'
+        | '\u{1fa8}'..='\u{1faf}'
+        | '\u{1fbc}'
+        | '\u{1fcc}'
+        | '\u{1ffc}' => {
+            buffer.push(c);
+            return;
+        }
+        _ => {
+            buffer.extend(c.to_uppercase());
+            return;
+        }
+    };
+    buffer.push_str(titlecase);
+}
+
 impl Utf8Array {
     pub fn endswith(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(pattern, |data: &str, pat: &str| data.ends_with(pat))
@@ -103,8 +252,10 @@ impl Utf8Array {
             (self_len, pattern_len) if self_len == pattern_len => split_array_on_patterns(
                 self_arrow.into_iter(),
                 pattern_arrow.into_iter(),
+                self_len,
                 buffer_len,
                 self.name(),
+                push_literal_splits,
             ),
             // Broadcast pattern case:
             (self_len, 1) => {
@@ -112,8 +263,10 @@ impl Utf8Array {
                 split_array_on_patterns(
                     self_arrow.into_iter(),
                     std::iter::repeat(Some(pattern_scalar_value)).take(self_len),
+                    self_len,
                     buffer_len,
                     self.name(),
+                    push_literal_splits,
                 )
             }
             // Broadcast self case:
@@ -122,8 +275,10 @@ impl Utf8Array {
                 split_array_on_patterns(
                     std::iter::repeat(Some(self_scalar_value)).take(pattern_len),
                     pattern_arrow.into_iter(),
+                    pattern_len,
                     buffer_len * pattern_len,
                     self.name(),
+                    push_literal_splits,
                 )
             }
             // Mismatched len case:
@@ -133,6 +288,88 @@ impl Utf8Array {
         }
     }
 
+    /// Splits each string on the matches of the corresponding regex pattern. An empty string is
+    /// split into a single empty string, like with a literal pattern.
+    pub fn split_regex(&self, pattern: &Utf8Array) -> DaftResult<ListArray> {
+        let len = broadcasted_len(&[self, pattern])?;
+        let buffer_len = if self.len() == len {
+            self.as_arrow().values().len()
+        } else {
+            self.as_arrow().values().len() * len
+        };
+        let mut cache = RegexCache::default();
+        split_array_on_patterns(
+            broadcasted_iter(self, len),
+            broadcasted_iter(pattern, len),
+            len,
+            buffer_len,
+            self.name(),
+            |val, pat, splits| {
+                let mut num_splits = 0i64;
+                for split in cache.get(pat)?.split(val) {
+                    splits.push(Some(split));
+                    num_splits += 1;
+                }
+                Ok(num_splits)
+            },
+        )
+    }
+
+    /// Whether each string contains a match of the corresponding regex pattern.
+    pub fn match_(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
+        let len = broadcasted_len(&[self, pattern])?;
+        let mut cache = RegexCache::default();
+        let result = broadcasted_iter(self, len)
+            .zip(broadcasted_iter(pattern, len))
+            .map(|(val, pat)| match (val, pat) {
+                (Some(val), Some(pat)) => Ok(Some(cache.get(pat)?.is_match(val))),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_result: arrow2::array::BooleanArray = result.into_iter().collect();
+        Ok(BooleanArray::from((self.name(), arrow_result)))
+    }
+
+    /// Extracts capture group `index` of the first match of the corresponding regex pattern from
+    /// each string, where group 0 is the entire match. Strings without a match produce a null.
+    pub fn extract(&self, pattern: &Utf8Array, index: usize) -> DaftResult<Utf8Array> {
+        let len = broadcasted_len(&[self, pattern])?;
+        let mut cache = RegexCache::default();
+        let result = broadcasted_iter(self, len)
+            .zip(broadcasted_iter(pattern, len))
+            .map(|(val, pat)| match (val, pat) {
+                (Some(val), Some(pat)) => Ok(cache
+                    .get(pat)?
+                    .captures(val)
+                    .and_then(|captures| captures.get(index))
+                    .map(|m| m.as_str())),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_result: arrow2::array::Utf8Array<i64> = result.into_iter().collect();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    /// Replaces all matches of the corresponding regex pattern in each string with the
+    /// replacement, which may refer to capture groups as `$1` or `${name}`.
+    pub fn replace(&self, pattern: &Utf8Array, replacement: &Utf8Array) -> DaftResult<Utf8Array> {
+        let len = broadcasted_len(&[self, pattern, replacement])?;
+        let mut cache = RegexCache::default();
+        let result = broadcasted_iter(self, len)
+            .zip(broadcasted_iter(pattern, len))
+            .zip(broadcasted_iter(replacement, len))
+            .map(|((val, pat), rep)| match (val, pat, rep) {
+                (Some(val), Some(pat), Some(rep)) => {
+                    Ok(Some(cache.get(pat)?.replace_all(val, rep)))
+                }
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_result: arrow2::array::Utf8Array<i64> = result.into_iter().collect();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    /// The number of bytes of the UTF-8 encoding of each string.
     pub fn length(&self) -> DaftResult<UInt64Array> {
         let self_arrow = self.as_arrow();
         let arrow_result = self_arrow
@@ -146,55 +383,135 @@ impl Utf8Array {
         Ok(UInt64Array::from((self.name(), Box::new(arrow_result))))
     }
 
-    pub fn lower(&self) -> DaftResult<Utf8Array> {
+    /// The number of characters, i.e. Unicode code points, of each string.
+    pub fn length_chars(&self) -> DaftResult<UInt64Array> {
         let self_arrow = self.as_arrow();
         let arrow_result = self_arrow
             .iter()
             .map(|val| {
                 let v = val?;
-                Some(v.to_lowercase())
+                Some(v.chars().count() as u64)
             })
-            .collect::<arrow2::array::Utf8Array<i64>>()
+            .collect::<arrow2::array::UInt64Array>()
             .with_validity(self_arrow.validity().cloned());
-        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    pub fn lower(&self) -> DaftResult<Utf8Array> {
+        self.convert_case(|v, buffer| {
+            if v.is_ascii() {
+                buffer.push_str(v);
+                buffer.make_ascii_lowercase();
+            } else {
+                push_lowercase(v, buffer);
+            }
+        })
     }
 
     pub fn upper(&self) -> DaftResult<Utf8Array> {
+        self.convert_case(|v, buffer| {
+            if v.is_ascii() {
+                buffer.push_str(v);
+                buffer.make_ascii_uppercase();
+            } else {
+                buffer.extend(v.chars().flat_map(char::to_uppercase));
+            }
+        })
+    }
+
+    /// Titlecases the first character of each string and lowercases the rest.
+    pub fn capitalize(&self) -> DaftResult<Utf8Array> {
+        self.convert_case(|v, buffer| {
+            if v.is_ascii() {
+                buffer.push_str(v);
+                buffer.make_ascii_lowercase();
+                if let Some(first) = buffer.get_mut(..1) {
+                    first.make_ascii_uppercase();
+                }
+            } else {
+                let mut chars = v.chars();
+                if let Some(first) = chars.next() {
+                    push_titlecase(first, buffer);
+                    push_lowercase(chars.as_str(), buffer);
+                }
+            }
+        })
+    }
+
+    /// Maps each string with `convert`, which writes the converted string into a reused buffer.
+    /// Case conversions mostly preserve the number of bytes, so the values of the result are
+    /// allocated up front with the size of the values of this array.
+    fn convert_case(&self, convert: impl Fn(&str, &mut String)) -> DaftResult<Utf8Array> {
+        let self_arrow = self.as_arrow();
+        let mut result = arrow2::array::MutableUtf8Array::<i64>::with_capacities(
+            self_arrow.len(),
+            self_arrow.values().len(),
+        );
+        let mut buffer = String::new();
+        for val in self_arrow.iter() {
+            match val {
+                Some(v) => {
+                    buffer.clear();
+                    convert(v, &mut buffer);
+                    result.push(Some(buffer.as_str()));
+                }
+                None => result.push_null(),
+            }
+        }
+        let arrow_result: arrow2::array::Utf8Array<i64> = result.into();
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    pub fn lstrip(&self) -> DaftResult<Utf8Array> {
         let self_arrow = self.as_arrow();
         let arrow_result = self_arrow
             .iter()
             .map(|val| {
                 let v = val?;
-                Some(v.to_uppercase())
+                Some(v.trim_start())
             })
             .collect::<arrow2::array::Utf8Array<i64>>()
             .with_validity(self_arrow.validity().cloned());
         Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
     }
 
-    pub fn lstrip(&self) -> DaftResult<Utf8Array> {
+    pub fn rstrip(&self) -> DaftResult<Utf8Array> {
         let self_arrow = self.as_arrow();
         let arrow_result = self_arrow
             .iter()
             .map(|val| {
                 let v = val?;
-                Some(v.trim_start())
+                Some(v.trim_end())
             })
             .collect::<arrow2::array::Utf8Array<i64>>()
             .with_validity(self_arrow.validity().cloned());
         Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
     }
 
-    pub fn rstrip(&self) -> DaftResult<Utf8Array> {
+    /// Takes the substring of each string that starts at character `start`, counted from the end
+    /// if negative, and spans at most `length` characters, or up to the end if `length` is `None`.
+    pub fn substr(&self, start: i64, length: Option<usize>) -> DaftResult<Utf8Array> {
         let self_arrow = self.as_arrow();
+        // Characters are bytes in ASCII strings, so the substrings can be sliced without decoding.
+        let is_ascii = self_arrow.values().is_ascii();
         let arrow_result = self_arrow
             .iter()
             .map(|val| {
                 let v = val?;
-                Some(v.trim_end())
+                if is_ascii {
+                    let (start, end) = substr_bounds(v.len(), start, length);
+                    return Some(&v[start..end]);
+                }
+                let start_char = if start < 0 {
+                    substr_bounds(v.chars().count(), start, None).0
+                } else {
+                    start as usize
+                };
+                let rest = &v[char_to_byte_offset(v, start_char)..];
+                let end = length.map_or(rest.len(), |length| char_to_byte_offset(rest, length));
+                Some(&rest[..end])
             })
-            .collect::<arrow2::array::Utf8Array<i64>>()
-            .with_validity(self_arrow.validity().cloned());
+            .collect::<arrow2::array::Utf8Array<i64>>();
         Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
     }
 
@@ -237,7 +554,7 @@ impl Utf8Array {
                 match other_scalar_value {
                     None => Ok(BooleanArray::full_null(
                         self.name(),
-                        self.data_type(),
+                        &DataType::Boolean,
                         self_len,
                     )),
                     Some(other_v) => {
@@ -255,7 +572,7 @@ impl Utf8Array {
                 match self_scalar_value {
                     None => Ok(BooleanArray::full_null(
                         self.name(),
-                        self.data_type(),
+                        &DataType::Boolean,
                         other_len,
                     )),
                     Some(self_v) => {
@@ -301,6 +618,141 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn check_substr() -> DaftResult<()> {
+        let ascii = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("hello"),
+                None,
+                Some(""),
+            ])),
+        ));
+        let substrs = |array: &Utf8Array, start, length| -> DaftResult<Vec<Option<String>>> {
+            Ok(array
+                .substr(start, length)?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect())
+        };
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(|v| v.to_string())).collect()
+        };
+        assert_eq!(
+            substrs(&ascii, 1, Some(3))?,
+            strings(&[Some("ell"), None, Some("")])
+        );
+        assert_eq!(
+            substrs(&ascii, -2, None)?,
+            strings(&[Some("lo"), None, Some("")])
+        );
+        assert_eq!(
+            substrs(&ascii, -10, Some(2))?,
+            strings(&[Some("he"), None, Some("")])
+        );
+        assert_eq!(
+            substrs(&ascii, 10, Some(2))?,
+            strings(&[Some(""), None, Some("")])
+        );
+
+        let non_ascii = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("héllo"),
+                Some("日本語"),
+            ])),
+        ));
+        assert_eq!(
+            substrs(&non_ascii, 1, Some(2))?,
+            strings(&[Some("él"), Some("本語")])
+        );
+        assert_eq!(
+            substrs(&non_ascii, -2, Some(1))?,
+            strings(&[Some("l"), Some("本")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_regex_kernels() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("GET /index.html 200"),
+                Some("POST /login 403"),
+                None,
+                Some("no request here"),
+            ])),
+        ));
+        let pattern = Utf8Array::from((
+            "pattern",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![Some(
+                r"^(\w+) (\S+) (\d+)$",
+            )])),
+        ));
+
+        let matched = data.match_(&pattern)?;
+        let matched: Vec<_> = matched.as_arrow().iter().collect();
+        assert_eq!(matched, [Some(true), Some(true), None, Some(false)]);
+
+        let extracted = data.extract(&pattern, 2)?;
+        let extracted: Vec<_> = extracted.as_arrow().iter().collect();
+        assert_eq!(extracted, [Some("/index.html"), Some("/login"), None, None]);
+
+        let replacement = Utf8Array::from((
+            "replacement",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![Some("$3 $1")])),
+        ));
+        let replaced = data.replace(&pattern, &replacement)?;
+        assert_eq!(replaced.name(), "data");
+        let replaced: Vec<_> = replaced.as_arrow().iter().collect();
+        assert_eq!(
+            replaced,
+            [
+                Some("200 GET"),
+                Some("403 POST"),
+                None,
+                Some("no request here")
+            ]
+        );
+
+        let invalid = Utf8Array::from((
+            "pattern",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![Some("(")])),
+        ));
+        assert!(data.match_(&invalid).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_split_regex() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a1b22c"),
+                Some(""),
+                None,
+                Some("abc"),
+            ])),
+        ));
+        let pattern = Utf8Array::from((
+            "pattern",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![Some(r"\d+")])),
+        ));
+
+        let result = data.split_regex(&pattern)?;
+        assert_eq!(result.name(), "data");
+        assert_eq!(result.offsets().as_slice(), &[0, 3, 4, 4, 5]);
+        assert_eq!(result.validity().map(|v| v.get_bit(2)), Some(false));
+        let splits: Vec<_> = result.flat_child.utf8()?.as_arrow().iter().collect();
+        assert_eq!(
+            splits,
+            [Some("a"), Some("b"), Some("c"), Some(""), Some("abc")]
+        );
+        Ok(())
+    }
+
     #[test]
     fn check_endswith_utf_arrays() -> DaftResult<()> {
         let data = Utf8Array::from((
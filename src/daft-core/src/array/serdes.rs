@@ -13,7 +13,9 @@ use crate::{
 #[cfg(feature = "python")]
 use crate::datatypes::PythonArray;
 
-use super::{ops::as_arrow::AsArrow, DataArray, FixedSizeListArray, ListArray, StructArray};
+use super::{
+    ops::as_arrow::AsArrow, CategoricalArray, DataArray, FixedSizeListArray, ListArray, StructArray,
+};
 
 // adapted from Polars Serdes iterator
 pub struct IterSer<I>
@@ -130,13 +132,94 @@ impl serde::Serialize for ExtensionArray {
     }
 }
 
+/// The objects of a Python array, serialized as a single pickled Python list with `None` for nulls.
+#[cfg(feature = "python")]
+pub(crate) struct PickledPyObjects(pub Vec<pyo3::PyObject>);
+
+#[cfg(feature = "python")]
+impl serde::Serialize for PickledPyObjects {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use pyo3::prelude::*;
+
+        Python::with_gil(|py| {
+            let pickle = PyModule::import(py, pyo3::intern!(py, "daft.pickle"))
+                .and_then(|pickle| pickle.getattr(pyo3::intern!(py, "dumps")))
+                .and_then(|dumps| dumps.call1((pyo3::types::PyList::new(py, &self.0),)))
+                .map_err(serde::ser::Error::custom)?;
+            let bytes: &[u8] = pickle.extract().map_err(serde::ser::Error::custom)?;
+            serializer.serialize_bytes(bytes)
+        })
+    }
+}
+
+#[cfg(feature = "python")]
+impl<'d> serde::Deserialize<'d> for PickledPyObjects {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'d>,
+    {
+        struct PickledPyObjectsVisitor;
+
+        impl<'d> serde::de::Visitor<'d> for PickledPyObjectsVisitor {
+            type Value = PickledPyObjects;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a byte array containing a pickled list of Python objects")
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use pyo3::prelude::*;
+
+                Python::with_gil(|py| {
+                    let objects = PyModule::import(py, pyo3::intern!(py, "daft.pickle"))
+                        .and_then(|pickle| pickle.getattr(pyo3::intern!(py, "loads")))
+                        .and_then(|loads| loads.call1((v,)))
+                        .and_then(|list| list.extract::<Vec<PyObject>>())
+                        .map_err(E::custom)?;
+                    Ok(PickledPyObjects(objects))
+                })
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_bytes(&v)
+            }
+
+            // Self-describing formats such as JSON serialize bytes as a sequence of integers.
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'d>,
+            {
+                let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    v.push(byte);
+                }
+                self.visit_bytes(&v)
+            }
+        }
+
+        deserializer.deserialize_bytes(PickledPyObjectsVisitor)
+    }
+}
+
 #[cfg(feature = "python")]
 impl serde::Serialize for PythonArray {
-    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        panic!("Rust Serde is not implemented for Python Arrays")
+        let mut s = serializer.serialize_map(Some(2))?;
+        s.serialize_entry("field", self.field())?;
+        s.serialize_entry("values", &PickledPyObjects(self.as_arrow().to_pyobj_vec()))?;
+        s.end()
     }
 }
 
@@ -207,6 +290,22 @@ impl serde::Serialize for FixedSizeListArray {
     }
 }
 
+impl serde::Serialize for CategoricalArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_map(Some(2))?;
+        let values = vec![
+            self.codes().clone().into_series(),
+            self.dictionary().clone().into_series(),
+        ];
+        s.serialize_entry("field", self.field.as_ref())?;
+        s.serialize_entry("values", &values)?;
+        s.end()
+    }
+}
+
 impl<L: DaftLogicalType> serde::Serialize for LogicalArray<L>
 where
     <<L as DaftLogicalType>::PhysicalType as crate::datatypes::DaftDataType>::ArrayType:
@@ -0,0 +1,89 @@
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions::PyValueError, prelude::*, types::PyBytes, PyObject, PyTypeInfo, ToPyObject,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+use std::string::ToString;
+
+use crate::impl_bincode_py_state_serialization;
+
+use common_error::{DaftError, DaftResult};
+
+/// Which bounds of a range check are inclusive.
+///
+/// | Both    - lower <= x <= upper.
+/// | Left    - lower <= x < upper.
+/// | Right   - lower < x <= upper.
+/// | Neither - lower < x < upper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum ClosedInterval {
+    Both = 1,
+    Left = 2,
+    Right = 3,
+    Neither = 4,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl ClosedInterval {
+    /// Create a ClosedInterval from its string representation.
+    ///
+    /// Args:
+    ///     closed: String representation of the closed interval, e.g. "both", "left", "right", or "neither".
+    #[staticmethod]
+    pub fn from_closed_interval_str(closed: &str) -> PyResult<Self> {
+        Self::from_str(closed).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl_bincode_py_state_serialization!(ClosedInterval);
+
+impl ClosedInterval {
+    pub fn iterator() -> std::slice::Iter<'static, ClosedInterval> {
+        use ClosedInterval::*;
+
+        static CLOSED_INTERVALS: [ClosedInterval; 4] = [Both, Left, Right, Neither];
+        CLOSED_INTERVALS.iter()
+    }
+
+    pub fn lower_inclusive(&self) -> bool {
+        matches!(self, ClosedInterval::Both | ClosedInterval::Left)
+    }
+
+    pub fn upper_inclusive(&self) -> bool {
+        matches!(self, ClosedInterval::Both | ClosedInterval::Right)
+    }
+}
+
+impl FromStr for ClosedInterval {
+    type Err = DaftError;
+
+    fn from_str(closed: &str) -> DaftResult<Self> {
+        use ClosedInterval::*;
+
+        match closed {
+            "both" => Ok(Both),
+            "left" => Ok(Left),
+            "right" => Ok(Right),
+            "neither" => Ok(Neither),
+            _ => Err(DaftError::TypeError(format!(
+                "Closed interval {} is not supported; only the following are supported: {:?}",
+                closed,
+                ClosedInterval::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for ClosedInterval {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
@@ -2,9 +2,14 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use common_error::{DaftError, DaftResult};
 
-use crate::{impl_binary_trait_by_reference, utils::supertype::try_get_supertype};
+use crate::impl_binary_trait_by_reference;
 
-use super::DataType;
+use super::{
+    supertype::{
+        decimal_operands, try_get_supertype, try_numeric_supertype, try_physical_supertype,
+    },
+    DataType, TimeUnit, DECIMAL128_MAX_PRECISION,
+};
 
 impl DataType {
     pub fn logical_op(&self, other: &Self) -> DaftResult<DataType> {
@@ -24,6 +29,42 @@ impl DataType {
             ))
         })
     }
+    pub fn bitwise_op(&self, other: &Self) -> DaftResult<DataType> {
+        // Whether a bitwise op (and, or, xor) is supported between the two types.
+        use DataType::*;
+        match (self, other) {
+            // Mixing 64-bit signed and unsigned integers has no integer supertype.
+            (s, o) if s.is_bitwise_integer() && o.is_bitwise_integer() => {
+                try_numeric_supertype(s, o).ok().filter(|t| t.is_integer())
+            }
+            (Null, o) | (o, Null) if o.is_bitwise_integer() => Some(o.clone()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            DaftError::TypeError(format!(
+                "Cannot perform bitwise operation on types: {}, {}",
+                self, other
+            ))
+        })
+    }
+    pub fn shift_op(&self, other: &Self) -> DaftResult<DataType> {
+        // Whether a bit shift is supported between the two types; the result keeps the type of
+        // the shifted value.
+        use DataType::*;
+        match (self, other) {
+            (s, o) if s.is_bitwise_integer() && (o.is_bitwise_integer() || o == &Null) => {
+                Ok(s.clone())
+            }
+            _ => Err(DaftError::TypeError(format!(
+                "Cannot perform bit shift on types: {}, {}",
+                self, other
+            ))),
+        }
+    }
+    fn is_bitwise_integer(&self) -> bool {
+        // Int128 is only used as the physical type of decimals, which don't support bit operations.
+        self.is_integer() && !matches!(self, DataType::Int128)
+    }
     pub fn comparison_op(
         &self,
         other: &Self,
@@ -36,6 +77,12 @@ impl DataType {
         let evaluator = || {
             use DataType::*;
             match (self, other) {
+                // Categoricals are compared as the strings they encode.
+                (Categorical, o) | (o, Categorical) => {
+                    let o = if o == &Categorical { &Utf8 } else { o };
+                    Utf8.comparison_op(o)?;
+                    Ok((Boolean, Some(Utf8), Utf8))
+                }
                 (s, o) if s == o => Ok((Boolean, None, s.to_physical())),
                 (s, o) if s.is_physical() && o.is_physical() => {
                     Ok((Boolean, None, try_physical_supertype(s, o)?))
@@ -50,6 +97,12 @@ impl DataType {
                     let pt = intermediate_type.to_physical();
                     Ok((Boolean, Some(intermediate_type), pt))
                 }
+                (s, o) if is_decimal_float_op(s, o) => Ok((Boolean, Some(Float64), Float64)),
+                (s, o) if decimal_operands(s, o).is_some() => {
+                    let intermediate_type = try_get_supertype(s, o)?;
+                    let pt = intermediate_type.to_physical();
+                    Ok((Boolean, Some(intermediate_type), pt))
+                }
                 _ => Err(DaftError::TypeError(format!(
                     "Cannot perform comparison on types: {}, {}",
                     self, other
@@ -57,12 +110,22 @@ impl DataType {
             }
         };
 
-        evaluator().map_err(|err| {
-            DaftError::TypeError(format!(
-                "Cannot perform comparison on types: {}, {}\nDetails:\n{err}",
-                self, other
-            ))
-        })
+        evaluator()
+            .and_then(|(output_type, intermediate, comp_type)| {
+                // The comparison kernels only support flat types.
+                if comp_type.is_nested() || comp_type.is_extension() {
+                    return Err(DaftError::TypeError(format!(
+                        "Comparisons are not supported for values of type {comp_type}"
+                    )));
+                }
+                Ok((output_type, intermediate, comp_type))
+            })
+            .map_err(|err| {
+                DaftError::TypeError(format!(
+                    "Cannot perform comparison on types: {}, {}\nDetails:\n{err}",
+                    self, other
+                ))
+            })
     }
     pub fn membership_op(
         &self,
@@ -73,6 +136,23 @@ impl DataType {
     }
 }
 
+/// Whether one operand of a binary operation is a decimal and the other is a float, in which case
+/// the operation is performed on Float64.
+fn is_decimal_float_op(l: &DataType, r: &DataType) -> bool {
+    matches!(
+        (l, r),
+        (DataType::Decimal128(..), o) | (o, DataType::Decimal128(..)) if o.is_floating()
+    )
+}
+
+/// Output type of adding or subtracting decimals, which is exact unless the precision is capped.
+fn decimal_add_type(l: &DataType, r: &DataType) -> Option<DataType> {
+    let ((lp, ls), (rp, rs)) = decimal_operands(l, r)?;
+    let scale = ls.max(rs);
+    let precision = ((lp - ls).max(rp - rs) + scale + 1).min(DECIMAL128_MAX_PRECISION);
+    Some(DataType::Decimal128(precision, scale))
+}
+
 impl Add for &DataType {
     type Output = DaftResult<DataType>;
 
@@ -89,6 +169,19 @@ impl Add for &DataType {
                 | (du @ Duration(..), ts @ Timestamp(..)) => Err(DaftError::TypeError(
                     format!("Cannot add due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", ts, du)
                 )),
+                // Durations must be whole days to be added to dates.
+                (Date, Duration(..)) | (Duration(..), Date) => Ok(Date),
+                (s, o) if is_decimal_float_op(s, o) => Ok(Float64),
+                (s, o) if decimal_operands(s, o).is_some() => Ok(decimal_add_type(s, o).unwrap()),
+                // Intervals are calendar-aware, so they keep the type of the date or timestamp.
+                (Date, Interval) | (Interval, Date) => Ok(Date),
+                (Timestamp(unit, tz), Interval) | (Interval, Timestamp(unit, tz)) => {
+                    Ok(Timestamp(*unit, tz.clone()))
+                }
+                (Duration(l_unit), Duration(r_unit)) if l_unit == r_unit => Ok(Duration(*l_unit)),
+                (l @ Duration(..), r @ Duration(..)) => Err(DaftError::TypeError(
+                    format!("Cannot add due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", l, r)
+                )),
                 (Null, other) | (other, Null) => {
                     match other {
                         // Condition is for backwards compatibility. TODO: remove
@@ -101,18 +194,12 @@ impl Add for &DataType {
                         )),
                     }
                 }
-                (Utf8, other) | (other, Utf8) => {
-                    match other {
-                        // Date condition is for backwards compatibility. TODO: remove
-                        Binary | Date => Err(DaftError::TypeError(
-                            format!("Cannot add types: {}, {}", self, other)
-                        )),
-                        other if other.is_physical() => Ok(Utf8),
-                        _ => Err(DaftError::TypeError(
-                            format!("Cannot add types: {}, {}", self, other)
-                        )),
-                    }
-                }
+                // Adding strings concatenates them, so require other operands to be cast to strings
+                // explicitly rather than implicitly formatting them.
+                (Utf8, Utf8) => Ok(Utf8),
+                (Utf8, other) | (other, Utf8) => Err(DaftError::TypeError(
+                    format!("Cannot add types: {}, {}. To concatenate them as strings, explicitly cast the {} operand to Utf8", self, other, other)
+                )),
                 (Boolean, other) | (other, Boolean)
                     if other.is_numeric() => Ok(other.clone()),
                 _ => Err(DaftError::TypeError(
@@ -137,6 +224,22 @@ impl Sub for &DataType {
                 (ts @ Timestamp(..), du @ Duration(..)) => Err(DaftError::TypeError(
                     format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", ts, du)
                 )),
+                (Timestamp(l_unit, l_tz), Timestamp(r_unit, r_tz))
+                    if l_unit == r_unit && l_tz == r_tz => Ok(Duration(*l_unit)),
+                (l @ Timestamp(..), r @ Timestamp(..)) => Err(DaftError::TypeError(
+                    format!("Cannot subtract due to differing precision or timezone: {}, {}. Please explicitly cast to the precision and timezone you wish to subtract in.", l, r)
+                )),
+                // Durations must be whole days to be subtracted from dates.
+                (Date, Duration(..)) => Ok(Date),
+                (s, o) if is_decimal_float_op(s, o) => Ok(Float64),
+                (s, o) if decimal_operands(s, o).is_some() => Ok(decimal_add_type(s, o).unwrap()),
+                (Date, Interval) => Ok(Date),
+                (Timestamp(unit, tz), Interval) => Ok(Timestamp(*unit, tz.clone())),
+                (Date, Date) => Ok(Duration(TimeUnit::Seconds)),
+                (Duration(l_unit), Duration(r_unit)) if l_unit == r_unit => Ok(Duration(*l_unit)),
+                (l @ Duration(..), r @ Duration(..)) => Err(DaftError::TypeError(
+                    format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to subtract in.", l, r)
+                )),
                 _ => Err(DaftError::TypeError(
                     format!("Cannot subtract types: {}, {}", self, other)
                 ))
@@ -154,6 +257,8 @@ impl Div for &DataType {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
             (s, o) if s.is_numeric() && o.is_numeric() => Ok(Float64),
+            (s, o) if is_decimal_float_op(s, o) || decimal_operands(s, o).is_some() => Ok(Float64),
+            (Duration(unit), other) if other.is_integer() => Ok(Duration(*unit)),
             _ => Err(DaftError::TypeError(format!(
                 "Cannot divide types: {}, {}",
                 self, other
@@ -170,6 +275,21 @@ impl Mul for &DataType {
         try_numeric_supertype(self, other).or(match (self, other) {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
+            (Duration(unit), other) | (other, Duration(unit)) if other.is_integer() => {
+                Ok(Duration(*unit))
+            }
+            (s, o) if is_decimal_float_op(s, o) => Ok(Float64),
+            // The scales of the operands add up, so only the precision can be capped.
+            (s, o) if decimal_operands(s, o).is_some() => {
+                let ((lp, ls), (rp, rs)) = decimal_operands(s, o).unwrap();
+                if ls + rs > DECIMAL128_MAX_PRECISION {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot multiply types: {}, {}, since the scale of the result would exceed {}",
+                        self, other, DECIMAL128_MAX_PRECISION
+                    )));
+                }
+                Ok(Decimal128((lp + rp).min(DECIMAL128_MAX_PRECISION), ls + rs))
+            }
             _ => Err(DaftError::TypeError(format!(
                 "Cannot multiply types: {}, {}",
                 self, other
@@ -199,107 +319,3 @@ impl_binary_trait_by_reference!(DataType, Sub, sub);
 impl_binary_trait_by_reference!(DataType, Mul, mul);
 impl_binary_trait_by_reference!(DataType, Div, div);
 impl_binary_trait_by_reference!(DataType, Rem, rem);
-
-pub fn try_physical_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
-    // Given two physical data types,
-    // get the physical data type that they can both be casted to.
-
-    use DataType::*;
-    try_numeric_supertype(l, r).or(match (l, r) {
-        (Null, other) | (other, Null) if other.is_physical() => Ok(other.clone()),
-        (Boolean, other) | (other, Boolean) if other.is_numeric() => Ok(other.clone()),
-        #[cfg(feature = "python")]
-        (Python, _) | (_, Python) => Ok(Python),
-        (Utf8, o) | (o, Utf8) if o.is_physical() && !matches!(o, Binary) => Ok(Utf8),
-        _ => Err(DaftError::TypeError(format!(
-            "Invalid arguments to try_physical_supertype: {}, {}",
-            l, r
-        ))),
-    })
-}
-
-pub fn try_numeric_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
-    // If given two numeric data types,
-    // get the numeric type that they should both be casted to
-    // for the purpose of performing numeric operations.
-
-    fn inner(l: &DataType, r: &DataType) -> Option<DataType> {
-        use DataType::*;
-
-        match (l, r) {
-            (Int8, Int8) => Some(Int8),
-            (Int8, Int16) => Some(Int16),
-            (Int8, Int32) => Some(Int32),
-            (Int8, Int64) => Some(Int64),
-            (Int8, UInt8) => Some(Int16),
-            (Int8, UInt16) => Some(Int32),
-            (Int8, UInt32) => Some(Int64),
-            (Int8, UInt64) => Some(Float64), // Follow numpy
-            (Int8, Float32) => Some(Float32),
-            (Int8, Float64) => Some(Float64),
-
-            (Int16, Int16) => Some(Int16),
-            (Int16, Int32) => Some(Int32),
-            (Int16, Int64) => Some(Int64),
-            (Int16, UInt8) => Some(Int16),
-            (Int16, UInt16) => Some(Int32),
-            (Int16, UInt32) => Some(Int64),
-            (Int16, UInt64) => Some(Float64), // Follow numpy
-            (Int16, Float32) => Some(Float32),
-            (Int16, Float64) => Some(Float64),
-
-            (Int32, Int32) => Some(Int32),
-            (Int32, Int64) => Some(Int64),
-            (Int32, UInt8) => Some(Int32),
-            (Int32, UInt16) => Some(Int32),
-            (Int32, UInt32) => Some(Int64),
-            (Int32, UInt64) => Some(Float64),  // Follow numpy
-            (Int32, Float32) => Some(Float64), // Follow numpy
-            (Int32, Float64) => Some(Float64),
-
-            (Int64, Int64) => Some(Int64),
-            (Int64, UInt8) => Some(Int64),
-            (Int64, UInt16) => Some(Int64),
-            (Int64, UInt32) => Some(Int64),
-            (Int64, UInt64) => Some(Float64),  // Follow numpy
-            (Int64, Float32) => Some(Float64), // Follow numpy
-            (Int64, Float64) => Some(Float64),
-
-            (UInt8, UInt8) => Some(UInt8),
-            (UInt8, UInt16) => Some(UInt16),
-            (UInt8, UInt32) => Some(UInt32),
-            (UInt8, UInt64) => Some(UInt64),
-            (UInt8, Float32) => Some(Float32),
-            (UInt8, Float64) => Some(Float64),
-
-            (UInt16, UInt16) => Some(UInt16),
-            (UInt16, UInt32) => Some(UInt32),
-            (UInt16, UInt64) => Some(UInt64),
-            (UInt16, Float32) => Some(Float32),
-            (UInt16, Float64) => Some(Float64),
-
-            (UInt32, UInt32) => Some(UInt32),
-            (UInt32, UInt64) => Some(UInt64),
-            (UInt32, Float32) => Some(Float64),
-            (UInt32, Float64) => Some(Float64),
-
-            (UInt64, UInt64) => Some(UInt64),
-            (UInt64, Float32) => Some(Float64),
-            (UInt64, Float64) => Some(Float64),
-
-            (Float32, Float32) => Some(Float32),
-            (Float32, Float64) => Some(Float64),
-
-            (Float64, Float64) => Some(Float64),
-
-            _ => None,
-        }
-    }
-
-    inner(l, r)
-        .or(inner(r, l))
-        .ok_or(DaftError::TypeError(format!(
-            "Invalid arguments to numeric supertype: {}, {}",
-            l, r
-        )))
-}
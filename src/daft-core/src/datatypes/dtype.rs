@@ -1,13 +1,21 @@
 use std::fmt::{Display, Formatter, Result};
 
-use arrow2::datatypes::DataType as ArrowType;
+use arrow2::{
+    compute::cast::can_cast_types,
+    datatypes::{DataType as ArrowType, IntervalUnit},
+};
 
-use crate::datatypes::{field::Field, image_mode::ImageMode, time_unit::TimeUnit};
+use crate::datatypes::{
+    extension::get_extension_type, field::Field, image_mode::ImageMode, time_unit::TimeUnit,
+};
 
 use common_error::{DaftError, DaftResult};
 
 use serde::{Deserialize, Serialize};
 
+/// Maximum number of digits of a [`DataType::Decimal128`].
+pub const DECIMAL128_MAX_PRECISION: usize = 38;
+
 // pub type TimeZone = String;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -66,16 +74,27 @@ pub enum DataType {
     Time(TimeUnit),
     /// Measure of elapsed time. This elapsed time is a physical duration (i.e. 1s as defined in S.I.)
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds.
+    ///
+    /// Unlike a [`DataType::Duration`], an interval doesn't have a fixed length: adding 1 month to
+    /// a date moves it to the same day of the next month, however many days that month has.
+    Interval,
     /// Opaque binary data of variable length whose offsets are represented as [`i64`].
     Binary,
     /// A variable-length UTF-8 encoded string whose offsets are represented as [`i64`].
     Utf8,
+    /// A UTF-8 encoded string that is dictionary-encoded, i.e. stored as [`u32`] codes into a
+    /// dictionary of unique values. Useful for columns with few distinct values.
+    Categorical,
     /// A list of some logical data type with a fixed number of elements.
     FixedSizeList(Box<DataType>, usize),
     /// A list of some logical data type whose offsets are represented as [`i64`].
     List(Box<DataType>),
     /// A nested [`DataType`] with a given number of [`Field`]s.
     Struct(Vec<Field>),
+    /// A map from keys of the first data type to values of the second data type, stored as a list
+    /// of `key`/`value` structs.
+    Map(Box<DataType>, Box<DataType>),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
     // Stop ArrowTypes
@@ -110,7 +129,7 @@ impl DataTypePayload {
         }
     }
 }
-const DAFT_SUPER_EXTENSION_NAME: &str = "daft.super_extension";
+pub(crate) const DAFT_SUPER_EXTENSION_NAME: &str = "daft.super_extension";
 
 impl DataType {
     pub fn new_null() -> DataType {
@@ -143,8 +162,14 @@ impl DataType {
             DataType::Date => Ok(ArrowType::Date32),
             DataType::Time(unit) => Ok(ArrowType::Time64(unit.to_arrow())),
             DataType::Duration(unit) => Ok(ArrowType::Duration(unit.to_arrow())),
+            DataType::Interval => Ok(ArrowType::Interval(IntervalUnit::MonthDayNano)),
             DataType::Binary => Ok(ArrowType::LargeBinary),
             DataType::Utf8 => Ok(ArrowType::LargeUtf8),
+            DataType::Categorical => Ok(ArrowType::Dictionary(
+                arrow2::datatypes::IntegerType::UInt32,
+                Box::new(ArrowType::LargeUtf8),
+                false,
+            )),
             DataType::FixedSizeList(child_dtype, size) => Ok(ArrowType::FixedSizeList(
                 Box::new(arrow2::datatypes::Field::new(
                     "item",
@@ -163,6 +188,18 @@ impl DataType {
                     .collect::<DaftResult<Vec<arrow2::datatypes::Field>>>()?;
                 ArrowType::Struct(fields)
             }),
+            // Arrow requires map keys to be non-nullable.
+            DataType::Map(key, value) => Ok(ArrowType::Map(
+                Box::new(arrow2::datatypes::Field::new(
+                    "entries",
+                    ArrowType::Struct(vec![
+                        arrow2::datatypes::Field::new("key", key.to_arrow()?, false),
+                        arrow2::datatypes::Field::new("value", value.to_arrow()?, true),
+                    ]),
+                    false,
+                )),
+                false,
+            )),
             DataType::Extension(name, dtype, metadata) => Ok(ArrowType::Extension(
                 name.clone(),
                 Box::new(dtype.to_arrow()?),
@@ -197,7 +234,16 @@ impl DataType {
             Decimal128(..) => Int128,
             Date => Int32,
             Duration(_) | Timestamp(..) | Time(_) => Int64,
+            Interval => Struct(vec![
+                Field::new("months", Int32),
+                Field::new("days", Int32),
+                Field::new("nanoseconds", Int64),
+            ]),
             List(child_dtype) => List(Box::new(child_dtype.to_physical())),
+            Map(key, value) => List(Box::new(Struct(vec![
+                Field::new("key", *key.clone()),
+                Field::new("value", *value.clone()),
+            ]))),
             FixedSizeList(child_dtype, size) => {
                 FixedSizeList(Box::new(child_dtype.to_physical()), *size)
             }
@@ -236,6 +282,26 @@ impl DataType {
         self.to_arrow().is_ok()
     }
 
+    /// Floating point type that can represent the values of this numeric type, which is what math
+    /// functions like `sqrt` produce.
+    pub fn to_floating_representation(&self) -> DaftResult<DataType> {
+        match self {
+            DataType::Int8
+            | DataType::Int16
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::Float32 => Ok(DataType::Float32),
+            DataType::Int32
+            | DataType::Int64
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float64 => Ok(DataType::Float64),
+            _ => Err(DaftError::TypeError(format!(
+                "Expected a numeric type but received {self}"
+            ))),
+        }
+    }
+
     #[inline]
     pub fn is_numeric(&self) -> bool {
         match self {
@@ -290,6 +356,11 @@ impl DataType {
         }
     }
 
+    #[inline]
+    pub fn is_embedding(&self) -> bool {
+        matches!(self, DataType::Embedding(..))
+    }
+
     #[inline]
     pub fn is_tensor(&self) -> bool {
         matches!(self, DataType::Tensor(..))
@@ -319,6 +390,11 @@ impl DataType {
         }
     }
 
+    #[inline]
+    pub fn is_struct(&self) -> bool {
+        matches!(self, DataType::Struct(..))
+    }
+
     #[inline]
     pub fn is_extension(&self) -> bool {
         matches!(self, DataType::Extension(..))
@@ -353,6 +429,7 @@ impl DataType {
             DataType::Float32 => Some(4.),
             DataType::Float64 => Some(8.),
             DataType::Utf8 => Some(VARIABLE_TYPE_SIZE),
+            DataType::Categorical => Some(4.),
             DataType::Binary => Some(VARIABLE_TYPE_SIZE),
             DataType::FixedSizeList(dtype, len) => {
                 dtype.estimate_size_bytes().map(|b| b * (len as f64))
@@ -380,6 +457,8 @@ impl DataType {
                 | DataType::Time(..)
                 | DataType::Timestamp(..)
                 | DataType::Duration(..)
+                | DataType::Interval
+                | DataType::Map(..)
                 | DataType::Embedding(..)
                 | DataType::Image(..)
                 | DataType::FixedShapeImage(..)
@@ -402,6 +481,80 @@ impl DataType {
         )
     }
 
+    /// Whether casting an array of this type to `dtype` is supported, which mirrors the dispatch
+    /// of the cast kernels without running them. Casts that can still fail on some values, e.g.
+    /// between tensors of different shapes, and casts involving Python objects or registered
+    /// extension types are only checked when they run.
+    pub fn can_cast_to(&self, dtype: &DataType) -> bool {
+        use DataType::*;
+        if self == dtype || self.is_python() || dtype.is_python() {
+            return true;
+        }
+        let has_kernels = |name: &str| !matches!(get_extension_type(name), Ok(None));
+        match (self, dtype) {
+            (Extension(name, ..), _) if has_kernels(name) => true,
+            (_, Extension(name, storage_dtype, _)) if has_kernels(name) => {
+                self.can_cast_to(storage_dtype)
+            }
+            (Categorical, Utf8) => true,
+            (Categorical, _) => Utf8.can_cast_to(dtype),
+            (Date, Utf8 | Int32 | Float32 | Float64 | Timestamp(..)) => true,
+            (Date, _) => false,
+            (Timestamp(..), Date | Time(..) | Utf8 | Float32 | Float64) => true,
+            (Time(..), Utf8 | Int64 | Float32 | Float64) => true,
+            (Duration(..), Float32 | Float64) => true,
+            (Duration(..), _) if dtype.is_integer() => true,
+            (Decimal128(..), Utf8) => true,
+            (Timestamp(..), Timestamp(..))
+            | (Time(..), Time(..))
+            | (Duration(..), Duration(..))
+            | (Decimal128(..), _) => arrow_logical_castable(self, dtype),
+            (Timestamp(..) | Time(..) | Duration(..), _) => arrow_castable(&Int64, dtype),
+            (Interval, Utf8) => true,
+            (Interval, _) => false,
+            (Map(..), Map(..)) => self.to_physical().can_cast_to(&dtype.to_physical()),
+            (Map(..), List(..)) => self.to_physical().can_cast_to(dtype),
+            (Map(..), _) => false,
+            (Embedding(..), Tensor(..))
+            | (Image(..), FixedShapeImage(..) | Tensor(..) | FixedShapeTensor(..) | Binary)
+            | (FixedShapeImage(..), Binary | Tensor(..) | Image(..))
+            | (Tensor(..), FixedShapeTensor(..) | Image(..) | FixedShapeImage(..))
+            | (FixedShapeTensor(..), Tensor(..)) => true,
+            (
+                Embedding(..) | Image(..) | FixedShapeImage(..) | Tensor(..) | FixedShapeTensor(..),
+                _,
+            ) => self.to_physical().can_cast_to(dtype),
+            (FixedSizeList(child, size), FixedSizeList(to_child, to_size)) => {
+                size == to_size && child.can_cast_to(to_child)
+            }
+            (FixedSizeList(child, _), List(to_child)) => child.can_cast_to(to_child),
+            (FixedSizeList(child, size), FixedShapeTensor(to_child, shape)) => {
+                child == to_child && shape.iter().product::<u64>() == *size as u64
+            }
+            (FixedSizeList(_, size), FixedShapeImage(mode, height, width)) => {
+                (height * width * mode.num_channels() as u32) as usize == *size
+            }
+            (FixedSizeList(..), _) => false,
+            (List(child), List(to_child) | FixedSizeList(to_child, _)) => {
+                child.can_cast_to(to_child)
+            }
+            (List(..), Map(..)) => self.can_cast_to(&dtype.to_physical()),
+            (List(..), _) => false,
+            // Fields missing from the source struct are filled with nulls.
+            (Struct(fields), Struct(to_fields)) => to_fields.iter().all(|to_field| {
+                fields
+                    .iter()
+                    .find(|field| field.name == to_field.name)
+                    .map_or(true, |field| field.dtype.can_cast_to(&to_field.dtype))
+            }),
+            (Struct(..), Tensor(..) | Image(..)) => self.can_cast_to(&dtype.to_physical()),
+            (Struct(..), _) => false,
+            (Utf8, Time(..) | Decimal128(..) | Categorical) => true,
+            (Binary, Image(..) | FixedShapeImage(..)) => true,
+            _ => arrow_castable(self, dtype),
+        }
+    }
+
     pub fn to_json(&self) -> DaftResult<String> {
         let payload = DataTypePayload::new(self);
         Ok(serde_json::to_string(&payload)?)
@@ -413,6 +566,31 @@ impl DataType {
     }
 }
 
+/// Whether Arrow can cast arrays of type `from` to `to`, either directly or between their physical
+/// types.
+fn arrow_castable(from: &DataType, to: &DataType) -> bool {
+    let (Ok(from_arrow), Ok(to_arrow)) = (from.to_arrow(), to.to_arrow()) else {
+        return false;
+    };
+    if can_cast_types(&from_arrow, &to_arrow) {
+        return true;
+    }
+    match (from.to_physical().to_arrow(), to.to_physical().to_arrow()) {
+        (Ok(from_physical), Ok(to_physical)) => {
+            to_physical != to_arrow && can_cast_types(&from_physical, &to_physical)
+        }
+        _ => false,
+    }
+}
+
+/// Whether Arrow can cast arrays of the logical type `from` to `to` directly.
+fn arrow_logical_castable(from: &DataType, to: &DataType) -> bool {
+    match (from.to_arrow(), to.to_arrow()) {
+        (Ok(from_arrow), Ok(to_arrow)) => can_cast_types(&from_arrow, &to_arrow),
+        _ => false,
+    }
+}
+
 impl From<&ArrowType> for DataType {
     fn from(item: &ArrowType) -> Self {
         match item {
@@ -426,29 +604,43 @@ impl From<&ArrowType> for DataType {
             ArrowType::UInt16 => DataType::UInt16,
             ArrowType::UInt32 => DataType::UInt32,
             ArrowType::UInt64 => DataType::UInt64,
-            // ArrowType::Float16 => DataType::Float16,
-            ArrowType::Float32 => DataType::Float32,
+            // Half-precision floats are widened when they are ingested.
+            ArrowType::Float16 | ArrowType::Float32 => DataType::Float32,
             ArrowType::Float64 => DataType::Float64,
             ArrowType::Timestamp(unit, timezone) => {
                 DataType::Timestamp(unit.into(), timezone.clone())
             }
             ArrowType::Date32 => DataType::Date,
             ArrowType::Date64 => DataType::Timestamp(TimeUnit::Milliseconds, None),
-            ArrowType::Time32(timeunit) | ArrowType::Time64(timeunit) => {
-                DataType::Time(timeunit.into())
-            }
+            // Times are stored with 64 bits, so 32-bit times are converted to microseconds when they
+            // are ingested.
+            ArrowType::Time32(_) => DataType::Time(TimeUnit::Microseconds),
+            ArrowType::Time64(timeunit) => DataType::Time(timeunit.into()),
             ArrowType::Duration(timeunit) => DataType::Duration(timeunit.into()),
+            ArrowType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
             ArrowType::Binary | ArrowType::LargeBinary | ArrowType::FixedSizeBinary(_) => {
                 DataType::Binary
             }
             ArrowType::Utf8 | ArrowType::LargeUtf8 => DataType::Utf8,
             ArrowType::Decimal(precision, scale) => DataType::Decimal128(*precision, *scale),
+            ArrowType::Dictionary(_, value, _) => match value.as_ref() {
+                ArrowType::Utf8 | ArrowType::LargeUtf8 => DataType::Categorical,
+                // Dictionaries of other types are decoded when they are ingested.
+                value => value.into(),
+            },
             ArrowType::List(field) | ArrowType::LargeList(field) => {
                 DataType::List(Box::new(field.as_ref().data_type().into()))
             }
             ArrowType::FixedSizeList(field, size) => {
                 DataType::FixedSizeList(Box::new(field.as_ref().data_type().into()), *size)
             }
+            ArrowType::Map(field, _) => match field.data_type() {
+                ArrowType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Box::new(fields[0].data_type().into()),
+                    Box::new(fields[1].data_type().into()),
+                ),
+                _ => panic!("Map DataType :{item:?} must have entries of key/value structs"),
+            },
             ArrowType::Struct(fields) => {
                 let fields: Vec<Field> = fields.iter().map(|fld| fld.into()).collect();
                 DataType::Struct(fields)
@@ -507,6 +699,7 @@ impl Display for DataType {
                     .join(", ");
                 write!(f, "Struct[{fields}]")
             }
+            DataType::Map(key, value) => write!(f, "Map[{}: {}]", key, value),
             DataType::Embedding(inner, size) => {
                 write!(f, "Embedding[{}; {}]", inner, size)
             }
@@ -524,3 +717,84 @@ impl Display for DataType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::*;
+
+    #[test]
+    fn arrow_round_trip() -> DaftResult<()> {
+        let struct_dtype = DataType::Struct(vec![
+            Field::new("a", DataType::Decimal128(10, 2)),
+            Field::new("b", DataType::List(Box::new(DataType::Categorical))).with_metadata(
+                std::collections::BTreeMap::from([("key".to_string(), "value".to_string())]),
+            ),
+        ]);
+        let dtypes = [
+            DataType::Decimal128(38, 10),
+            DataType::Timestamp(TimeUnit::Nanoseconds, Some("America/New_York".to_string())),
+            DataType::Timestamp(TimeUnit::Seconds, Some("+07:00".to_string())),
+            DataType::Categorical,
+            DataType::List(Box::new(DataType::Time(TimeUnit::Microseconds))),
+            DataType::FixedSizeList(Box::new(DataType::Int16), 3),
+            struct_dtype.clone(),
+            DataType::Map(Box::new(DataType::Utf8), Box::new(struct_dtype.clone())),
+            DataType::Extension(
+                "test.ext".to_string(),
+                Box::new(struct_dtype),
+                Some("metadata".to_string()),
+            ),
+            DataType::Embedding(Box::new(DataType::Float32), 4),
+            DataType::Image(Some(ImageMode::RGB)),
+        ];
+        for dtype in dtypes {
+            let arrow_dtype = dtype.to_arrow()?;
+            assert_eq!(DataType::from(&arrow_dtype), dtype);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn foreign_arrow_types() {
+        let cases = [
+            (ArrowType::Float16, DataType::Float32),
+            (
+                ArrowType::Date64,
+                DataType::Timestamp(TimeUnit::Milliseconds, None),
+            ),
+            (
+                ArrowType::Time32(arrow2::datatypes::TimeUnit::Millisecond),
+                DataType::Time(TimeUnit::Microseconds),
+            ),
+            (
+                ArrowType::Dictionary(
+                    arrow2::datatypes::IntegerType::Int8,
+                    Box::new(ArrowType::Utf8),
+                    false,
+                ),
+                DataType::Categorical,
+            ),
+            (
+                ArrowType::Dictionary(
+                    arrow2::datatypes::IntegerType::Int32,
+                    Box::new(ArrowType::Int64),
+                    false,
+                ),
+                DataType::Int64,
+            ),
+            (
+                ArrowType::List(Box::new(arrow2::datatypes::Field::new(
+                    "element",
+                    ArrowType::Utf8,
+                    true,
+                ))),
+                DataType::List(Box::new(DataType::Utf8)),
+            ),
+        ];
+        for (arrow_dtype, expected) in cases {
+            assert_eq!(DataType::from(&arrow_dtype), expected);
+        }
+    }
+}
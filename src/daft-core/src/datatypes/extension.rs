@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, MutexGuard},
+};
+
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::{dtype::DAFT_SUPER_EXTENSION_NAME, ExtensionArray, Field, UInt64Array},
+    utils::arrow::{ExtensionRegistryEntry, REGISTRY},
+    DataType, Series,
+};
+
+/// Kernels for a user-defined extension type, i.e. a [`DataType::Extension`] with the name that the
+/// implementation is registered under with [`register_extension_type`].
+///
+/// Arrays of an extension type are stored as arrays of its storage type, along with the type's
+/// name and serialized metadata, which are preserved in Arrow round-trips. Every kernel defaults to
+/// running on the storage of the array.
+pub trait ExtensionTypeImpl: Send + Sync {
+    /// Checks that the storage type and metadata of an extension type are valid for this extension.
+    /// This is called whenever an array of the extension type is created.
+    fn validate(&self, _storage_dtype: &DataType, _metadata: Option<&str>) -> DaftResult<()> {
+        Ok(())
+    }
+
+    /// Casts an array of this extension type to another type.
+    fn cast(&self, array: &ExtensionArray, dtype: &DataType) -> DaftResult<Series> {
+        array.storage()?.cast(dtype)
+    }
+
+    /// Formats the element at `idx` for display.
+    fn str_value(&self, array: &ExtensionArray, idx: usize) -> DaftResult<String> {
+        array.storage()?.str_value(idx)
+    }
+
+    /// Returns the indices that sort the array.
+    fn argsort(&self, array: &ExtensionArray, descending: bool) -> DaftResult<UInt64Array> {
+        Ok(array
+            .storage()?
+            .argsort(descending, descending)?
+            .u64()?
+            .clone())
+    }
+}
+
+fn registry() -> DaftResult<MutexGuard<'static, HashMap<String, ExtensionRegistryEntry>>> {
+    REGISTRY.lock().map_err(|_| {
+        DaftError::InternalError(
+            "The extension type registry was poisoned by a panic while it was being updated"
+                .to_string(),
+        )
+    })
+}
+
+/// Registers the kernels of the extension type named `name`, which are then used by all arrays of
+/// [`DataType::Extension`] types with that name.
+///
+/// A name can only be registered once per process, and processes that read arrays of the extension
+/// type must register it under the same name to use its kernels.
+pub fn register_extension_type<E>(name: &str, extension: E) -> DaftResult<()>
+where
+    E: ExtensionTypeImpl + 'static,
+{
+    if name == DAFT_SUPER_EXTENSION_NAME {
+        return Err(DaftError::ValueError(format!(
+            "The extension type name \"{name}\" is reserved"
+        )));
+    }
+    let mut registry = registry()?;
+    let entry = registry.entry(name.to_string()).or_default();
+    if entry.kernels.is_some() {
+        return Err(DaftError::ValueError(format!(
+            "An extension type named \"{name}\" is already registered"
+        )));
+    }
+    entry.kernels = Some(Arc::new(extension));
+    Ok(())
+}
+
+/// The kernels registered for the extension type named `name`, if any.
+pub fn get_extension_type(name: &str) -> DaftResult<Option<Arc<dyn ExtensionTypeImpl>>> {
+    Ok(registry()?
+        .get(name)
+        .and_then(|entry| entry.kernels.clone()))
+}
+
+/// Validates `dtype` against its registered extension type, if it is one.
+pub(crate) fn validate_extension_type(dtype: &DataType) -> DaftResult<()> {
+    if let DataType::Extension(name, storage_dtype, metadata) = dtype {
+        if let Some(extension) = get_extension_type(name)? {
+            extension.validate(storage_dtype, metadata.as_deref())?;
+        }
+    }
+    Ok(())
+}
+
+impl ExtensionArray {
+    /// Creates an array of the extension type `dtype` from an array of its storage type.
+    pub fn from_storage(name: &str, dtype: &DataType, storage: &Series) -> DaftResult<Self> {
+        let DataType::Extension(_, storage_dtype, _) = dtype else {
+            return Err(DaftError::TypeError(format!(
+                "Expected an extension type, but received {dtype}"
+            )));
+        };
+        if storage.data_type() != storage_dtype.as_ref() {
+            return Err(DaftError::TypeError(format!(
+                "Expected storage of type {storage_dtype} for extension type {dtype}, but received {}",
+                storage.data_type()
+            )));
+        }
+        validate_extension_type(dtype)?;
+        ExtensionArray::new(
+            Arc::new(Field::new(name, dtype.clone())),
+            storage.to_arrow().to_type(dtype.to_arrow()?),
+        )
+    }
+
+    /// The array of the storage type that this array is stored as.
+    pub fn storage(&self) -> DaftResult<Series> {
+        let DataType::Extension(_, storage_dtype, _) = self.data_type() else {
+            unreachable!("Extension array must have Extension datatype")
+        };
+        Series::from_arrow(
+            Arc::new(Field::new(self.name(), storage_dtype.as_ref().clone())),
+            self.data().to_boxed().to_type(storage_dtype.to_arrow()?),
+        )
+    }
+
+    /// The kernels registered for the extension type of this array, if any.
+    pub fn extension_type(&self) -> DaftResult<Option<Arc<dyn ExtensionTypeImpl>>> {
+        match self.data_type() {
+            DataType::Extension(name, ..) => get_extension_type(name),
+            _ => unreachable!("Extension array must have Extension datatype"),
+        }
+    }
+
+    pub fn argsort(&self, descending: bool) -> DaftResult<UInt64Array> {
+        match self.extension_type()? {
+            Some(extension) => extension.argsort(self, descending),
+            None => Err(DaftError::ValueError(format!(
+                "Cannot sort an array of unregistered extension type {}",
+                self.data_type()
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::*;
+    use crate::{array::ops::as_arrow::AsArrow, datatypes::Int64Array, IntoSeries};
+
+    /// Integers that are displayed in brackets and sort in reverse, and require a unit as metadata.
+    struct ReversedInt;
+
+    impl ExtensionTypeImpl for ReversedInt {
+        fn validate(&self, storage_dtype: &DataType, metadata: Option<&str>) -> DaftResult<()> {
+            if storage_dtype != &DataType::Int64 || metadata.is_none() {
+                return Err(DaftError::TypeError(
+                    "ReversedInt must be stored as Int64 with a unit".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        fn str_value(&self, array: &ExtensionArray, idx: usize) -> DaftResult<String> {
+            Ok(format!("<{}>", array.storage()?.str_value(idx)?))
+        }
+
+        fn argsort(&self, array: &ExtensionArray, descending: bool) -> DaftResult<UInt64Array> {
+            Ok(array
+                .storage()?
+                .argsort(!descending, !descending)?
+                .u64()?
+                .clone())
+        }
+    }
+
+    fn reversed_int_dtype(metadata: Option<&str>) -> DataType {
+        DataType::Extension(
+            "test.reversed_int".to_string(),
+            Box::new(DataType::Int64),
+            metadata.map(|m| m.to_string()),
+        )
+    }
+
+    #[test]
+    fn registered_extension_type() -> DaftResult<()> {
+        register_extension_type("test.reversed_int", ReversedInt)?;
+        assert!(register_extension_type("test.reversed_int", ReversedInt).is_err());
+
+        let storage = Int64Array::from(("a", vec![2, 3, 1])).into_series();
+        assert!(ExtensionArray::from_storage("a", &reversed_int_dtype(None), &storage).is_err());
+
+        let dtype = reversed_int_dtype(Some("seconds"));
+        let array = storage.cast(&dtype)?;
+        assert_eq!(array.data_type(), &dtype);
+        assert_eq!(array.str_value(0)?, "<2>");
+
+        let sorted = array.sort(false, false)?.cast(&DataType::Int64)?;
+        assert_eq!(sorted.i64()?.as_arrow().values().as_slice(), &[3, 2, 1]);
+
+        // The extension type and its validation survive Arrow round-trips.
+        let roundtrip = Series::try_from(("a", array.to_arrow()))?;
+        assert_eq!(roundtrip.data_type(), &dtype);
+        let invalid = storage
+            .to_arrow()
+            .to_type(reversed_int_dtype(None).to_arrow()?);
+        assert!(Series::try_from(("a", invalid)).is_err());
+        Ok(())
+    }
+}
@@ -15,6 +15,14 @@ pub struct Field {
     pub name: String,
     pub dtype: DataType,
     pub metadata: Arc<Metadata>,
+    /// Whether values of the field may be null. This is `true` unless the field is provably
+    /// non-null, e.g. the result of `count()`.
+    #[serde(default = "default_nullable")]
+    pub nullable: bool,
+}
+
+fn default_nullable() -> bool {
+    true
 }
 
 pub type FieldRef = Arc<Field>;
@@ -59,20 +67,29 @@ impl Field {
             name,
             dtype,
             metadata: Default::default(),
+            nullable: true,
         }
     }
 
     pub fn with_metadata<M: Into<Arc<Metadata>>>(self, metadata: M) -> Self {
         Self {
-            name: self.name,
-            dtype: self.dtype,
             metadata: metadata.into(),
+            ..self
         }
     }
 
+    pub fn with_nullable(self, nullable: bool) -> Self {
+        Self { nullable, ..self }
+    }
+
+    /// Whether the two fields are equal apart from their nullability.
+    pub fn eq_ignoring_nullability(&self, other: &Field) -> bool {
+        self.name == other.name && self.dtype == other.dtype && self.metadata == other.metadata
+    }
+
     pub fn to_arrow(&self) -> DaftResult<ArrowField> {
         Ok(
-            ArrowField::new(self.name.clone(), self.dtype.to_arrow()?, true)
+            ArrowField::new(self.name.clone(), self.dtype.to_arrow()?, self.nullable)
                 .with_metadata(self.metadata.as_ref().clone()),
         )
     }
@@ -80,8 +97,7 @@ impl Field {
     pub fn rename<S: Into<String>>(&self, name: S) -> Self {
         Self {
             name: name.into(),
-            dtype: self.dtype.clone(),
-            metadata: self.metadata.clone(),
+            ..self.clone()
         }
     }
 
@@ -94,6 +110,7 @@ impl Field {
             name: self.name.clone(),
             dtype: list_dtype,
             metadata: self.metadata.clone(),
+            nullable: true,
         })
     }
 
@@ -104,6 +121,7 @@ impl Field {
                     name: self.name.clone(),
                     dtype: child_dtype.as_ref().clone(),
                     metadata: self.metadata.clone(),
+                    nullable: true,
                 })
             }
             _ => Err(DaftError::ValueError(format!(
@@ -120,6 +138,7 @@ impl From<&ArrowField> for Field {
             name: af.name.clone(),
             dtype: af.data_type().into(),
             metadata: af.metadata.clone().into(),
+            nullable: af.is_nullable,
         }
     }
 }
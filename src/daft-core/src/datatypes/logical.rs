@@ -1,7 +1,7 @@
 use std::{marker::PhantomData, sync::Arc};
 
 use crate::{
-    array::StructArray,
+    array::{ListArray, StructArray},
     datatypes::{DaftLogicalType, DateType, Field},
     with_match_daft_logical_primitive_types,
 };
@@ -9,8 +9,8 @@ use common_error::DaftResult;
 
 use super::{
     DaftArrayType, DaftDataType, DataArray, DataType, Decimal128Type, DurationType, EmbeddingType,
-    FixedShapeImageType, FixedShapeTensorType, FixedSizeListArray, ImageType, TensorType, TimeType,
-    TimestampType,
+    FixedShapeImageType, FixedShapeTensorType, FixedSizeListArray, ImageType, IntervalType,
+    MapType, TensorType, TimeType, TimestampType,
 };
 
 /// A LogicalArray is a wrapper on top of some underlying array, applying the semantic meaning of its
@@ -137,6 +137,12 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, StructArray> {
     impl_logical_type!(StructArray);
 
     pub fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        // Intervals are stored as structs, but arrow has a native month/day/nanosecond layout.
+        if self.data_type() == &DataType::Interval {
+            return crate::array::ops::interval::physical_arrow_to_interval(
+                self.physical.to_arrow().as_ref(),
+            );
+        }
         let mut struct_arrow_array = self.physical.to_arrow();
         let arrow_logical_type = self.data_type().to_arrow().unwrap();
         struct_arrow_array.change_type(arrow_logical_type);
@@ -144,18 +150,40 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, StructArray> {
     }
 }
 
+/// Implementation for a LogicalArray that wraps a ListArray
+impl<L: DaftLogicalType> LogicalArrayImpl<L, ListArray> {
+    impl_logical_type!(ListArray);
+
+    pub fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        let arrow_logical_type = self.data_type().to_arrow().unwrap();
+        // Maps are stored as lists of key/value structs, but arrow has a native map layout.
+        if let arrow2::datatypes::DataType::Map(..) = arrow_logical_type {
+            return crate::array::ops::map::physical_arrow_to_map(
+                self.physical.to_arrow().as_ref(),
+                arrow_logical_type,
+            )
+            .unwrap();
+        }
+        let mut list_arrow_array = self.physical.to_arrow();
+        list_arrow_array.change_type(arrow_logical_type);
+        list_arrow_array
+    }
+}
+
 pub type LogicalArray<L> =
     LogicalArrayImpl<L, <<L as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType>;
 pub type Decimal128Array = LogicalArray<Decimal128Type>;
 pub type DateArray = LogicalArray<DateType>;
 pub type TimeArray = LogicalArray<TimeType>;
 pub type DurationArray = LogicalArray<DurationType>;
+pub type IntervalArray = LogicalArray<IntervalType>;
 pub type ImageArray = LogicalArray<ImageType>;
 pub type TimestampArray = LogicalArray<TimestampType>;
 pub type TensorArray = LogicalArray<TensorType>;
 pub type EmbeddingArray = LogicalArray<EmbeddingType>;
 pub type FixedShapeTensorArray = LogicalArray<FixedShapeTensorType>;
 pub type FixedShapeImageArray = LogicalArray<FixedShapeImageType>;
+pub type MapArray = LogicalArray<MapType>;
 
 pub trait DaftImageryType: DaftLogicalType {}
 
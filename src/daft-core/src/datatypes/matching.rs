@@ -25,11 +25,14 @@ macro_rules! with_match_daft_types {(
         Date => __with_ty__! { DateType },
         Time(_) => __with_ty__! { TimeType },
         Duration(_) => __with_ty__! { DurationType },
+        Interval => __with_ty__! { IntervalType },
         Binary => __with_ty__! { BinaryType },
         Utf8 => __with_ty__! { Utf8Type },
+        Categorical => __with_ty__! { CategoricalType },
         FixedSizeList(_, _) => __with_ty__! { FixedSizeListType },
         List(_) => __with_ty__! { ListType },
         Struct(_) => __with_ty__! { StructType },
+        Map(..) => __with_ty__! { MapType },
         Extension(_, _, _) => __with_ty__! { ExtensionType },
         #[cfg(feature = "python")]
         Python => __with_ty__! { PythonType },
@@ -74,6 +77,7 @@ macro_rules! with_match_physical_daft_types {(
         Float64 => __with_ty__! { Float64Type },
         Binary => __with_ty__! { BinaryType },
         Utf8 => __with_ty__! { Utf8Type },
+        Categorical => __with_ty__! { CategoricalType },
         FixedSizeList(_, _) => __with_ty__! { FixedSizeListType },
         List(_) => __with_ty__! { ListType },
         Struct(_) => __with_ty__! { StructType },
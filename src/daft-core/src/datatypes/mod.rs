@@ -1,19 +1,23 @@
 mod binary_ops;
 mod dtype;
+mod extension;
 mod field;
 mod image_format;
 mod image_mode;
 mod matching;
+pub mod supertype;
 mod time_unit;
+mod timezone;
 
+use crate::array::{CategoricalArray, ListArray, StructArray};
 pub use crate::array::{DataArray, FixedSizeListArray};
-use crate::array::{ListArray, StructArray};
 use arrow2::{
     compute::comparison::Simd8,
     types::{simd::Simd, NativeType},
 };
-pub use binary_ops::try_physical_supertype;
-pub use dtype::DataType;
+pub use supertype::try_physical_supertype;
+pub use dtype::{DataType, DECIMAL128_MAX_PRECISION};
+pub use extension::{get_extension_type, register_extension_type, ExtensionTypeImpl};
 pub use field::Field;
 pub use field::FieldID;
 pub use field::FieldRef;
@@ -23,6 +27,7 @@ use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, ToPrimitive, Zero}
 use serde::Serialize;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 pub use time_unit::TimeUnit;
+pub use timezone::Timezone;
 
 pub mod logical;
 
@@ -170,12 +175,15 @@ impl_daft_arrow_datatype!(ExtensionType, Unknown);
 impl_nested_datatype!(FixedSizeListType, FixedSizeListArray);
 impl_nested_datatype!(StructType, StructArray);
 impl_nested_datatype!(ListType, ListArray);
+impl_nested_datatype!(CategoricalType, CategoricalArray);
 
 impl_daft_logical_data_array_datatype!(Decimal128Type, Unknown, Int128Type);
 impl_daft_logical_data_array_datatype!(TimestampType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DateType, Date, Int32Type);
 impl_daft_logical_data_array_datatype!(TimeType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DurationType, Unknown, Int64Type);
+impl_daft_logical_data_array_datatype!(IntervalType, Interval, StructType);
+impl_daft_logical_data_array_datatype!(MapType, Unknown, ListType);
 impl_daft_logical_data_array_datatype!(ImageType, Unknown, StructType);
 impl_daft_logical_data_array_datatype!(TensorType, Unknown, StructType);
 impl_daft_logical_fixed_size_list_datatype!(EmbeddingType, Unknown);
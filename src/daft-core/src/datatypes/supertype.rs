@@ -0,0 +1,408 @@
+//! The type promotion lattice: the supertype of two types is the type that values of both can be
+//! cast to without failing, which binary operations, `if_else`, `concat` and `coalesce` cast their
+//! inputs to before operating on them.
+
+use common_error::{DaftError, DaftResult};
+
+use super::{DataType, Field, TimeUnit, DECIMAL128_MAX_PRECISION};
+
+/// Largely influenced by polars supertype logic which is based on numpy / python type propagation
+// Copyright (c) 2020 Ritchie Vink
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Unifies two time units to the coarser of the two, so that values of either unit fit in the result
+/// without overflowing, with occasional loss of precision.
+fn unify_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
+    *tu_l.max(tu_r)
+}
+
+/// Precision and scale of the decimal type that this type can be cast to without loss, if it is a
+/// decimal or an integer.
+fn decimal_precision_and_scale(dtype: &DataType) -> Option<(usize, usize)> {
+    use DataType::*;
+    match dtype {
+        Decimal128(precision, scale) => Some((*precision, *scale)),
+        Int8 | UInt8 => Some((3, 0)),
+        Int16 | UInt16 => Some((5, 0)),
+        Int32 | UInt32 => Some((10, 0)),
+        Int64 => Some((19, 0)),
+        UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
+
+/// Precisions and scales of the operands of a binary operation, if one of them is a decimal and the
+/// other is a decimal or an integer.
+pub(crate) fn decimal_operands(
+    l: &DataType,
+    r: &DataType,
+) -> Option<((usize, usize), (usize, usize))> {
+    if !matches!(l, DataType::Decimal128(..)) && !matches!(r, DataType::Decimal128(..)) {
+        return None;
+    }
+    Some((
+        decimal_precision_and_scale(l)?,
+        decimal_precision_and_scale(r)?,
+    ))
+}
+
+pub fn try_get_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
+    match get_supertype(l, r) {
+        Some(dt) => Ok(dt),
+        None => Err(DaftError::TypeError(format!(
+            "could not determine supertype of {l:?} and {r:?}"
+        ))),
+    }
+}
+
+pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
+    fn inner(l: &DataType, r: &DataType) -> Option<DataType> {
+        use DataType::*;
+
+        if l == r {
+            return Some(l.clone());
+        }
+
+        match (l, r) {
+            #[cfg(feature = "python")]
+            // The supertype of anything and Python is Python.
+            (_, Python) => Some(Python),
+
+            (l, r) if l.is_numeric() && r.is_numeric() => try_numeric_supertype(l, r).ok(),
+            (Boolean, r) if r.is_numeric() => Some(r.clone()),
+
+            (l, r) if decimal_operands(l, r).is_some() => {
+                let ((lp, ls), (rp, rs)) = decimal_operands(l, r).unwrap();
+                let scale = ls.max(rs);
+                let precision = ((lp - ls).max(rp - rs) + scale).min(DECIMAL128_MAX_PRECISION);
+                Some(Decimal128(precision, scale))
+            }
+            (Decimal128(..), r) if r.is_floating() => Some(Float64),
+
+            (Date, UInt8) => Some(Int64),
+            (Date, UInt16) => Some(Int64),
+            (Date, UInt32) => Some(Int64),
+            (Date, UInt64) => Some(Int64),
+            (Date, Int8) => Some(Int32),
+            (Date, Int16) => Some(Int32),
+            (Date, Int32) => Some(Int32),
+            (Date, Int64) => Some(Int64),
+            (Date, Float32) => Some(Float32),
+            (Date, Float64) => Some(Float64),
+            (Date, Timestamp(tu, tz)) => Some(Timestamp(*tu, tz.clone())),
+
+            (Timestamp(_, _), UInt32) => Some(Int64),
+            (Timestamp(_, _), UInt64) => Some(Int64),
+            (Timestamp(_, _), Int32) => Some(Int64),
+            (Timestamp(_, _), Int64) => Some(Int64),
+            (Timestamp(_, _), Float32) => Some(Float64),
+            (Timestamp(_, _), Float64) => Some(Float64),
+
+            (Duration(_), UInt32) => Some(Int64),
+            (Duration(_), UInt64) => Some(Int64),
+            (Duration(_), Int32) => Some(Int64),
+            (Duration(_), Int64) => Some(Int64),
+            (Duration(_), Float32) => Some(Float64),
+            (Duration(_), Float64) => Some(Float64),
+
+            (Time(_), Int32) => Some(Int64),
+            (Time(_), Int64) => Some(Int64),
+            (Time(_), Float32) => Some(Float64),
+            (Time(_), Float64) => Some(Float64),
+            (Time(lu), Time(ru)) => Some(Time(unify_time_units(lu, ru))),
+
+            (Duration(lu), Timestamp(ru, Some(tz))) => {
+                if tz.is_empty() {
+                    Some(Timestamp(unify_time_units(lu, ru), None))
+                } else {
+                    Some(Timestamp(unify_time_units(lu, ru), Some(tz.clone())))
+                }
+            }
+            (Duration(lu), Timestamp(ru, None)) => Some(Timestamp(unify_time_units(lu, ru), None)),
+            (Duration(_), Date) => Some(Date),
+            (Duration(lu), Duration(ru)) => Some(Duration(unify_time_units(lu, ru))),
+
+            // Some() timezones that are non equal
+            (Timestamp(tu_l, Some(tz_l)), Timestamp(tu_r, Some(tz_r)))
+                if !tz_l.is_empty() && !tz_r.is_empty() && tz_l != tz_r =>
+            {
+                Some(Timestamp(
+                    unify_time_units(tu_l, tu_r),
+                    Some("UTC".to_string()),
+                ))
+            }
+            // Both None or the same time zone
+            (Timestamp(tu_l, tz_l), Timestamp(tu_r, tz_r)) if tz_l == tz_r => {
+                Some(Timestamp(unify_time_units(tu_l, tu_r), tz_r.clone()))
+            }
+
+            (List(inner_left_dtype), List(inner_right_dtype)) => {
+                let inner_st =
+                    get_supertype(inner_left_dtype.as_ref(), inner_right_dtype.as_ref())?;
+                Some(DataType::List(Box::new(inner_st)))
+            }
+            (Map(lk, lv), Map(rk, rv)) => Some(Map(
+                Box::new(get_supertype(lk, rk)?),
+                Box::new(get_supertype(lv, rv)?),
+            )),
+            // Structs are cast by field name, so the supertype has the fields of both structs, with the
+            // fields of the left struct first.
+            (Struct(l_fields), Struct(r_fields)) => {
+                let mut fields = l_fields.clone();
+                for r_field in r_fields {
+                    match fields.iter_mut().find(|f| f.name == r_field.name) {
+                        Some(field) => {
+                            *field = Field::new(
+                                field.name.as_str(),
+                                get_supertype(&field.dtype, &r_field.dtype)?,
+                            );
+                        }
+                        None => fields.push(r_field.clone()),
+                    }
+                }
+                Some(Struct(fields))
+            }
+            // TODO(Clark): Add support for getting supertype for two fixed size lists once Arrow2 supports such a cast.
+            // (FixedSizeList(inner_left_field, inner_left_size), FixedSizeList(inner_right_field, inner_right_size)) if inner_left_size == inner_right_size => {
+            //     let inner_st = inner(&inner_left_field.dtype, &inner_right_field.dtype)?;
+            //     Some(DataType::FixedSizeList(Box::new(Field::new(inner_left_field.name.clone(), inner_st)), *inner_left_size))
+            // }
+            // TODO(Clark): Add support for getting supertype for a fixed size list and a list once Arrow2 supports such a cast.
+            // (FixedSizeList(inner_left_field, _inner_left_size), List(inner_right_field)) => {
+            //     let inner_st = get_supertype(&inner_left_field.dtype, &inner_right_field.dtype)?;
+            //     Some(DataType::List(Box::new(Field::new(inner_left_field.name.clone(), inner_st))))
+            // }
+
+            // every known type can be casted to a string except binary
+            (dt, Utf8) if dt.ne(&Binary) => Some(Utf8),
+            (dt, Null) => Some(dt.clone()), // Drop Null Type
+
+            _ => None,
+        }
+    }
+    match inner(l, r) {
+        Some(dt) => Some(dt),
+        None => inner(r, l),
+    }
+}
+
+pub fn try_physical_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
+    // Given two physical data types,
+    // get the physical data type that they can both be casted to.
+
+    use DataType::*;
+    try_numeric_supertype(l, r).or(match (l, r) {
+        (Null, other) | (other, Null) if other.is_physical() => Ok(other.clone()),
+        (Boolean, other) | (other, Boolean) if other.is_numeric() => Ok(other.clone()),
+        #[cfg(feature = "python")]
+        (Python, _) | (_, Python) => Ok(Python),
+        (Utf8, o) | (o, Utf8) if o.is_physical() && !matches!(o, Binary) => Ok(Utf8),
+        _ => Err(DaftError::TypeError(format!(
+            "Invalid arguments to try_physical_supertype: {}, {}",
+            l, r
+        ))),
+    })
+}
+
+pub fn try_numeric_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
+    // If given two numeric data types,
+    // get the numeric type that they should both be casted to
+    // for the purpose of performing numeric operations.
+
+    fn inner(l: &DataType, r: &DataType) -> Option<DataType> {
+        use DataType::*;
+
+        match (l, r) {
+            (Int8, Int8) => Some(Int8),
+            (Int8, Int16) => Some(Int16),
+            (Int8, Int32) => Some(Int32),
+            (Int8, Int64) => Some(Int64),
+            (Int8, UInt8) => Some(Int16),
+            (Int8, UInt16) => Some(Int32),
+            (Int8, UInt32) => Some(Int64),
+            (Int8, UInt64) => Some(Float64), // Follow numpy
+            (Int8, Float32) => Some(Float32),
+            (Int8, Float64) => Some(Float64),
+
+            (Int16, Int16) => Some(Int16),
+            (Int16, Int32) => Some(Int32),
+            (Int16, Int64) => Some(Int64),
+            (Int16, UInt8) => Some(Int16),
+            (Int16, UInt16) => Some(Int32),
+            (Int16, UInt32) => Some(Int64),
+            (Int16, UInt64) => Some(Float64), // Follow numpy
+            (Int16, Float32) => Some(Float32),
+            (Int16, Float64) => Some(Float64),
+
+            (Int32, Int32) => Some(Int32),
+            (Int32, Int64) => Some(Int64),
+            (Int32, UInt8) => Some(Int32),
+            (Int32, UInt16) => Some(Int32),
+            (Int32, UInt32) => Some(Int64),
+            (Int32, UInt64) => Some(Float64),  // Follow numpy
+            (Int32, Float32) => Some(Float64), // Follow numpy
+            (Int32, Float64) => Some(Float64),
+
+            (Int64, Int64) => Some(Int64),
+            (Int64, UInt8) => Some(Int64),
+            (Int64, UInt16) => Some(Int64),
+            (Int64, UInt32) => Some(Int64),
+            (Int64, UInt64) => Some(Float64),  // Follow numpy
+            (Int64, Float32) => Some(Float64), // Follow numpy
+            (Int64, Float64) => Some(Float64),
+
+            (UInt8, UInt8) => Some(UInt8),
+            (UInt8, UInt16) => Some(UInt16),
+            (UInt8, UInt32) => Some(UInt32),
+            (UInt8, UInt64) => Some(UInt64),
+            (UInt8, Float32) => Some(Float32),
+            (UInt8, Float64) => Some(Float64),
+
+            (UInt16, UInt16) => Some(UInt16),
+            (UInt16, UInt32) => Some(UInt32),
+            (UInt16, UInt64) => Some(UInt64),
+            (UInt16, Float32) => Some(Float32),
+            (UInt16, Float64) => Some(Float64),
+
+            (UInt32, UInt32) => Some(UInt32),
+            (UInt32, UInt64) => Some(UInt64),
+            (UInt32, Float32) => Some(Float64),
+            (UInt32, Float64) => Some(Float64),
+
+            (UInt64, UInt64) => Some(UInt64),
+            (UInt64, Float32) => Some(Float64),
+            (UInt64, Float64) => Some(Float64),
+
+            (Float32, Float32) => Some(Float32),
+            (Float32, Float64) => Some(Float64),
+
+            (Float64, Float64) => Some(Float64),
+
+            _ => None,
+        }
+    }
+
+    inner(l, r)
+        .or(inner(r, l))
+        .ok_or(DaftError::TypeError(format!(
+            "Invalid arguments to numeric supertype: {}, {}",
+            l, r
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supertypes() {
+        use DataType::*;
+
+        let utc = || Some("UTC".to_string());
+        let cases = [
+            // Numeric widening.
+            (Int8, Int16, Some(Int16)),
+            (Int8, UInt8, Some(Int16)),
+            (Int32, Float32, Some(Float64)),
+            (Int64, UInt64, Some(Float64)),
+            (UInt8, UInt32, Some(UInt32)),
+            (Float32, Float64, Some(Float64)),
+            (Boolean, Int32, Some(Int32)),
+            (Boolean, Float32, Some(Float32)),
+            (Decimal128(5, 2), Decimal128(10, 4), Some(Decimal128(10, 4))),
+            (Decimal128(5, 2), Int32, Some(Decimal128(12, 2))),
+            (Decimal128(5, 2), Float32, Some(Float64)),
+            // Temporal unit unification.
+            (
+                Timestamp(TimeUnit::Nanoseconds, None),
+                Timestamp(TimeUnit::Milliseconds, None),
+                Some(Timestamp(TimeUnit::Milliseconds, None)),
+            ),
+            (
+                Timestamp(TimeUnit::Seconds, None),
+                Timestamp(TimeUnit::Nanoseconds, None),
+                Some(Timestamp(TimeUnit::Seconds, None)),
+            ),
+            (
+                Timestamp(TimeUnit::Microseconds, Some("Asia/Tokyo".to_string())),
+                Timestamp(TimeUnit::Microseconds, Some("Europe/Paris".to_string())),
+                Some(Timestamp(TimeUnit::Microseconds, utc())),
+            ),
+            (
+                Timestamp(TimeUnit::Microseconds, utc()),
+                Timestamp(TimeUnit::Microseconds, None),
+                None,
+            ),
+            (
+                Date,
+                Timestamp(TimeUnit::Milliseconds, None),
+                Some(Timestamp(TimeUnit::Milliseconds, None)),
+            ),
+            (
+                Duration(TimeUnit::Nanoseconds),
+                Duration(TimeUnit::Microseconds),
+                Some(Duration(TimeUnit::Microseconds)),
+            ),
+            (
+                Time(TimeUnit::Nanoseconds),
+                Time(TimeUnit::Microseconds),
+                Some(Time(TimeUnit::Microseconds)),
+            ),
+            // Null absorption.
+            (Null, Int8, Some(Int8)),
+            (Null, Date, Some(Date)),
+            (Null, List(Box::new(Utf8)), Some(List(Box::new(Utf8)))),
+            // Utf8 fallbacks.
+            (Int64, Utf8, Some(Utf8)),
+            (Date, Utf8, Some(Utf8)),
+            (Categorical, Utf8, Some(Utf8)),
+            (Binary, Utf8, None),
+            // Nested types.
+            (
+                List(Box::new(Int8)),
+                List(Box::new(Float64)),
+                Some(List(Box::new(Float64))),
+            ),
+            (List(Box::new(Int8)), List(Box::new(Binary)), None),
+            (
+                Map(Box::new(Utf8), Box::new(Int8)),
+                Map(Box::new(Utf8), Box::new(Int64)),
+                Some(Map(Box::new(Utf8), Box::new(Int64))),
+            ),
+            (
+                Struct(vec![Field::new("a", Int8), Field::new("b", Utf8)]),
+                Struct(vec![Field::new("c", Boolean), Field::new("a", Int32)]),
+                Some(Struct(vec![
+                    Field::new("a", Int32),
+                    Field::new("b", Utf8),
+                    Field::new("c", Boolean),
+                ])),
+            ),
+            // Types without a supertype.
+            (Boolean, Date, None),
+            (Binary, Int64, None),
+        ];
+        for (l, r, expected) in cases {
+            assert_eq!(get_supertype(&l, &r), expected, "supertype of {l} and {r}");
+            if !matches!((&l, &r), (Struct(..), Struct(..))) {
+                assert_eq!(get_supertype(&r, &l), expected, "supertype of {r} and {l}");
+            }
+        }
+    }
+}
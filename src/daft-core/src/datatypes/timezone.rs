@@ -0,0 +1,141 @@
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Timelike};
+use common_error::{DaftError, DaftResult};
+
+use crate::DstResolution;
+
+/// The timezone of a Timestamp datatype, which is either a fixed offset like "+08:00" or a name in
+/// the tz database like "Europe/Berlin". The offsets of named timezones follow their daylight
+/// saving time transitions.
+#[derive(Clone, Copy, Debug)]
+pub enum Timezone {
+    Offset(FixedOffset),
+    Tz(chrono_tz::Tz),
+}
+
+impl Timezone {
+    pub fn parse(tz: &str) -> DaftResult<Self> {
+        if let Ok(offset) = arrow2::temporal_conversions::parse_offset(tz) {
+            Ok(Self::Offset(offset))
+        } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+            Ok(Self::Tz(tz))
+        } else {
+            Err(DaftError::ValueError(format!(
+                "Cannot parse timezone \"{tz}\", expected an offset like \"+08:00\" or a name in the tz database like \"Europe/Berlin\""
+            )))
+        }
+    }
+
+    /// The offset from UTC of this timezone at the instant `utc`.
+    pub fn offset_at(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Offset(offset) => *offset,
+            Self::Tz(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        }
+    }
+
+    /// The local time in this timezone at the instant `utc`.
+    pub fn to_local(&self, utc: &NaiveDateTime) -> NaiveDateTime {
+        *utc + Duration::seconds(self.offset_at(utc).local_minus_utc() as i64)
+    }
+
+    /// The instant, as a UTC datetime, of the local time `local` in this timezone.
+    ///
+    /// Local times that occur twice because clocks are turned back are resolved with `ambiguous`,
+    /// and local times that are skipped because clocks are turned forward with `nonexistent`.
+    pub fn to_utc(
+        &self,
+        local: &NaiveDateTime,
+        ambiguous: DstResolution,
+        nonexistent: DstResolution,
+    ) -> DaftResult<Option<NaiveDateTime>> {
+        let tz = match self {
+            Self::Offset(offset) => {
+                return Ok(Some(
+                    *local - Duration::seconds(offset.local_minus_utc() as i64),
+                ))
+            }
+            Self::Tz(tz) => tz,
+        };
+        let (earliest, latest, resolution) = match tz.from_local_datetime(local) {
+            LocalResult::Single(dt) => return Ok(Some(dt.naive_utc())),
+            LocalResult::Ambiguous(earliest, latest) => {
+                (earliest.naive_utc(), latest.naive_utc(), ambiguous)
+            }
+            LocalResult::None => {
+                let transition = self.next_transition_after(local);
+                (transition, transition, nonexistent)
+            }
+        };
+        match resolution {
+            DstResolution::Raise => Err(DaftError::ValueError(format!(
+                "Local time {local} is {} in timezone {} because of a daylight saving time transition",
+                if earliest == latest {
+                    "nonexistent"
+                } else {
+                    "ambiguous"
+                },
+                tz.name()
+            ))),
+            DstResolution::Null => Ok(None),
+            DstResolution::Earliest => Ok(Some(earliest)),
+            DstResolution::Latest => Ok(Some(latest)),
+        }
+    }
+
+    /// The instant of the transition that skips over the nonexistent local time `local`, i.e. the
+    /// first instant whose local time is after `local`.
+    fn next_transition_after(&self, local: &NaiveDateTime) -> NaiveDateTime {
+        // Offsets are within a day of UTC, and transitions happen on whole seconds.
+        let start = (*local - Duration::days(1)).with_nanosecond(0).unwrap();
+        let (mut lo, mut hi) = (0i64, 2 * 24 * 60 * 60);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.to_local(&(start + Duration::seconds(mid))) > *local {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        start + Duration::seconds(lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn datetime(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 3, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn dst_transitions() -> DaftResult<()> {
+        let tz = Timezone::parse("America/New_York")?;
+        let local = |utc: NaiveDateTime| tz.to_local(&utc);
+        // Clocks are turned forward from 02:00 EST to 03:00 EDT on March 12, 2023.
+        assert_eq!(local(datetime(12, 6, 59)), datetime(12, 1, 59));
+        assert_eq!(local(datetime(12, 7, 0)), datetime(12, 3, 0));
+
+        let resolve = |local: NaiveDateTime, resolution| tz.to_utc(&local, resolution, resolution);
+        assert_eq!(
+            resolve(datetime(12, 1, 30), DstResolution::Raise)?,
+            Some(datetime(12, 6, 30))
+        );
+        assert!(resolve(datetime(12, 2, 30), DstResolution::Raise).is_err());
+        assert_eq!(resolve(datetime(12, 2, 30), DstResolution::Null)?, None);
+        assert_eq!(
+            resolve(datetime(12, 2, 30), DstResolution::Earliest)?,
+            Some(datetime(12, 7, 0))
+        );
+
+        assert!(Timezone::parse("Not/A_Timezone").is_err());
+        let offset = Timezone::parse("+08:00")?;
+        assert_eq!(offset.to_local(&datetime(12, 20, 0)), datetime(13, 4, 0));
+        Ok(())
+    }
+}
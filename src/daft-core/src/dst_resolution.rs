@@ -0,0 +1,84 @@
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions::PyValueError, prelude::*, types::PyBytes, PyObject, PyTypeInfo, ToPyObject,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+use std::string::ToString;
+
+use crate::impl_bincode_py_state_serialization;
+
+use common_error::{DaftError, DaftResult};
+
+/// How local times that are ambiguous or nonexistent in a timezone because of a daylight saving
+/// time transition are resolved to instants.
+///
+/// | Raise    - Raise an error.
+/// | Null     - Resolve the local time to null.
+/// | Earliest - Resolve an ambiguous local time to its earlier instant, and a nonexistent local time
+/// |            to the instant of the transition.
+/// | Latest   - Resolve an ambiguous local time to its later instant, and a nonexistent local time
+/// |            to the instant of the transition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum DstResolution {
+    Raise = 1,
+    Null = 2,
+    Earliest = 3,
+    Latest = 4,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl DstResolution {
+    /// Create a DstResolution from its string representation.
+    ///
+    /// Args:
+    ///     resolution: String representation of the resolution, e.g. "raise", "null", "earliest" or "latest".
+    #[staticmethod]
+    pub fn from_dst_resolution_str(resolution: &str) -> PyResult<Self> {
+        Self::from_str(resolution).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl_bincode_py_state_serialization!(DstResolution);
+
+impl DstResolution {
+    pub fn iterator() -> std::slice::Iter<'static, DstResolution> {
+        use DstResolution::*;
+
+        static DST_RESOLUTIONS: [DstResolution; 4] = [Raise, Null, Earliest, Latest];
+        DST_RESOLUTIONS.iter()
+    }
+}
+
+impl FromStr for DstResolution {
+    type Err = DaftError;
+
+    fn from_str(resolution: &str) -> DaftResult<Self> {
+        use DstResolution::*;
+
+        match resolution {
+            "raise" => Ok(Raise),
+            "null" => Ok(Null),
+            "earliest" => Ok(Earliest),
+            "latest" => Ok(Latest),
+            _ => Err(DaftError::TypeError(format!(
+                "DST resolution {} is not supported; only the following resolutions are supported: {:?}",
+                resolution,
+                DstResolution::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for DstResolution {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions::PyValueError, prelude::*, types::PyBytes, PyObject, PyTypeInfo, ToPyObject,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+use std::string::ToString;
+
+use crate::impl_bincode_py_state_serialization;
+
+use common_error::{DaftError, DaftResult};
+
+/// How nulls are filled when they aren't filled with a given value.
+///
+/// | Forward  - Fill each null with the last non-null value before it.
+/// | Backward - Fill each null with the first non-null value after it.
+/// | Min      - Fill nulls with the minimum of the non-null values.
+/// | Max      - Fill nulls with the maximum of the non-null values.
+/// | Mean     - Fill nulls with the mean of the non-null values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum FillNullStrategy {
+    Forward = 1,
+    Backward = 2,
+    Min = 3,
+    Max = 4,
+    Mean = 5,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl FillNullStrategy {
+    /// Create a FillNullStrategy from its string representation.
+    ///
+    /// Args:
+    ///     strategy: String representation of the strategy, e.g. "forward", "backward", "min", "max" or "mean".
+    #[staticmethod]
+    pub fn from_fill_null_strategy_str(strategy: &str) -> PyResult<Self> {
+        Self::from_str(strategy).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl_bincode_py_state_serialization!(FillNullStrategy);
+
+impl FillNullStrategy {
+    pub fn iterator() -> std::slice::Iter<'static, FillNullStrategy> {
+        use FillNullStrategy::*;
+
+        static FILL_NULL_STRATEGIES: [FillNullStrategy; 5] = [Forward, Backward, Min, Max, Mean];
+        FILL_NULL_STRATEGIES.iter()
+    }
+}
+
+impl FromStr for FillNullStrategy {
+    type Err = DaftError;
+
+    fn from_str(strategy: &str) -> DaftResult<Self> {
+        use FillNullStrategy::*;
+
+        match strategy {
+            "forward" => Ok(Forward),
+            "backward" => Ok(Backward),
+            "min" => Ok(Min),
+            "max" => Ok(Max),
+            "mean" => Ok(Mean),
+            _ => Err(DaftError::TypeError(format!(
+                "Fill null strategy {} is not supported; only the following strategies are supported: {:?}",
+                strategy,
+                FillNullStrategy::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for FillNullStrategy {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
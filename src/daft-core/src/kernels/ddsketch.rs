@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+
+/// Relative accuracy guaranteed for every quantile estimate.
+const RELATIVE_ACCURACY: f64 = 0.01;
+
+/// A DDSketch over f64 values, used to estimate quantiles of a column without sorting it.
+///
+/// Values are counted in logarithmically sized buckets, so that any quantile estimate is within
+/// 1% of the true value (relative to that value). Buckets are keyed by their index, which lets two
+/// sketches be merged by summing their counts, e.g. to combine sketches built over different
+/// partitions of the same column.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DDSketch {
+    positive: BTreeMap<i32, u64>,
+    negative: BTreeMap<i32, u64>,
+    zero_count: u64,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for DDSketch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DDSketch {
+    pub fn new() -> Self {
+        Self {
+            positive: BTreeMap::new(),
+            negative: BTreeMap::new(),
+            zero_count: 0,
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> DaftResult<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|e| DaftError::ValueError(format!("Failed to deserialize DDSketch: {e}")))
+    }
+
+    pub fn to_bytes(&self) -> DaftResult<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|e| DaftError::ValueError(format!("Failed to serialize DDSketch: {e}")))
+    }
+
+    fn gamma() -> f64 {
+        (1.0 + RELATIVE_ACCURACY) / (1.0 - RELATIVE_ACCURACY)
+    }
+
+    fn key(magnitude: f64) -> i32 {
+        (magnitude.ln() / Self::gamma().ln()).ceil() as i32
+    }
+
+    fn value(key: i32) -> f64 {
+        let gamma = Self::gamma();
+        2.0 * gamma.powi(key) / (gamma + 1.0)
+    }
+
+    /// Adds a value to the sketch. NaNs are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if value > f64::MIN_POSITIVE {
+            *self.positive.entry(Self::key(value)).or_default() += 1;
+        } else if value < -f64::MIN_POSITIVE {
+            *self.negative.entry(Self::key(-value)).or_default() += 1;
+        } else {
+            self.zero_count += 1;
+        }
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (key, count) in other.positive.iter() {
+            *self.positive.entry(*key).or_default() += count;
+        }
+        for (key, count) in other.negative.iter() {
+            *self.negative.entry(*key).or_default() += count;
+        }
+        self.zero_count += other.zero_count;
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Estimates the value at quantile `q` in `[0, 1]`, or None if the sketch is empty.
+    pub fn quantile(&self, q: f64) -> DaftResult<Option<f64>> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(DaftError::ValueError(format!(
+                "Expected quantile to be between 0 and 1, but received {q}"
+            )));
+        }
+        if self.count == 0 {
+            return Ok(None);
+        }
+
+        let rank = (q * (self.count - 1) as f64).round() as u64;
+        if rank == 0 {
+            return Ok(Some(self.min));
+        } else if rank == self.count - 1 {
+            return Ok(Some(self.max));
+        }
+        let mut seen = 0;
+        // Walk the buckets in increasing order of value: negative buckets from the largest magnitude
+        // down, then zeros, then positive buckets from the smallest magnitude up.
+        for (key, count) in self.negative.iter().rev() {
+            seen += count;
+            if seen > rank {
+                return Ok(Some((-Self::value(*key)).clamp(self.min, self.max)));
+            }
+        }
+        seen += self.zero_count;
+        if seen > rank {
+            return Ok(Some(0.0));
+        }
+        for (key, count) in self.positive.iter() {
+            seen += count;
+            if seen > rank {
+                return Ok(Some(Self::value(*key).clamp(self.min, self.max)));
+            }
+        }
+        Ok(Some(self.max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DDSketch, RELATIVE_ACCURACY};
+    use common_error::DaftResult;
+
+    /// The value at quantile `q` of `sorted`, using the same rank as `DDSketch::quantile`.
+    fn exact_quantile(sorted: &[f64], q: f64) -> f64 {
+        sorted[(q * (sorted.len() - 1) as f64).round() as usize]
+    }
+
+    /// Asserts that every quantile estimate of `sketch` is within the documented relative
+    /// accuracy of the true quantile of `values`.
+    fn assert_within_accuracy(sketch: &DDSketch, values: &[f64]) -> DaftResult<()> {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for q in (0..=100).map(|p| p as f64 / 100.0) {
+            let expected = exact_quantile(&sorted, q);
+            let estimate = sketch.quantile(q)?.unwrap();
+            assert!(
+                (estimate - expected).abs() <= RELATIVE_ACCURACY * expected.abs(),
+                "estimate {estimate} of quantile {q} too far from {expected}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_quantiles() -> DaftResult<()> {
+        assert_eq!(DDSketch::new().quantile(0.5)?, None);
+
+        let values: Vec<f64> = (-500..=1000).map(|v| v as f64).collect();
+        let mut sketch = DDSketch::new();
+        for v in values.iter() {
+            sketch.add(*v);
+        }
+        sketch.add(f64::NAN);
+        assert_eq!(sketch.quantile(0.0)?, Some(-500.0));
+        assert_eq!(sketch.quantile(1.0)?, Some(1000.0));
+        assert_within_accuracy(&sketch, &values)?;
+        assert!(sketch.quantile(1.5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn estimate_quantiles_across_magnitudes() -> DaftResult<()> {
+        let values: Vec<f64> = (-60..=60)
+            .flat_map(|e| [1.5f64.powi(e), -(1.3f64.powi(e))])
+            .collect();
+        let mut sketch = DDSketch::new();
+        for v in values.iter() {
+            sketch.add(*v);
+        }
+        assert_within_accuracy(&sketch, &values)
+    }
+
+    #[test]
+    fn merge_and_roundtrip_sketches() -> DaftResult<()> {
+        let values: Vec<f64> = (1..=1000).map(|v| v as f64).collect();
+        let mut left = DDSketch::new();
+        let mut right = DDSketch::new();
+        for v in values.iter() {
+            if *v as i64 % 2 == 0 {
+                left.add(*v);
+            } else {
+                right.add(*v);
+            }
+        }
+        left.merge(&right);
+        assert_within_accuracy(&left, &values)?;
+
+        let roundtripped = DDSketch::from_bytes(&left.to_bytes()?)?;
+        assert_eq!(roundtripped, left);
+        Ok(())
+    }
+}
@@ -6,107 +6,147 @@ use arrow2::{
     types::{NativeType, Offset},
 };
 
-use xxhash_rust::const_xxh3;
 use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+use xxhash_rust::xxh64::xxh64;
 
-fn hash_primitive<T: NativeType>(
+/// A 64-bit hash function over bytes, with and without a seed.
+trait HashFunction {
+    fn hash(bytes: &[u8]) -> u64;
+    fn hash_with_seed(bytes: &[u8], seed: u64) -> u64;
+}
+
+/// XXH3, used for hash joins, groupbys and repartitioning.
+struct Xxh3;
+
+impl HashFunction for Xxh3 {
+    #[inline]
+    fn hash(bytes: &[u8]) -> u64 {
+        xxh3_64(bytes)
+    }
+
+    #[inline]
+    fn hash_with_seed(bytes: &[u8], seed: u64) -> u64 {
+        xxh3_64_with_seed(bytes, seed)
+    }
+}
+
+/// XXH64, whose output is stable across versions and implementations.
+struct Xxh64;
+
+impl HashFunction for Xxh64 {
+    #[inline]
+    fn hash(bytes: &[u8]) -> u64 {
+        xxh64(bytes, 0)
+    }
+
+    #[inline]
+    fn hash_with_seed(bytes: &[u8], seed: u64) -> u64 {
+        xxh64(bytes, seed)
+    }
+}
+
+fn hash_primitive<H: HashFunction, T: NativeType>(
     array: &PrimitiveArray<T>,
     seed: Option<&PrimitiveArray<u64>>,
 ) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
     let hashes = if let Some(seed) = seed {
         array
             .iter()
             .zip(seed.values_iter())
             .map(|(v, s)| match v {
-                Some(v) => xxh3_64_with_seed(v.to_le_bytes().as_ref(), *s),
-                None => NULL_HASH,
+                Some(v) => H::hash_with_seed(v.to_le_bytes().as_ref(), *s),
+                None => H::hash_with_seed(b"", *s),
             })
             .collect::<Vec<_>>()
     } else {
+        let null_hash = H::hash(b"");
         array
             .iter()
             .map(|v| match v {
-                Some(v) => xxh3_64(v.to_le_bytes().as_ref()),
-                None => NULL_HASH,
+                Some(v) => H::hash(v.to_le_bytes().as_ref()),
+                None => null_hash,
             })
             .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
 
-fn hash_boolean(array: &BooleanArray, seed: Option<&PrimitiveArray<u64>>) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
-
-    const FALSE_HASH: u64 = const_xxh3::xxh3_64(b"0");
-    const TRUE_HASH: u64 = const_xxh3::xxh3_64(b"1");
-
+fn hash_boolean<H: HashFunction>(
+    array: &BooleanArray,
+    seed: Option<&PrimitiveArray<u64>>,
+) -> PrimitiveArray<u64> {
     let hashes = if let Some(seed) = seed {
         array
             .iter()
             .zip(seed.values_iter())
             .map(|(v, s)| match v {
-                Some(true) => xxh3_64_with_seed(b"1", *s),
-                Some(false) => xxh3_64_with_seed(b"0", *s),
-                None => NULL_HASH,
+                Some(true) => H::hash_with_seed(b"1", *s),
+                Some(false) => H::hash_with_seed(b"0", *s),
+                None => H::hash_with_seed(b"", *s),
             })
             .collect::<Vec<_>>()
     } else {
+        let (null_hash, false_hash, true_hash) = (H::hash(b""), H::hash(b"0"), H::hash(b"1"));
         array
             .iter()
             .map(|v| match v {
-                Some(true) => TRUE_HASH,
-                Some(false) => FALSE_HASH,
-                None => NULL_HASH,
+                Some(true) => true_hash,
+                Some(false) => false_hash,
+                None => null_hash,
             })
             .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
 
-fn hash_null(array: &NullArray, seed: Option<&PrimitiveArray<u64>>) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
-
+fn hash_null<H: HashFunction>(
+    array: &NullArray,
+    seed: Option<&PrimitiveArray<u64>>,
+) -> PrimitiveArray<u64> {
     let hashes = if let Some(seed) = seed {
         seed.values_iter()
-            .map(|s| xxh3_64_with_seed(b"", *s))
+            .map(|s| H::hash_with_seed(b"", *s))
             .collect::<Vec<_>>()
     } else {
-        (0..array.len()).map(|_| NULL_HASH).collect::<Vec<_>>()
+        vec![H::hash(b""); array.len()]
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
 
-fn hash_binary<O: Offset>(
+fn hash_binary<H: HashFunction, O: Offset>(
     array: &BinaryArray<O>,
     seed: Option<&PrimitiveArray<u64>>,
 ) -> PrimitiveArray<u64> {
+    // Nulls hash like empty values, regardless of what their slots hold.
     let hashes = if let Some(seed) = seed {
         array
-            .values_iter()
+            .iter()
             .zip(seed.values_iter())
-            .map(|(v, s)| xxh3_64_with_seed(v, *s))
+            .map(|(v, s)| H::hash_with_seed(v.unwrap_or_default(), *s))
             .collect::<Vec<_>>()
     } else {
-        array.values_iter().map(xxh3_64).collect::<Vec<_>>()
+        array
+            .iter()
+            .map(|v| H::hash(v.unwrap_or_default()))
+            .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
 
-fn hash_utf8<O: Offset>(
+fn hash_utf8<H: HashFunction, O: Offset>(
     array: &Utf8Array<O>,
     seed: Option<&PrimitiveArray<u64>>,
 ) -> PrimitiveArray<u64> {
     let hashes = if let Some(seed) = seed {
         array
-            .values_iter()
+            .iter()
             .zip(seed.values_iter())
-            .map(|(v, s)| xxh3_64_with_seed(v.as_bytes(), *s))
+            .map(|(v, s)| H::hash_with_seed(v.unwrap_or_default().as_bytes(), *s))
             .collect::<Vec<_>>()
     } else {
         array
-            .values_iter()
-            .map(|v| xxh3_64(v.as_bytes()))
+            .iter()
+            .map(|v| H::hash(v.unwrap_or_default().as_bytes()))
             .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
@@ -139,7 +179,23 @@ macro_rules! with_match_hashing_primitive_type {(
     }
 })}
 
+/// Hashes each value of `array` with XXH3, chaining from the per-row `seed` hashes if provided.
 pub fn hash(array: &dyn Array, seed: Option<&PrimitiveArray<u64>>) -> Result<PrimitiveArray<u64>> {
+    hash_with::<Xxh3>(array, seed)
+}
+
+/// Hashes each value of `array` with XXH64, chaining from the per-row `seed` hashes if provided.
+pub fn hash_xxh64(
+    array: &dyn Array,
+    seed: Option<&PrimitiveArray<u64>>,
+) -> Result<PrimitiveArray<u64>> {
+    hash_with::<Xxh64>(array, seed)
+}
+
+fn hash_with<H: HashFunction>(
+    array: &dyn Array,
+    seed: Option<&PrimitiveArray<u64>>,
+) -> Result<PrimitiveArray<u64>> {
     if let Some(s) = seed {
         if s.len() != array.len() {
             return Err(Error::InvalidArgumentError(format!(
@@ -159,15 +215,15 @@ pub fn hash(array: &dyn Array, seed: Option<&PrimitiveArray<u64>>) -> Result<Pri
 
     use PhysicalType::*;
     Ok(match array.data_type().to_physical_type() {
-        Null => hash_null(array.as_any().downcast_ref().unwrap(), seed),
-        Boolean => hash_boolean(array.as_any().downcast_ref().unwrap(), seed),
+        Null => hash_null::<H>(array.as_any().downcast_ref().unwrap(), seed),
+        Boolean => hash_boolean::<H>(array.as_any().downcast_ref().unwrap(), seed),
         Primitive(primitive) => with_match_hashing_primitive_type!(primitive, |$T| {
-            hash_primitive::<$T>(array.as_any().downcast_ref().unwrap(), seed)
+            hash_primitive::<H, $T>(array.as_any().downcast_ref().unwrap(), seed)
         }),
-        Binary => hash_binary::<i32>(array.as_any().downcast_ref().unwrap(), seed),
-        LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap(), seed),
-        Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap(), seed),
-        LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap(), seed),
+        Binary => hash_binary::<H, i32>(array.as_any().downcast_ref().unwrap(), seed),
+        LargeBinary => hash_binary::<H, i64>(array.as_any().downcast_ref().unwrap(), seed),
+        Utf8 => hash_utf8::<H, i32>(array.as_any().downcast_ref().unwrap(), seed),
+        LargeUtf8 => hash_utf8::<H, i64>(array.as_any().downcast_ref().unwrap(), seed),
         t => {
             return Err(Error::NotYetImplemented(format!(
                 "Hash not implemented for type {t:?}"
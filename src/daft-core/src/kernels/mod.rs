@@ -1,3 +1,5 @@
+pub mod ddsketch;
 pub mod hashing;
+pub mod hyperloglog;
 pub mod search_sorted;
 pub mod utf8;
@@ -13,62 +13,79 @@ use arrow2::{
 };
 use num_traits::Float;
 
-#[allow(clippy::eq_op)]
-fn search_sorted_primitive_array<T: NativeType + PartialOrd>(
-    sorted_array: &PrimitiveArray<T>,
-    keys: &PrimitiveArray<T>,
-    input_reversed: bool,
-) -> PrimitiveArray<u64>
-where {
-    let array_size = sorted_array.len();
+/// Which index is returned for keys that are equal to values of the sorted array: the index of the
+/// first equal value if `Left`, and the index after the last equal value if `Right`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchSortedSide {
+    Left,
+    Right,
+}
 
-    let mut left = 0_usize;
-    let mut right = array_size;
+impl SearchSortedSide {
+    /// Whether a key that compares to a value of the sorted array with `ordering` is placed before
+    /// that value.
+    #[inline]
+    fn is_before(self, ordering: Ordering) -> bool {
+        match self {
+            SearchSortedSide::Left => ordering.is_le(),
+            SearchSortedSide::Right => ordering.is_lt(),
+        }
+    }
+}
 
-    let mut results: Vec<u64> = Vec::with_capacity(array_size);
+/// Orders `l` and `r` in the order of the sorted array, where nulls are the largest values, i.e.
+/// placed last, or first if the array is reversed.
+#[inline]
+fn sorted_ordering<K>(
+    l: Option<K>,
+    r: Option<K>,
+    input_reversed: bool,
+    cmp: &impl Fn(&K, &K) -> Ordering,
+) -> Ordering {
+    let ordering = match (l, r) {
+        (Some(l), Some(r)) => cmp(&l, &r),
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+    };
+    if input_reversed {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
 
-    let mut last_key = keys.iter().next().unwrap_or(None);
-    let less = |l: &T, r: &T| l < r || (r != r && l == l);
-    for key_val in keys.iter() {
-        let is_last_key_lt = match (last_key, key_val) {
-            (None, None) => false,
-            (None, Some(_)) => input_reversed,
-            (Some(last_key), Some(key_val)) => {
-                if !input_reversed {
-                    less(last_key, key_val)
-                } else {
-                    less(key_val, last_key)
-                }
-            }
-            (Some(_), None) => !input_reversed,
-        };
-        if is_last_key_lt {
-            right = array_size;
+/// Binary searches the sorted values for each key, reusing the bounds of the previous search when
+/// the keys are sorted too.
+fn search_sorted_by<K: Copy>(
+    sorted_len: usize,
+    sorted_value: impl Fn(usize) -> Option<K>,
+    keys: impl Iterator<Item = Option<K>>,
+    cmp: impl Fn(&K, &K) -> Ordering,
+    input_reversed: bool,
+    side: SearchSortedSide,
+) -> PrimitiveArray<u64> {
+    let mut left = 0_usize;
+    let mut right = sorted_len;
+
+    let mut results: Vec<u64> = Vec::with_capacity(sorted_len);
+    let mut keys = keys.peekable();
+    let mut last_key = keys.peek().copied().unwrap_or(None);
+    for key_val in keys {
+        if sorted_ordering(last_key, key_val, input_reversed, &cmp).is_lt() {
+            right = sorted_len;
         } else {
             left = 0;
-            right = if right < array_size {
+            right = if right < sorted_len {
                 right + 1
             } else {
-                array_size
+                sorted_len
             };
         }
         while left < right {
             let mid_idx = left + ((right - left) >> 1);
-            let mid_val = unsafe { sorted_array.value_unchecked(mid_idx) };
-            let is_key_val_lt = match (key_val, sorted_array.is_valid(mid_idx)) {
-                (None, false) => false,
-                (None, true) => input_reversed,
-                (Some(key_val), true) => {
-                    if !input_reversed {
-                        less(key_val, &mid_val)
-                    } else {
-                        less(&mid_val, key_val)
-                    }
-                }
-                (Some(_), false) => !input_reversed,
-            };
-
-            if is_key_val_lt {
+            let ordering = sorted_ordering(key_val, sorted_value(mid_idx), input_reversed, &cmp);
+            if side.is_before(ordering) {
                 right = mid_idx;
             } else {
                 left = mid_idx + 1;
@@ -81,130 +98,75 @@ where {
     PrimitiveArray::<u64>::new(DataType::UInt64, results.into(), None)
 }
 
-fn search_sorted_utf_array<O: Offset>(
-    sorted_array: &Utf8Array<O>,
-    keys: &Utf8Array<O>,
+#[allow(clippy::eq_op)]
+fn search_sorted_primitive_array<T: NativeType + PartialOrd>(
+    sorted_array: &PrimitiveArray<T>,
+    keys: &PrimitiveArray<T>,
     input_reversed: bool,
+    side: SearchSortedSide,
 ) -> PrimitiveArray<u64> {
-    let array_size = sorted_array.len();
-    let mut left = 0_usize;
-    let mut right = array_size;
-
-    let mut results: Vec<u64> = Vec::with_capacity(array_size);
-    let mut last_key = keys.iter().next().unwrap_or(None);
-    for key_val in keys.iter() {
-        let is_last_key_lt = match (last_key, key_val) {
-            (None, None) => false,
-            (None, Some(_)) => input_reversed,
-            (Some(last_key), Some(key_val)) => {
-                if !input_reversed {
-                    last_key.lt(key_val)
-                } else {
-                    last_key.gt(key_val)
-                }
-            }
-            (Some(_), None) => !input_reversed,
-        };
-        if is_last_key_lt {
-            right = array_size;
-        } else {
-            left = 0;
-            right = if right < array_size {
-                right + 1
-            } else {
-                array_size
-            };
-        }
-        while left < right {
-            let mid_idx = left + ((right - left) >> 1);
-            let mid_val = unsafe { sorted_array.value_unchecked(mid_idx) };
-            let is_key_val_lt = match (key_val, sorted_array.is_valid(mid_idx)) {
-                (None, false) => false,
-                (None, true) => input_reversed,
-                (Some(key_val), true) => {
-                    if !input_reversed {
-                        key_val.lt(mid_val)
-                    } else {
-                        mid_val.lt(key_val)
-                    }
-                }
-                (Some(_), false) => !input_reversed,
-            };
-
-            if is_key_val_lt {
-                right = mid_idx;
+    // NaNs are larger than all other values.
+    let less = |l: &T, r: &T| l < r || (r != r && l == l);
+    search_sorted_by(
+        sorted_array.len(),
+        |i| {
+            sorted_array
+                .is_valid(i)
+                .then(|| unsafe { sorted_array.value_unchecked(i) })
+        },
+        keys.iter().map(|key| key.copied()),
+        |l, r| {
+            if less(l, r) {
+                Ordering::Less
+            } else if less(r, l) {
+                Ordering::Greater
             } else {
-                left = mid_idx + 1;
+                Ordering::Equal
             }
-        }
-        results.push(left.try_into().unwrap());
-        last_key = key_val;
-    }
+        },
+        input_reversed,
+        side,
+    )
+}
 
-    PrimitiveArray::<u64>::new(DataType::UInt64, results.into(), None)
+fn search_sorted_utf_array<O: Offset>(
+    sorted_array: &Utf8Array<O>,
+    keys: &Utf8Array<O>,
+    input_reversed: bool,
+    side: SearchSortedSide,
+) -> PrimitiveArray<u64> {
+    search_sorted_by(
+        sorted_array.len(),
+        |i| {
+            sorted_array
+                .is_valid(i)
+                .then(|| unsafe { sorted_array.value_unchecked(i) })
+        },
+        keys.iter(),
+        |l, r| l.cmp(r),
+        input_reversed,
+        side,
+    )
 }
 
 fn search_sorted_binary_array<O: Offset>(
     sorted_array: &BinaryArray<O>,
     keys: &BinaryArray<O>,
     input_reversed: bool,
+    side: SearchSortedSide,
 ) -> PrimitiveArray<u64> {
-    let array_size = sorted_array.len();
-    let mut left = 0_usize;
-    let mut right = array_size;
-
-    let mut results: Vec<u64> = Vec::with_capacity(array_size);
-    let mut last_key = keys.iter().next().unwrap_or(None);
-    for key_val in keys.iter() {
-        let is_last_key_lt = match (last_key, key_val) {
-            (None, None) => false,
-            (None, Some(_)) => input_reversed,
-            (Some(last_key), Some(key_val)) => {
-                if !input_reversed {
-                    last_key.lt(key_val)
-                } else {
-                    last_key.gt(key_val)
-                }
-            }
-            (Some(_), None) => !input_reversed,
-        };
-        if is_last_key_lt {
-            right = array_size;
-        } else {
-            left = 0;
-            right = if right < array_size {
-                right + 1
-            } else {
-                array_size
-            };
-        }
-        while left < right {
-            let mid_idx = left + ((right - left) >> 1);
-            let mid_val = unsafe { sorted_array.value_unchecked(mid_idx) };
-            let is_key_val_lt = match (key_val, sorted_array.is_valid(mid_idx)) {
-                (None, false) => false,
-                (None, true) => input_reversed,
-                (Some(key_val), true) => {
-                    if !input_reversed {
-                        key_val.lt(mid_val)
-                    } else {
-                        mid_val.lt(key_val)
-                    }
-                }
-                (Some(_), false) => !input_reversed,
-            };
-
-            if is_key_val_lt {
-                right = mid_idx;
-            } else {
-                left = mid_idx + 1;
-            }
-        }
-        results.push(left.try_into().unwrap());
-        last_key = key_val;
-    }
-
-    PrimitiveArray::<u64>::new(DataType::UInt64, results.into(), None)
+    search_sorted_by(
+        sorted_array.len(),
+        |i| {
+            sorted_array
+                .is_valid(i)
+                .then(|| unsafe { sorted_array.value_unchecked(i) })
+        },
+        keys.iter(),
+        |l, r| l.cmp(r),
+        input_reversed,
+        side,
+    )
 }
 
 macro_rules! with_match_searching_primitive_type {(
@@ -294,34 +256,41 @@ pub fn build_compare_with_nan(left: &dyn Array, right: &dyn Array) -> Result<Dyn
     }
 }
 
+/// Compares values where nulls are the largest values, i.e. placed last, or first if reversed.
 pub fn build_compare_with_nulls(
     left: &dyn Array,
     right: &dyn Array,
     reversed: bool,
+) -> Result<DynComparator> {
+    build_compare_with_null_placement(left, right, reversed, reversed)
+}
+
+/// Compares values where nulls are placed first if `nulls_first` and last otherwise, independently
+/// of whether the order of the other values is reversed.
+pub fn build_compare_with_null_placement(
+    left: &dyn Array,
+    right: &dyn Array,
+    reversed: bool,
+    nulls_first: bool,
 ) -> Result<DynComparator> {
     let comparator = build_compare_with_nan(left, right)?;
     let left_is_valid = build_is_valid(left);
     let right_is_valid = build_is_valid(right);
-
-    if reversed {
-        Ok(Box::new(move |i: usize, j: usize| {
-            match (left_is_valid(i), right_is_valid(j)) {
-                (true, true) => comparator(i, j).reverse(),
-                (false, true) => Ordering::Less,
-                (false, false) => Ordering::Equal,
-                (true, false) => Ordering::Greater,
-            }
-        }))
+    let null_ordering = if nulls_first {
+        Ordering::Less
     } else {
-        Ok(Box::new(move |i: usize, j: usize| {
-            match (left_is_valid(i), right_is_valid(j)) {
-                (true, true) => comparator(i, j),
-                (false, true) => Ordering::Greater,
-                (false, false) => Ordering::Equal,
-                (true, false) => Ordering::Less,
-            }
-        }))
-    }
+        Ordering::Greater
+    };
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        match (left_is_valid(i), right_is_valid(j)) {
+            (true, true) if reversed => comparator(i, j).reverse(),
+            (true, true) => comparator(i, j),
+            (false, true) => null_ordering,
+            (false, false) => Ordering::Equal,
+            (true, false) => null_ordering.reverse(),
+        }
+    }))
 }
 
 /// Compare the values at two arbitrary indices in two arrays.
@@ -434,6 +403,7 @@ pub fn search_sorted(
     sorted_array: &dyn Array,
     keys: &dyn Array,
     input_reversed: bool,
+    side: SearchSortedSide,
 ) -> Result<PrimitiveArray<u64>> {
     use PhysicalType::*;
     if sorted_array.data_type() != keys.data_type() {
@@ -447,27 +417,31 @@ pub fn search_sorted(
     Ok(match sorted_array.data_type().to_physical_type() {
         // Boolean => hash_boolean(array.as_any().downcast_ref().unwrap()),
         Primitive(primitive) => with_match_searching_primitive_type!(primitive, |$T| {
-            search_sorted_primitive_array::<$T>(sorted_array.as_any().downcast_ref().unwrap(), keys.as_any().downcast_ref().unwrap(), input_reversed)
+            search_sorted_primitive_array::<$T>(sorted_array.as_any().downcast_ref().unwrap(), keys.as_any().downcast_ref().unwrap(), input_reversed, side)
         }),
         Utf8 => search_sorted_utf_array::<i32>(
             sorted_array.as_any().downcast_ref().unwrap(),
             keys.as_any().downcast_ref().unwrap(),
             input_reversed,
+            side,
         ),
         LargeUtf8 => search_sorted_utf_array::<i64>(
             sorted_array.as_any().downcast_ref().unwrap(),
             keys.as_any().downcast_ref().unwrap(),
             input_reversed,
+            side,
         ),
         Binary => search_sorted_binary_array::<i32>(
             sorted_array.as_any().downcast_ref().unwrap(),
             keys.as_any().downcast_ref().unwrap(),
             input_reversed,
+            side,
         ),
         LargeBinary => search_sorted_binary_array::<i64>(
             sorted_array.as_any().downcast_ref().unwrap(),
             keys.as_any().downcast_ref().unwrap(),
             input_reversed,
+            side,
         ),
         t => {
             return Err(Error::NotYetImplemented(format!(
@@ -1,21 +1,29 @@
 #![feature(let_chains)]
 #![feature(int_roundings)]
 pub mod array;
+pub mod closed_interval;
 pub mod count_mode;
 pub mod datatypes;
+pub mod dst_resolution;
 #[cfg(feature = "python")]
 pub mod ffi;
+pub mod fill_null_strategy;
 pub mod kernels;
 #[cfg(feature = "python")]
 pub mod python;
+pub mod round_mode;
 pub mod schema;
 pub mod series;
 pub mod utils;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+pub use closed_interval::ClosedInterval;
 pub use count_mode::CountMode;
 pub use datatypes::DataType;
+pub use dst_resolution::DstResolution;
+pub use fill_null_strategy::FillNullStrategy;
+pub use round_mode::RoundMode;
 pub use series::{IntoSeries, Series};
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -31,6 +39,10 @@ pub const DAFT_BUILD_TYPE: &str = {
 #[cfg(feature = "python")]
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<CountMode>()?;
+    parent.add_class::<ClosedInterval>()?;
+    parent.add_class::<RoundMode>()?;
+    parent.add_class::<DstResolution>()?;
+    parent.add_class::<FillNullStrategy>()?;
 
     Ok(())
 }
@@ -150,6 +150,11 @@ impl PyDataType {
         Ok(DataType::Utf8.into())
     }
 
+    #[staticmethod]
+    pub fn categorical() -> PyResult<Self> {
+        Ok(DataType::Categorical.into())
+    }
+
     #[staticmethod]
     pub fn decimal128(precision: usize, scale: usize) -> PyResult<Self> {
         Ok(DataType::Decimal128(precision, scale).into())
@@ -184,6 +189,11 @@ impl PyDataType {
         Ok(DataType::Duration(timeunit.timeunit).into())
     }
 
+    #[staticmethod]
+    pub fn interval() -> PyResult<Self> {
+        Ok(DataType::Interval.into())
+    }
+
     #[staticmethod]
     pub fn list(data_type: Self) -> PyResult<Self> {
         Ok(DataType::List(Box::new(data_type.dtype)).into())
@@ -216,6 +226,11 @@ impl PyDataType {
         .into())
     }
 
+    #[staticmethod]
+    pub fn map(key_type: Self, value_type: Self) -> PyResult<Self> {
+        Ok(DataType::Map(Box::new(key_type.dtype), Box::new(value_type.dtype)).into())
+    }
+
     #[staticmethod]
     pub fn extension(
         name: &str,
@@ -342,6 +357,10 @@ impl PyDataType {
         Ok(self.dtype.is_fixed_shape_image())
     }
 
+    pub fn is_embedding(&self) -> PyResult<bool> {
+        Ok(self.dtype.is_embedding())
+    }
+
     pub fn is_tensor(&self) -> PyResult<bool> {
         Ok(self.dtype.is_tensor())
     }
@@ -11,7 +11,10 @@ use crate::{
     array::{ops::DaftLogical, pseudo_arrow::PseudoArrowArray, DataArray},
     count_mode::CountMode,
     datatypes::{DataType, Field, ImageFormat, ImageMode, PythonType},
+    dst_resolution::DstResolution,
     ffi,
+    fill_null_strategy::FillNullStrategy,
+    round_mode::RoundMode,
     series::{self, IntoSeries, Series},
     utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed},
 };
@@ -116,6 +119,54 @@ impl PySeries {
         Ok(self.series.floor()?.into())
     }
 
+    pub fn round(&self, decimals: i32, mode: RoundMode) -> PyResult<Self> {
+        Ok(self.series.round(decimals, mode)?.into())
+    }
+
+    pub fn sqrt(&self) -> PyResult<Self> {
+        Ok(self.series.sqrt()?.into())
+    }
+
+    pub fn exp(&self) -> PyResult<Self> {
+        Ok(self.series.exp()?.into())
+    }
+
+    pub fn ln(&self) -> PyResult<Self> {
+        Ok(self.series.ln()?.into())
+    }
+
+    pub fn sin(&self) -> PyResult<Self> {
+        Ok(self.series.sin()?.into())
+    }
+
+    pub fn cos(&self) -> PyResult<Self> {
+        Ok(self.series.cos()?.into())
+    }
+
+    pub fn tan(&self) -> PyResult<Self> {
+        Ok(self.series.tan()?.into())
+    }
+
+    pub fn pow(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.series.pow(&other.series)?.into())
+    }
+
+    pub fn log(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.series.log(&other.series)?.into())
+    }
+
+    pub fn atan2(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.series.atan2(&other.series)?.into())
+    }
+
+    pub fn sign(&self) -> PyResult<Self> {
+        Ok(self.series.sign()?.into())
+    }
+
+    pub fn clip(&self, lower: &Self, upper: &Self) -> PyResult<Self> {
+        Ok(self.series.clip(&lower.series, &upper.series)?.into())
+    }
+
     pub fn take(&self, idx: &Self) -> PyResult<Self> {
         Ok(self.series.take(&idx.series)?.into())
     }
@@ -149,12 +200,12 @@ impl PySeries {
         Ok(self.series.filter(mask.series.downcast()?)?.into())
     }
 
-    pub fn sort(&self, descending: bool) -> PyResult<Self> {
-        Ok(self.series.sort(descending)?.into())
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> PyResult<Self> {
+        Ok(self.series.sort(descending, nulls_first)?.into())
     }
 
-    pub fn argsort(&self, descending: bool) -> PyResult<Self> {
-        Ok(self.series.argsort(descending)?.into())
+    pub fn argsort(&self, descending: bool, nulls_first: bool) -> PyResult<Self> {
+        Ok(self.series.argsort(descending, nulls_first)?.into())
     }
 
     pub fn hash(&self, seed: Option<PySeries>) -> PyResult<Self> {
@@ -185,6 +236,14 @@ impl PySeries {
         }
     }
 
+    pub fn eq_null_safe(&self, other: &Self) -> PyResult<Self> {
+        Ok(self
+            .series
+            .eq_null_safe(&other.series)?
+            .into_series()
+            .into())
+    }
+
     pub fn __invert__(&self) -> PyResult<Self> {
         use std::ops::Not;
         Ok((&self.series).not()?.into())
@@ -218,6 +277,10 @@ impl PySeries {
         Ok(self.series.cast(&dtype.into())?.into())
     }
 
+    pub fn strict_cast(&self, dtype: PyDataType) -> PyResult<Self> {
+        Ok(self.series.strict_cast(&dtype.into())?.into())
+    }
+
     #[staticmethod]
     pub fn concat(series: Vec<Self>) -> PyResult<Self> {
         let series: Vec<_> = series.iter().map(|s| &s.series).collect();
@@ -236,6 +299,10 @@ impl PySeries {
         Ok(self.series.size_bytes()?)
     }
 
+    pub fn null_count(&self) -> PyResult<usize> {
+        Ok(self.series.null_count())
+    }
+
     pub fn name(&self) -> PyResult<String> {
         Ok(self.series.name().to_string())
     }
@@ -260,14 +327,18 @@ impl PySeries {
         Ok(self.series.utf8_contains(&pattern.series)?.into())
     }
 
-    pub fn utf8_split(&self, pattern: &Self) -> PyResult<Self> {
-        Ok(self.series.utf8_split(&pattern.series)?.into())
+    pub fn utf8_split(&self, pattern: &Self, regex: bool) -> PyResult<Self> {
+        Ok(self.series.utf8_split(&pattern.series, regex)?.into())
     }
 
     pub fn utf8_length(&self) -> PyResult<Self> {
         Ok(self.series.utf8_length()?.into())
     }
 
+    pub fn utf8_length_chars(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_length_chars()?.into())
+    }
+
     pub fn utf8_lower(&self) -> PyResult<Self> {
         Ok(self.series.utf8_lower()?.into())
     }
@@ -276,6 +347,10 @@ impl PySeries {
         Ok(self.series.utf8_upper()?.into())
     }
 
+    pub fn utf8_capitalize(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_capitalize()?.into())
+    }
+
     pub fn utf8_lstrip(&self) -> PyResult<Self> {
         Ok(self.series.utf8_lstrip()?.into())
     }
@@ -288,10 +363,26 @@ impl PySeries {
         Ok(self.series.utf8_reverse()?.into())
     }
 
+    pub fn utf8_substr(&self, start: i64, length: Option<usize>) -> PyResult<Self> {
+        Ok(self.series.utf8_substr(start, length)?.into())
+    }
+
     pub fn is_nan(&self) -> PyResult<Self> {
         Ok(self.series.is_nan()?.into())
     }
 
+    pub fn is_inf(&self) -> PyResult<Self> {
+        Ok(self.series.is_inf()?.into())
+    }
+
+    pub fn not_nan(&self) -> PyResult<Self> {
+        Ok(self.series.not_nan()?.into())
+    }
+
+    pub fn fill_nan(&self, fill_value: &Self) -> PyResult<Self> {
+        Ok(self.series.fill_nan(&fill_value.series)?.into())
+    }
+
     pub fn dt_date(&self) -> PyResult<Self> {
         Ok(self.series.dt_date()?.into())
     }
@@ -304,6 +395,10 @@ impl PySeries {
         Ok(self.series.dt_hour()?.into())
     }
 
+    pub fn dt_minute(&self) -> PyResult<Self> {
+        Ok(self.series.dt_minute()?.into())
+    }
+
     pub fn dt_month(&self) -> PyResult<Self> {
         Ok(self.series.dt_month()?.into())
     }
@@ -316,6 +411,22 @@ impl PySeries {
         Ok(self.series.dt_day_of_week()?.into())
     }
 
+    pub fn dt_tz_localize(
+        &self,
+        tz: &str,
+        ambiguous: DstResolution,
+        nonexistent: DstResolution,
+    ) -> PyResult<Self> {
+        Ok(self
+            .series
+            .dt_tz_localize(tz, ambiguous, nonexistent)?
+            .into())
+    }
+
+    pub fn dt_tz_convert(&self, tz: &str) -> PyResult<Self> {
+        Ok(self.series.dt_tz_convert(tz)?.into())
+    }
+
     pub fn partitioning_days(&self) -> PyResult<Self> {
         Ok(self.series.partitioning_days()?.into())
     }
@@ -352,6 +463,18 @@ impl PySeries {
         Ok(self.series.list_get(&idx.series, &default.series)?.into())
     }
 
+    pub fn list_slice(&self, start: &Self, end: &Self) -> PyResult<Self> {
+        Ok(self.series.list_slice(&start.series, &end.series)?.into())
+    }
+
+    pub fn list_contains(&self, item: &Self) -> PyResult<Self> {
+        Ok(self
+            .series
+            .list_contains(&item.series)?
+            .into_series()
+            .into())
+    }
+
     pub fn image_decode(&self) -> PyResult<Self> {
         Ok(self.series.image_decode()?.into())
     }
@@ -390,6 +513,18 @@ impl PySeries {
         Ok(self.series.not_null()?.into())
     }
 
+    pub fn unique(&self) -> PyResult<Self> {
+        Ok(self.series.unique()?.into())
+    }
+
+    pub fn fill_null(&self, fill_value: &Self) -> PyResult<Self> {
+        Ok(self.series.fill_null(&fill_value.series)?.into())
+    }
+
+    pub fn fill_null_with_strategy(&self, strategy: FillNullStrategy) -> PyResult<Self> {
+        Ok(self.series.fill_null_with_strategy(strategy)?.into())
+    }
+
     pub fn _debug_bincode_serialize(&self, py: Python) -> PyResult<PyObject> {
         let values = bincode::serialize(&self.series).unwrap();
         Ok(PyBytes::new(py, &values).to_object(py))
@@ -0,0 +1,75 @@
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions::PyValueError, prelude::*, types::PyBytes, PyObject, PyTypeInfo, ToPyObject,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+use std::string::ToString;
+
+use crate::impl_bincode_py_state_serialization;
+
+use common_error::{DaftError, DaftResult};
+
+/// How values exactly halfway between two rounding candidates are rounded.
+///
+/// | HalfEven         - Round to the even candidate, e.g. 2.5 -> 2 and 3.5 -> 4.
+/// | HalfAwayFromZero - Round to the candidate further from zero, e.g. 2.5 -> 3 and -2.5 -> -3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum RoundMode {
+    HalfEven = 1,
+    HalfAwayFromZero = 2,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RoundMode {
+    /// Create a RoundMode from its string representation.
+    ///
+    /// Args:
+    ///     mode: String representation of the round mode, e.g. "half_even" or "half_away_from_zero".
+    #[staticmethod]
+    pub fn from_round_mode_str(mode: &str) -> PyResult<Self> {
+        Self::from_str(mode).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl_bincode_py_state_serialization!(RoundMode);
+
+impl RoundMode {
+    pub fn iterator() -> std::slice::Iter<'static, RoundMode> {
+        use RoundMode::*;
+
+        static ROUND_MODES: [RoundMode; 2] = [HalfEven, HalfAwayFromZero];
+        ROUND_MODES.iter()
+    }
+}
+
+impl FromStr for RoundMode {
+    type Err = DaftError;
+
+    fn from_str(mode: &str) -> DaftResult<Self> {
+        use RoundMode::*;
+
+        match mode {
+            "half_even" => Ok(HalfEven),
+            "half_away_from_zero" => Ok(HalfAwayFromZero),
+            _ => Err(DaftError::TypeError(format!(
+                "Round mode {} is not supported; only the following modes are supported: {:?}",
+                mode,
+                RoundMode::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for RoundMode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
@@ -73,23 +73,60 @@ impl Schema {
         self.fields.keys().cloned().collect()
     }
 
+    /// Whether the two schemas have the same fields, apart from their nullability.
+    pub fn eq_ignoring_nullability(&self, other: &Schema) -> bool {
+        self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .values()
+                .zip(other.fields.values())
+                .all(|(a, b)| a.eq_ignoring_nullability(b))
+    }
+
+    /// The fields of this schema followed by the fields of `other`, which must not share any names.
     pub fn union(&self, other: &Schema) -> DaftResult<Schema> {
         let self_keys: HashSet<&String> = HashSet::from_iter(self.fields.keys());
-        let other_keys: HashSet<&String> = HashSet::from_iter(self.fields.keys());
-        match self_keys.difference(&other_keys).count() {
-            0 => {
-                let mut fields = IndexMap::new();
-                for (k, v) in self.fields.iter().chain(other.fields.iter()) {
-                    fields.insert(k.clone(), v.clone());
-                }
-                Ok(Schema { fields })
-            }
+        let other_keys: HashSet<&String> = HashSet::from_iter(other.fields.keys());
+        match self_keys.intersection(&other_keys).count() {
+            0 => Ok(self.merge(other)),
             _ => Err(DaftError::ValueError(
                 "Cannot union two schemas with overlapping keys".to_string(),
             )),
         }
     }
 
+    /// The fields of this schema followed by the fields of `other`, where a field of `other` replaces
+    /// the field of the same name in this schema but keeps its position.
+    pub fn merge(&self, other: &Schema) -> Schema {
+        let mut fields = self.fields.clone();
+        for (k, v) in other.fields.iter() {
+            fields.insert(k.clone(), v.clone());
+        }
+        Schema { fields }
+    }
+
+    /// The fields with the given names, in the order of `names`.
+    pub fn project<S: AsRef<str>>(&self, names: &[S]) -> DaftResult<Schema> {
+        let fields = names
+            .iter()
+            .map(|name| self.get_field(name.as_ref()).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        Schema::new(fields)
+    }
+
+    /// Whether both schemas have the same fields in the same order, ignoring field metadata.
+    ///
+    /// Unlike `==`, which compares fields by name regardless of their order, this is the equality
+    /// that matters when the columns of tables with these schemas are consumed positionally.
+    pub fn structurally_eq(&self, other: &Schema) -> bool {
+        self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .values()
+                .zip(other.fields.values())
+                .all(|(l, r)| l.name == r.name && l.dtype == r.dtype)
+    }
+
     pub fn apply_hints(&self, hints: &Schema) -> DaftResult<Schema> {
         let applied_fields = self
             .fields
@@ -227,3 +264,59 @@ impl TryFrom<&arrow2::datatypes::Schema> for Schema {
         Self::new(daft_fields)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::DataType;
+
+    fn schema(fields: &[(&str, DataType)]) -> Schema {
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, dtype)| Field::new(*name, dtype.clone()))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn union_merge_and_project() -> DaftResult<()> {
+        let left = schema(&[("a", DataType::Int64), ("b", DataType::Utf8)]);
+        let right = schema(&[("c", DataType::Boolean), ("a", DataType::Float64)]);
+
+        assert!(left.union(&right).is_err());
+        let unioned = left.union(&schema(&[("c", DataType::Boolean)]))?;
+        assert_eq!(unioned.names(), vec!["a", "b", "c"]);
+
+        let merged = left.merge(&right);
+        assert_eq!(merged.names(), vec!["a", "b", "c"]);
+        assert_eq!(merged.get_field("a")?.dtype, DataType::Float64);
+
+        let projected = merged.project(&["c", "a"])?;
+        assert_eq!(projected.names(), vec!["c", "a"]);
+        assert!(merged.project(&["d"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn structural_equality() {
+        let fields = [("a", DataType::Int64), ("b", DataType::Utf8)];
+        let reordered = [("b", DataType::Utf8), ("a", DataType::Int64)];
+        assert_eq!(schema(&fields), schema(&reordered));
+        assert!(!schema(&fields).structurally_eq(&schema(&reordered)));
+
+        let with_metadata = Schema::new(vec![
+            Field::new("a", DataType::Int64)
+                .with_metadata(BTreeMap::from([("key".to_string(), "value".to_string())])),
+            Field::new("b", DataType::Utf8),
+        ])
+        .unwrap();
+        assert_ne!(schema(&fields), with_metadata);
+        assert!(schema(&fields).structurally_eq(&with_metadata));
+    }
+}
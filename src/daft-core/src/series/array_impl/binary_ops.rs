@@ -1,20 +1,20 @@
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use crate::{
     array::{
-        ops::{DaftCompare, DaftLogical},
-        FixedSizeListArray, ListArray, StructArray,
+        ops::{as_arrow::AsArrow, DaftCompare, DaftLogical},
+        CategoricalArray, FixedSizeListArray, ListArray, StructArray,
     },
-    datatypes::{logical::Decimal128Array, Int128Array},
+    datatypes::{logical::Decimal128Array, Field, Int128Array, TimeUnit, DECIMAL128_MAX_PRECISION},
     series::series_like::SeriesLike,
     with_match_comparable_daft_types, with_match_numeric_daft_types, DataType,
 };
 
 use crate::datatypes::logical::{
     DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray, FixedShapeTensorArray,
-    ImageArray, TensorArray, TimeArray, TimestampArray,
+    ImageArray, IntervalArray, MapArray, TensorArray, TimeArray, TimestampArray,
 };
 use crate::datatypes::{
     BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int16Array, Int32Array,
@@ -140,6 +140,104 @@ macro_rules! physical_compare_op {
     }};
 }
 
+/// Compares categoricals with strings or other categoricals directly on their codes, and falls back to
+/// comparing strings otherwise.
+macro_rules! categorical_compare_op {
+    ($self:expr, $rhs:expr, $op:ident, $pyop:expr) => {{
+        match $rhs.data_type() {
+            DataType::Categorical | DataType::Utf8 => {
+                let rhs = $rhs.cast(&DataType::Categorical)?;
+                $self.0.$op(rhs.categorical()?)
+            }
+            _ => physical_compare_op!($self, $rhs, $op, $pyop),
+        }
+    }};
+}
+
+/// Converts a duration series into whole days at the physical type of dates, erroring on durations
+/// that aren't whole days since dates can't hold the remainder.
+fn duration_to_days(duration: &Series) -> DaftResult<Series> {
+    let DataType::Duration(unit) = duration.data_type() else {
+        panic!("Expected Duration series, got {}", duration.data_type())
+    };
+    let units_per_day: i64 = match unit {
+        TimeUnit::Seconds => 86_400,
+        TimeUnit::Milliseconds => 86_400_000,
+        TimeUnit::Microseconds => 86_400_000_000,
+        TimeUnit::Nanoseconds => 86_400_000_000_000,
+    };
+    let physical = duration.as_physical()?;
+    let physical = physical.downcast::<Int64Array>()?;
+    if physical
+        .as_arrow()
+        .iter()
+        .flatten()
+        .any(|v| v % units_per_day != 0)
+    {
+        return Err(DaftError::ValueError(format!(
+            "Date arithmetic only supports durations of whole days, but {} has a shorter remainder. Cast the date to a timestamp to add sub-day durations.",
+            duration.name()
+        )));
+    }
+    physical
+        .apply(|v| v / units_per_day)?
+        .into_series()
+        .cast(&DataType::Int32)
+}
+
+/// Exact arithmetic on decimals (or integers, for one of the operands), where results that don't fit
+/// in the precision of the output decimal type are null.
+///
+/// If `rescale`, operands are first rescaled to the scale of the output type, as for addition.
+/// Otherwise they keep their own scale, as for multiplication where the scales add up.
+fn decimal_binary_op(
+    lhs: &Series,
+    rhs: &Series,
+    output_type: &DataType,
+    rescale: bool,
+    op: fn(i128, i128) -> Option<i128>,
+) -> DaftResult<Series> {
+    let DataType::Decimal128(precision, scale) = output_type else {
+        panic!("Expected Decimal128 output type, got {output_type}")
+    };
+    let operand = |s: &Series| {
+        let operand_scale = match s.data_type() {
+            _ if rescale => *scale,
+            DataType::Decimal128(_, s) => *s,
+            _ => 0,
+        };
+        s.cast(&DataType::Decimal128(
+            DECIMAL128_MAX_PRECISION,
+            operand_scale,
+        ))
+    };
+    let (lhs_operand, rhs_operand) = (operand(lhs)?, operand(rhs)?);
+    let (l, r) = (lhs_operand.decimal128()?, rhs_operand.decimal128()?);
+    let len = match (l.len(), r.len()) {
+        (a, b) if a == b => a,
+        (1, b) => b,
+        (a, 1) => a,
+        (a, b) => {
+            return Err(DaftError::ValueError(format!(
+                "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+            )))
+        }
+    };
+    let bound = 10i128.pow(*precision as u32);
+    let values = (0..len)
+        .map(|i| {
+            let lv = l.get(if l.len() == 1 { 0 } else { i })?;
+            let rv = r.get(if r.len() == 1 { 0 } else { i })?;
+            op(lv, rv).filter(|v| -bound < *v && *v < bound)
+        })
+        .collect::<arrow2::array::PrimitiveArray<i128>>();
+    Ok(Decimal128Array::new(
+        Field::new(lhs.name(), output_type.clone()),
+        Int128Array::from((lhs.name(), Box::new(values))),
+    )
+    .into_series())
+}
+
 pub(crate) trait SeriesBinaryOps: SeriesLike {
     fn add(&self, rhs: &Series) -> DaftResult<Series> {
         let output_type = (self.data_type().add(rhs.data_type()))?;
@@ -149,6 +247,7 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
             #[cfg(feature = "python")]
             Python => Ok(py_binary_op!(lhs, rhs, "add")),
             Utf8 => cast_downcast_op_into_series!(lhs, rhs, &Utf8, Utf8Array, add),
+            Decimal128(..) => decimal_binary_op(&lhs, rhs, &output_type, true, i128::checked_add),
             output_type if output_type.is_numeric() => {
                 with_match_numeric_daft_types!(output_type, |$T| {
                     cast_downcast_op_into_series!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, add)
@@ -158,10 +257,36 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
         }
     }
     fn sub(&self, rhs: &Series) -> DaftResult<Series> {
-        py_numeric_binary_op!(self, rhs, sub, "sub")
+        let output_type = (self.data_type().sub(rhs.data_type()))?;
+        match &output_type {
+            DataType::Decimal128(..) => decimal_binary_op(
+                &self.into_series(),
+                rhs,
+                &output_type,
+                true,
+                i128::checked_sub,
+            ),
+            _ => py_numeric_binary_op!(self, rhs, sub, "sub"),
+        }
     }
     fn mul(&self, rhs: &Series) -> DaftResult<Series> {
-        py_numeric_binary_op!(self, rhs, mul, "mul")
+        let output_type = (self.data_type().mul(rhs.data_type()))?;
+        match &output_type {
+            DataType::Decimal128(..) => decimal_binary_op(
+                &self.into_series(),
+                rhs,
+                &output_type,
+                false,
+                i128::checked_mul,
+            ),
+            // Durations scaled by integers.
+            DataType::Duration(..) => {
+                let lhs = self.into_series().as_physical()?.cast(&DataType::Int64)?;
+                let rhs = rhs.as_physical()?.cast(&DataType::Int64)?;
+                lhs.mul(rhs)?.cast(&output_type)
+            }
+            _ => py_numeric_binary_op!(self, rhs, mul, "mul"),
+        }
     }
     fn div(&self, rhs: &Series) -> DaftResult<Series> {
         let output_type = (self.data_type().div(rhs.data_type()))?;
@@ -171,6 +296,12 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
             #[cfg(feature = "python")]
             Python => Ok(py_binary_op!(lhs, rhs, "truediv")),
             Float64 => cast_downcast_op_into_series!(lhs, rhs, &Float64, Float64Array, div),
+            // Durations divided by integers, truncated towards zero, or null when dividing by zero.
+            Duration(..) => {
+                let lhs = lhs.as_physical()?.cast(&Int64)?;
+                let rhs = rhs.cast(&Int64)?;
+                (lhs.i64()? / rhs.i64()?)?.into_series().cast(&output_type)
+            }
             _ => binary_op_unimplemented!(lhs, "/", rhs, output_type),
         }
     }
@@ -226,9 +357,65 @@ impl SeriesBinaryOps for ArrayWrapper<Utf8Array> {}
 impl SeriesBinaryOps for ArrayWrapper<FixedSizeListArray> {}
 impl SeriesBinaryOps for ArrayWrapper<ListArray> {}
 impl SeriesBinaryOps for ArrayWrapper<StructArray> {}
+impl SeriesBinaryOps for ArrayWrapper<CategoricalArray> {
+    fn equal(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, equal, "eq")
+    }
+    fn not_equal(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, not_equal, "ne")
+    }
+    fn lt(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, lt, "lt")
+    }
+    fn lte(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, lte, "le")
+    }
+    fn gt(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, gt, "gt")
+    }
+    fn gte(&self, rhs: &Series) -> DaftResult<BooleanArray> {
+        categorical_compare_op!(self, rhs, gte, "ge")
+    }
+}
 impl SeriesBinaryOps for ArrayWrapper<ExtensionArray> {}
 impl SeriesBinaryOps for ArrayWrapper<Decimal128Array> {}
-impl SeriesBinaryOps for ArrayWrapper<DateArray> {}
+impl SeriesBinaryOps for ArrayWrapper<DateArray> {
+    fn add(&self, rhs: &Series) -> DaftResult<Series> {
+        use DataType::*;
+        let output_type = (self.data_type() + rhs.data_type())?;
+        let lhs = self.0.clone().into_series();
+        match rhs.data_type() {
+            Duration(..) => {
+                let lhs = lhs.as_physical()?;
+                let physical_result = lhs.add(duration_to_days(rhs)?)?;
+                physical_result.cast(&output_type)
+            }
+            Interval => Ok(self.0.add_interval(rhs.interval()?, false)?.into_series()),
+            _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
+        }
+    }
+    fn sub(&self, rhs: &Series) -> DaftResult<Series> {
+        use DataType::*;
+        let output_type = (self.data_type() - rhs.data_type())?;
+        let lhs = self.0.clone().into_series();
+        match rhs.data_type() {
+            Duration(..) => {
+                let lhs = lhs.as_physical()?;
+                let physical_result = lhs.sub(duration_to_days(rhs)?)?;
+                physical_result.cast(&output_type)
+            }
+            Interval => Ok(self.0.add_interval(rhs.interval()?, true)?.into_series()),
+            Date => {
+                let lhs = lhs.as_physical()?;
+                let rhs = rhs.as_physical()?;
+                let days = lhs.sub(rhs)?.cast(&Int64)?;
+                let seconds = days.downcast::<Int64Array>()?.apply(|v| v * 86_400)?;
+                seconds.into_series().cast(&output_type)
+            }
+            _ => binary_op_unimplemented!(lhs, "-", rhs, output_type),
+        }
+    }
+}
 impl SeriesBinaryOps for ArrayWrapper<TimeArray> {}
 impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
     fn add(&self, rhs: &Series) -> DaftResult<Series> {
@@ -236,15 +423,34 @@ impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
         let output_type = (self.data_type() + rhs.data_type())?;
         let lhs = self.0.clone().into_series();
         match rhs.data_type() {
-            Timestamp(..) => {
+            Timestamp(..) | Duration(..) => {
                 let lhs = lhs.as_physical()?;
                 let rhs = rhs.as_physical()?;
                 let physical_result = lhs.add(rhs)?;
                 physical_result.cast(&output_type)
             }
+            Date => {
+                let rhs = rhs.as_physical()?;
+                let physical_result = duration_to_days(&lhs)?.add(rhs)?;
+                physical_result.cast(&output_type)
+            }
             _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
         }
     }
+    fn sub(&self, rhs: &Series) -> DaftResult<Series> {
+        use DataType::*;
+        let output_type = (self.data_type() - rhs.data_type())?;
+        let lhs = self.0.clone().into_series();
+        match rhs.data_type() {
+            Duration(..) => {
+                let lhs = lhs.as_physical()?;
+                let rhs = rhs.as_physical()?;
+                let physical_result = lhs.sub(rhs)?;
+                physical_result.cast(&output_type)
+            }
+            _ => binary_op_unimplemented!(lhs, "-", rhs, output_type),
+        }
+    }
 }
 impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
     fn add(&self, rhs: &Series) -> DaftResult<Series> {
@@ -258,6 +464,7 @@ impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
                 let physical_result = lhs.add(rhs)?;
                 physical_result.cast(&output_type)
             }
+            Interval => Ok(self.0.add_interval(rhs.interval()?, false)?.into_series()),
             _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
         }
     }
@@ -266,18 +473,40 @@ impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
         let output_type = (self.data_type() - rhs.data_type())?;
         let lhs = self.0.clone().into_series();
         match rhs.data_type() {
-            Duration(..) => {
+            Duration(..) | Timestamp(..) => {
                 let lhs = lhs.as_physical()?;
                 let rhs = rhs.as_physical()?;
                 let physical_result = lhs.sub(rhs)?;
                 physical_result.cast(&output_type)
             }
+            Interval => Ok(self.0.add_interval(rhs.interval()?, true)?.into_series()),
             _ => binary_op_unimplemented!(lhs, "-", rhs, output_type),
         }
     }
 }
+impl SeriesBinaryOps for ArrayWrapper<IntervalArray> {
+    fn add(&self, rhs: &Series) -> DaftResult<Series> {
+        use DataType::*;
+        let output_type = (self.data_type() + rhs.data_type())?;
+        let lhs = self.0.clone().into_series();
+        match rhs.data_type() {
+            Date => Ok(rhs
+                .date()?
+                .add_interval(&self.0, false)?
+                .into_series()
+                .rename(lhs.name())),
+            Timestamp(..) => Ok(rhs
+                .timestamp()?
+                .add_interval(&self.0, false)?
+                .into_series()
+                .rename(lhs.name())),
+            _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
+        }
+    }
+}
 impl SeriesBinaryOps for ArrayWrapper<EmbeddingArray> {}
 impl SeriesBinaryOps for ArrayWrapper<ImageArray> {}
 impl SeriesBinaryOps for ArrayWrapper<FixedShapeImageArray> {}
 impl SeriesBinaryOps for ArrayWrapper<TensorArray> {}
 impl SeriesBinaryOps for ArrayWrapper<FixedShapeTensorArray> {}
+impl SeriesBinaryOps for ArrayWrapper<MapArray> {}
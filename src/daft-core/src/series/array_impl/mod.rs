@@ -1,4 +1,5 @@
 pub mod binary_ops;
+pub mod categorical_array;
 pub mod data_array;
 pub mod logical_array;
 pub mod nested_array;
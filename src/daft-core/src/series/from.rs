@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use crate::{
-    datatypes::{DataType, Field},
+    datatypes::{extension::validate_extension_type, DataType, Field},
+    utils::arrow::cast_array_for_daft_if_needed,
     with_match_daft_types,
 };
 use common_error::{DaftError, DaftResult};
@@ -20,6 +21,7 @@ impl Series {
         // Corner-case nested logical types that have not yet been migrated to new Array formats
         // to hold only casted physical arrow arrays.
         let dtype = &field.dtype;
+        validate_extension_type(dtype)?;
         if matches!(dtype, DataType::List(..) | DataType::Extension(..)) && let physical_type = dtype.to_physical() && &physical_type != dtype {
             let arrow_physical_type = physical_type.to_arrow()?;
             let casted_array = arrow2::compute::cast::cast(
@@ -46,6 +48,8 @@ impl TryFrom<(&str, Box<dyn arrow2::array::Array>)> for Series {
 
     fn try_from(item: (&str, Box<dyn arrow2::array::Array>)) -> DaftResult<Self> {
         let (name, array) = item;
+        // Coerce arrow types that Daft stores differently, e.g. Utf8 to LargeUtf8.
+        let array = cast_array_for_daft_if_needed(array);
         let source_arrow_type = array.data_type();
         let dtype: DataType = source_arrow_type.into();
         let field = Arc::new(Field::new(name, dtype.clone()));
@@ -8,10 +8,10 @@ impl Series {
 
         use DataType::*;
         match self.data_type() {
-            Int8 => Ok(self.i8().unwrap().abs()?.into_series()),
-            Int16 => Ok(self.i16().unwrap().abs()?.into_series()),
-            Int32 => Ok(self.i32().unwrap().abs()?.into_series()),
-            Int64 => Ok(self.i64().unwrap().abs()?.into_series()),
+            Int8 => Ok(self.i8().unwrap().checked_abs()?.into_series()),
+            Int16 => Ok(self.i16().unwrap().checked_abs()?.into_series()),
+            Int32 => Ok(self.i32().unwrap().checked_abs()?.into_series()),
+            Int64 => Ok(self.i64().unwrap().checked_abs()?.into_series()),
             UInt8 | UInt16 | UInt32 | UInt64 => Ok(self.clone()),
             Float32 => Ok(self.f32().unwrap().abs()?.into_series()),
             Float64 => Ok(self.f64().unwrap().abs()?.into_series()),
@@ -61,6 +61,23 @@ impl Series {
                 .into_series()),
                 None => Ok(DaftSumAggable::sum(&self.downcast::<Float64Array>()?)?.into_series()),
             },
+            // Durations are summed over their physical counts of time units.
+            Duration(..) => self.as_physical()?.sum(groups)?.cast(self.data_type()),
+            // Decimals are summed exactly over their unscaled values, at the maximum precision so
+            // that sums of partial sums keep the same type.
+            Decimal128(_, scale) => {
+                let physical = self.as_physical()?;
+                let physical = physical.downcast::<Int128Array>()?;
+                let sum = match groups {
+                    Some(groups) => DaftSumAggable::grouped_sum(&physical, groups)?,
+                    None => DaftSumAggable::sum(&physical)?,
+                };
+                let output_type = Decimal128(DECIMAL128_MAX_PRECISION, *scale);
+                Ok(
+                    logical::Decimal128Array::new(Field::new(self.name(), output_type), sum)
+                        .into_series(),
+                )
+            }
             other => Err(DaftError::TypeError(format!(
                 "Numeric sum is not implemented for type {}",
                 other
@@ -83,6 +100,13 @@ impl Series {
                     None => Ok(DaftMeanAggable::mean(&casted.f64()?)?.into_series()),
                 }
             }
+            Decimal128(..) => self.cast(&Float64)?.mean(groups),
+            // The mean of durations is truncated towards zero to a whole number of time units.
+            Duration(..) => self
+                .as_physical()?
+                .mean(groups)?
+                .cast(&Int64)?
+                .cast(self.data_type()),
             other => Err(DaftError::TypeError(format!(
                 "Numeric mean is not implemented for type {}",
                 other
@@ -90,6 +114,69 @@ impl Series {
         }
     }
 
+    pub fn median(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftMedianAggable;
+
+        let casted = self.cast_numeric_to_f64("median")?;
+        match groups {
+            Some(groups) => Ok(casted.f64()?.grouped_median(groups)?.into_series()),
+            None => Ok(casted.f64()?.median()?.into_series()),
+        }
+    }
+
+    pub fn approx_percentile(
+        &self,
+        groups: Option<&GroupIndices>,
+        percentile: f64,
+    ) -> DaftResult<Series> {
+        let sketches = self.dd_sketch(groups)?;
+        Ok(sketches
+            .binary()?
+            .dd_sketch_quantile(percentile)?
+            .into_series())
+    }
+
+    /// Builds a serialized DDSketch of the non-null values of each group, which can be merged with
+    /// sketches of other partitions by [`Series::dd_merge`].
+    pub fn dd_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftDDSketchAggable;
+
+        let casted = self.cast_numeric_to_f64("approx_percentile")?;
+        match groups {
+            Some(groups) => Ok(casted.f64()?.grouped_dd_sketch(groups)?.into_series()),
+            None => Ok(casted.f64()?.dd_sketch()?.into_series()),
+        }
+    }
+
+    pub fn dd_merge(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftDDSketchMergeAggable;
+        match self.data_type() {
+            DataType::Binary => {
+                let downcasted = self.binary()?;
+                match groups {
+                    Some(groups) => Ok(downcasted.grouped_dd_merge(groups)?.into_series()),
+                    None => Ok(downcasted.dd_merge()?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "DDSketches can only be merged from Binary types, got {}",
+                other
+            ))),
+        }
+    }
+
+    fn cast_numeric_to_f64(&self, op_name: &str) -> DaftResult<Series> {
+        use crate::datatypes::DataType::*;
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            | Null => self.cast(&Float64),
+            other => Err(DaftError::TypeError(format!(
+                "Numeric {} is not implemented for type {}",
+                op_name, other
+            ))),
+        }
+    }
+
     pub fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         self.inner.min(groups)
     }
@@ -134,10 +221,163 @@ impl Series {
         )?)
     }
 
+    /// Takes the row picked by `pick` out of the indices of each group.
+    fn take_per_group<F>(&self, groups: Option<&GroupIndices>, pick: F) -> DaftResult<Series>
+    where
+        F: Fn(&[u64]) -> Option<u64>,
+    {
+        let indices = match groups {
+            Some(groups) => groups
+                .iter()
+                .map(|g| pick(g))
+                .collect::<PrimitiveArray<u64>>(),
+            None => {
+                let all = (0..self.len() as u64).collect::<Vec<_>>();
+                PrimitiveArray::from([pick(&all)])
+            }
+        };
+        self.take(&Series::from_arrow(
+            Field::new("", DataType::UInt64).into(),
+            Box::new(indices),
+        )?)
+    }
+
+    /// Value of the first row of each group, in the order of the rows, which may be null.
+    pub fn first(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.take_per_group(groups, |g| g.first().copied())
+    }
+
+    /// Value of the last row of each group, in the order of the rows, which may be null.
+    pub fn last(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.take_per_group(groups, |g| g.last().copied())
+    }
+
+    pub fn approx_count_distinct(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        let sketches = self.hll_sketch(groups)?;
+        Ok(sketches.binary()?.hll_cardinality()?.into_series())
+    }
+
+    /// Builds a serialized HyperLogLog sketch of the non-null values of each group, which can be
+    /// merged with sketches of other partitions by [`Series::hll_merge`].
+    pub fn hll_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::{full::FullNull, DaftHllSketchAggable};
+
+        let hashes = if self.data_type().is_null() {
+            UInt64Array::full_null(self.name(), &DataType::UInt64, self.len())
+        } else {
+            self.hash(None)?.with_validity(self.validity().cloned())?
+        };
+        match groups {
+            Some(groups) => Ok(hashes.grouped_hll_sketch(groups)?.into_series()),
+            None => Ok(hashes.hll_sketch()?.into_series()),
+        }
+    }
+
+    pub fn hll_merge(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftHllMergeAggable;
+        match self.data_type() {
+            DataType::Binary => {
+                let downcasted = self.binary()?;
+                match groups {
+                    Some(groups) => Ok(downcasted.grouped_hll_merge(groups)?.into_series()),
+                    None => Ok(downcasted.hll_merge()?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "HyperLogLog sketches can only be merged from Binary types, got {}",
+                other
+            ))),
+        }
+    }
+
+    /// Indices of the distinct non-null values of each group, using the first index of each value.
+    fn distinct_groups(&self, groups: Option<&GroupIndices>) -> DaftResult<GroupIndices> {
+        let full_group;
+        let groups = match groups {
+            Some(groups) => groups,
+            None => {
+                full_group = vec![(0..self.len() as u64).collect()];
+                &full_group
+            }
+        };
+        if self.data_type().is_null() {
+            return Ok(vec![vec![]; groups.len()]);
+        }
+        if !matches!(
+            self.data_type().to_physical(),
+            DataType::Boolean
+                | DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::Int128
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64
+                | DataType::Utf8
+                | DataType::Binary
+        ) {
+            return Err(DaftError::TypeError(format!(
+                "count_distinct not implemented for {}",
+                self.data_type()
+            )));
+        }
+        let validity = self.validity();
+        Ok(self
+            .grouped_unique_indices(groups)?
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .filter(|i| validity.map_or(true, |v| v.get_bit(*i as usize)))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Exact number of distinct non-null values of each group.
+    pub fn count_distinct(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        let counts = self
+            .distinct_groups(groups)?
+            .iter()
+            .map(|group| group.len() as u64)
+            .collect::<Vec<_>>();
+        Ok(UInt64Array::from((self.name(), counts)).into_series())
+    }
+
+    /// Collects the distinct non-null values of each group into a list. These lists are the partial
+    /// states of a distributed [`Series::count_distinct`], which are concatenated across partitions
+    /// and then counted with [`Series::list_count_distinct`].
+    pub fn agg_set(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.agg_list(Some(&self.distinct_groups(groups)?))
+    }
+
     pub fn agg_list(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         self.inner.agg_list(groups)
     }
 
+    /// Joins the non-null strings of each group with `separator`, where groups without any
+    /// non-null strings are null.
+    pub fn str_join(&self, groups: Option<&GroupIndices>, separator: &str) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => {
+                let downcasted = self.utf8()?;
+                let joined = match groups {
+                    Some(groups) => downcasted.grouped_str_join(groups, separator)?,
+                    None => downcasted
+                        .grouped_str_join(&vec![(0..self.len() as u64).collect()], separator)?,
+                };
+                Ok(joined.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "str_join aggregation is only valid for Utf8 types, got {dt}"
+            ))),
+        }
+    }
+
     pub fn agg_concat(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         use crate::array::ops::DaftConcatAggable;
         match self.data_type() {
@@ -150,6 +390,15 @@ impl Series {
                     None => Ok(DaftConcatAggable::concat(downcasted)?.into_series()),
                 }
             }
+            DataType::Utf8 => {
+                let downcasted = self.downcast::<Utf8Array>()?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftConcatAggable::grouped_concat(downcasted, groups)?.into_series())
+                    }
+                    None => Ok(DaftConcatAggable::concat(downcasted)?.into_series()),
+                }
+            }
             #[cfg(feature = "python")]
             DataType::Python => {
                 let downcasted = self.downcast::<PythonArray>()?;
@@ -161,7 +410,7 @@ impl Series {
                 }
             }
             _ => Err(DaftError::TypeError(format!(
-                "concat aggregation is only valid for List or Python types, got {}",
+                "concat aggregation is only valid for List, Utf8 or Python types, got {}",
                 self.data_type()
             ))),
         }
@@ -0,0 +1,60 @@
+use common_error::DaftResult;
+
+use crate::{
+    array::ops::DaftBetween, closed_interval::ClosedInterval,
+    datatypes::supertype::try_get_supertype, with_match_comparable_daft_types, IntoSeries, Series,
+};
+
+#[cfg(feature = "python")]
+use crate::{
+    array::ops::{DaftCompare, DaftLogical},
+    DataType,
+};
+
+impl Series {
+    pub fn between(
+        &self,
+        lower: &Series,
+        upper: &Series,
+        closed: ClosedInterval,
+    ) -> DaftResult<Series> {
+        let supertype = try_get_supertype(
+            &try_get_supertype(self.data_type(), lower.data_type())?,
+            upper.data_type(),
+        )?;
+        let (_output_type, _intermediate, comp_type) = supertype.comparison_op(&supertype)?;
+
+        // Only the bounds are broadcast by the kernel, so a length-1 input is expanded here.
+        let values = match (self.len(), lower.len().max(upper.len())) {
+            (1, n) if n > 1 => self.broadcast(n)?,
+            _ => self.clone(),
+        };
+
+        match comp_type {
+            #[cfg(feature = "python")]
+            DataType::Python => {
+                let above_lower = if closed.lower_inclusive() {
+                    values.gte(lower)?
+                } else {
+                    values.gt(lower)?
+                };
+                let below_upper = if closed.upper_inclusive() {
+                    values.lte(upper)?
+                } else {
+                    values.lt(upper)?
+                };
+                Ok(above_lower.and(&below_upper)?.into_series())
+            }
+            _ => with_match_comparable_daft_types!(comp_type, |$T| {
+                let values = values.cast(&supertype)?.as_physical()?;
+                let lower = lower.cast(&supertype)?.as_physical()?;
+                let upper = upper.cast(&supertype)?.as_physical()?;
+                let values = values.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                let lower = lower.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                let upper = upper.downcast::<<$T as DaftDataType>::ArrayType>()?;
+
+                Ok(values.between(lower, upper, closed)?.into_series())
+            }),
+        }
+    }
+}
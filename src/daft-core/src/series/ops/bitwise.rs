@@ -0,0 +1,48 @@
+use common_error::DaftResult;
+
+use crate::{
+    datatypes::{DataType, Int64Array},
+    series::{IntoSeries, Series},
+    with_match_integer_daft_types,
+};
+
+use super::broadcast_operands;
+
+macro_rules! impl_bitwise_op {
+    ($fname:ident) => {
+        pub fn $fname(&self, rhs: &Series) -> DaftResult<Series> {
+            let output_type = self.data_type().bitwise_op(rhs.data_type())?;
+            let (lhs, rhs) = broadcast_operands(self, rhs)?;
+            let lhs = lhs.cast(&output_type)?;
+            let rhs = rhs.cast(&output_type)?;
+            with_match_integer_daft_types!(output_type, |$T| {
+                let lhs = lhs.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                let rhs = rhs.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                Ok(lhs.$fname(rhs)?.into_series().rename(self.name()))
+            })
+        }
+    };
+}
+
+macro_rules! impl_shift_op {
+    ($fname:ident) => {
+        pub fn $fname(&self, rhs: &Series) -> DaftResult<Series> {
+            let output_type = self.data_type().shift_op(rhs.data_type())?;
+            let (lhs, rhs) = broadcast_operands(self, rhs)?;
+            let rhs = rhs.cast(&DataType::Int64)?;
+            let amounts = rhs.downcast::<Int64Array>()?;
+            with_match_integer_daft_types!(output_type, |$T| {
+                let lhs = lhs.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                Ok(lhs.$fname(amounts)?.into_series().rename(self.name()))
+            })
+        }
+    };
+}
+
+impl Series {
+    impl_bitwise_op!(bitwise_and);
+    impl_bitwise_op!(bitwise_or);
+    impl_bitwise_op!(bitwise_xor);
+    impl_shift_op!(shift_left);
+    impl_shift_op!(shift_right);
+}
@@ -1,8 +1,108 @@
-use crate::{datatypes::DataType, series::Series};
-use common_error::DaftResult;
+use arrow2::compute::cast::{cast, CastOptions};
+
+use crate::{
+    array::{FixedSizeListArray, ListArray, StructArray},
+    datatypes::{get_extension_type, DataType, ExtensionArray, Field},
+    series::{IntoSeries, Series},
+};
+use common_error::{DaftError, DaftResult};
 
 impl Series {
     pub fn cast(&self, datatype: &DataType) -> DaftResult<Series> {
+        if self.data_type() != datatype {
+            // Registered extension types cast with their own kernel, and are cast into through
+            // their storage type.
+            if let DataType::Extension(name, ..) = self.data_type() && let Some(extension) = get_extension_type(name)? {
+                return extension.cast(self.downcast::<ExtensionArray>()?, datatype);
+            }
+            if let DataType::Extension(name, storage_dtype, _) = datatype && get_extension_type(name)?.is_some() {
+                let storage = self.cast(storage_dtype)?;
+                return Ok(ExtensionArray::from_storage(self.name(), datatype, &storage)?.into_series());
+            }
+        }
         self.inner.cast(datatype)
     }
+
+    /// Casts like [`Series::cast`], but errors instead of producing a null when a non-null value
+    /// cannot be represented in `datatype`, e.g. when a string fails to parse or a number overflows.
+    pub fn strict_cast(&self, datatype: &DataType) -> DaftResult<Series> {
+        use DataType::*;
+        let field = Field::new(self.name(), datatype.clone());
+        match (self.data_type(), datatype) {
+            // Null series have no values that could fail to cast.
+            (Null, _) => return self.cast(datatype),
+            // Nested values are cast strictly by casting their children strictly.
+            (List(..), List(child_dtype)) => {
+                let list = self.list()?;
+                return Ok(ListArray::new(
+                    field,
+                    list.flat_child.strict_cast(child_dtype)?,
+                    list.offsets().clone(),
+                    list.validity().cloned(),
+                )
+                .into_series());
+            }
+            (FixedSizeList(_, size), FixedSizeList(child_dtype, target_size))
+                if size == target_size =>
+            {
+                let list = self.fixed_size_list()?;
+                return Ok(FixedSizeListArray::new(
+                    field,
+                    list.flat_child.strict_cast(child_dtype)?,
+                    list.validity().cloned(),
+                )
+                .into_series());
+            }
+            (Struct(..), Struct(target_fields)) => {
+                let array = self.struct_()?;
+                let children = target_fields
+                    .iter()
+                    .map(
+                        |target| match array.children.iter().find(|c| c.name() == target.name) {
+                            Some(child) => child.strict_cast(&target.dtype),
+                            None => Ok(Series::full_null(&target.name, &target.dtype, self.len())),
+                        },
+                    )
+                    .collect::<DaftResult<Vec<_>>>()?;
+                return Ok(
+                    StructArray::new(field, children, array.validity().cloned()).into_series()
+                );
+            }
+            _ => {}
+        }
+
+        // Numbers that overflow an integer type wrap around in `cast`, so they are cast with
+        // arrow's checked cast instead, which nulls them out.
+        let is_plain_integer = |dtype: &DataType| dtype.is_integer() && dtype != &Int128;
+        let result = if (is_plain_integer(self.data_type()) || self.data_type().is_floating())
+            && is_plain_integer(datatype)
+        {
+            let options = CastOptions {
+                wrapped: false,
+                partial: false,
+            };
+            let checked = cast(self.to_arrow().as_ref(), &datatype.to_arrow()?, options)?;
+            Series::try_from((self.name(), checked))?
+        } else {
+            self.cast(datatype)?
+        };
+
+        let failed = match (self.validity(), result.validity()) {
+            (_, None) => None,
+            (None, Some(checked)) => checked.iter().position(|valid| !valid),
+            (Some(original), Some(checked)) => original
+                .iter()
+                .zip(checked.iter())
+                .position(|(original, checked)| original && !checked),
+        };
+        if let Some(idx) = failed {
+            return Err(DaftError::ValueError(format!(
+                "Cannot cast value {} of type {} to {}",
+                self.str_value(idx)?,
+                self.data_type(),
+                datatype
+            )));
+        }
+        Ok(result)
+    }
 }
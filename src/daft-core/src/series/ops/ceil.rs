@@ -11,6 +11,7 @@ impl Series {
             Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 => Ok(self.clone()),
             Float32 => Ok(self.f32().unwrap().ceil()?.into_series()),
             Float64 => Ok(self.f64().unwrap().ceil()?.into_series()),
+            Decimal128(..) => Ok(self.decimal128().unwrap().ceil()?.into_series()),
             dt => Err(DaftError::TypeError(format!(
                 "ceil not implemented for {}",
                 dt
@@ -0,0 +1,67 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use crate::with_match_numeric_daft_types;
+use common_error::DaftError;
+use common_error::DaftResult;
+impl Series {
+    /// Clips values to be at least `lower` and then at most `upper`, where null bounds leave values
+    /// unbounded on that side. Bounds of length 1 are broadcast.
+    ///
+    /// Fractional bounds of integer inputs are rounded inwards (the lower bound up and the upper
+    /// bound down), and bounds that aren't representable in the input type are rejected.
+    pub fn clip(&self, lower: &Series, upper: &Series) -> DaftResult<Series> {
+        use crate::series::array_impl::IntoSeries;
+
+        let dtype = self.data_type();
+        if !matches!(
+            dtype,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64
+        ) {
+            return Err(DaftError::TypeError(format!(
+                "clip not implemented for {dtype}"
+            )));
+        }
+        for bound in [lower, upper] {
+            let bound_type = bound.data_type();
+            if !bound_type.is_numeric() && bound_type != &DataType::Null {
+                return Err(DaftError::TypeError(format!(
+                    "Expected clip bounds to be numeric, got {bound_type}"
+                )));
+            }
+        }
+        let len = self.len().max(lower.len()).max(upper.len());
+        let broadcast = |s: &Series| match s.len() {
+            l if l == len => Ok(s.clone()),
+            1 => s.broadcast(len),
+            l => Err(DaftError::ValueError(format!(
+                "Cannot clip arrays of different lengths: {l} vs {len}"
+            ))),
+        };
+        let cast_bound = |bound: Series, round: fn(&Series) -> DaftResult<Series>| {
+            let bound = if dtype.is_integer() && bound.data_type().is_floating() {
+                round(&bound)?
+            } else {
+                bound
+            };
+            bound.strict_cast(dtype)
+        };
+        let input = broadcast(self)?;
+        let lower = cast_bound(broadcast(lower)?, Series::ceil)?;
+        let upper = cast_bound(broadcast(upper)?, Series::floor)?;
+        with_match_numeric_daft_types!(dtype, |$T| {
+            let input = input.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            let lower = lower.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            let upper = upper.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            Ok(input.clip(lower, upper)?.into_series())
+        })
+    }
+}
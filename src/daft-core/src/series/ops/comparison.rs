@@ -1,5 +1,5 @@
 use crate::{
-    array::ops::{DaftCompare, DaftLogical},
+    array::ops::{as_arrow::AsArrow, DaftCompare, DaftLogical},
     datatypes::BooleanArray,
     series::Series,
 };
@@ -31,3 +31,25 @@ impl DaftLogical<&Series> for Series {
     call_inner!(or);
     call_inner!(xor);
 }
+
+impl Series {
+    /// Null-safe equality (`<=>`), where two nulls are equal and a null is unequal to any non-null
+    /// value, so the result is never null. Either side may be of length 1.
+    pub fn eq_null_safe(&self, other: &Series) -> DaftResult<BooleanArray> {
+        let eq = self.equal(other)?;
+        let lhs_nulls = self.is_null()?;
+        let rhs_nulls = other.is_null()?;
+        let lhs_nulls = lhs_nulls.bool()?.as_arrow();
+        let rhs_nulls = rhs_nulls.bool()?.as_arrow();
+        let is_null = |nulls: &arrow2::array::BooleanArray, i: usize| {
+            nulls.value(if nulls.len() == 1 { 0 } else { i })
+        };
+        let result = eq
+            .as_arrow()
+            .iter()
+            .enumerate()
+            .map(|(i, eq)| eq.unwrap_or_else(|| is_null(lhs_nulls, i) && is_null(rhs_nulls, i)))
+            .collect::<arrow2::array::BooleanArray>();
+        Ok(BooleanArray::from((eq.name(), result)))
+    }
+}
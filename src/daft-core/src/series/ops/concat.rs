@@ -1,3 +1,4 @@
+use crate::datatypes::supertype::get_supertype;
 use crate::series::{IntoSeries, Series};
 use crate::with_match_daft_types;
 use common_error::{DaftError, DaftResult};
@@ -14,15 +15,24 @@ impl Series {
             return Ok((*series.first().unwrap()).clone());
         }
 
+        // Series of different types are cast to their supertype.
         let first_dtype = series.first().unwrap().data_type();
+        let mut supertype = first_dtype.clone();
         for s in series.iter().skip(1) {
-            if first_dtype != s.data_type() {
-                return Err(DaftError::TypeError(format!(
-                    "Series concat requires all data types to match, {} vs {}",
-                    first_dtype,
+            supertype = get_supertype(&supertype, s.data_type()).ok_or_else(|| {
+                DaftError::TypeError(format!(
+                    "Series concat requires all data types to have a supertype, {} vs {}",
+                    supertype,
                     s.data_type()
-                )));
-            }
+                ))
+            })?;
+        }
+        if series.iter().any(|s| s.data_type() != &supertype) {
+            let casted = series
+                .iter()
+                .map(|s| s.cast(&supertype))
+                .collect::<DaftResult<Vec<_>>>()?;
+            return Self::concat(casted.iter().collect::<Vec<_>>().as_slice());
         }
 
         with_match_daft_types!(first_dtype, |$T| {
@@ -0,0 +1,72 @@
+use crate::datatypes::{DataType, UInt64Array};
+use crate::series::{IntoSeries, Series};
+use crate::with_match_numeric_daft_types;
+use common_error::{DaftError, DaftResult};
+
+impl Series {
+    /// Checks that this series is numeric, and casts it to the type that `fn_name` accumulates in.
+    /// Sums widen like the sum aggregation, while the running min and max keep the input type.
+    fn cumulative_input(&self, fn_name: &str, widen: bool) -> DaftResult<Series> {
+        let dtype = self.data_type();
+        match dtype {
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 if widen => {
+                self.cast(&DataType::Int64)
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 if widen => {
+                self.cast(&DataType::UInt64)
+            }
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64 => Ok(self.clone()),
+            _ => Err(DaftError::TypeError(format!(
+                "{fn_name} not implemented for {dtype}"
+            ))),
+        }
+    }
+
+    /// Running sum of the values in order. Nulls stay null and are skipped over.
+    pub fn cumsum(&self) -> DaftResult<Series> {
+        let s = self.cumulative_input("cumsum", true)?;
+        with_match_numeric_daft_types!(s.data_type(), |$T| {
+            Ok(s.downcast::<<$T as DaftDataType>::ArrayType>()?.cumsum()?.into_series())
+        })
+    }
+
+    /// Running maximum of the values in order. Nulls stay null and are skipped over.
+    pub fn cummax(&self) -> DaftResult<Series> {
+        let s = self.cumulative_input("cummax", false)?;
+        with_match_numeric_daft_types!(s.data_type(), |$T| {
+            Ok(s.downcast::<<$T as DaftDataType>::ArrayType>()?.cummax()?.into_series())
+        })
+    }
+
+    /// Running minimum of the values in order. Nulls stay null and are skipped over.
+    pub fn cummin(&self) -> DaftResult<Series> {
+        let s = self.cumulative_input("cummin", false)?;
+        with_match_numeric_daft_types!(s.data_type(), |$T| {
+            Ok(s.downcast::<<$T as DaftDataType>::ArrayType>()?.cummin()?.into_series())
+        })
+    }
+
+    /// Running count of the non-null values in order, for values of any type.
+    pub fn cumcount(&self) -> DaftResult<Series> {
+        let validity = self.validity();
+        let mut count = 0u64;
+        let counts = (0..self.len())
+            .map(|i| {
+                if validity.map_or(true, |v| v.get_bit(i)) {
+                    count += 1;
+                }
+                count
+            })
+            .collect::<Vec<_>>();
+        Ok(UInt64Array::from((self.name(), counts)).into_series())
+    }
+}
@@ -3,6 +3,7 @@ use crate::series::array_impl::IntoSeries;
 use crate::{
     datatypes::{logical::DateArray, DataType},
     series::Series,
+    DstResolution,
 };
 use common_error::{DaftError, DaftResult};
 
@@ -51,6 +52,19 @@ impl Series {
         }
     }
 
+    pub fn dt_minute(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.downcast::<TimestampArray>()?;
+                Ok(ts_array.minute()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run minute() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
     pub fn dt_month(&self) -> DaftResult<Self> {
         match self.data_type() {
             DataType::Date => {
@@ -101,4 +115,37 @@ impl Series {
             ))),
         }
     }
+
+    pub fn dt_tz_localize(
+        &self,
+        tz: &str,
+        ambiguous: DstResolution,
+        nonexistent: DstResolution,
+    ) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.downcast::<TimestampArray>()?;
+                Ok(ts_array
+                    .tz_localize(tz, ambiguous, nonexistent)?
+                    .into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run tz_localize() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_tz_convert(&self, tz: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.downcast::<TimestampArray>()?;
+                Ok(ts_array.tz_convert(tz)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run tz_convert() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
 }
@@ -1,6 +1,7 @@
-use crate::array::{FixedSizeListArray, ListArray, StructArray};
+use crate::array::{CategoricalArray, FixedSizeListArray, ListArray, StructArray};
 use crate::datatypes::logical::{
-    DateArray, Decimal128Array, FixedShapeImageArray, TimeArray, TimestampArray,
+    DateArray, Decimal128Array, FixedShapeImageArray, IntervalArray, MapArray, TimeArray,
+    TimestampArray,
 };
 use crate::datatypes::*;
 use crate::series::array_impl::ArrayWrapper;
@@ -81,6 +82,10 @@ impl Series {
         self.downcast()
     }
 
+    pub fn categorical(&self) -> DaftResult<&CategoricalArray> {
+        self.downcast()
+    }
+
     pub fn fixed_size_list(&self) -> DaftResult<&FixedSizeListArray> {
         self.downcast()
     }
@@ -109,6 +114,14 @@ impl Series {
         self.downcast()
     }
 
+    pub fn interval(&self) -> DaftResult<&IntervalArray> {
+        self.downcast()
+    }
+
+    pub fn map(&self) -> DaftResult<&MapArray> {
+        self.downcast()
+    }
+
     pub fn decimal128(&self) -> DaftResult<&Decimal128Array> {
         self.downcast()
     }
@@ -0,0 +1,36 @@
+use crate::datatypes::logical::EmbeddingArray;
+use crate::datatypes::{DataType, Float64Array};
+use crate::series::{IntoSeries, Series};
+use common_error::{DaftError, DaftResult};
+
+impl Series {
+    /// Applies `op` to this embedding Series and `rhs`, which is cast to the same embedding type.
+    fn embedding_op(
+        &self,
+        rhs: &Series,
+        op_name: &str,
+        op: impl Fn(&EmbeddingArray, &EmbeddingArray) -> DaftResult<Float64Array>,
+    ) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Embedding(..) => {
+                let rhs = rhs.cast(self.data_type())?;
+                Ok(op(
+                    self.downcast::<EmbeddingArray>()?,
+                    rhs.downcast::<EmbeddingArray>()?,
+                )?
+                .into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "embedding.{op_name} not implemented for {dt}"
+            ))),
+        }
+    }
+
+    pub fn embedding_dot(&self, rhs: &Series) -> DaftResult<Series> {
+        self.embedding_op(rhs, "dot", EmbeddingArray::dot)
+    }
+
+    pub fn embedding_cosine_similarity(&self, rhs: &Series) -> DaftResult<Series> {
+        self.embedding_op(rhs, "cosine_similarity", EmbeddingArray::cosine_similarity)
+    }
+}
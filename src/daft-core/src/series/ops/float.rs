@@ -11,4 +11,25 @@ impl Series {
             Ok(DaftIsNan::is_nan(self.downcast::<<$T as DaftDataType>::ArrayType>()?)?.into_series())
         })
     }
+
+    pub fn is_inf(&self) -> DaftResult<Series> {
+        use crate::array::ops::DaftIsInf;
+        with_match_float_and_null_daft_types!(self.data_type(), |$T| {
+            Ok(DaftIsInf::is_inf(self.downcast::<<$T as DaftDataType>::ArrayType>()?)?.into_series())
+        })
+    }
+
+    pub fn not_nan(&self) -> DaftResult<Series> {
+        use crate::array::ops::DaftNotNan;
+        with_match_float_and_null_daft_types!(self.data_type(), |$T| {
+            Ok(DaftNotNan::not_nan(self.downcast::<<$T as DaftDataType>::ArrayType>()?)?.into_series())
+        })
+    }
+
+    pub fn fill_nan(&self, fill_value: &Series) -> DaftResult<Series> {
+        // Nulls aren't NaNs, so they are kept as nulls rather than filled.
+        let predicate = self.is_nan()?;
+        let fill_value = fill_value.cast(self.data_type())?;
+        Ok(fill_value.if_else(self, &predicate)?.rename(self.name()))
+    }
 }
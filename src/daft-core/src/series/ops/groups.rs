@@ -1,12 +1,16 @@
 use crate::{
     array::ops::{GroupIndicesPair, IntoGroups},
     series::Series,
-    with_match_comparable_daft_types,
+    with_match_comparable_daft_types, DataType,
 };
 use common_error::DaftResult;
 
 impl IntoGroups for Series {
     fn make_groups(&self) -> DaftResult<GroupIndicesPair> {
+        // Categorical arrays are grouped directly on their codes.
+        if self.data_type() == &DataType::Categorical {
+            return self.categorical()?.make_groups();
+        }
         let s = self.as_physical()?;
         with_match_comparable_daft_types!(s.data_type(), |$T| {
             let array = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
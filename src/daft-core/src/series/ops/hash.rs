@@ -1,17 +1,44 @@
 use crate::{
-    datatypes::{Int32Array, UInt64Array},
+    datatypes::{ExtensionArray, Int32Array, UInt64Array},
     series::Series,
-    with_match_comparable_daft_types,
+    with_match_comparable_daft_types, DataType,
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 impl Series {
+    /// Hashes each element with xxhash, seeding the hash of each element with the corresponding
+    /// element of `seed` if given, which chains hashes across multiple columns. Nulls hash like
+    /// empty values, and nested values are hashed from the hashes of their children.
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        if let Some(seed) = seed && seed.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "seed length does not match array length: {} vs {}",
+                seed.len(),
+                self.len()
+            )));
+        }
+        match self.data_type() {
+            DataType::Categorical => return self.categorical()?.hash(seed),
+            DataType::Extension(..) => {
+                return self.downcast::<ExtensionArray>()?.storage()?.hash(seed)
+            }
+            _ => {}
+        }
         let s = self.as_physical()?;
-        with_match_comparable_daft_types!(s.data_type(), |$T| {
-            let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
-            downcasted.hash(seed)
-        })
+        match s.data_type() {
+            DataType::Struct(..) => s.struct_()?.hash(seed),
+            DataType::List(..) => s.list()?.hash(seed),
+            DataType::FixedSizeList(..) => s.fixed_size_list()?.hash(seed),
+            #[cfg(feature = "python")]
+            DataType::Python => Err(DaftError::TypeError(format!(
+                "Hashing is not supported for {}",
+                s.data_type()
+            ))),
+            _ => with_match_comparable_daft_types!(s.data_type(), |$T| {
+                let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                downcasted.hash(seed)
+            }),
+        }
     }
 
     pub fn murmur3_32(&self) -> DaftResult<Int32Array> {
@@ -26,6 +53,7 @@ impl Series {
             UInt32 => self.u32()?.murmur3_32(),
             UInt64 => self.u64()?.murmur3_32(),
             Utf8 => self.utf8()?.murmur3_32(),
+            Categorical => self.categorical()?.decode()?.murmur3_32(),
             Binary => self.binary()?.murmur3_32(),
             Date => self.date()?.murmur3_32(),
             Time(..) => self.time()?.murmur3_32(),
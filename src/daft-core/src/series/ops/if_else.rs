@@ -1,11 +1,24 @@
 use super::match_types_on_series;
-use crate::series::Series;
+use crate::{series::Series, DataType};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 impl Series {
+    /// Chooses the values of this Series where `predicate` is true and the values of `other` where
+    /// it's false, with nulls where it's null. Each of the three Series may be of length 1, in which
+    /// case it's broadcast to the length of the others, and the result is of the supertype of this
+    /// Series and `other`.
     pub fn if_else(&self, other: &Series, predicate: &Series) -> DaftResult<Series> {
+        let predicate = match predicate.data_type() {
+            DataType::Boolean => predicate.clone(),
+            DataType::Null => predicate.cast(&DataType::Boolean)?,
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "Expected the predicate of if_else to be Boolean, but received {other}"
+                )))
+            }
+        };
         let (if_true, if_false) = match_types_on_series(self, other)?;
-        if_true.inner.if_else(&if_false, predicate)
+        if_true.inner.if_else(&if_false, &predicate)
     }
 }
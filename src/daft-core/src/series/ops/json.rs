@@ -0,0 +1,20 @@
+use crate::{
+    array::ops::full::FullNull,
+    datatypes::{DataType, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use common_error::{DaftError, DaftResult};
+
+impl Series {
+    pub fn json_query(&self, query: &str) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.json_query(query)?.into_series()),
+            DataType::Null => {
+                Ok(Utf8Array::full_null(self.name(), &DataType::Utf8, self.len()).into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "JSON query not implemented for type {dt}"
+            ))),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::datatypes::{DataType, UInt64Array, Utf8Array};
-use crate::series::Series;
+use crate::array::ops::GroupIndices;
+use crate::datatypes::{BooleanArray, DataType, UInt64Array, Utf8Array};
+use crate::series::{IntoSeries, Series};
+use arrow2::bitmap::Bitmap;
 use common_error::DaftError;
 
 use common_error::DaftResult;
@@ -67,4 +69,83 @@ impl Series {
             ))),
         }
     }
+
+    pub fn list_slice(&self, start: &Series, end: &Series) -> DaftResult<Series> {
+        let start = start.cast(&DataType::Int64)?;
+        let start_arr = start.i64().unwrap();
+        let end = end.cast(&DataType::Int64)?;
+        let end_arr = end.i64().unwrap();
+
+        match self.data_type() {
+            DataType::List(_) => Ok(self
+                .list()?
+                .slice_children(start_arr, end_arr)?
+                .into_series()),
+            DataType::FixedSizeList(..) => Ok(self
+                .fixed_size_list()?
+                .slice_children(start_arr, end_arr)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Slice not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.contains(item),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.contains(item),
+            dt => Err(DaftError::TypeError(format!(
+                "Contains not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    /// Flattened values of this list series, with the child indices of each list as a group.
+    fn list_groups(&self, fn_name: &str) -> DaftResult<(&Series, GroupIndices, Option<Bitmap>)> {
+        match self.data_type() {
+            DataType::List(_) => {
+                let list = self.list()?;
+                let groups = list
+                    .offsets()
+                    .as_slice()
+                    .windows(2)
+                    .map(|w| (w[0] as u64..w[1] as u64).collect())
+                    .collect::<Vec<_>>();
+                Ok((&list.flat_child, groups, list.validity().cloned()))
+            }
+            DataType::FixedSizeList(_, size) => {
+                let list = self.fixed_size_list()?;
+                let size = *size as u64;
+                let groups = (0..list.len() as u64)
+                    .map(|i| (i * size..(i + 1) * size).collect())
+                    .collect::<Vec<_>>();
+                Ok((&list.flat_child, groups, list.validity().cloned()))
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "{} not implemented for {}",
+                fn_name, dt
+            ))),
+        }
+    }
+
+    /// Computes the exact median of the values of each list.
+    pub fn list_median(&self) -> DaftResult<Series> {
+        let (flat_child, groups, validity) = self.list_groups("Median")?;
+        flat_child
+            .median(Some(&groups))?
+            .rename(self.name())
+            .with_validity(validity)
+    }
+
+    /// Computes the exact number of distinct non-null values of each list.
+    pub fn list_count_distinct(&self) -> DaftResult<Series> {
+        let (flat_child, groups, validity) = self.list_groups("count_distinct")?;
+        flat_child
+            .count_distinct(Some(&groups))?
+            .rename(self.name())
+            .with_validity(validity)
+    }
 }
@@ -0,0 +1,61 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::DataType,
+    series::{IntoSeries, Series},
+};
+
+use super::broadcast_operands;
+
+/// Casts a numeric series to its floating point representation for math functions.
+fn to_float_series(series: &Series, fn_name: &str) -> DaftResult<Series> {
+    let float_type = series
+        .data_type()
+        .to_floating_representation()
+        .map_err(|_| {
+            DaftError::TypeError(format!(
+                "{fn_name} not implemented for {}",
+                series.data_type()
+            ))
+        })?;
+    series.cast(&float_type)
+}
+
+macro_rules! impl_unary_math_op {
+    ($($fname:ident),*) => {
+        $(
+            pub fn $fname(&self) -> DaftResult<Series> {
+                let input = to_float_series(self, stringify!($fname))?;
+                match input.data_type() {
+                    DataType::Float32 => Ok(input.f32().unwrap().$fname()?.into_series()),
+                    DataType::Float64 => Ok(input.f64().unwrap().$fname()?.into_series()),
+                    _ => unreachable!(),
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_binary_math_op {
+    ($($fname:ident),*) => {
+        $(
+            pub fn $fname(&self, rhs: &Series) -> DaftResult<Series> {
+                let lhs = to_float_series(self, stringify!($fname))?;
+                let rhs = to_float_series(rhs, stringify!($fname))?;
+                let (lhs, rhs) = broadcast_operands(&lhs, &rhs)?;
+                if lhs.data_type() == &DataType::Float32 && rhs.data_type() == &DataType::Float32 {
+                    Ok(lhs.f32().unwrap().$fname(rhs.f32().unwrap())?.into_series())
+                } else {
+                    let lhs = lhs.cast(&DataType::Float64)?;
+                    let rhs = rhs.cast(&DataType::Float64)?;
+                    Ok(lhs.f64().unwrap().$fname(rhs.f64().unwrap())?.into_series())
+                }
+            }
+        )*
+    };
+}
+
+impl Series {
+    impl_unary_math_op!(sqrt, exp, ln, sin, cos, tan);
+    impl_binary_math_op!(pow, log, atan2);
+}
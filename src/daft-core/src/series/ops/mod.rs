@@ -1,18 +1,23 @@
-use crate::utils::supertype::try_get_supertype;
-use common_error::DaftResult;
+use crate::datatypes::supertype::try_get_supertype;
+use common_error::{DaftError, DaftResult};
 
 use super::Series;
 
 pub mod abs;
 pub mod agg;
 pub mod arithmetic;
+pub mod between;
+pub mod bitwise;
 pub mod broadcast;
 pub mod cast;
 pub mod ceil;
+pub mod clip;
 pub mod comparison;
 pub mod concat;
+pub mod cumulative;
 pub mod date;
 pub mod downcast;
+pub mod embedding;
 pub mod filter;
 pub mod float;
 pub mod floor;
@@ -21,15 +26,23 @@ pub mod hash;
 pub mod if_else;
 pub mod image;
 pub mod is_in;
+pub mod json;
 pub mod len;
 pub mod list;
+pub mod map;
+pub mod math;
 pub mod not;
 pub mod null;
 pub mod partitioning;
+pub mod random;
+pub mod round;
 pub mod search_sorted;
+pub mod shift;
+pub mod sign;
 pub mod sort;
 pub mod struct_;
 pub mod take;
+pub mod unique;
 pub mod utf8;
 
 fn match_types_on_series(l: &Series, r: &Series) -> DaftResult<(Series, Series)> {
@@ -49,6 +62,18 @@ fn match_types_on_series(l: &Series, r: &Series) -> DaftResult<(Series, Series)>
     Ok((lhs, rhs))
 }
 
+/// Broadcasts a length-1 operand to the length of the other operand.
+fn broadcast_operands(lhs: &Series, rhs: &Series) -> DaftResult<(Series, Series)> {
+    match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => Ok((lhs.clone(), rhs.clone())),
+        (a, 1) => Ok((lhs.clone(), rhs.broadcast(a)?)),
+        (1, b) => Ok((lhs.broadcast(b)?, rhs.clone())),
+        (a, b) => Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+        ))),
+    }
+}
+
 #[cfg(feature = "python")]
 macro_rules! py_binary_op_utilfn {
     ($lhs:expr, $rhs:expr, $pyoperator:expr, $utilfn:expr) => {{
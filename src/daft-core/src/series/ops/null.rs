@@ -1,4 +1,8 @@
-use crate::series::Series;
+use crate::{
+    datatypes::UInt64Array,
+    series::{IntoSeries, Series},
+    DataType, FillNullStrategy,
+};
 
 use common_error::DaftResult;
 
@@ -10,4 +14,50 @@ impl Series {
     pub fn not_null(&self) -> DaftResult<Series> {
         self.inner.not_null()
     }
+
+    /// The number of nulls, read from the validity bitmap without scanning the values.
+    pub fn null_count(&self) -> usize {
+        match self.data_type() {
+            DataType::Null => self.len(),
+            _ => self.validity().map_or(0, |validity| validity.unset_bits()),
+        }
+    }
+
+    /// Replaces null values with the corresponding values of `fill_value`, which may be of length 1.
+    /// The result is cast to the supertype of both series.
+    pub fn fill_null(&self, fill_value: &Series) -> DaftResult<Series> {
+        let predicate = self.not_null()?;
+        self.if_else(fill_value, &predicate)
+    }
+
+    /// Replaces null values according to `strategy`. Nulls that have no value to be filled with,
+    /// e.g. leading nulls when filling forward, are kept as nulls.
+    pub fn fill_null_with_strategy(&self, strategy: FillNullStrategy) -> DaftResult<Series> {
+        match strategy {
+            FillNullStrategy::Forward | FillNullStrategy::Backward => {
+                let Some(validity) = self.validity().filter(|_| self.null_count() > 0) else {
+                    return Ok(self.clone());
+                };
+                // For each element, the index of the non-null element it's filled with, if any.
+                let mut fill_indices = vec![None; self.len()];
+                let mut last_valid = None;
+                let mut fill = |i: usize| {
+                    if validity.get_bit(i) {
+                        last_valid = Some(i as u64);
+                    }
+                    fill_indices[i] = last_valid;
+                };
+                if strategy == FillNullStrategy::Forward {
+                    (0..self.len()).for_each(&mut fill);
+                } else {
+                    (0..self.len()).rev().for_each(&mut fill);
+                }
+                let fill_indices = UInt64Array::from_iter(self.name(), fill_indices.into_iter());
+                self.take(&fill_indices.into_series())
+            }
+            FillNullStrategy::Min => self.fill_null(&self.min(None)?),
+            FillNullStrategy::Max => self.fill_null(&self.max(None)?),
+            FillNullStrategy::Mean => self.fill_null(&self.mean(None)?),
+        }
+    }
 }
@@ -0,0 +1,123 @@
+use common_error::DaftResult;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::array::ops::as_arrow::AsArrow;
+use crate::datatypes::{DataType, Float64Array, Utf8Array};
+use crate::series::{IntoSeries, Series};
+
+fn rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(rand::thread_rng()).unwrap(),
+    }
+}
+
+impl Series {
+    /// Generates `len` values sampled uniformly from [0, 1). With a seed, the same values are
+    /// generated every time.
+    pub fn rand(name: &str, len: usize, seed: Option<u64>) -> DaftResult<Series> {
+        let mut rng = rng(seed);
+        let values = (0..len).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+        Ok(Float64Array::from((name, values)).into_series())
+    }
+
+    /// Generates a value sampled uniformly from [0, 1) for each of the given row ids, by mixing
+    /// the seed with the id. Unlike [`Series::rand`], rows of different partitions get different
+    /// values as long as their ids differ, e.g. the ids of `monotonically_increasing_id()`.
+    pub fn rand_with_ids(name: &str, ids: &Series, seed: u64) -> DaftResult<Series> {
+        let ids = ids.cast(&DataType::UInt64)?;
+        let values = ids
+            .u64()?
+            .as_arrow()
+            .values_iter()
+            .map(|id| {
+                // SplitMix64, keeping the upper 53 bits as the mantissa of a float in [0, 1).
+                let mut z = seed.wrapping_add(id.wrapping_add(1).wrapping_mul(0x9E3779B97F4A7C15));
+                z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+                z ^= z >> 31;
+                (z >> 11) as f64 / (1u64 << 53) as f64
+            })
+            .collect::<Vec<_>>();
+        Ok(Float64Array::from((name, values)).into_series())
+    }
+
+    /// Generates `len` random (version 4) UUIDs, formatted as hyphenated lowercase hex strings.
+    pub fn uuid4(name: &str, len: usize) -> DaftResult<Series> {
+        let mut rng = rng(None);
+        let arrow_result = (0..len)
+            .map(|_| {
+                let mut bytes = rng.gen::<[u8; 16]>();
+                // Set the version (4) and the variant (RFC 4122) bits.
+                bytes[6] = (bytes[6] & 0x0f) | 0x40;
+                bytes[8] = (bytes[8] & 0x3f) | 0x80;
+                let hex = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+                Some(format!(
+                    "{}-{}-{}-{}-{}",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                ))
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((name, Box::new(arrow_result))).into_series())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, UInt64Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn rand_is_seeded() -> DaftResult<()> {
+        let a = Series::rand("a", 100, Some(42))?;
+        assert_eq!(a.data_type(), &DataType::Float64);
+        assert_eq!(a.len(), 100);
+        let values = a.f64()?.as_arrow().values().to_vec();
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        assert_eq!(
+            Series::rand("b", 100, Some(42))?
+                .f64()?
+                .as_arrow()
+                .values()
+                .to_vec(),
+            values
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rand_with_ids_depends_on_ids() -> DaftResult<()> {
+        let ids = UInt64Array::from(("ids", vec![0, 1, 1 << 36, (1 << 36) + 1])).into_series();
+        let a = Series::rand_with_ids("a", &ids, 42)?;
+        let values = a.f64()?.as_arrow().values().to_vec();
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        // Partitions with the same row offsets get different values.
+        assert_ne!(values[0], values[2]);
+        assert_ne!(values[1], values[3]);
+        assert_eq!(Series::rand_with_ids("b", &ids, 42)?, a.rename("b"));
+        assert_ne!(Series::rand_with_ids("a", &ids, 7)?, a);
+        Ok(())
+    }
+
+    #[test]
+    fn uuid4_format() -> DaftResult<()> {
+        let s = Series::uuid4("id", 10)?;
+        let values = s.utf8()?.as_arrow().values_iter().collect::<Vec<_>>();
+        for uuid in values.iter() {
+            assert_eq!(uuid.len(), 36);
+            assert_eq!(&uuid[14..15], "4");
+            assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"));
+        }
+        assert_ne!(values[0], values[1]);
+        Ok(())
+    }
+}
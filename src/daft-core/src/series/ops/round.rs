@@ -0,0 +1,28 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use crate::RoundMode;
+use common_error::DaftError;
+use common_error::DaftResult;
+impl Series {
+    pub fn round(&self, decimals: i32, mode: RoundMode) -> DaftResult<Series> {
+        use crate::series::array_impl::IntoSeries;
+
+        use DataType::*;
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 if decimals >= 0 => {
+                Ok(self.clone())
+            }
+            Float32 => Ok(self.f32().unwrap().round(decimals, mode)?.into_series()),
+            Float64 => Ok(self.f64().unwrap().round(decimals, mode)?.into_series()),
+            Decimal128(..) => Ok(self
+                .decimal128()
+                .unwrap()
+                .round(decimals, mode)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "round with {} decimals not implemented for {}",
+                decimals, dt
+            ))),
+        }
+    }
+}
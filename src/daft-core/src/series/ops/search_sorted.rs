@@ -1,20 +1,81 @@
 use crate::{
     datatypes::UInt64Array,
+    kernels::search_sorted::SearchSortedSide,
     series::{ops::match_types_on_series, Series},
-    with_match_comparable_daft_types,
+    with_match_comparable_daft_types, DataType,
 };
 use common_error::DaftResult;
 
 impl Series {
-    pub fn search_sorted(&self, keys: &Self, descending: bool) -> DaftResult<UInt64Array> {
+    /// For each key, the index at which it would be inserted into this Series to keep it sorted,
+    /// where the Series is sorted in descending order if `descending` and with nulls as the largest
+    /// values. `side` decides whether keys are inserted before or after values equal to them.
+    pub fn search_sorted(
+        &self,
+        keys: &Self,
+        descending: bool,
+        side: SearchSortedSide,
+    ) -> DaftResult<UInt64Array> {
         let (lhs, rhs) = match_types_on_series(self, keys)?;
+        // Codes aren't ordered like their strings, so search the strings instead.
+        let (lhs, rhs) = if lhs.data_type() == &DataType::Categorical {
+            (lhs.cast(&DataType::Utf8)?, rhs.cast(&DataType::Utf8)?)
+        } else {
+            (lhs, rhs)
+        };
         let lhs = lhs.as_physical()?;
         let rhs = rhs.as_physical()?;
 
         with_match_comparable_daft_types!(lhs.data_type(), |$T| {
             let lhs = lhs.downcast::<<$T as DaftDataType>::ArrayType>().unwrap();
             let rhs = rhs.downcast::<<$T as DaftDataType>::ArrayType>().unwrap();
-            lhs.search_sorted(rhs, descending)
+            lhs.search_sorted(rhs, descending, side)
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Int64Array, Utf8Array},
+        kernels::search_sorted::SearchSortedSide,
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn search_sorted_sides() -> DaftResult<()> {
+        let sorted = Int64Array::from_iter(
+            "s",
+            vec![Some(1), Some(2), Some(2), Some(4), None].into_iter(),
+        )
+        .into_series();
+        let keys = Int64Array::from_iter("k", vec![Some(2), Some(0), None, Some(3)].into_iter())
+            .into_series();
+
+        let left = sorted.search_sorted(&keys, false, SearchSortedSide::Left)?;
+        assert_eq!(left.as_arrow().values().as_slice(), &[1, 0, 4, 3]);
+        let right = sorted.search_sorted(&keys, false, SearchSortedSide::Right)?;
+        assert_eq!(right.as_arrow().values().as_slice(), &[3, 0, 5, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn search_sorted_descending() -> DaftResult<()> {
+        let sorted = Utf8Array::from_iter(
+            "s",
+            vec![None, Some("c"), Some("b"), Some("b"), Some("a")].into_iter(),
+        )
+        .into_series();
+        let keys =
+            Utf8Array::from_iter("k", vec![Some("b"), None, Some("z")].into_iter()).into_series();
+
+        let left = sorted.search_sorted(&keys, true, SearchSortedSide::Left)?;
+        assert_eq!(left.as_arrow().values().as_slice(), &[2, 0, 1]);
+        let right = sorted.search_sorted(&keys, true, SearchSortedSide::Right)?;
+        assert_eq!(right.as_arrow().values().as_slice(), &[4, 1, 1]);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    datatypes::BooleanArray,
+    series::{IntoSeries, Series},
+};
+use common_error::{DaftError, DaftResult};
+
+impl Series {
+    /// Shifts the values by `offset` rows, towards the end for a positive offset (i.e. every row
+    /// gets the value `offset` rows before it) and towards the start for a negative offset. Rows
+    /// without a value at that offset get the corresponding value of `default`, which is either a
+    /// single value or one value per row. A null `default` fills them with nulls.
+    pub fn shift(&self, offset: i64, default: &Series) -> DaftResult<Series> {
+        let len = self.len();
+        let default = match default.len() {
+            1 => default.cast(self.data_type())?.broadcast(len)?,
+            l if l == len => default.cast(self.data_type())?,
+            l => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected default of shift to have length 1 or {len}, but received length {l}"
+                )))
+            }
+        };
+        let n = (offset.unsigned_abs() as usize).min(len);
+        let nulls = Series::full_null(self.name(), self.data_type(), n);
+        let shifted = if offset >= 0 {
+            Series::concat(&[&nulls, &self.slice(0, len - n)?])?
+        } else {
+            Series::concat(&[&self.slice(n, len)?, &nulls])?
+        };
+        let from_default = (0..len)
+            .map(|i| if offset >= 0 { i < n } else { i >= len - n })
+            .collect::<Vec<_>>();
+        let from_default = BooleanArray::from(("", from_default.as_slice())).into_series();
+        Ok(default
+            .if_else(&shifted, &from_default)?
+            .rename(self.name()))
+    }
+}
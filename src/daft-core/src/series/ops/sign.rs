@@ -0,0 +1,33 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use crate::with_match_numeric_daft_types;
+use common_error::DaftError;
+use common_error::DaftResult;
+impl Series {
+    pub fn sign(&self) -> DaftResult<Series> {
+        use crate::series::array_impl::IntoSeries;
+
+        let dtype = self.data_type();
+        if !matches!(
+            dtype,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64
+        ) {
+            return Err(DaftError::TypeError(format!(
+                "sign not implemented for {dtype}"
+            )));
+        }
+        with_match_numeric_daft_types!(dtype, |$T| {
+            let array = self.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            Ok(array.sign()?.into_series())
+        })
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{series::Series, with_match_comparable_daft_types};
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{ExtensionArray, UInt64Array, UInt64Type},
+    kernels::search_sorted::build_compare_with_null_placement,
+    series::Series,
+    with_match_comparable_daft_types, DataType,
+};
 use common_error::DaftError;
 
 use common_error::DaftResult;
@@ -6,7 +12,31 @@ use common_error::DaftResult;
 use crate::series::array_impl::IntoSeries;
 
 impl Series {
-    pub fn argsort(&self, descending: bool) -> DaftResult<Series> {
+    /// Returns the indices that sort the Series, with nulls placed first if `nulls_first` and last
+    /// otherwise. The sort is stable, i.e. equal values keep their relative order.
+    pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<Series> {
+        let indices = self.argsort_with_nulls_largest(descending)?;
+        place_nulls(indices.u64()?, self, nulls_first)
+    }
+
+    /// Returns the indices that sort the Series, where the kernels of each type place nulls like
+    /// the largest values.
+    fn argsort_with_nulls_largest(&self, descending: bool) -> DaftResult<Series> {
+        if self.data_type() == &DataType::Categorical {
+            return Ok(self
+                .categorical()?
+                .argsort::<UInt64Type>(descending)?
+                .into_series());
+        }
+        if self.data_type().is_extension() {
+            return Ok(self
+                .downcast::<ExtensionArray>()?
+                .argsort(descending)?
+                .into_series());
+        }
+        if self.data_type().to_physical().is_struct() {
+            return Series::argsort_multikey(&[self.clone()], &[descending], &[descending]);
+        }
         let series = self.as_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -14,23 +44,50 @@ impl Series {
         })
     }
 
-    pub fn argsort_multikey(sort_keys: &[Series], descending: &[bool]) -> DaftResult<Series> {
-        if sort_keys.len() != descending.len() {
+    /// Returns the indices that stably sort by `sort_keys` lexicographically, where each key has
+    /// its own `descending` and `nulls_first` options.
+    ///
+    /// Struct keys are sorted lexicographically by their fields, and keys of logical types by their
+    /// physical representation.
+    pub fn argsort_multikey(
+        sort_keys: &[Series],
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> DaftResult<Series> {
+        if sort_keys.len() != descending.len() || sort_keys.len() != nulls_first.len() {
             return Err(DaftError::ValueError(format!(
-                "sort_keys and descending length must match, got {} vs {}",
+                "sort_keys, descending and nulls_first length must match, got {} vs {} vs {}",
                 sort_keys.len(),
-                descending.len()
+                descending.len(),
+                nulls_first.len()
             )));
         }
+        let (sort_keys, descending, nulls_first) =
+            normalize_sort_keys(sort_keys, descending, nulls_first)?;
+        let (sort_keys, descending, nulls_first) = (
+            sort_keys.as_slice(),
+            descending.as_slice(),
+            nulls_first.as_slice(),
+        );
 
         if sort_keys.len() == 1 {
             return sort_keys
                 .first()
                 .unwrap()
-                .argsort(*descending.first().unwrap());
+                .argsort(*descending.first().unwrap(), *nulls_first.first().unwrap());
+        }
+        if let Some(key) = sort_keys.iter().find(|key| key.data_type().is_extension()) {
+            return Err(DaftError::ValueError(format!(
+                "Cannot sort by multiple keys when one is of extension type {}",
+                key.data_type()
+            )));
         }
 
-        let first = sort_keys.first().unwrap().as_physical()?;
+        if descending != nulls_first {
+            return argsort_multikey_with_null_placement(sort_keys, descending, nulls_first);
+        }
+
+        let first = sort_keys.first().unwrap();
         with_match_comparable_daft_types!(first.data_type(), |$T| {
             let downcasted = first.downcast::<<$T as DaftDataType>::ArrayType>()?;
             let result = downcasted.argsort_multikey::<UInt64Type>(&sort_keys[1..], descending)?;
@@ -38,7 +95,128 @@ impl Series {
         })
     }
 
-    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
-        self.inner.sort(descending)
+    /// Sorts the Series, with nulls placed first if `nulls_first` and last otherwise.
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        if nulls_first == descending {
+            self.inner.sort(descending)
+        } else {
+            self.take_unchecked(&self.argsort(descending, nulls_first)?)
+        }
+    }
+}
+
+/// Moves the indices of the nulls of `series` in `indices` to the front if `nulls_first` and to the
+/// back otherwise, with the nulls in their original order.
+fn place_nulls(indices: &UInt64Array, series: &Series, nulls_first: bool) -> DaftResult<Series> {
+    let validity = match series.validity() {
+        Some(validity) if validity.unset_bits() > 0 => validity,
+        _ => return Ok(indices.clone().into_series()),
+    };
+    let valid = indices
+        .as_arrow()
+        .values_iter()
+        .copied()
+        .filter(|i| validity.get_bit(*i as usize));
+    let nulls = (0..series.len() as u64).filter(|i| !validity.get_bit(*i as usize));
+    let placed = if nulls_first {
+        nulls.chain(valid).collect::<Vec<_>>()
+    } else {
+        valid.chain(nulls).collect::<Vec<_>>()
+    };
+    Ok(UInt64Array::from((indices.name(), placed)).into_series())
+}
+
+/// Rewrites sort keys into keys that the comparison kernels can sort directly, with the same order:
+/// categoricals become the ranks of their strings, keys of logical types become their physical
+/// representation, and structs are replaced by whether they are null followed by their fields.
+fn normalize_sort_keys(
+    sort_keys: &[Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<(Vec<Series>, Vec<bool>, Vec<bool>)> {
+    let mut normalized = (vec![], vec![], vec![]);
+    for ((key, desc), nf) in sort_keys.iter().zip(descending).zip(nulls_first) {
+        push_sort_key(key, *desc, *nf, &mut normalized)?;
+    }
+    Ok(normalized)
+}
+
+fn push_sort_key(
+    key: &Series,
+    descending: bool,
+    nulls_first: bool,
+    normalized: &mut (Vec<Series>, Vec<bool>, Vec<bool>),
+) -> DaftResult<()> {
+    let key = match key.data_type() {
+        DataType::Categorical => key.categorical()?.ranks()?.into_series(),
+        DataType::Extension(..) => key.clone(),
+        _ => key.as_physical()?,
+    };
+    if key.data_type().is_struct() {
+        // Null structs sort before all other structs exactly when the nulls come first.
+        let is_null = key.is_null()?;
+        normalized.0.push(is_null);
+        normalized.1.push(nulls_first);
+        normalized.2.push(nulls_first);
+        for child in key.struct_()?.children.iter() {
+            push_sort_key(child, descending, nulls_first, normalized)?;
+        }
+    } else {
+        normalized.0.push(key);
+        normalized.1.push(descending);
+        normalized.2.push(nulls_first);
+    }
+    Ok(())
+}
+
+fn argsort_multikey_with_null_placement(
+    sort_keys: &[Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<Series> {
+    let arrays = sort_keys.iter().map(|s| s.to_arrow()).collect::<Vec<_>>();
+    let comparators = arrays
+        .iter()
+        .zip(descending.iter().zip(nulls_first.iter()))
+        .map(|(a, (desc, nf))| {
+            build_compare_with_null_placement(a.as_ref(), a.as_ref(), *desc, *nf)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut indices = (0..sort_keys[0].len() as u64).collect::<Vec<_>>();
+    indices.sort_by(|a, b| {
+        comparators
+            .iter()
+            .map(|cmp| cmp(*a as usize, *b as usize))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(UInt64Array::from((sort_keys[0].name(), indices)).into_series())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::ops::as_arrow::AsArrow;
+    use crate::datatypes::{Int64Array, Utf8Array};
+    use crate::series::{array_impl::IntoSeries, Series};
+    use common_error::DaftResult;
+
+    #[test]
+    fn argsort_multikey_null_placement() -> DaftResult<()> {
+        let a = Int64Array::from_iter("a", vec![Some(1), None, Some(1), None, Some(0)].into_iter())
+            .into_series();
+        let b = Utf8Array::from_iter(
+            "b",
+            vec![Some("x"), Some("y"), None, Some("x"), Some("z")].into_iter(),
+        )
+        .into_series();
+
+        // Ascending with nulls first in `a`, then descending with nulls last in `b`.
+        let indices = Series::argsort_multikey(&[a, b], &[false, true], &[true, false])?;
+        assert_eq!(
+            indices.u64()?.as_arrow().values().as_slice(),
+            &[1, 3, 4, 0, 2]
+        );
+        Ok(())
     }
 }
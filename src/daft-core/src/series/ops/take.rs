@@ -1,7 +1,11 @@
-use crate::{datatypes::Utf8Array, series::Series, IntoSeries};
+use crate::{
+    array::ops::as_arrow::AsArrow, datatypes::Utf8Array, series::Series,
+    with_match_integer_daft_types, IntoSeries,
+};
 
 use arrow2::types::IndexRange;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
+use num_traits::ToPrimitive;
 
 impl Series {
     pub fn head(&self, num: usize) -> DaftResult<Series> {
@@ -16,7 +20,39 @@ impl Series {
         self.inner.slice(start.min(l), end.min(l))
     }
 
+    /// Gathers the elements at the indices in `idx`, which must be an integer Series. Null indices
+    /// produce nulls, and indices that are out of bounds are an error.
     pub fn take(&self, idx: &Series) -> DaftResult<Series> {
+        if !idx.data_type().is_integer() {
+            return Err(DaftError::TypeError(format!(
+                "Expected integer indices to take from Series, but received {}",
+                idx.data_type()
+            )));
+        }
+        let out_of_bounds = with_match_integer_daft_types!(idx.data_type(), |$T| {
+            idx.downcast::<<$T as DaftDataType>::ArrayType>()?
+                .as_arrow()
+                .iter()
+                .flatten()
+                .find(|i| i.to_usize().map_or(true, |i| i >= self.len()))
+                .map(|i| i.to_string())
+        });
+        if let Some(i) = out_of_bounds {
+            return Err(DaftError::ValueError(format!(
+                "Take index out of bounds: the length of the Series is {} but the index is {i}",
+                self.len()
+            )));
+        }
+        self.inner.take(idx)
+    }
+
+    /// Like [`Series::take`], but skips checking that the indices are in bounds, for indices that
+    /// are in bounds by construction such as the results of sorts and join probes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    pub fn take_unchecked(&self, idx: &Series) -> DaftResult<Series> {
         self.inner.take(idx)
     }
 
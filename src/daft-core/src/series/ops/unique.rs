@@ -0,0 +1,113 @@
+use common_error::DaftResult;
+use fnv::FnvHashMap;
+
+use crate::{
+    array::ops::{arrow2::comparison::build_multi_array_is_equal, as_arrow::AsArrow, GroupIndices},
+    datatypes::{ExtensionArray, UInt64Array},
+    series::{IntoSeries, Series},
+    DataType,
+};
+
+impl Series {
+    /// Indices of the distinct values of the Series in order of first occurrence, using the first
+    /// index of each value. All nulls are counted as a single value.
+    pub fn unique_indices(&self) -> DaftResult<Vec<u64>> {
+        let full_group = vec![(0..self.len() as u64).collect()];
+        Ok(self.grouped_unique_indices(&full_group)?.swap_remove(0))
+    }
+
+    /// Indices of the distinct values within each group, in order of first occurrence within the
+    /// group. All rows are hashed and compared in a single pass, keyed by their group.
+    pub fn grouped_unique_indices(&self, groups: &GroupIndices) -> DaftResult<GroupIndices> {
+        if self.data_type().is_null() {
+            return Ok(groups
+                .iter()
+                .map(|group| group.first().copied().into_iter().collect())
+                .collect());
+        }
+        let hashes = self.hash(None)?;
+        let hashes = hashes.as_arrow();
+        // Values are compared like they're hashed: categoricals by their strings, since equal strings
+        // may have different codes, and extension types by their storage.
+        let values = match self.data_type() {
+            DataType::Categorical => self.categorical()?.decode()?.into_series(),
+            DataType::Extension(..) => self.downcast::<ExtensionArray>()?.storage()?,
+            _ => self.as_physical()?,
+        };
+        let is_equal = build_multi_array_is_equal(&[values.clone()], &[values], true, true)?;
+
+        // Indices of the distinct values seen so far, by group and hash.
+        let mut seen = FnvHashMap::<(usize, u64), Vec<u64>>::default();
+        let mut unique = vec![vec![]; groups.len()];
+        for (group_idx, group) in groups.iter().enumerate() {
+            for &i in group {
+                let candidates = seen
+                    .entry((group_idx, hashes.value(i as usize)))
+                    .or_default();
+                if !candidates.iter().any(|j| is_equal(i as usize, *j as usize)) {
+                    candidates.push(i);
+                    unique[group_idx].push(i);
+                }
+            }
+        }
+        Ok(unique)
+    }
+
+    /// The distinct values of the Series in order of first occurrence, with a single null if it has
+    /// any nulls.
+    pub fn unique(&self) -> DaftResult<Series> {
+        let indices = UInt64Array::from((self.name(), self.unique_indices()?)).into_series();
+        self.take(&indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{Float64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn unique_in_order_of_first_occurrence() -> DaftResult<()> {
+        let s = Utf8Array::from_iter(
+            "s",
+            vec![Some("b"), None, Some("a"), Some("b"), None, Some("c")].into_iter(),
+        )
+        .into_series();
+        assert_eq!(s.unique_indices()?, vec![0, 1, 2, 5]);
+
+        let unique = s.unique()?;
+        assert_eq!(unique.name(), "s");
+        let unique = unique.utf8()?;
+        assert_eq!(
+            (0..4).map(|i| unique.get(i)).collect::<Vec<_>>(),
+            vec![Some("b"), None, Some("a"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unique_floats_with_nan() -> DaftResult<()> {
+        let s = Float64Array::from(("f", vec![1.0, f64::NAN, 1.0, f64::NAN, 0.0])).into_series();
+        assert_eq!(s.unique_indices()?, vec![0, 1, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_unique_indices() -> DaftResult<()> {
+        let s = Utf8Array::from_iter(
+            "s",
+            vec![Some("a"), Some("b"), Some("a"), Some("a"), None, Some("b")].into_iter(),
+        )
+        .into_series();
+        let groups = vec![vec![0, 2, 5], vec![1, 3, 4], vec![]];
+        assert_eq!(
+            s.grouped_unique_indices(&groups)?,
+            vec![vec![0, 5], vec![1, 3, 4], vec![]]
+        );
+        Ok(())
+    }
+}
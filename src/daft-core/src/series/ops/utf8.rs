@@ -6,37 +6,74 @@ use crate::series::array_impl::IntoSeries;
 
 impl Series {
     pub fn utf8_endswith(&self, pattern: &Series) -> DaftResult<Series> {
+        self.utf8_substring_predicate(pattern, "Endswith", Utf8Array::endswith)
+    }
+
+    pub fn utf8_startswith(&self, pattern: &Series) -> DaftResult<Series> {
+        self.utf8_substring_predicate(pattern, "Startswith", Utf8Array::startswith)
+    }
+
+    pub fn utf8_contains(&self, pattern: &Series) -> DaftResult<Series> {
+        self.utf8_substring_predicate(pattern, "Contains", Utf8Array::contains)
+    }
+
+    /// Evaluates a predicate of each string and the pattern in the same row, where either side may
+    /// be a single value that's broadcast. Null strings or patterns give nulls, including when
+    /// either side is of the Null type.
+    fn utf8_substring_predicate(
+        &self,
+        pattern: &Series,
+        name: &str,
+        predicate: impl Fn(&Utf8Array, &Utf8Array) -> DaftResult<BooleanArray>,
+    ) -> DaftResult<Series> {
+        match (self.data_type(), pattern.data_type()) {
+            (DataType::Utf8 | DataType::Null, DataType::Utf8 | DataType::Null) => {
+                let data = self.cast(&DataType::Utf8)?;
+                let pattern = pattern.cast(&DataType::Utf8)?;
+                Ok(predicate(data.utf8()?, pattern.utf8()?)?.into_series())
+            }
+            (dt, pattern_dt) => Err(DaftError::TypeError(format!(
+                "{name} not implemented for type {dt} with pattern of type {pattern_dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_split(&self, pattern: &Series, regex: bool) -> DaftResult<Series> {
         match self.data_type() {
-            DataType::Utf8 => Ok(self.utf8()?.endswith(pattern.utf8()?)?.into_series()),
+            DataType::Utf8 if regex => Ok(self.utf8()?.split_regex(pattern.utf8()?)?.into_series()),
+            DataType::Utf8 => Ok(self.utf8()?.split(pattern.utf8()?)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
-                "Endswith not implemented for type {dt}"
+                "Split not implemented for type {dt}"
             ))),
         }
     }
 
-    pub fn utf8_startswith(&self, pattern: &Series) -> DaftResult<Series> {
+    pub fn utf8_match(&self, pattern: &Series) -> DaftResult<Series> {
         match self.data_type() {
-            DataType::Utf8 => Ok(self.utf8()?.startswith(pattern.utf8()?)?.into_series()),
+            DataType::Utf8 => Ok(self.utf8()?.match_(pattern.utf8()?)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
-                "Startswith not implemented for type {dt}"
+                "Match not implemented for type {dt}"
             ))),
         }
     }
 
-    pub fn utf8_contains(&self, pattern: &Series) -> DaftResult<Series> {
+    pub fn utf8_extract(&self, pattern: &Series, index: usize) -> DaftResult<Series> {
         match self.data_type() {
-            DataType::Utf8 => Ok(self.utf8()?.contains(pattern.utf8()?)?.into_series()),
+            DataType::Utf8 => Ok(self.utf8()?.extract(pattern.utf8()?, index)?.into_series()),
             dt => Err(DaftError::TypeError(format!(
-                "Contains not implemented for type {dt}"
+                "Extract not implemented for type {dt}"
             ))),
         }
     }
 
-    pub fn utf8_split(&self, pattern: &Series) -> DaftResult<Series> {
+    pub fn utf8_replace(&self, pattern: &Series, replacement: &Series) -> DaftResult<Series> {
         match self.data_type() {
-            DataType::Utf8 => Ok(self.utf8()?.split(pattern.utf8()?)?.into_series()),
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .replace(pattern.utf8()?, replacement.utf8()?)?
+                .into_series()),
             dt => Err(DaftError::TypeError(format!(
-                "Split not implemented for type {dt}"
+                "Replace not implemented for type {dt}"
             ))),
         }
     }
@@ -51,6 +88,16 @@ impl Series {
         }
     }
 
+    pub fn utf8_length_chars(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.length_chars()?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Length in characters not implemented for type {dt}"
+            ))),
+        }
+    }
+
     pub fn utf8_lower(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::Utf8 => Ok(self.utf8()?.lower()?.into_series()),
@@ -71,6 +118,16 @@ impl Series {
         }
     }
 
+    pub fn utf8_capitalize(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.capitalize()?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Capitalize not implemented for type {dt}"
+            ))),
+        }
+    }
+
     pub fn utf8_lstrip(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::Utf8 => Ok(self.utf8()?.lstrip()?.into_series()),
@@ -91,6 +148,16 @@ impl Series {
         }
     }
 
+    pub fn utf8_substr(&self, start: i64, length: Option<usize>) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.substr(start, length)?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Substr not implemented for type {dt}"
+            ))),
+        }
+    }
+
     pub fn utf8_reverse(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::Utf8 => Ok(self.utf8()?.reverse()?.into_series()),
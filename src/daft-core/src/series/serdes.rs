@@ -6,11 +6,12 @@ use serde::{de::Visitor, Deserializer};
 use crate::{
     array::{
         ops::{as_arrow::AsArrow, full::FullNull},
-        ListArray, StructArray,
+        CategoricalArray, ListArray, StructArray,
     },
     datatypes::logical::{
         DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-        FixedShapeTensorArray, ImageArray, TensorArray, TimeArray, TimestampArray,
+        FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+        TimestampArray,
     },
     with_match_daft_types, DataType, IntoSeries, Series,
 };
@@ -188,6 +189,21 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .unwrap();
                         Ok(ListArray::new(field, flat_child, offsets, validity).into_series())
                     }
+                    Categorical => {
+                        let mut all_series = map.next_value::<Vec<Series>>()?;
+                        let dictionary = all_series
+                            .pop()
+                            .ok_or_else(|| serde::de::Error::missing_field("dictionary"))?;
+                        let codes = all_series
+                            .pop()
+                            .ok_or_else(|| serde::de::Error::missing_field("codes"))?;
+                        Ok(CategoricalArray::new(
+                            field,
+                            codes.u32().unwrap().clone(),
+                            dictionary.utf8().unwrap().clone(),
+                        )
+                        .into_series())
+                    }
                     FixedSizeList(..) => {
                         let mut all_series = map.next_value::<Vec<Option<Series>>>()?;
                         let validity = all_series
@@ -246,6 +262,26 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .into_series(),
                         )
                     }
+                    Interval => {
+                        type PType = <<IntervalType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
+                        let physical = map.next_value::<Series>()?;
+                        Ok(
+                            IntervalArray::new(
+                                field,
+                                physical.downcast::<PType>().unwrap().clone(),
+                            )
+                            .into_series(),
+                        )
+                    }
+                    Map(..) => {
+                        type PType =
+                            <<MapType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
+                        let physical = map.next_value::<Series>()?;
+                        Ok(
+                            MapArray::new(field, physical.downcast::<PType>().unwrap().clone())
+                                .into_series(),
+                        )
+                    }
                     Embedding(..) => {
                         type PType = <<EmbeddingType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
                         let physical = map.next_value::<Series>()?;
@@ -289,8 +325,23 @@ impl<'d> serde::Deserialize<'d> for Series {
                                 .into_series(),
                         )
                     }
+                    #[cfg(feature = "python")]
+                    Python => {
+                        use crate::array::{
+                            pseudo_arrow::PseudoArrowArray, serdes::PickledPyObjects,
+                        };
+
+                        let objects = map.next_value::<PickledPyObjects>()?.0;
+                        Ok(PythonArray::new(
+                            Arc::new(field),
+                            Box::new(PseudoArrowArray::from_pyobj_vec(objects)),
+                        )
+                        .map_err(serde::de::Error::custom)?
+                        .into_series())
+                    }
+                    #[cfg(not(feature = "python"))]
                     Python => {
-                        panic!("python deserialization not implemented for rust Serde");
+                        panic!("python deserialization requires the python feature");
                     }
                     Unknown => {
                         panic!("Unable to deserialize Unknown DataType");
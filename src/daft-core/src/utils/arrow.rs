@@ -1,12 +1,23 @@
 use lazy_static::lazy_static;
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use arrow2::compute::cast;
 
+use crate::datatypes::ExtensionTypeImpl;
+
+/// Everything known about an extension type, keyed by its name in [`REGISTRY`].
+#[derive(Default)]
+pub(crate) struct ExtensionRegistryEntry {
+    /// The Arrow type of the extension as it was read, before coercion to a Daft-compatible type.
+    pub arrow_dtype: Option<arrow2::datatypes::DataType>,
+    /// The kernels registered for the extension with `register_extension_type`.
+    pub kernels: Option<Arc<dyn ExtensionTypeImpl>>,
+}
+
 // TODO(Clark): Refactor to GILOnceCell in order to avoid deadlock between the below mutex and the Python GIL.
 lazy_static! {
-    static ref REGISTRY: Mutex<HashMap<std::string::String, arrow2::datatypes::DataType>> =
+    pub(crate) static ref REGISTRY: Mutex<HashMap<std::string::String, ExtensionRegistryEntry>> =
         Mutex::new(HashMap::new());
 }
 
@@ -15,6 +26,10 @@ fn coerce_to_daft_compatible_type(
 ) -> Option<arrow2::datatypes::DataType> {
     match dtype {
         arrow2::datatypes::DataType::Utf8 => Some(arrow2::datatypes::DataType::LargeUtf8),
+        arrow2::datatypes::DataType::Float16 => Some(arrow2::datatypes::DataType::Float32),
+        arrow2::datatypes::DataType::Time32(_) => Some(arrow2::datatypes::DataType::Time64(
+            arrow2::datatypes::TimeUnit::Microsecond,
+        )),
         arrow2::datatypes::DataType::Binary | arrow2::datatypes::DataType::FixedSizeBinary(_) => {
             Some(arrow2::datatypes::DataType::LargeBinary)
         }
@@ -78,9 +93,30 @@ fn coerce_to_daft_compatible_type(
                 Some(arrow2::datatypes::DataType::Struct(new_fields))
             }
         }
+        // String dictionaries become Categoricals, and other dictionaries are decoded.
+        arrow2::datatypes::DataType::Dictionary(key, value, _) => match value.as_ref() {
+            arrow2::datatypes::DataType::LargeUtf8
+                if *key == arrow2::datatypes::IntegerType::UInt32 =>
+            {
+                None
+            }
+            arrow2::datatypes::DataType::Utf8 | arrow2::datatypes::DataType::LargeUtf8 => {
+                Some(arrow2::datatypes::DataType::Dictionary(
+                    arrow2::datatypes::IntegerType::UInt32,
+                    Box::new(arrow2::datatypes::DataType::LargeUtf8),
+                    false,
+                ))
+            }
+            value => Some(coerce_to_daft_compatible_type(value).unwrap_or_else(|| value.clone())),
+        },
         arrow2::datatypes::DataType::Extension(name, inner, metadata) => {
             let new_inner_dtype = coerce_to_daft_compatible_type(inner.as_ref())?;
-            REGISTRY.lock().unwrap().insert(name.clone(), dtype.clone());
+            REGISTRY
+                .lock()
+                .unwrap()
+                .entry(name.clone())
+                .or_default()
+                .arrow_dtype = Some(dtype.clone());
             Some(arrow2::datatypes::DataType::Extension(
                 name.clone(),
                 Box::new(new_inner_dtype),
@@ -94,6 +130,31 @@ fn coerce_to_daft_compatible_type(
 pub fn cast_array_for_daft_if_needed(
     arrow_array: Box<dyn arrow2::array::Array>,
 ) -> Box<dyn arrow2::array::Array> {
+    // Arrow can't cast maps, so coerce their entries instead.
+    if let arrow2::datatypes::DataType::Map(field, sorted) = arrow_array.data_type() {
+        let map = arrow_array
+            .as_any()
+            .downcast_ref::<arrow2::array::MapArray>()
+            .unwrap();
+        let entries = cast_array_for_daft_if_needed(map.field().clone());
+        let map_type = arrow2::datatypes::DataType::Map(
+            Box::new(
+                arrow2::datatypes::Field::new(
+                    field.name.clone(),
+                    entries.data_type().clone(),
+                    field.is_nullable,
+                )
+                .with_metadata(field.metadata.clone()),
+            ),
+            *sorted,
+        );
+        return Box::new(arrow2::array::MapArray::new(
+            map_type,
+            map.offsets().clone(),
+            entries,
+            map.validity().cloned(),
+        ));
+    }
     match coerce_to_daft_compatible_type(arrow_array.data_type()) {
         Some(coerced_dtype) => cast::cast(
             arrow_array.as_ref(),
@@ -112,12 +173,11 @@ fn coerce_from_daft_compatible_type(
     dtype: &arrow2::datatypes::DataType,
 ) -> Option<arrow2::datatypes::DataType> {
     match dtype {
-        arrow2::datatypes::DataType::Extension(name, _, _)
-            if REGISTRY.lock().unwrap().contains_key(name) =>
-        {
-            let entry = REGISTRY.lock().unwrap();
-            Some(entry.get(name).unwrap().clone())
-        }
+        arrow2::datatypes::DataType::Extension(name, _, _) => REGISTRY
+            .lock()
+            .unwrap()
+            .get(name)
+            .and_then(|entry| entry.arrow_dtype.clone()),
         _ => None,
     }
 }
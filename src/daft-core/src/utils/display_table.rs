@@ -1,6 +1,6 @@
 use crate::{
     datatypes::{Field, TimeUnit},
-    Series,
+    DataType, Series,
 };
 
 use itertools::Itertools;
@@ -54,6 +54,34 @@ pub fn display_timestamp(val: i64, unit: &TimeUnit, timezone: &Option<String>) -
     )
 }
 
+pub fn display_decimal128(val: i128, precision: u8, scale: i8) -> String {
+    use crate::array::ops::cast::decimal128_to_str;
+
+    decimal128_to_str(val, precision, scale)
+}
+
+/// Displays an interval as an ISO 8601 duration, e.g. "P1M2DT1.5S".
+pub fn display_interval(months: i32, days: i32, nanoseconds: i64) -> String {
+    let mut out = "P".to_string();
+    if months != 0 {
+        out += &format!("{months}M");
+    }
+    if days != 0 {
+        out += &format!("{days}D");
+    }
+    if nanoseconds != 0 || out == "P" {
+        let sign = if nanoseconds < 0 { "-" } else { "" };
+        let seconds = nanoseconds.unsigned_abs() / 1_000_000_000;
+        let fraction = nanoseconds.unsigned_abs() % 1_000_000_000;
+        out += &format!("T{sign}{seconds}");
+        if fraction != 0 {
+            out += format!(".{fraction:09}").trim_end_matches('0');
+        }
+        out += "S";
+    }
+    out
+}
+
 pub fn display_series_literal(series: &Series) -> String {
     if !series.is_empty() {
         format!(
@@ -67,6 +95,84 @@ pub fn display_series_literal(series: &Series) -> String {
     }
 }
 
+/// Maximum number of children of a nested type that are displayed in its tree.
+const MAX_DISPLAYED_CHILDREN: usize = 10;
+
+/// Maximum number of fields that are displayed in a schema table; the fields in the middle of wider
+/// schemas are elided.
+const MAX_DISPLAYED_FIELDS: usize = 40;
+
+/// The label of a type in its tree, which omits the children of nested types.
+fn dtype_tree_label(dtype: &DataType) -> String {
+    match dtype {
+        DataType::List(_) => "List".to_string(),
+        DataType::FixedSizeList(_, size) => format!("FixedSizeList[{size}]"),
+        DataType::Struct(_) if dtype_tree_children(dtype).is_empty() => "Struct[]".to_string(),
+        DataType::Struct(_) => "Struct".to_string(),
+        DataType::Map(..) => "Map".to_string(),
+        _ => format!("{dtype}"),
+    }
+}
+
+/// The children of a nested type, along with their names for struct fields and map entries.
+fn dtype_tree_children(dtype: &DataType) -> Vec<(Option<&str>, &DataType)> {
+    match dtype {
+        DataType::List(inner) | DataType::FixedSizeList(inner, _) => vec![(None, inner.as_ref())],
+        DataType::Struct(fields) => fields
+            .iter()
+            // Empty structs are stored with a single unnamed null field.
+            .filter(|f| !(f.name.is_empty() && f.dtype == DataType::Null))
+            .map(|f| (Some(f.name.as_str()), &f.dtype))
+            .collect(),
+        DataType::Map(key, value) => {
+            vec![(Some("key"), key.as_ref()), (Some("value"), value.as_ref())]
+        }
+        _ => vec![],
+    }
+}
+
+fn push_dtype_tree_children(dtype: &DataType, prefix: &str, lines: &mut Vec<String>) {
+    let children = dtype_tree_children(dtype);
+    let num_children = children.len();
+    // Elide the children in the middle of very wide types, but always show the last child.
+    let elided = num_children.saturating_sub(MAX_DISPLAYED_CHILDREN);
+    let shown = children
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| elided == 0 || *i < MAX_DISPLAYED_CHILDREN - 1 || *i == num_children - 1);
+    for (i, (name, child)) in shown {
+        let is_last = i == num_children - 1;
+        if is_last && elided > 0 {
+            lines.push(format!("{prefix}├─ … ({elided} hidden)"));
+        }
+        let (connector, child_prefix) = if is_last {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        let label = dtype_tree_label(child);
+        lines.push(match name {
+            Some(name) => format!("{prefix}{connector}{name}: {label}"),
+            None => format!("{prefix}{connector}{label}"),
+        });
+        push_dtype_tree_children(child, &format!("{prefix}{child_prefix}"), lines);
+    }
+}
+
+/// Displays a type as a tree, with the children of nested types on indented lines below it, e.g.
+///
+/// ```text
+/// Struct
+/// ├─ a: Int64
+/// └─ b: List
+///    └─ Utf8
+/// ```
+pub fn display_dtype_tree(dtype: &DataType) -> String {
+    let mut lines = vec![dtype_tree_label(dtype)];
+    push_dtype_tree_children(dtype, "", &mut lines);
+    lines.join("\n")
+}
+
 pub fn make_schema_vertical_table<F: AsRef<Field>>(fields: &[F]) -> comfy_table::Table {
     let mut table = comfy_table::Table::new();
 
@@ -86,12 +192,28 @@ pub fn make_schema_vertical_table<F: AsRef<Field>>(fields: &[F]) -> comfy_table:
     ];
     table.set_header(header);
 
-    for f in fields.iter() {
+    let num_fields = fields.len();
+    let (head_fields, tail_fields) = if num_fields > MAX_DISPLAYED_FIELDS {
+        ((MAX_DISPLAYED_FIELDS + 1) / 2, MAX_DISPLAYED_FIELDS / 2)
+    } else {
+        (num_fields, 0)
+    };
+    for f in fields.iter().take(head_fields) {
         table.add_row(vec![
             f.as_ref().name.to_string(),
-            format!("{}", f.as_ref().dtype),
+            display_dtype_tree(&f.as_ref().dtype),
         ]);
     }
+    if tail_fields > 0 {
+        let unseen_fields = num_fields - (head_fields + tail_fields);
+        table.add_row(vec!["…".to_string(), format!("({unseen_fields} hidden)")]);
+        for f in fields.iter().skip(num_fields - tail_fields) {
+            table.add_row(vec![
+                f.as_ref().name.to_string(),
+                display_dtype_tree(&f.as_ref().dtype),
+            ]);
+        }
+    }
     table
 }
 
@@ -255,3 +377,51 @@ pub fn make_comfy_table<F: AsRef<Field>>(
     }
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dtype_tree() {
+        let dtype = DataType::Struct(vec![
+            Field::new("a", DataType::Int64),
+            Field::new(
+                "b",
+                DataType::Map(
+                    Box::new(DataType::Utf8),
+                    Box::new(DataType::List(Box::new(DataType::Float32))),
+                ),
+            ),
+            Field::new("c", DataType::Struct(vec![Field::new("", DataType::Null)])),
+        ]);
+        assert_eq!(
+            display_dtype_tree(&dtype),
+            [
+                "Struct",
+                "├─ a: Int64",
+                "├─ b: Map",
+                "│  ├─ key: Utf8",
+                "│  └─ value: List",
+                "│     └─ Float32",
+                "└─ c: Struct[]",
+            ]
+            .join("\n")
+        );
+        assert_eq!(display_dtype_tree(&DataType::Int64), "Int64");
+
+        let wide = DataType::Struct(
+            (0..15)
+                .map(|i| Field::new(format!("f{i}"), DataType::Boolean))
+                .collect(),
+        );
+        let lines = display_dtype_tree(&wide)
+            .lines()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), MAX_DISPLAYED_CHILDREN + 2);
+        assert_eq!(lines[MAX_DISPLAYED_CHILDREN - 1], "├─ f8: Boolean");
+        assert_eq!(lines[MAX_DISPLAYED_CHILDREN], "├─ … (5 hidden)");
+        assert_eq!(lines[MAX_DISPLAYED_CHILDREN + 1], "└─ f14: Boolean");
+    }
+}
@@ -4,38 +4,53 @@ use std::{
 };
 
 // An float newtype wrapper that implements basic hashability.
+//
+// Equality and hashing are performed on a canonical bit representation, where all NaNs are
+// considered equal to each other and -0.0 is considered equal to 0.0. These are the semantics of
+// grouping and hash keys; value comparisons that follow IEEE equality must handle NaNs themselves.
 pub struct FloatWrapper<T>(pub T);
 
 macro_rules! impl_hash_for_float_wrapper {
     ($T:ident, $UintEquivalent:ident) => {
+        impl FloatWrapper<$T> {
+            #[inline]
+            fn canonical_bits(&self) -> $UintEquivalent {
+                if self.0.is_nan() {
+                    $T::NAN.to_bits()
+                } else if self.0 == 0.0 {
+                    0
+                } else {
+                    self.0.to_bits()
+                }
+            }
+        }
+
         impl Hash for FloatWrapper<$T> {
             fn hash<H: Hasher>(&self, state: &mut H) {
-                // This is a super basic hash function that could lead to e.g. different hashes for different
-                // NaN representations. Look to crates like https://docs.rs/ordered-float/latest/ordered_float/index.html
-                // for a more advanced Hash implementation, if we end up needing it.
-                state.write(&$UintEquivalent::from_ne_bytes(self.0.to_ne_bytes()).to_ne_bytes())
+                state.write(&self.canonical_bits().to_ne_bytes())
+            }
+        }
+
+        impl PartialEq for FloatWrapper<$T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.canonical_bits() == other.canonical_bits()
             }
         }
+
+        impl Eq for FloatWrapper<$T> {}
     };
 }
 impl_hash_for_float_wrapper!(f32, u32);
 impl_hash_for_float_wrapper!(f64, u64);
 
-impl<T: PartialEq> PartialEq for FloatWrapper<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.eq(&other.0)
-    }
-}
-impl Eq for FloatWrapper<f32> {}
-impl Eq for FloatWrapper<f64> {}
-
-impl<T: PartialOrd> PartialOrd for FloatWrapper<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.0.partial_cmp(&other.0)
-    }
-}
 macro_rules! impl_ord_for_float_wrapper {
     ($T:ident) => {
+        impl PartialOrd for FloatWrapper<$T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
         impl Ord for FloatWrapper<$T> {
             fn cmp(&self, other: &Self) -> Ordering {
                 // This implementation of cmp considers NaNs to be equal to each other, and less than any other value.
@@ -1,7 +1,6 @@
 pub mod arrow;
 pub mod display_table;
 pub mod hashable_float_wrapper;
-pub mod supertype;
 
 #[macro_export]
 macro_rules! impl_binary_trait_by_reference {
@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter, Result, Write};
+
+use daft_core::closed_interval::ClosedInterval;
+
+use crate::{functions::FunctionEvaluator, AggExpr, Expr, LiteralValue, Operator};
+
+impl Expr {
+    /// Renders this expression as SQL-like text, e.g. `a + 1 AS b`.
+    pub fn to_sql_like_string(&self) -> String {
+        SqlLike(self).to_string()
+    }
+
+    /// Renders this expression as a tree with one node per line, and children indented under
+    /// their parent. This is also available through the alternate Display mode, i.e. `{expr:#}`.
+    pub fn to_tree_string(&self) -> String {
+        let mut out = String::new();
+        write_tree(&mut out, self, 0).unwrap();
+        out
+    }
+}
+
+fn agg_name(agg_expr: &AggExpr) -> &'static str {
+    use AggExpr::*;
+    match agg_expr {
+        Count(..) => "count",
+        Sum(..) => "sum",
+        Mean(..) => "mean",
+        Min(..) => "min",
+        Max(..) => "max",
+        AnyValue(..) => "any_value",
+        First(..) => "first",
+        Last(..) => "last",
+        ApproxCountDistinct(..) => "approx_count_distinct",
+        CountDistinct(..) => "count_distinct",
+        Set(..) => "set",
+        ApproxPercentile(..) => "approx_percentile",
+        Median(..) => "median",
+        ApproxSketch(..) => "approx_sketch",
+        MergeSketch(..) => "merge_sketch",
+        List(..) => "list",
+        Concat(..) => "concat",
+        StrJoin(..) => "str_join",
+        MapGroups { func, .. } => func.fn_name(),
+    }
+}
+
+/// Label of a single node of the tree display, without its children.
+fn tree_label(expr: &Expr) -> String {
+    use Expr::*;
+    match expr {
+        Alias(_, name) => format!("Alias: {name}"),
+        Agg(agg_expr) => format!("Agg: {}", agg_name(agg_expr)),
+        BinaryOp { op, .. } => format!("BinaryOp: {op}"),
+        Cast(_, dtype) => format!("Cast: {dtype}"),
+        Column(name) => format!("Column: {name}"),
+        Not(..) => "Not".to_string(),
+        IsNull(..) => "IsNull".to_string(),
+        NotNull(..) => "NotNull".to_string(),
+        IsIn(..) => "IsIn".to_string(),
+        Between { closed, .. } => format!("Between: closed={closed}"),
+        Literal(val) => format!("Literal: {val}"),
+        Function { func, .. } => format!("Function: {}", func.fn_name()),
+        IfElse { .. } => "IfElse".to_string(),
+        Window(_, spec) => format!("Window: {spec}"),
+    }
+}
+
+fn write_tree(out: &mut String, expr: &Expr, depth: usize) -> Result {
+    if depth > 0 {
+        writeln!(out)?;
+    }
+    write!(
+        out,
+        "{:indent$}{}",
+        "",
+        tree_label(expr),
+        indent = depth * 2
+    )?;
+    let children = match expr {
+        // The spec is already part of the window's label, so only show the aggregation under it.
+        Expr::Window(agg, _) => vec![agg.clone()],
+        _ => expr.children(),
+    };
+    for child in children.iter() {
+        write_tree(out, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Display adapter for [`Expr::to_sql_like_string`].
+struct SqlLike<'a>(&'a Expr);
+
+fn sql_operator(op: &Operator) -> &'static str {
+    use Operator::*;
+    match op {
+        Eq => "=",
+        NotEq => "<>",
+        EqNullSafe => "<=>",
+        Lt => "<",
+        LtEq => "<=",
+        Gt => ">",
+        GtEq => ">=",
+        Plus => "+",
+        Minus => "-",
+        Multiply => "*",
+        TrueDivide => "/",
+        FloorDivide => "//",
+        Modulus => "%",
+        And => "AND",
+        Or => "OR",
+        Xor => "XOR",
+        BitwiseAnd => "&",
+        BitwiseOr => "|",
+        BitwiseXor => "^",
+        ShiftLeft => "<<",
+        ShiftRight => ">>",
+    }
+}
+
+fn write_sql_literal(f: &mut Formatter, val: &LiteralValue) -> Result {
+    match val {
+        LiteralValue::Null => write!(f, "NULL"),
+        LiteralValue::Boolean(true) => write!(f, "TRUE"),
+        LiteralValue::Boolean(false) => write!(f, "FALSE"),
+        LiteralValue::Utf8(val) => write!(f, "'{}'", val.replace('\'', "''")),
+        _ => write!(f, "{val}"),
+    }
+}
+
+fn write_sql_list(f: &mut Formatter, exprs: &[Expr]) -> Result {
+    for (i, expr) in exprs.iter().enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", SqlLike(expr))?;
+    }
+    Ok(())
+}
+
+impl Display for SqlLike<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        use Expr::*;
+        // Operands of operators are parenthesized if they are operators themselves, and aliases
+        // of operands are dropped since they don't affect the result.
+        let operand = |expr: &Expr| match expr {
+            Alias(e, _) => SqlLike(e).to_string(),
+            BinaryOp { .. } | Not(..) | IsNull(..) | NotNull(..) | IsIn(..) | Between { .. } => {
+                format!("({})", SqlLike(expr))
+            }
+            _ => SqlLike(expr).to_string(),
+        };
+        match self.0 {
+            Alias(expr, name) => write!(f, "{} AS {name}", SqlLike(expr)),
+            Agg(agg_expr) => match agg_expr {
+                AggExpr::Count(expr, mode) => write!(f, "count({}, {mode})", SqlLike(expr)),
+                AggExpr::AnyValue(expr, ignore_nulls) => write!(
+                    f,
+                    "any_value({}, ignore_nulls={ignore_nulls})",
+                    SqlLike(expr)
+                ),
+                AggExpr::ApproxPercentile(expr, percentile) => {
+                    write!(f, "approx_percentile({}, {percentile})", SqlLike(expr))
+                }
+                AggExpr::StrJoin(expr, separator) => {
+                    write!(f, "str_join({}, {separator:?})", SqlLike(expr))
+                }
+                AggExpr::ApproxSketch(expr, sketch_type)
+                | AggExpr::MergeSketch(expr, sketch_type) => write!(
+                    f,
+                    "{}({}, {sketch_type})",
+                    agg_name(agg_expr),
+                    SqlLike(expr)
+                ),
+                AggExpr::MapGroups { func, inputs } => {
+                    write!(f, "{}(", func.fn_name())?;
+                    write_sql_list(f, inputs)?;
+                    write!(f, ")")
+                }
+                _ => write!(
+                    f,
+                    "{}({})",
+                    agg_name(agg_expr),
+                    SqlLike(&agg_expr.children()[0])
+                ),
+            },
+            BinaryOp { op, left, right } => write!(
+                f,
+                "{} {} {}",
+                operand(left),
+                sql_operator(op),
+                operand(right)
+            ),
+            Cast(expr, dtype) => write!(f, "CAST({} AS {dtype})", SqlLike(expr)),
+            Column(name) => write!(f, "{name}"),
+            Not(expr) => write!(f, "NOT {}", operand(expr)),
+            IsNull(expr) => write!(f, "{} IS NULL", operand(expr)),
+            NotNull(expr) => write!(f, "{} IS NOT NULL", operand(expr)),
+            IsIn(expr, items) => write!(f, "{} IN {}", operand(expr), SqlLike(items)),
+            Between {
+                expr,
+                lower,
+                upper,
+                closed: ClosedInterval::Both,
+            } => write!(
+                f,
+                "{} BETWEEN {} AND {}",
+                operand(expr),
+                operand(lower),
+                operand(upper)
+            ),
+            Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => write!(
+                f,
+                "between({}, {}, {}, closed={closed})",
+                SqlLike(expr),
+                SqlLike(lower),
+                SqlLike(upper)
+            ),
+            Literal(val) => write_sql_literal(f, val),
+            Function { func, inputs } => {
+                write!(f, "{}(", func.fn_name())?;
+                write_sql_list(f, inputs)?;
+                write!(f, ")")
+            }
+            IfElse {
+                if_true,
+                if_false,
+                predicate,
+            } => write!(
+                f,
+                "CASE WHEN {} THEN {} ELSE {} END",
+                SqlLike(predicate),
+                SqlLike(if_true),
+                SqlLike(if_false)
+            ),
+            Window(expr, spec) => {
+                write!(f, "{} OVER (", SqlLike(expr))?;
+                if !spec.partition_by.is_empty() {
+                    write!(f, "PARTITION BY ")?;
+                    for (i, e) in spec.partition_by.iter().enumerate() {
+                        if i != 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", SqlLike(e))?;
+                    }
+                }
+                if !spec.order_by.is_empty() {
+                    if !spec.partition_by.is_empty() {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "ORDER BY ")?;
+                    for (i, (e, desc)) in
+                        spec.order_by.iter().zip(spec.descending.iter()).enumerate()
+                    {
+                        if i != 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{} {}", SqlLike(e), if *desc { "DESC" } else { "ASC" })?;
+                    }
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::DataType;
+
+    use crate::{
+        binary_op, col, functions::utf8::lower, lit, null_lit_with_dtype, when, Operator, Window,
+    };
+
+    #[test]
+    fn sql_like_string() {
+        assert_eq!(
+            (col("a") + lit(1)).alias("b").to_sql_like_string(),
+            "a + 1 AS b"
+        );
+        assert_eq!(
+            ((col("a") + lit(1)) * col("c"))
+                .eq(&lit("it's"))
+                .not()
+                .to_sql_like_string(),
+            "NOT (((a + 1) * c) = 'it''s')"
+        );
+        assert_eq!(
+            col("a")
+                .is_null()
+                .or(&col("a").cast(&DataType::Int64).gt(&lit(2)))
+                .to_sql_like_string(),
+            "(a IS NULL) OR (CAST(a AS Int64) > 2)"
+        );
+        assert_eq!(
+            when(&col("a").is_null())
+                .then(&null_lit_with_dtype(&DataType::Utf8))
+                .otherwise(&lower(&col("s")))
+                .to_sql_like_string(),
+            "CASE WHEN a IS NULL THEN CAST(NULL AS Utf8) ELSE lower(s) END"
+        );
+        assert_eq!(
+            col("x")
+                .sum()
+                .over(&Window::partition_by(&[col("k")]).order_by(&[col("t")], &[true]))
+                .to_sql_like_string(),
+            "sum(x) OVER (PARTITION BY k ORDER BY t DESC)"
+        );
+        assert_eq!(
+            binary_op(Operator::BitwiseAnd, &col("a"), &lit(1)).to_sql_like_string(),
+            "a & 1"
+        );
+    }
+
+    #[test]
+    fn tree_string() {
+        let expr = (col("a") + lit(1)).alias("b");
+        let expected = "Alias: b\n  BinaryOp: +\n    Column: a\n    Literal: 1";
+        assert_eq!(expr.to_tree_string(), expected);
+        assert_eq!(format!("{expr:#}"), expected);
+        assert_eq!(format!("{expr}"), "col(a) + lit(1) AS b");
+    }
+}
@@ -1,15 +1,17 @@
 use daft_core::{
+    closed_interval::ClosedInterval,
     count_mode::CountMode,
     datatypes::DataType,
-    datatypes::{Field, FieldID},
+    datatypes::{supertype::try_get_supertype, Field, FieldID, DECIMAL128_MAX_PRECISION},
     schema::Schema,
-    utils::supertype::try_get_supertype,
+    utils::hashable_float_wrapper::FloatWrapper,
 };
 
 use crate::{
     functions::{function_display, function_semantic_id, struct_::StructExpr, FunctionEvaluator},
-    lit,
+    lit, null_lit,
     optimization::{get_required_columns, requires_computation},
+    WindowSpec,
 };
 
 use common_error::{DaftError, DaftResult};
@@ -17,6 +19,7 @@ use common_error::{DaftError, DaftResult};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display, Formatter, Result},
+    hash::{Hash, Hasher},
     sync::Arc,
 };
 
@@ -43,12 +46,19 @@ pub enum Expr {
     IsNull(ExprRef),
     NotNull(ExprRef),
     IsIn(ExprRef, ExprRef),
+    Between {
+        expr: ExprRef,
+        lower: ExprRef,
+        upper: ExprRef,
+        closed: ClosedInterval,
+    },
     Literal(lit::LiteralValue),
     IfElse {
         if_true: ExprRef,
         if_false: ExprRef,
         predicate: ExprRef,
     },
+    Window(ExprRef, WindowSpec),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -59,14 +69,73 @@ pub enum AggExpr {
     Min(ExprRef),
     Max(ExprRef),
     AnyValue(ExprRef, bool),
+    /// Value of the first row of each group, in the order of the input rows.
+    First(ExprRef),
+    /// Value of the last row of each group, in the order of the input rows.
+    Last(ExprRef),
+    ApproxCountDistinct(ExprRef),
+    /// Exact number of distinct non-null values.
+    CountDistinct(ExprRef),
+    /// Collects the distinct non-null values of each group into a list, as the mergeable state of a
+    /// distributed [`AggExpr::CountDistinct`].
+    Set(ExprRef),
+    ApproxPercentile(ExprRef, Percentile),
+    Median(ExprRef),
+    /// Builds a mergeable sketch of each group, as the first stage of a distributed approximate aggregation.
+    ApproxSketch(ExprRef, SketchType),
+    /// Merges the sketches built by [`AggExpr::ApproxSketch`]. HyperLogLog sketches are merged into
+    /// the estimated distinct count, while DDSketches are merged into a single sketch per group.
+    MergeSketch(ExprRef, SketchType),
     List(ExprRef),
     Concat(ExprRef),
+    /// Joins the non-null strings of each group with the separator.
+    StrJoin(ExprRef, String),
     MapGroups {
         func: FunctionExpr,
         inputs: Vec<Expr>,
     },
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SketchType {
+    HyperLogLog,
+    DDSketch,
+}
+
+impl Display for SketchType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SketchType::HyperLogLog => write!(f, "hll"),
+            SketchType::DDSketch => write!(f, "ddsketch"),
+        }
+    }
+}
+
+/// A percentile in `[0, 1]`, which is compared and hashed by its canonical bit representation so
+/// that it can be stored in an expression.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct Percentile(pub f64);
+
+impl PartialEq for Percentile {
+    fn eq(&self, other: &Self) -> bool {
+        FloatWrapper(self.0) == FloatWrapper(other.0)
+    }
+}
+
+impl Eq for Percentile {}
+
+impl Hash for Percentile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatWrapper(self.0).hash(state)
+    }
+}
+
+impl Display for Percentile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 pub fn col<S: Into<Arc<str>>>(name: S) -> Expr {
     Expr::Column(name.into())
 }
@@ -89,8 +158,18 @@ impl AggExpr {
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | First(expr)
+            | Last(expr)
+            | ApproxCountDistinct(expr)
+            | CountDistinct(expr)
+            | Set(expr)
+            | ApproxPercentile(expr, _)
+            | Median(expr)
+            | ApproxSketch(expr, _)
+            | MergeSketch(expr, _)
             | List(expr)
-            | Concat(expr) => expr.name(),
+            | Concat(expr)
+            | StrJoin(expr, _) => expr.name(),
             MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
     }
@@ -124,6 +203,42 @@ impl AggExpr {
                     "{child_id}.local_any_value(ignore_nulls={ignore_nulls})"
                 ))
             }
+            First(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_first()"))
+            }
+            Last(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_last()"))
+            }
+            ApproxCountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_count_distinct()"))
+            }
+            CountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_count_distinct()"))
+            }
+            Set(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_set()"))
+            }
+            ApproxPercentile(expr, percentile) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_percentile({percentile})"))
+            }
+            Median(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_median()"))
+            }
+            ApproxSketch(expr, sketch_type) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_sketch({sketch_type})"))
+            }
+            MergeSketch(expr, sketch_type) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_sketch({sketch_type})"))
+            }
             List(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_list()"))
@@ -132,6 +247,10 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_concat()"))
             }
+            StrJoin(expr, separator) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_str_join({separator:?})"))
+            }
             MapGroups { func, inputs } => function_semantic_id(func, inputs, schema),
         }
     }
@@ -145,18 +264,87 @@ impl AggExpr {
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | First(expr)
+            | Last(expr)
+            | ApproxCountDistinct(expr)
+            | CountDistinct(expr)
+            | Set(expr)
+            | ApproxPercentile(expr, _)
+            | Median(expr)
+            | ApproxSketch(expr, _)
+            | MergeSketch(expr, _)
             | List(expr)
-            | Concat(expr) => vec![expr.clone()],
+            | Concat(expr)
+            | StrJoin(expr, _) => vec![expr.clone()],
             MapGroups { func: _, inputs } => inputs.iter().map(|e| e.clone().into()).collect(),
         }
     }
 
+    /// Returns a copy of this aggregation with its children replaced, in the order returned by
+    /// [`AggExpr::children`].
+    pub fn with_new_children(&self, children: &[ExprRef]) -> Self {
+        use AggExpr::*;
+        if let MapGroups { func, inputs } = self {
+            assert_eq!(children.len(), inputs.len());
+            return MapGroups {
+                func: func.clone(),
+                inputs: children.iter().map(|e| e.as_ref().clone()).collect(),
+            };
+        }
+        let [expr] = children else {
+            panic!(
+                "Aggregation {self} has one child, but got {}",
+                children.len()
+            )
+        };
+        let expr = expr.clone();
+        match self {
+            Count(_, mode) => Count(expr, *mode),
+            Sum(_) => Sum(expr),
+            Mean(_) => Mean(expr),
+            Min(_) => Min(expr),
+            Max(_) => Max(expr),
+            AnyValue(_, ignore_nulls) => AnyValue(expr, *ignore_nulls),
+            First(_) => First(expr),
+            Last(_) => Last(expr),
+            ApproxCountDistinct(_) => ApproxCountDistinct(expr),
+            CountDistinct(_) => CountDistinct(expr),
+            Set(_) => Set(expr),
+            ApproxPercentile(_, percentile) => ApproxPercentile(expr, *percentile),
+            Median(_) => Median(expr),
+            ApproxSketch(_, sketch_type) => ApproxSketch(expr, *sketch_type),
+            MergeSketch(_, sketch_type) => MergeSketch(expr, *sketch_type),
+            List(_) => List(expr),
+            Concat(_) => Concat(expr),
+            StrJoin(_, separator) => StrJoin(expr, separator.clone()),
+            MapGroups { .. } => unreachable!(),
+        }
+    }
+
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         use AggExpr::*;
         match self {
-            Count(expr, ..) => {
+            Count(expr, ..) | ApproxCountDistinct(expr) | CountDistinct(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name.as_str(), DataType::UInt64).with_nullable(false))
+            }
+            ApproxSketch(expr, _) => {
                 let field = expr.to_field(schema)?;
-                Ok(Field::new(field.name.as_str(), DataType::UInt64))
+                Ok(Field::new(field.name.as_str(), DataType::Binary))
+            }
+            MergeSketch(expr, sketch_type) => {
+                let field = expr.to_field(schema)?;
+                let merged_dtype = match sketch_type {
+                    SketchType::HyperLogLog => DataType::UInt64,
+                    SketchType::DDSketch => DataType::Binary,
+                };
+                match field.dtype {
+                    DataType::Binary => Ok(Field::new(field.name.as_str(), merged_dtype)),
+                    other => Err(DaftError::TypeError(format!(
+                        "Expected input to merge_sketch() to be Binary but received dtype {} for column \"{}\"",
+                        other, field.name,
+                    ))),
+                }
             }
             Sum(expr) => {
                 let field = expr.to_field(schema)?;
@@ -172,6 +360,10 @@ impl AggExpr {
                         | DataType::UInt64 => DataType::UInt64,
                         DataType::Float32 => DataType::Float32,
                         DataType::Float64 => DataType::Float64,
+                        DataType::Duration(unit) => DataType::Duration(*unit),
+                        DataType::Decimal128(_, scale) => {
+                            DataType::Decimal128(DECIMAL128_MAX_PRECISION, *scale)
+                        }
                         other => {
                             return Err(DaftError::TypeError(format!(
                                 "Expected input to sum() to be numeric but received dtype {} for column \"{}\"",
@@ -195,7 +387,9 @@ impl AggExpr {
                         | DataType::UInt32
                         | DataType::UInt64
                         | DataType::Float32
-                        | DataType::Float64 => DataType::Float64,
+                        | DataType::Float64
+                        | DataType::Decimal128(..) => DataType::Float64,
+                        DataType::Duration(unit) => DataType::Duration(*unit),
                         other => {
                             return Err(DaftError::TypeError(format!(
                                 "Numeric mean is not implemented for column \"{}\" of type {}",
@@ -205,23 +399,43 @@ impl AggExpr {
                     },
                 ))
             }
-            Min(expr) | Max(expr) | AnyValue(expr, _) => {
+            ApproxPercentile(expr, _) | Median(expr) => {
+                let field = expr.to_field(schema)?;
+                if !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to {} to be numeric but received dtype {} for column \"{}\"",
+                        self, field.dtype, field.name,
+                    )));
+                }
+                Ok(Field::new(field.name.as_str(), DataType::Float64))
+            }
+            Min(expr) | Max(expr) | AnyValue(expr, _) | First(expr) | Last(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
-            List(expr) => expr.to_field(schema)?.to_list_field(),
+            List(expr) | Set(expr) => expr.to_field(schema)?.to_list_field(),
             Concat(expr) => {
                 let field = expr.to_field(schema)?;
                 match field.dtype {
-                    DataType::List(..) => Ok(field),
+                    DataType::List(..) | DataType::Utf8 => Ok(field),
                     #[cfg(feature = "python")]
                     DataType::Python => Ok(field),
                     _ => Err(DaftError::TypeError(format!(
-                        "We can only perform List Concat Agg on List or Python Types, got dtype {} for column \"{}\"",
+                        "We can only perform Concat Agg on List, Utf8 or Python Types, got dtype {} for column \"{}\"",
                         field.dtype, field.name
                     ))),
                 }
             }
+            StrJoin(expr, _) => {
+                let field = expr.to_field(schema)?;
+                match field.dtype {
+                    DataType::Utf8 => Ok(field),
+                    other => Err(DaftError::TypeError(format!(
+                        "Expected input to str_join() to be Utf8 but received dtype {} for column \"{}\"",
+                        other, field.name,
+                    ))),
+                }
+            }
             MapGroups { func, inputs } => {
                 func.to_field(inputs.as_slice(), schema, &Expr::Agg(self.clone()))
             }
@@ -237,6 +451,11 @@ impl AggExpr {
             "min" => Ok(Min(child.clone().into())),
             "max" => Ok(Max(child.clone().into())),
             "list" => Ok(List(child.clone().into())),
+            "first" => Ok(First(child.clone().into())),
+            "last" => Ok(Last(child.clone().into())),
+            "approx_count_distinct" => Ok(ApproxCountDistinct(child.clone().into())),
+            "count_distinct" => Ok(CountDistinct(child.clone().into())),
+            "median" => Ok(Median(child.clone().into())),
             _ => Err(DaftError::ValueError(format!(
                 "{} not a valid aggregation name",
                 name
@@ -292,6 +511,33 @@ impl Expr {
         Expr::Agg(AggExpr::AnyValue(self.clone().into(), ignore_nulls))
     }
 
+    pub fn first(&self) -> Self {
+        Expr::Agg(AggExpr::First(self.clone().into()))
+    }
+
+    pub fn last(&self) -> Self {
+        Expr::Agg(AggExpr::Last(self.clone().into()))
+    }
+
+    pub fn approx_count_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::ApproxCountDistinct(self.clone().into()))
+    }
+
+    pub fn count_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::CountDistinct(self.clone().into()))
+    }
+
+    pub fn approx_percentile(&self, percentile: f64) -> Self {
+        Expr::Agg(AggExpr::ApproxPercentile(
+            self.clone().into(),
+            Percentile(percentile),
+        ))
+    }
+
+    pub fn median(&self) -> Self {
+        Expr::Agg(AggExpr::Median(self.clone().into()))
+    }
+
     pub fn agg_list(&self) -> Self {
         Expr::Agg(AggExpr::List(self.clone().into()))
     }
@@ -300,6 +546,84 @@ impl Expr {
         Expr::Agg(AggExpr::Concat(self.clone().into()))
     }
 
+    pub fn str_join(&self, separator: &str) -> Self {
+        Expr::Agg(AggExpr::StrJoin(self.clone().into(), separator.to_string()))
+    }
+
+    /// Only aggregates the rows for which `predicate` is true, like SQL's `FILTER (WHERE ...)`.
+    ///
+    /// The other rows are masked out of the input of the aggregation as nulls, so this is supported
+    /// for aggregations that skip nulls, and for counts, which are rewritten to count only the
+    /// included rows.
+    pub fn filter(&self, predicate: &Expr) -> DaftResult<Self> {
+        use AggExpr::*;
+        let mask = |e: &Expr| predicate.if_else(e, &null_lit());
+        let agg_expr = match self {
+            Expr::Alias(expr, name) => return Ok(expr.filter(predicate)?.alias(name.clone())),
+            Expr::Agg(agg_expr) => agg_expr,
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected an aggregation to filter, but received {self}"
+                )))
+            }
+        };
+        let filtered = match agg_expr {
+            Count(e, CountMode::Valid) => Count(mask(e).into(), CountMode::Valid),
+            Count(e, CountMode::All) => Count(mask(&e.not_null()).into(), CountMode::Valid),
+            Count(e, CountMode::Null) => Count(
+                predicate
+                    .and(&e.is_null())
+                    .if_else(&e.is_null(), &null_lit())
+                    .into(),
+                CountMode::Valid,
+            ),
+            AnyValue(e, true) => AnyValue(mask(e).into(), true),
+            Sum(e) => Sum(mask(e).into()),
+            Mean(e) => Mean(mask(e).into()),
+            Min(e) => Min(mask(e).into()),
+            Max(e) => Max(mask(e).into()),
+            ApproxCountDistinct(e) => ApproxCountDistinct(mask(e).into()),
+            CountDistinct(e) => CountDistinct(mask(e).into()),
+            Set(e) => Set(mask(e).into()),
+            ApproxPercentile(e, percentile) => ApproxPercentile(mask(e).into(), *percentile),
+            Median(e) => Median(mask(e).into()),
+            ApproxSketch(e, sketch_type) => ApproxSketch(mask(e).into(), *sketch_type),
+            MergeSketch(e, sketch_type) => MergeSketch(mask(e).into(), *sketch_type),
+            Concat(e) => Concat(mask(e).into()),
+            StrJoin(e, separator) => StrJoin(mask(e).into(), separator.clone()),
+            AnyValue(_, false) | First(..) | Last(..) | List(..) | MapGroups { .. } => {
+                return Err(DaftError::ValueError(format!(
+                    "Filtering is only supported for aggregations that skip nulls, but received {self}"
+                )))
+            }
+        };
+        Ok(Expr::Agg(filtered))
+    }
+
+    pub fn over(&self, spec: &WindowSpec) -> Self {
+        Expr::Window(self.clone().into(), spec.clone())
+    }
+
+    /// Whether this is a function whose result for a row depends on the other rows it runs over and
+    /// their order, i.e. a cumulative, ranking or offset function. These can be evaluated over a
+    /// window like aggregations.
+    pub fn is_window_function(&self) -> bool {
+        matches!(
+            self,
+            Expr::Function {
+                func: FunctionExpr::Cumulative(..)
+                    | FunctionExpr::Ranking(..)
+                    | FunctionExpr::Offset(..),
+                ..
+            }
+        )
+    }
+
+    /// Hashes each value into a UInt64, see [`hash_columns`](crate::functions::hash::hash_columns).
+    pub fn hash_with_seed(&self, seed: Option<u64>) -> Self {
+        crate::functions::hash::hash_columns(&[self.clone()], seed)
+    }
+
     pub fn not(&self) -> Self {
         Expr::Not(self.clone().into())
     }
@@ -312,10 +636,23 @@ impl Expr {
         Expr::NotNull(self.clone().into())
     }
 
+    pub fn fill_null(&self, fill_value: &Self) -> Self {
+        crate::functions::null::fill_null(self, fill_value)
+    }
+
     pub fn is_in(&self, items: &Self) -> Self {
         Expr::IsIn(self.clone().into(), items.clone().into())
     }
 
+    pub fn between(&self, lower: &Self, upper: &Self, closed: ClosedInterval) -> Self {
+        Expr::Between {
+            expr: self.clone().into(),
+            lower: lower.clone().into(),
+            upper: upper.clone().into(),
+            closed,
+        }
+    }
+
     pub fn eq(&self, other: &Self) -> Self {
         binary_op(Operator::Eq, self, other)
     }
@@ -324,6 +661,11 @@ impl Expr {
         binary_op(Operator::NotEq, self, other)
     }
 
+    /// Null-safe equality, where two nulls are equal and a null is unequal to any non-null value.
+    pub fn eq_null_safe(&self, other: &Self) -> Self {
+        binary_op(Operator::EqNullSafe, self, other)
+    }
+
     pub fn and(&self, other: &Self) -> Self {
         binary_op(Operator::And, self, other)
     }
@@ -380,6 +722,19 @@ impl Expr {
                 let items_id = items.semantic_id(schema);
                 FieldID::new(format!("{child_id}.is_in({items_id})"))
             }
+            Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => {
+                let child_id = expr.semantic_id(schema);
+                let lower_id = lower.semantic_id(schema);
+                let upper_id = upper.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.between({lower_id},{upper_id},{closed})"
+                ))
+            }
             Function { func, inputs } => function_semantic_id(func, inputs, schema),
             BinaryOp { op, left, right } => {
                 let left_id = left.semantic_id(schema);
@@ -404,6 +759,12 @@ impl Expr {
 
             // Agg: Separate path.
             Agg(agg_expr) => agg_expr.semantic_id(schema),
+
+            Window(expr, spec) => {
+                let child_id = expr.semantic_id(schema);
+                let spec_id = spec.semantic_id(schema);
+                FieldID::new(format!("{child_id}.over({spec_id})"))
+            }
         }
     }
 
@@ -426,6 +787,9 @@ impl Expr {
                 vec![left.clone(), right.clone()]
             }
             IsIn(expr, items) => vec![expr.clone(), items.clone()],
+            Between {
+                expr, lower, upper, ..
+            } => vec![expr.clone(), lower.clone(), upper.clone()],
             IfElse {
                 if_true,
                 if_false,
@@ -433,78 +797,143 @@ impl Expr {
             } => {
                 vec![predicate.clone(), if_true.clone(), if_false.clone()]
             }
+            Window(expr, spec) => std::iter::once(expr.clone())
+                .chain(spec.children())
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of this expression with its children replaced, in the order returned by
+    /// [`Expr::children`]. Panics if the number of children doesn't match.
+    pub fn with_new_children(&self, children: &[ExprRef]) -> Self {
+        use Expr::*;
+        let num_children = self.children().len();
+        assert_eq!(
+            children.len(),
+            num_children,
+            "Expression {self} has {num_children} children, but got {}",
+            children.len()
+        );
+        match self {
+            Column(..) | Literal(..) => self.clone(),
+            Not(_) => Not(children[0].clone()),
+            IsNull(_) => IsNull(children[0].clone()),
+            NotNull(_) => NotNull(children[0].clone()),
+            Cast(_, dtype) => Cast(children[0].clone(), dtype.clone()),
+            Alias(_, name) => Alias(children[0].clone(), name.clone()),
+            Agg(agg_expr) => Agg(agg_expr.with_new_children(children)),
+            Function { func, .. } => Function {
+                func: func.clone(),
+                inputs: children.iter().map(|e| e.as_ref().clone()).collect(),
+            },
+            BinaryOp { op, .. } => BinaryOp {
+                op: *op,
+                left: children[0].clone(),
+                right: children[1].clone(),
+            },
+            IsIn(..) => IsIn(children[0].clone(), children[1].clone()),
+            Between { closed, .. } => Between {
+                expr: children[0].clone(),
+                lower: children[1].clone(),
+                upper: children[2].clone(),
+                closed: *closed,
+            },
+            IfElse { .. } => IfElse {
+                predicate: children[0].clone(),
+                if_true: children[1].clone(),
+                if_false: children[2].clone(),
+            },
+            Window(_, spec) => Window(
+                children[0].clone(),
+                spec.with_children(children[1..].to_vec()),
+            ),
         }
     }
 
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         use Expr::*;
         match self {
-            Alias(expr, name) => Ok(Field::new(name.as_ref(), expr.get_type(schema)?)),
+            Alias(expr, name) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(name.as_ref(), field.dtype).with_nullable(field.nullable))
+            }
             Agg(agg_expr) => agg_expr.to_field(schema),
-            Cast(expr, dtype) => Ok(Field::new(expr.name()?, dtype.clone())),
+            Cast(expr, dtype) => {
+                let child_field = expr.to_field(schema)?;
+                if !child_field.dtype.can_cast_to(dtype) {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot cast {child_field} to {dtype}"
+                    )));
+                }
+                // Casts between different types may fail for some values, which become null.
+                Ok(Field::new(expr.name()?, dtype.clone())
+                    .with_nullable(child_field.nullable || child_field.dtype != *dtype))
+            }
             Column(name) => Ok(schema.get_field(name).cloned()?),
             Not(expr) => {
                 let child_field = expr.to_field(schema)?;
                 match child_field.dtype {
-                    DataType::Boolean => Ok(Field::new(expr.name()?, DataType::Boolean)),
+                    DataType::Boolean => Ok(Field::new(expr.name()?, DataType::Boolean)
+                        .with_nullable(child_field.nullable)),
                     _ => Err(DaftError::TypeError(format!(
                         "Expected argument to be a Boolean expression, but received {child_field}",
                     ))),
                 }
             }
-            IsNull(expr) => Ok(Field::new(expr.name()?, DataType::Boolean)),
-            NotNull(expr) => Ok(Field::new(expr.name()?, DataType::Boolean)),
-            IsIn(expr, ..) => Ok(Field::new(expr.name()?, DataType::Boolean)),
-            Literal(value) => Ok(Field::new("literal", value.get_type())),
+            IsNull(expr) => Ok(Field::new(expr.name()?, DataType::Boolean).with_nullable(false)),
+            NotNull(expr) => Ok(Field::new(expr.name()?, DataType::Boolean).with_nullable(false)),
+            IsIn(expr, ..) => Ok(Field::new(expr.name()?, DataType::Boolean)
+                .with_nullable(expr.to_field(schema)?.nullable)),
+            Between {
+                expr, lower, upper, ..
+            } => {
+                let expr_field = expr.to_field(schema)?;
+                let lower_field = lower.to_field(schema)?;
+                let upper_field = upper.to_field(schema)?;
+                expr_field.dtype.comparison_op(&lower_field.dtype)?;
+                expr_field.dtype.comparison_op(&upper_field.dtype)?;
+                Ok(
+                    Field::new(expr_field.name.as_str(), DataType::Boolean).with_nullable(
+                        expr_field.nullable || lower_field.nullable || upper_field.nullable,
+                    ),
+                )
+            }
+            Literal(value) => Ok(Field::new("literal", value.get_type())
+                .with_nullable(matches!(value, lit::LiteralValue::Null))),
             Function { func, inputs } => func.to_field(inputs.as_slice(), schema, self),
             BinaryOp { op, left, right } => {
                 let left_field = left.to_field(schema)?;
                 let right_field = right.to_field(schema)?;
-
-                match op {
-                    // Logical operations
-                    Operator::And | Operator::Or | Operator::Xor => {
-                        let result_type = left_field.dtype.logical_op(&right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-
-                    // Comparison operations
-                    Operator::Lt
-                    | Operator::Gt
-                    | Operator::Eq
+                // Point to the innermost expression with mismatched types, since errors of the
+                // operands are propagated as-is.
+                let result_type = binary_op_type(op, &left_field.dtype, &right_field.dtype)
+                    .map_err(|err| match err {
+                        DaftError::TypeError(msg) => {
+                            DaftError::TypeError(format!("{msg}\nin expression: {self}"))
+                        }
+                        err => err,
+                    })?;
+                let nullable = match op {
+                    Operator::EqNullSafe => false,
+                    // Only these operators are defined for all non-null operands.
+                    Operator::Eq
                     | Operator::NotEq
+                    | Operator::Lt
                     | Operator::LtEq
-                    | Operator::GtEq => {
-                        let (result_type, _intermediate, _comp_type) =
-                            left_field.dtype.comparison_op(&right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-
-                    // Arithmetic operations
-                    Operator::Plus => {
-                        let result_type = (&left_field.dtype + &right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Minus => {
-                        let result_type = (&left_field.dtype - &right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Multiply => {
-                        let result_type = (&left_field.dtype * &right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::TrueDivide => {
-                        let result_type = (&left_field.dtype / &right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::Modulus => {
-                        let result_type = (&left_field.dtype % &right_field.dtype)?;
-                        Ok(Field::new(left_field.name.as_str(), result_type))
-                    }
-                    Operator::FloorDivide => {
-                        unimplemented!()
-                    }
-                }
+                    | Operator::Gt
+                    | Operator::GtEq
+                    | Operator::And
+                    | Operator::Or
+                    | Operator::Xor
+                    | Operator::BitwiseAnd
+                    | Operator::BitwiseOr
+                    | Operator::BitwiseXor
+                    | Operator::Plus
+                    | Operator::Minus
+                    | Operator::Multiply => left_field.nullable || right_field.nullable,
+                    _ => true,
+                };
+                Ok(Field::new(left_field.name.as_str(), result_type).with_nullable(nullable))
             }
             IfElse {
                 if_true,
@@ -514,16 +943,38 @@ impl Expr {
                 let if_true_field = if_true.to_field(schema)?;
                 let if_false_field = if_false.to_field(schema)?;
                 let predicate_field = predicate.to_field(schema)?;
-                if predicate_field.dtype != DataType::Boolean {
+                if !matches!(predicate_field.dtype, DataType::Boolean | DataType::Null) {
                     return Err(DaftError::TypeError(format!(
                         "Expected predicate for if_else to be boolean but received {predicate_field}",
                     )));
                 }
                 match try_get_supertype(&if_true_field.dtype, &if_false_field.dtype) {
-                    Ok(supertype) => Ok(Field::new(if_true_field.name, supertype)),
+                    Ok(supertype) => Ok(Field::new(if_true_field.name, supertype).with_nullable(
+                        if_true_field.nullable
+                            || if_false_field.nullable
+                            || predicate_field.nullable,
+                    )),
                     Err(_) => Err(DaftError::TypeError(format!("Expected if_true and if_false arguments for if_else to be castable to the same supertype, but received {if_true_field} and {if_false_field}")))
                 }
             }
+            Window(expr, spec) => {
+                if !matches!(expr.as_ref(), Agg(..)) && !expr.is_window_function() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected an aggregation or a window function to evaluate over a window, but received {expr}"
+                    )));
+                }
+                if spec.order_by.len() != spec.descending.len() {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected one descending flag per window order_by expression, but received {} expressions and {} flags",
+                        spec.order_by.len(),
+                        spec.descending.len()
+                    )));
+                }
+                for e in spec.children() {
+                    e.to_field(schema)?;
+                }
+                expr.to_field(schema)
+            }
         }
     }
 
@@ -538,10 +989,15 @@ impl Expr {
             IsNull(expr) => expr.name(),
             NotNull(expr) => expr.name(),
             IsIn(expr, ..) => expr.name(),
+            Between { expr, .. } => expr.name(),
             Literal(..) => Ok("literal"),
             Function { func, inputs } => match func {
                 FunctionExpr::Struct(StructExpr::Get(name)) => Ok(name),
-                _ => inputs.first().unwrap().name(),
+                // Functions without inputs, e.g. ranking functions, are named after themselves.
+                _ => match inputs.first() {
+                    Some(input) => input.name(),
+                    None => Ok(func.fn_name()),
+                },
             },
             BinaryOp {
                 op: _,
@@ -549,6 +1005,7 @@ impl Expr {
                 right: _,
             } => left.name(),
             IfElse { if_true, .. } => if_true.name(),
+            Window(expr, ..) => expr.name(),
         }
     }
 
@@ -574,6 +1031,9 @@ impl Display for Expr {
     // `f` is a buffer, and this method must write the formatted string into it
     fn fmt(&self, f: &mut Formatter) -> Result {
         use Expr::*;
+        if f.alternate() {
+            return write!(f, "{}", self.to_tree_string());
+        }
         match self {
             Alias(expr, name) => write!(f, "{expr} AS {name}"),
             Agg(agg_expr) => write!(f, "{agg_expr}"),
@@ -595,6 +1055,12 @@ impl Display for Expr {
             IsNull(expr) => write!(f, "is_null({expr})"),
             NotNull(expr) => write!(f, "not_null({expr})"),
             IsIn(expr, items) => write!(f, "{expr} in {items}"),
+            Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => write!(f, "between({expr}, {lower}, {upper}, closed={closed})"),
             Literal(val) => write!(f, "lit({val})"),
             Function { func, inputs } => function_display(f, func, inputs),
             IfElse {
@@ -604,6 +1070,7 @@ impl Display for Expr {
             } => {
                 write!(f, "if [{predicate}] then [{if_true}] else [{if_false}]")
             }
+            Window(expr, spec) => write!(f, "{expr} over ({spec})"),
         }
     }
 }
@@ -620,8 +1087,20 @@ impl Display for AggExpr {
             AnyValue(expr, ignore_nulls) => {
                 write!(f, "any_value({expr}, ignore_nulls={ignore_nulls})")
             }
+            First(expr) => write!(f, "first({expr})"),
+            Last(expr) => write!(f, "last({expr})"),
+            ApproxCountDistinct(expr) => write!(f, "approx_count_distinct({expr})"),
+            CountDistinct(expr) => write!(f, "count_distinct({expr})"),
+            Set(expr) => write!(f, "set({expr})"),
+            ApproxPercentile(expr, percentile) => {
+                write!(f, "approx_percentile({expr}, {percentile})")
+            }
+            Median(expr) => write!(f, "median({expr})"),
+            ApproxSketch(expr, sketch_type) => write!(f, "approx_sketch({expr}, {sketch_type})"),
+            MergeSketch(expr, sketch_type) => write!(f, "merge_sketch({expr}, {sketch_type})"),
             List(expr) => write!(f, "list({expr})"),
-            Concat(expr) => write!(f, "list({expr})"),
+            Concat(expr) => write!(f, "concat({expr})"),
+            StrJoin(expr, separator) => write!(f, "str_join({expr}, {separator:?})"),
             MapGroups { func, inputs } => function_display(f, func, inputs),
         }
     }
@@ -632,6 +1111,7 @@ impl Display for AggExpr {
 pub enum Operator {
     Eq,
     NotEq,
+    EqNullSafe,
     Lt,
     LtEq,
     Gt,
@@ -645,6 +1125,11 @@ pub enum Operator {
     And,
     Or,
     Xor,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 impl Display for Operator {
@@ -653,6 +1138,7 @@ impl Display for Operator {
         let tkn = match self {
             Eq => "==",
             NotEq => "!=",
+            EqNullSafe => "<=>",
             Lt => "<",
             LtEq => "<=",
             Gt => ">",
@@ -666,6 +1152,11 @@ impl Display for Operator {
             And => "&",
             Or => "|",
             Xor => "^",
+            BitwiseAnd => "&",
+            BitwiseOr => "|",
+            BitwiseXor => "^",
+            ShiftLeft => "<<",
+            ShiftRight => ">>",
         };
         write!(f, "{tkn}")
     }
@@ -678,6 +1169,7 @@ impl Operator {
             self,
             Self::Eq
                 | Self::NotEq
+                | Self::EqNullSafe
                 | Self::Lt
                 | Self::LtEq
                 | Self::Gt
@@ -693,6 +1185,38 @@ impl Operator {
     }
 }
 
+/// Resolves the result type of a binary operation, with numeric operands promoted to their
+/// supertype, e.g. Int32 + Float64 -> Float64.
+fn binary_op_type(op: &Operator, left: &DataType, right: &DataType) -> DaftResult<DataType> {
+    match op {
+        // Logical operations
+        Operator::And | Operator::Or | Operator::Xor => left.logical_op(right),
+
+        // Bitwise operations
+        Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor => left.bitwise_op(right),
+        Operator::ShiftLeft | Operator::ShiftRight => left.shift_op(right),
+
+        // Comparison operations
+        Operator::Lt
+        | Operator::Gt
+        | Operator::Eq
+        | Operator::NotEq
+        | Operator::EqNullSafe
+        | Operator::LtEq
+        | Operator::GtEq => Ok(left.comparison_op(right)?.0),
+
+        // Arithmetic operations
+        Operator::Plus => left + right,
+        Operator::Minus => left - right,
+        Operator::Multiply => left * right,
+        Operator::TrueDivide => left / right,
+        Operator::Modulus => left % right,
+        Operator::FloorDivide => {
+            unimplemented!()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -713,6 +1237,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn check_nullability() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64).with_nullable(false),
+            Field::new("b", DataType::Int64),
+        ])?;
+        let nullable = |e: Expr| e.to_field(&schema).map(|f| f.nullable);
+
+        assert!(!nullable(col("a").gt(&lit(1)))?);
+        assert!(!nullable(
+            binary_op(Operator::Plus, &col("a"), &lit(1)).alias("c")
+        )?);
+        assert!(nullable(col("a").gt(&col("b")))?);
+        assert!(nullable(binary_op(
+            Operator::TrueDivide,
+            &col("a"),
+            &lit(1)
+        ))?);
+        assert!(!nullable(col("b").count(CountMode::Valid))?);
+        assert!(!nullable(col("b").is_null())?);
+        assert!(nullable(col("a").cast(&DataType::Utf8))?);
+        Ok(())
+    }
+
     #[test]
     fn check_alias_type() -> DaftResult<()> {
         let a = col("a");
@@ -779,4 +1327,172 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn check_binary_op_type_errors() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::Float64),
+            Field::new("s", DataType::Utf8),
+        ])?;
+
+        assert_eq!((col("a") + col("b")).get_type(&schema)?, DataType::Float64);
+        assert_eq!((col("s") + col("s")).get_type(&schema)?, DataType::Utf8);
+
+        let err = (col("s") + col("a"))
+            .to_field(&schema)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("explicitly cast"), "{err}");
+        assert!(err.contains("in expression: col(s) + col(a)"), "{err}");
+
+        // Only the innermost mismatched expression is reported.
+        let err = ((col("s") + col("a")) * lit(2))
+            .to_field(&schema)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err.matches("in expression").count(), 1, "{err}");
+        assert!(err.contains("in expression: col(s) + col(a)"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn check_filtered_agg() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("y", DataType::Int64),
+        ])?;
+        let predicate = col("y").gt(&lit(0));
+
+        let z = col("x").sum().alias("s").filter(&predicate)?;
+        assert_eq!(
+            z,
+            predicate.if_else(&col("x"), &null_lit()).sum().alias("s")
+        );
+        assert_eq!(z.to_field(&schema)?, Field::new("s", DataType::Int64));
+
+        let z = col("x").count(CountMode::All).filter(&predicate)?;
+        assert_eq!(
+            z.to_field(&schema)?,
+            Field::new("x", DataType::UInt64).with_nullable(false)
+        );
+
+        assert!(col("x").first().filter(&predicate).is_err());
+        assert!(col("x").filter(&predicate).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_if_else_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("pred", DataType::Boolean),
+            Field::new("x", DataType::Int32),
+            Field::new("y", DataType::Float64),
+        ])?;
+
+        let z = col("pred").if_else(&col("x"), &col("y"));
+        assert_eq!(z.to_field(&schema)?, Field::new("x", DataType::Float64));
+
+        let z = col("pred").if_else(&col("x"), &lit(1i64));
+        assert_eq!(z.get_type(&schema)?, DataType::Int64);
+
+        let z = col("x").if_else(&col("x"), &col("y"));
+        assert!(z.to_field(&schema).is_err());
+
+        let z = col("pred").if_else(&col("x"), &lit(b"a".as_slice()));
+        assert!(z.to_field(&schema).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_between_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("s", DataType::Binary),
+        ])?;
+        let z = col("x").between(&lit(1), &lit(10.5), ClosedInterval::Left);
+        assert_eq!(z.to_field(&schema)?, Field::new("x", DataType::Boolean));
+        assert_eq!(
+            z.to_string(),
+            "between(col(x), lit(1), lit(10.5), closed=Left)"
+        );
+        let z = col("s").between(&lit("a"), &lit("z"), ClosedInterval::Both);
+        assert!(z.to_field(&schema).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_cast_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("s", DataType::Utf8),
+            Field::new("l", DataType::List(Box::new(DataType::Int64))),
+        ])?;
+        let z = col("x").cast(&DataType::Float64);
+        assert_eq!(z.to_field(&schema)?, Field::new("x", DataType::Float64));
+        let z = col("s").cast(&DataType::Int64);
+        assert_eq!(z.to_field(&schema)?, Field::new("s", DataType::Int64));
+        let z = col("s").cast(&DataType::Struct(vec![Field::new("a", DataType::Int64)]));
+        assert!(z.to_field(&schema).is_err());
+        let z = col("l").cast(&DataType::List(Box::new(DataType::Float64)));
+        assert_eq!(
+            z.to_field(&schema)?.dtype,
+            DataType::List(Box::new(DataType::Float64))
+        );
+        let z = col("l").cast(&DataType::Utf8);
+        assert!(z.to_field(&schema).is_err());
+        let z = col("missing").cast(&DataType::Int64);
+        assert!(z.to_field(&schema).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_agg_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("u", DataType::UInt8),
+            Field::new("f", DataType::Float32),
+            Field::new("s", DataType::Utf8),
+        ])?;
+        assert_eq!(
+            col("x").sum().to_field(&schema)?,
+            Field::new("x", DataType::Int64)
+        );
+        assert_eq!(
+            col("u").sum().to_field(&schema)?,
+            Field::new("u", DataType::UInt64)
+        );
+        assert_eq!(
+            col("f").sum().to_field(&schema)?,
+            Field::new("f", DataType::Float32)
+        );
+        assert_eq!(
+            col("x").mean().to_field(&schema)?,
+            Field::new("x", DataType::Float64)
+        );
+        assert_eq!(
+            col("s").min().to_field(&schema)?,
+            Field::new("s", DataType::Utf8)
+        );
+        assert_eq!(
+            col("s").count(CountMode::Null).to_field(&schema)?,
+            Field::new("s", DataType::UInt64).with_nullable(false)
+        );
+        assert_eq!(
+            col("x").approx_count_distinct().to_field(&schema)?,
+            Field::new("x", DataType::UInt64).with_nullable(false)
+        );
+        assert_eq!(
+            col("u").approx_percentile(0.99).to_field(&schema)?,
+            Field::new("u", DataType::Float64)
+        );
+        assert_eq!(
+            col("x").median().to_field(&schema)?,
+            Field::new("x", DataType::Float64)
+        );
+        assert!(col("s").sum().to_field(&schema).is_err());
+        assert!(col("s").mean().to_field(&schema).is_err());
+        assert!(col("s").median().to_field(&schema).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,56 @@
+mod running;
+
+use running::{CumCountEvaluator, CumMaxEvaluator, CumMinEvaluator, CumSumEvaluator};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+/// Cumulative functions carry a running state from each row to the next, so their results depend
+/// on the order of the rows. On their own they run over a table in its current order, and over a
+/// window they run over each window partition in the window's order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum CumulativeExpr {
+    Sum,
+    Max,
+    Min,
+    Count,
+}
+
+impl CumulativeExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use CumulativeExpr::*;
+        match self {
+            Sum => &CumSumEvaluator {},
+            Max => &CumMaxEvaluator {},
+            Min => &CumMinEvaluator {},
+            Count => &CumCountEvaluator {},
+        }
+    }
+}
+
+fn cumulative(func: CumulativeExpr, input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Cumulative(func),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn cumsum(input: &Expr) -> Expr {
+    cumulative(CumulativeExpr::Sum, input)
+}
+
+pub fn cummax(input: &Expr) -> Expr {
+    cumulative(CumulativeExpr::Max, input)
+}
+
+pub fn cummin(input: &Expr) -> Expr {
+    cumulative(CumulativeExpr::Min, input)
+}
+
+pub fn cumcount(input: &Expr) -> Expr {
+    cumulative(CumulativeExpr::Count, input)
+}
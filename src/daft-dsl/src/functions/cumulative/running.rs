@@ -0,0 +1,104 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use super::super::FunctionEvaluator;
+
+/// Type of the running state of a numeric cumulative function. Sums widen like the sum
+/// aggregation, while running maxima and minima keep the input type.
+fn numeric_field(fn_name: &str, input: &Expr, schema: &Schema, widen: bool) -> DaftResult<Field> {
+    let field = input.to_field(schema)?;
+    let dtype = match &field.dtype {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 if widen => {
+            DataType::Int64
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 if widen => {
+            DataType::UInt64
+        }
+        dtype @ (DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64) => dtype.clone(),
+        dtype => {
+            return Err(DaftError::TypeError(format!(
+                "Expected input to {fn_name} to be numeric, got {dtype}"
+            )))
+        }
+    };
+    Ok(Field::new(field.name, dtype))
+}
+
+macro_rules! numeric_cumulative_evaluator {
+    ($name:ident, $fname:ident, widen = $widen:expr) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                stringify!($fname)
+            }
+
+            fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+                match inputs {
+                    [input] => numeric_field(self.fn_name(), input, schema, $widen),
+                    _ => Err(DaftError::SchemaMismatch(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+
+            fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+                match inputs {
+                    [input] => input.$fname(),
+                    _ => Err(DaftError::ValueError(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+numeric_cumulative_evaluator!(CumSumEvaluator, cumsum, widen = true);
+numeric_cumulative_evaluator!(CumMaxEvaluator, cummax, widen = false);
+numeric_cumulative_evaluator!(CumMinEvaluator, cummin, widen = false);
+
+pub(super) struct CumCountEvaluator {}
+
+impl FunctionEvaluator for CumCountEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "cumcount"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => Ok(Field::new(input.to_field(schema)?.name, DataType::UInt64)),
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.cumcount(),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
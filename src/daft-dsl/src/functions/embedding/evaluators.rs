@@ -0,0 +1,82 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+/// Both inputs of a binary embedding op must be embeddings of the same size, and the result is a
+/// Float64 per row.
+fn binary_embedding_to_field(inputs: &[Expr], schema: &Schema) -> DaftResult<Field> {
+    match inputs {
+        [input, other] => {
+            let input_field = input.to_field(schema)?;
+            let other_field = other.to_field(schema)?;
+
+            match (&input_field.dtype, &other_field.dtype) {
+                (DataType::Embedding(_, size), DataType::Embedding(_, other_size))
+                    if size == other_size =>
+                {
+                    Ok(Field::new(input_field.name, DataType::Float64))
+                }
+                _ => Err(DaftError::TypeError(format!(
+                    "Expected inputs to be embeddings of the same size, received: {} and {}",
+                    input_field.dtype, other_field.dtype
+                ))),
+            }
+        }
+        _ => Err(DaftError::SchemaMismatch(format!(
+            "Expected 2 input args, got {}",
+            inputs.len()
+        ))),
+    }
+}
+
+fn evaluate_binary_embedding(
+    inputs: &[Series],
+    op: fn(&Series, &Series) -> DaftResult<Series>,
+) -> DaftResult<Series> {
+    match inputs {
+        [input, other] => op(input, other),
+        _ => Err(DaftError::ValueError(format!(
+            "Expected 2 input args, got {}",
+            inputs.len()
+        ))),
+    }
+}
+
+pub(super) struct DotEvaluator {}
+
+impl FunctionEvaluator for DotEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "embedding_dot"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        binary_embedding_to_field(inputs, schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        evaluate_binary_embedding(inputs, Series::embedding_dot)
+    }
+}
+
+pub(super) struct CosineSimilarityEvaluator {}
+
+impl FunctionEvaluator for CosineSimilarityEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "embedding_cosine_similarity"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        binary_embedding_to_field(inputs, schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        evaluate_binary_embedding(inputs, Series::embedding_cosine_similarity)
+    }
+}
@@ -0,0 +1,39 @@
+mod evaluators;
+
+use evaluators::{CosineSimilarityEvaluator, DotEvaluator};
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum EmbeddingExpr {
+    Dot,
+    CosineSimilarity,
+}
+
+impl EmbeddingExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use EmbeddingExpr::*;
+        match self {
+            Dot => &DotEvaluator {},
+            CosineSimilarity => &CosineSimilarityEvaluator {},
+        }
+    }
+}
+
+pub fn dot(input: &Expr, other: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Embedding(EmbeddingExpr::Dot),
+        inputs: vec![input.clone(), other.clone()],
+    }
+}
+
+pub fn cosine_similarity(input: &Expr, other: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Embedding(EmbeddingExpr::CosineSimilarity),
+        inputs: vec![input.clone(), other.clone()],
+    }
+}
@@ -0,0 +1,53 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct FillNanEvaluator {}
+
+impl FunctionEvaluator for FillNanEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "fill_nan"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [data, fill_value] => {
+                let data_field = data.to_field(schema)?;
+                let fill_value_field = fill_value.to_field(schema)?;
+                match (&data_field.dtype, &fill_value_field.dtype) {
+                    // DataType::Float16 |
+                    (DataType::Float32 | DataType::Float64, fill_dtype)
+                        if fill_dtype.is_numeric() || fill_dtype == &DataType::Null =>
+                    {
+                        Ok(data_field)
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to fill_nan to be float and fill value to be numeric, but received {data_field} and {fill_value_field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [data, fill_value] => data.fill_nan(fill_value),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,6 +1,12 @@
+mod fill_nan;
+mod is_inf;
 mod is_nan;
+mod not_nan;
 
+use fill_nan::FillNanEvaluator;
+use is_inf::IsInfEvaluator;
 use is_nan::IsNanEvaluator;
+use not_nan::NotNanEvaluator;
 use serde::{Deserialize, Serialize};
 
 use crate::Expr;
@@ -10,6 +16,9 @@ use super::FunctionEvaluator;
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum FloatExpr {
     IsNan,
+    IsInf,
+    NotNan,
+    FillNan,
 }
 
 impl FloatExpr {
@@ -18,6 +27,9 @@ impl FloatExpr {
         use FloatExpr::*;
         match self {
             IsNan => &IsNanEvaluator {},
+            IsInf => &IsInfEvaluator {},
+            NotNan => &NotNanEvaluator {},
+            FillNan => &FillNanEvaluator {},
         }
     }
 }
@@ -28,3 +40,24 @@ pub fn is_nan(data: &Expr) -> Expr {
         inputs: vec![data.clone()],
     }
 }
+
+pub fn is_inf(data: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Float(FloatExpr::IsInf),
+        inputs: vec![data.clone()],
+    }
+}
+
+pub fn not_nan(data: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Float(FloatExpr::NotNan),
+        inputs: vec![data.clone()],
+    }
+}
+
+pub fn fill_nan(data: &Expr, fill_value: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Float(FloatExpr::FillNan),
+        inputs: vec![data.clone(), fill_value.clone()],
+    }
+}
@@ -0,0 +1,86 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{functions::FunctionExpr, Expr};
+
+use super::{super::FunctionEvaluator, GeneratorExpr};
+
+macro_rules! generator_evaluator {
+    ($name:ident, $fname:literal, $dtype:expr) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                $fname
+            }
+
+            fn to_field(&self, inputs: &[Expr], _: &Schema, _: &Expr) -> DaftResult<Field> {
+                if !inputs.is_empty() {
+                    return Err(DaftError::SchemaMismatch(format!(
+                        "Expected 0 input args, got {}",
+                        inputs.len()
+                    )));
+                }
+                Ok(Field::new($fname, $dtype))
+            }
+
+            fn evaluate(&self, _: &[Series], _: &Expr) -> DaftResult<Series> {
+                Err(DaftError::ValueError(format!(
+                    "{} can only be evaluated on a table of known length",
+                    $fname
+                )))
+            }
+        }
+    };
+}
+
+pub(super) struct RandEvaluator {}
+
+impl FunctionEvaluator for RandEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "rand"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [] => Ok(Field::new("rand", DataType::Float64)),
+            // Seeded rand is given the row ids to mix into the seed while planning.
+            [ids] => match ids.to_field(schema)?.dtype {
+                DataType::UInt64 => Ok(Field::new("rand", DataType::Float64)),
+                other => Err(DaftError::TypeError(format!(
+                    "Expected the row ids of rand to be UInt64, but received {other}"
+                ))),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 0 or 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        match (inputs, expr) {
+            (
+                [ids],
+                Expr::Function {
+                    func: FunctionExpr::Generator(GeneratorExpr::Rand(Some(seed))),
+                    ..
+                },
+            ) => Series::rand_with_ids("rand", ids, *seed),
+            _ => Err(DaftError::ValueError(
+                "rand can only be evaluated on a table of known length".to_string(),
+            )),
+        }
+    }
+}
+
+generator_evaluator!(Uuid4Evaluator, "uuid4", DataType::Utf8);
+generator_evaluator!(
+    MonotonicallyIncreasingIdEvaluator,
+    "monotonically_increasing_id",
+    DataType::UInt64
+);
@@ -0,0 +1,76 @@
+mod generate;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::series::Series;
+use generate::{MonotonicallyIncreasingIdEvaluator, RandEvaluator, Uuid4Evaluator};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+/// Generators produce a new value for every row without any inputs, so they are evaluated with the
+/// number of rows to generate instead of input series, see [`GeneratorExpr::generate`].
+///
+/// Their results are non-deterministic, except for `Rand` with a seed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GeneratorExpr {
+    /// With a seed, given the ids of `MonotonicallyIncreasingId` as input when planning, so that
+    /// partitions don't all generate the same values.
+    Rand(Option<u64>),
+    Uuid4,
+    /// Replaced by a column of ids from the partition-aware `MonotonicallyIncreasingId` plan node
+    /// when projecting, since the ids of a partition depend on its position in the DataFrame.
+    MonotonicallyIncreasingId,
+}
+
+impl GeneratorExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use GeneratorExpr::*;
+        match self {
+            Rand(..) => &RandEvaluator {},
+            Uuid4 => &Uuid4Evaluator {},
+            MonotonicallyIncreasingId => &MonotonicallyIncreasingIdEvaluator {},
+        }
+    }
+
+    /// Whether evaluating the generator twice produces the same values.
+    pub fn is_deterministic(&self) -> bool {
+        use GeneratorExpr::*;
+        matches!(self, Rand(Some(_)) | MonotonicallyIncreasingId)
+    }
+
+    /// Generates the values of `len` rows.
+    pub fn generate(&self, len: usize, name: &str) -> DaftResult<Series> {
+        use GeneratorExpr::*;
+        match self {
+            Rand(seed) => Series::rand(name, len, *seed),
+            Uuid4 => Series::uuid4(name, len),
+            MonotonicallyIncreasingId => Err(DaftError::ValueError(
+                "monotonically_increasing_id can only be evaluated in a projection of a DataFrame"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+fn generator(func: GeneratorExpr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Generator(func),
+        inputs: vec![],
+    }
+}
+
+pub fn rand(seed: Option<u64>) -> Expr {
+    generator(GeneratorExpr::Rand(seed))
+}
+
+pub fn uuid4() -> Expr {
+    generator(GeneratorExpr::Uuid4)
+}
+
+pub fn monotonically_increasing_id() -> Expr {
+    generator(GeneratorExpr::MonotonicallyIncreasingId)
+}
@@ -0,0 +1,98 @@
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{DataType, Field, UInt64Array},
+    kernels::hashing::hash_xxh64,
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
+
+use crate::{
+    functions::{hash::HashExpr, FunctionExpr},
+    Expr,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct HashEvaluator {}
+
+/// Hashes the physical values of `series` with XXH64, chaining from `seed` if provided.
+fn hash_series(series: &Series, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+    let physical = series.as_physical()?;
+    let hashes = hash_xxh64(physical.to_arrow().as_ref(), seed.map(|s| s.as_arrow()))?;
+    Ok(UInt64Array::from((series.name(), Box::new(hashes))))
+}
+
+fn is_hashable(dtype: &DataType) -> bool {
+    let physical = dtype.to_physical();
+    physical.is_numeric()
+        || matches!(
+            physical,
+            DataType::Null | DataType::Boolean | DataType::Utf8 | DataType::Binary
+        )
+}
+
+impl FunctionEvaluator for HashEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "hash"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        if inputs.is_empty() {
+            return Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg to hash, got 0".to_string(),
+            ));
+        }
+        let fields = inputs
+            .iter()
+            .map(|e| e.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(field) = fields.iter().find(|f| !is_hashable(&f.dtype)) {
+            return Err(DaftError::TypeError(format!(
+                "Expects inputs to hash to be primitive, string, binary or temporal, but received {field}",
+            )));
+        }
+        Ok(Field::new(fields[0].name.clone(), DataType::UInt64))
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        let seed = match expr {
+            Expr::Function {
+                func: FunctionExpr::Hash(HashExpr::Hash(seed)),
+                inputs: _,
+            } => *seed,
+            _ => panic!("Expected Hash Expr, got {expr}"),
+        };
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::ValueError(
+                "Expected at least 1 input arg to hash, got 0".to_string(),
+            ));
+        };
+        // Literal inputs are broadcast to the length of the other inputs.
+        let len = inputs.iter().map(|s| s.len()).max().unwrap();
+        let broadcast = |s: &Series| {
+            if s.len() == len {
+                Ok(s.clone())
+            } else if s.len() == 1 {
+                s.broadcast(len)
+            } else {
+                Err(DaftError::ValueError(format!(
+                    "Expected inputs to hash to have the same length, but received {} and {len}",
+                    s.len()
+                )))
+            }
+        };
+        let mut hashes = match seed {
+            Some(seed) => {
+                let seeds = UInt64Array::from(("seed", vec![seed; len]));
+                hash_series(&broadcast(first)?, Some(&seeds))?
+            }
+            None => hash_series(&broadcast(first)?, None)?,
+        };
+        for input in inputs.iter().skip(1) {
+            hashes = hash_series(&broadcast(input)?, Some(&hashes))?;
+        }
+        Ok(hashes.rename(first.name()).into_series())
+    }
+}
@@ -0,0 +1,32 @@
+mod evaluators;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+use evaluators::HashEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HashExpr {
+    /// Hashes the rows of one or more inputs into a UInt64, optionally with a seed.
+    Hash(Option<u64>),
+}
+
+impl HashExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use HashExpr::*;
+        match self {
+            Hash(..) => &HashEvaluator {},
+        }
+    }
+}
+
+/// Hashes each row of `inputs` into a single UInt64 with XXH64, combining the columns in order.
+pub fn hash_columns(inputs: &[Expr], seed: Option<u64>) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Hash(HashExpr::Hash(seed)),
+        inputs: inputs.to_vec(),
+    }
+}
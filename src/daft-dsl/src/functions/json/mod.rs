@@ -0,0 +1,56 @@
+mod query;
+
+use query::JsonQueryEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum JsonExpr {
+    Query(String),
+}
+
+impl JsonExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use JsonExpr::*;
+        match self {
+            Query(_) => &JsonQueryEvaluator {},
+        }
+    }
+}
+
+/// Evaluates a JSONPath-like `query` such as `$.a.b[0]` over each JSON string of `input`.
+pub fn query(input: &Expr, query: &str) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Json(JsonExpr::Query(query.to_string())),
+        inputs: vec![input.clone()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::col;
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    #[test]
+    fn check_json_query_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("payload", DataType::Utf8),
+            Field::new("x", DataType::Int64),
+        ])?;
+        assert_eq!(
+            query(&col("payload"), "$.a[0]").to_field(&schema)?,
+            Field::new("payload", DataType::Utf8)
+        );
+        assert!(query(&col("x"), "$.a").to_field(&schema).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{
+    functions::{json::JsonExpr, FunctionExpr},
+    Expr,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct JsonQueryEvaluator {}
+
+impl FunctionEvaluator for JsonQueryEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "json_query"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(field) => match &field.dtype {
+                    DataType::Utf8 | DataType::Null => Ok(Field::new(field.name, DataType::Utf8)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to json query to be utf8, but received {field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        let query = match expr {
+            Expr::Function {
+                func: FunctionExpr::Json(JsonExpr::Query(query)),
+                inputs: _,
+            } => query,
+            _ => panic!("Expected Json Query Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => input.json_query(query),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
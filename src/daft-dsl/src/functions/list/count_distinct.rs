@@ -0,0 +1,50 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct CountDistinctEvaluator {}
+
+impl FunctionEvaluator for CountDistinctEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "count_distinct"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+
+                match &input_field.dtype {
+                    DataType::List(_) | DataType::FixedSizeList(..) => {
+                        Ok(Field::new(input_field.name, DataType::UInt64))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a list, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.list_count_distinct(),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct MedianEvaluator {}
+
+impl FunctionEvaluator for MedianEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "median"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+
+                match &input_field.dtype {
+                    DataType::List(child_dtype) | DataType::FixedSizeList(child_dtype, _)
+                        if child_dtype.is_numeric() =>
+                    {
+                        Ok(Field::new(input_field.name, DataType::Float64))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a list of numeric values, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.list_median(),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,13 +1,21 @@
+mod contains;
+mod count_distinct;
 mod explode;
 mod get;
 mod join;
 mod lengths;
+mod median;
+mod slice;
 
+use contains::ContainsEvaluator;
+use count_distinct::CountDistinctEvaluator;
 use explode::ExplodeEvaluator;
 use get::GetEvaluator;
 use join::JoinEvaluator;
 use lengths::LengthsEvaluator;
+use median::MedianEvaluator;
 use serde::{Deserialize, Serialize};
+use slice::SliceEvaluator;
 
 use crate::Expr;
 
@@ -19,6 +27,10 @@ pub enum ListExpr {
     Join,
     Lengths,
     Get,
+    Slice,
+    Contains,
+    Median,
+    CountDistinct,
 }
 
 impl ListExpr {
@@ -30,6 +42,10 @@ impl ListExpr {
             Join => &JoinEvaluator {},
             Lengths => &LengthsEvaluator {},
             Get => &GetEvaluator {},
+            Slice => &SliceEvaluator {},
+            Contains => &ContainsEvaluator {},
+            Median => &MedianEvaluator {},
+            CountDistinct => &CountDistinctEvaluator {},
         }
     }
 }
@@ -61,3 +77,31 @@ pub fn get(input: &Expr, idx: &Expr, default: &Expr) -> Expr {
         inputs: vec![input.clone(), idx.clone(), default.clone()],
     }
 }
+
+pub fn slice(input: &Expr, start: &Expr, end: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Slice),
+        inputs: vec![input.clone(), start.clone(), end.clone()],
+    }
+}
+
+pub fn contains(input: &Expr, item: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Contains),
+        inputs: vec![input.clone(), item.clone()],
+    }
+}
+
+pub fn median(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Median),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn count_distinct(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::CountDistinct),
+        inputs: vec![input.clone()],
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct SliceEvaluator {}
+
+impl FunctionEvaluator for SliceEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "slice"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input, start, end] => {
+                let input_field = input.to_field(schema)?;
+                for bound in [start, end] {
+                    let bound_field = bound.to_field(schema)?;
+                    if !bound_field.dtype.is_integer() && bound_field.dtype != DataType::Null {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected slice bounds to be integer, received: {}",
+                            bound_field.dtype
+                        )));
+                    }
+                }
+
+                match input_field.dtype {
+                    DataType::List(child_dtype) | DataType::FixedSizeList(child_dtype, _) => {
+                        Ok(Field::new(input_field.name, DataType::List(child_dtype)))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a list type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input, start, end] => input.list_slice(start, end),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
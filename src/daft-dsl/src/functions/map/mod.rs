@@ -0,0 +1,58 @@
+mod get;
+
+use get::GetEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MapExpr {
+    Get,
+}
+
+impl MapExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use MapExpr::*;
+        match self {
+            Get => &GetEvaluator {},
+        }
+    }
+}
+
+pub fn get(input: &Expr, key: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Map(MapExpr::Get),
+        inputs: vec![input.clone(), key.clone()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, lit};
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    #[test]
+    fn check_map_get_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new(
+                "m",
+                DataType::Map(Box::new(DataType::Utf8), Box::new(DataType::Int64)),
+            ),
+            Field::new("x", DataType::Int64),
+        ])?;
+        assert_eq!(
+            get(&col("m"), &lit("a")).to_field(&schema)?,
+            Field::new("m", DataType::Int64)
+        );
+        assert!(get(&col("x"), &lit("a")).to_field(&schema).is_err());
+        Ok(())
+    }
+}
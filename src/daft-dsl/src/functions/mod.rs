@@ -1,8 +1,19 @@
+pub mod cumulative;
+pub mod embedding;
 pub mod float;
+pub mod generator;
+pub mod hash;
 pub mod image;
+pub mod json;
 pub mod list;
+pub mod map;
+pub mod null;
 pub mod numeric;
+pub mod offset;
 pub mod partitioning;
+pub mod ranking;
+pub mod rust;
+pub mod sketch;
 pub mod struct_;
 pub mod temporal;
 pub mod uri;
@@ -10,10 +21,21 @@ pub mod utf8;
 
 use std::fmt::{Formatter, Result};
 
+use self::cumulative::CumulativeExpr;
+use self::embedding::EmbeddingExpr;
+use self::generator::GeneratorExpr;
+use self::hash::HashExpr;
 use self::image::ImageExpr;
+use self::json::JsonExpr;
 use self::list::ListExpr;
+use self::map::MapExpr;
+use self::null::NullExpr;
 use self::numeric::NumericExpr;
+use self::offset::OffsetExpr;
 use self::partitioning::PartitioningExpr;
+use self::ranking::RankingExpr;
+use self::rust::RustUDF;
+use self::sketch::SketchExpr;
 use self::struct_::StructExpr;
 use self::temporal::TemporalExpr;
 use self::utf8::Utf8Expr;
@@ -34,13 +56,24 @@ use super::Expr;
 pub enum FunctionExpr {
     Numeric(NumericExpr),
     Float(FloatExpr),
+    Null(NullExpr),
     Utf8(Utf8Expr),
     Temporal(TemporalExpr),
     List(ListExpr),
     Struct(StructExpr),
+    Map(MapExpr),
+    Embedding(EmbeddingExpr),
+    Sketch(SketchExpr),
     Image(ImageExpr),
+    Hash(HashExpr),
+    Json(JsonExpr),
+    Cumulative(CumulativeExpr),
+    Ranking(RankingExpr),
+    Offset(OffsetExpr),
+    Generator(GeneratorExpr),
     #[cfg(feature = "python")]
     Python(PythonUDF),
+    Rust(RustUDF),
     Partitioning(PartitioningExpr),
     Uri(UriExpr),
 }
@@ -58,14 +91,25 @@ impl FunctionExpr {
         match self {
             Numeric(expr) => expr.get_evaluator(),
             Float(expr) => expr.get_evaluator(),
+            Null(expr) => expr.get_evaluator(),
             Utf8(expr) => expr.get_evaluator(),
             Temporal(expr) => expr.get_evaluator(),
             List(expr) => expr.get_evaluator(),
             Struct(expr) => expr.get_evaluator(),
+            Map(expr) => expr.get_evaluator(),
+            Embedding(expr) => expr.get_evaluator(),
+            Sketch(expr) => expr.get_evaluator(),
             Image(expr) => expr.get_evaluator(),
+            Hash(expr) => expr.get_evaluator(),
+            Json(expr) => expr.get_evaluator(),
+            Cumulative(expr) => expr.get_evaluator(),
+            Ranking(expr) => expr.get_evaluator(),
+            Offset(expr) => expr.get_evaluator(),
+            Generator(expr) => expr.get_evaluator(),
             Uri(expr) => expr.get_evaluator(),
             #[cfg(feature = "python")]
             Python(expr) => expr,
+            Rust(expr) => expr,
             Partitioning(expr) => expr.get_evaluator(),
         }
     }
@@ -0,0 +1,50 @@
+use daft_core::{
+    datatypes::{supertype::try_get_supertype, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct CoalesceEvaluator {}
+
+impl FunctionEvaluator for CoalesceEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "coalesce"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [] => Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+            [first, rest @ ..] => {
+                let first_field = first.to_field(schema)?;
+                let supertype = rest.iter().try_fold(first_field.dtype, |dtype, input| {
+                    let field = input.to_field(schema)?;
+                    try_get_supertype(&dtype, &field.dtype).map_err(|_| {
+                        DaftError::TypeError(format!(
+                            "Expected all inputs of coalesce to be castable to the same supertype, but could not unify {dtype} and {field}",
+                        ))
+                    })
+                })?;
+                Ok(Field::new(first_field.name, supertype))
+            }
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [] => Err(DaftError::ValueError(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+            [first, rest @ ..] => rest
+                .iter()
+                .try_fold(first.clone(), |acc, input| acc.fill_null(input)),
+        }
+    }
+}
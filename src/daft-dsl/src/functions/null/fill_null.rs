@@ -0,0 +1,48 @@
+use daft_core::{
+    datatypes::{supertype::try_get_supertype, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct FillNullEvaluator {}
+
+impl FunctionEvaluator for FillNullEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "fill_null"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [data, fill_value] => {
+                let data_field = data.to_field(schema)?;
+                let fill_value_field = fill_value.to_field(schema)?;
+                match try_get_supertype(&data_field.dtype, &fill_value_field.dtype) {
+                    Ok(supertype) => Ok(Field::new(data_field.name, supertype)),
+                    Err(_) => Err(DaftError::TypeError(format!(
+                        "Expected input and fill value of fill_null to be castable to the same supertype, but received {data_field} and {fill_value_field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [data, fill_value] => data.fill_null(fill_value),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
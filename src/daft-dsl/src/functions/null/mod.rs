@@ -0,0 +1,42 @@
+mod coalesce;
+mod fill_null;
+
+use coalesce::CoalesceEvaluator;
+use fill_null::FillNullEvaluator;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum NullExpr {
+    FillNull,
+    Coalesce,
+}
+
+impl NullExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use NullExpr::*;
+        match self {
+            FillNull => &FillNullEvaluator {},
+            Coalesce => &CoalesceEvaluator {},
+        }
+    }
+}
+
+pub fn fill_null(input: &Expr, fill_value: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Null(NullExpr::FillNull),
+        inputs: vec![input.clone(), fill_value.clone()],
+    }
+}
+
+pub fn coalesce(inputs: &[Expr]) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Null(NullExpr::Coalesce),
+        inputs: inputs.to_vec(),
+    }
+}
@@ -1,5 +1,9 @@
 use common_error::{DaftError, DaftResult};
-use daft_core::{datatypes::Field, schema::Schema, series::Series};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
 
 use crate::Expr;
 
@@ -20,9 +24,9 @@ impl FunctionEvaluator for CeilEvaluator {
             )));
         }
         let field = inputs.first().unwrap().to_field(schema)?;
-        if !field.dtype.is_numeric() {
+        if !field.dtype.is_numeric() && !matches!(field.dtype, DataType::Decimal128(..)) {
             return Err(DaftError::TypeError(format!(
-                "Expected input to ceil to be numeric, got {}",
+                "Expected input to ceil to be numeric or decimal, got {}",
                 field.dtype
             )));
         }
@@ -0,0 +1,56 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct ClipEvaluator {}
+
+impl FunctionEvaluator for ClipEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "clip"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input, lower, upper] => {
+                let field = input.to_field(schema)?;
+                if !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to clip to be numeric, got {}",
+                        field.dtype
+                    )));
+                }
+                for bound in [lower, upper] {
+                    let bound = bound.to_field(schema)?;
+                    if !bound.dtype.is_numeric() && bound.dtype != DataType::Null {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected clip bounds to be numeric, got {}",
+                            bound.dtype
+                        )));
+                    }
+                }
+                Ok(field)
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input, lower, upper] => input.clip(lower, upper),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use common_error::{DaftError, DaftResult};
-use daft_core::{datatypes::Field, schema::Schema, series::Series};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
 
 use super::super::FunctionEvaluator;
 use crate::Expr;
@@ -19,9 +23,9 @@ impl FunctionEvaluator for FloorEvaluator {
             )));
         }
         let field = inputs.first().unwrap().to_field(schema)?;
-        if !field.dtype.is_numeric() {
+        if !field.dtype.is_numeric() && !matches!(field.dtype, DataType::Decimal128(..)) {
             return Err(DaftError::TypeError(format!(
-                "Expected input to floor to be numeric, got {}",
+                "Expected input to floor to be numeric or decimal, got {}",
                 field.dtype
             )));
         }
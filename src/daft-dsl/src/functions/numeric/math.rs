@@ -0,0 +1,103 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use super::super::FunctionEvaluator;
+
+fn float_field(fn_name: &str, input: &Expr, schema: &Schema) -> DaftResult<Field> {
+    let field = input.to_field(schema)?;
+    let dtype = field.dtype.to_floating_representation().map_err(|_| {
+        DaftError::TypeError(format!(
+            "Expected input to {fn_name} to be numeric, got {}",
+            field.dtype
+        ))
+    })?;
+    Ok(Field::new(field.name, dtype))
+}
+
+macro_rules! unary_math_evaluator {
+    ($name:ident, $fname:ident) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                stringify!($fname)
+            }
+
+            fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+                match inputs {
+                    [input] => float_field(self.fn_name(), input, schema),
+                    _ => Err(DaftError::SchemaMismatch(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+
+            fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+                match inputs {
+                    [input] => input.$fname(),
+                    _ => Err(DaftError::ValueError(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! binary_math_evaluator {
+    ($name:ident, $fname:ident) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                stringify!($fname)
+            }
+
+            fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+                match inputs {
+                    [lhs, rhs] => {
+                        let lhs = float_field(self.fn_name(), lhs, schema)?;
+                        let rhs = float_field(self.fn_name(), rhs, schema)?;
+                        let dtype = match (&lhs.dtype, &rhs.dtype) {
+                            (DataType::Float32, DataType::Float32) => DataType::Float32,
+                            _ => DataType::Float64,
+                        };
+                        Ok(Field::new(lhs.name, dtype))
+                    }
+                    _ => Err(DaftError::SchemaMismatch(format!(
+                        "Expected 2 input args, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+
+            fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+                match inputs {
+                    [lhs, rhs] => lhs.$fname(rhs),
+                    _ => Err(DaftError::ValueError(format!(
+                        "Expected 2 input args, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+unary_math_evaluator!(SqrtEvaluator, sqrt);
+unary_math_evaluator!(ExpEvaluator, exp);
+unary_math_evaluator!(LnEvaluator, ln);
+unary_math_evaluator!(SinEvaluator, sin);
+unary_math_evaluator!(CosEvaluator, cos);
+unary_math_evaluator!(TanEvaluator, tan);
+binary_math_evaluator!(PowEvaluator, pow);
+binary_math_evaluator!(LogEvaluator, log);
+binary_math_evaluator!(Atan2Evaluator, atan2);
@@ -1,11 +1,23 @@
 mod abs;
 mod ceil;
+mod clip;
 mod floor;
+mod math;
+mod round;
+mod sign;
 
 use abs::AbsEvaluator;
 use ceil::CeilEvaluator;
+use clip::ClipEvaluator;
 use floor::FloorEvaluator;
+use math::{
+    Atan2Evaluator, CosEvaluator, ExpEvaluator, LnEvaluator, LogEvaluator, PowEvaluator,
+    SinEvaluator, SqrtEvaluator, TanEvaluator,
+};
+use round::RoundEvaluator;
+use sign::SignEvaluator;
 
+use daft_core::RoundMode;
 use serde::{Deserialize, Serialize};
 
 use crate::Expr;
@@ -17,6 +29,18 @@ pub enum NumericExpr {
     Abs,
     Ceil,
     Floor,
+    Round(i32, RoundMode),
+    Sqrt,
+    Exp,
+    Ln,
+    Log,
+    Pow,
+    Sin,
+    Cos,
+    Tan,
+    Atan2,
+    Sign,
+    Clip,
 }
 
 impl NumericExpr {
@@ -27,6 +51,18 @@ impl NumericExpr {
             Abs => &AbsEvaluator {},
             Ceil => &CeilEvaluator {},
             Floor => &FloorEvaluator {},
+            Round(..) => &RoundEvaluator {},
+            Sqrt => &SqrtEvaluator {},
+            Exp => &ExpEvaluator {},
+            Ln => &LnEvaluator {},
+            Log => &LogEvaluator {},
+            Pow => &PowEvaluator {},
+            Sin => &SinEvaluator {},
+            Cos => &CosEvaluator {},
+            Tan => &TanEvaluator {},
+            Atan2 => &Atan2Evaluator {},
+            Sign => &SignEvaluator {},
+            Clip => &ClipEvaluator {},
         }
     }
 }
@@ -51,3 +87,91 @@ pub fn floor(input: &Expr) -> Expr {
         inputs: vec![input.clone()],
     }
 }
+
+pub fn round(input: &Expr, decimals: i32, mode: RoundMode) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Round(decimals, mode)),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn sqrt(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Sqrt),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn exp(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Exp),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn ln(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Ln),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn sin(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Sin),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn cos(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Cos),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn tan(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Tan),
+        inputs: vec![input.clone()],
+    }
+}
+
+/// Logarithm of `input` in the given `base`.
+pub fn log(input: &Expr, base: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Log),
+        inputs: vec![input.clone(), base.clone()],
+    }
+}
+
+pub fn pow(input: &Expr, exponent: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Pow),
+        inputs: vec![input.clone(), exponent.clone()],
+    }
+}
+
+/// Four-quadrant arctangent of `y / x`, in radians.
+pub fn atan2(y: &Expr, x: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Atan2),
+        inputs: vec![y.clone(), x.clone()],
+    }
+}
+
+pub fn sign(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Sign),
+        inputs: vec![input.clone()],
+    }
+}
+
+/// Clips `input` to be at least `lower` and then at most `upper`, where null bounds leave values
+/// unbounded on that side.
+pub fn clip(input: &Expr, lower: &Expr, upper: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Numeric(NumericExpr::Clip),
+        inputs: vec![input.clone(), lower.clone(), upper.clone()],
+    }
+}
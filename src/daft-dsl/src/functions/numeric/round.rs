@@ -0,0 +1,63 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{functions::FunctionExpr, Expr};
+
+use super::{super::FunctionEvaluator, NumericExpr};
+
+pub(super) struct RoundEvaluator {}
+
+impl FunctionEvaluator for RoundEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "round"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, expr: &Expr) -> DaftResult<Field> {
+        if inputs.len() != 1 {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        }
+        let field = inputs.first().unwrap().to_field(schema)?;
+        let decimals = match expr {
+            Expr::Function {
+                func: FunctionExpr::Numeric(NumericExpr::Round(decimals, _)),
+                inputs: _,
+            } => *decimals,
+            _ => panic!("Expected Round Expr, got {expr}"),
+        };
+        match &field.dtype {
+            DataType::Float32 | DataType::Float64 | DataType::Decimal128(..) => Ok(field),
+            dtype if dtype.is_integer() && decimals >= 0 => Ok(field),
+            dtype if dtype.is_integer() => Err(DaftError::TypeError(format!(
+                "Expected input to round with negative decimals to be a float or decimal, got {}",
+                dtype
+            ))),
+            dtype => Err(DaftError::TypeError(format!(
+                "Expected input to round to be numeric or decimal, got {}",
+                dtype
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        if inputs.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        }
+        match expr {
+            Expr::Function {
+                func: FunctionExpr::Numeric(NumericExpr::Round(decimals, mode)),
+                inputs: _,
+            } => inputs.first().unwrap().round(*decimals, *mode),
+            _ => panic!("Expected Round Expr, got {expr}"),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+mod shift;
+
+use shift::{LagEvaluator, LeadEvaluator};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+/// Offset functions give every row the value of the row a number of rows after (`Lead`) or before
+/// (`Lag`) it, or a default value if there is no such row. On their own they run over a table in
+/// its current order, and over a window they run over each window partition in the window's order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum OffsetExpr {
+    Lead(u64),
+    Lag(u64),
+}
+
+impl OffsetExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use OffsetExpr::*;
+        match self {
+            Lead(..) => &LeadEvaluator {},
+            Lag(..) => &LagEvaluator {},
+        }
+    }
+}
+
+pub fn lead(input: &Expr, n: u64, default: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Offset(OffsetExpr::Lead(n)),
+        inputs: vec![input.clone(), default.clone()],
+    }
+}
+
+pub fn lag(input: &Expr, n: u64, default: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Offset(OffsetExpr::Lag(n)),
+        inputs: vec![input.clone(), default.clone()],
+    }
+}
@@ -0,0 +1,74 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{supertype::try_get_supertype, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{functions::FunctionExpr, Expr};
+
+use super::{super::FunctionEvaluator, OffsetExpr};
+
+/// Offset to shift the input by, in the direction of [`Series::shift`].
+fn shift_offset(expr: &Expr) -> DaftResult<i64> {
+    let (n, sign) = match expr {
+        Expr::Function {
+            func: FunctionExpr::Offset(OffsetExpr::Lag(n)),
+            ..
+        } => (*n, 1),
+        Expr::Function {
+            func: FunctionExpr::Offset(OffsetExpr::Lead(n)),
+            ..
+        } => (*n, -1),
+        _ => panic!("Expected Offset Expr, got {expr}"),
+    };
+    i64::try_from(n)
+        .map(|n| n * sign)
+        .map_err(|_| DaftError::ValueError(format!("Offset {n} is too large in {expr}")))
+}
+
+macro_rules! offset_evaluator {
+    ($name:ident, $fname:literal) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                $fname
+            }
+
+            fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+                match inputs {
+                    [input, default] => {
+                        let field = input.to_field(schema)?;
+                        let default_field = default.to_field(schema)?;
+                        // The default is cast to the type of the input to fill in its values.
+                        if try_get_supertype(&field.dtype, &default_field.dtype).is_err() {
+                            return Err(DaftError::TypeError(format!(
+                                "Expected default of {} to be castable to {}, but received {}",
+                                $fname, field.dtype, default_field.dtype
+                            )));
+                        }
+                        Ok(field)
+                    }
+                    _ => Err(DaftError::SchemaMismatch(format!(
+                        "Expected 2 input args, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+
+            fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+                match inputs {
+                    [input, default] => input.shift(shift_offset(expr)?, default),
+                    _ => Err(DaftError::ValueError(format!(
+                        "Expected 2 input args, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+offset_evaluator!(LeadEvaluator, "lead");
+offset_evaluator!(LagEvaluator, "lag");
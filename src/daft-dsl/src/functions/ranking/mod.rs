@@ -0,0 +1,53 @@
+mod rank;
+
+use rank::{DenseRankEvaluator, RankEvaluator, RowNumberEvaluator};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+use super::FunctionEvaluator;
+
+/// Ranking functions number the rows of each window partition in the window's order. They have no
+/// inputs, and can only be evaluated over a window.
+///
+/// Rows with equal order_by values are peers: `RowNumber` numbers them arbitrarily, `Rank` gives
+/// them the same rank and skips the following ranks (1, 1, 3) and `DenseRank` gives them the same
+/// rank without gaps (1, 1, 2). Without an order_by, all rows of a partition are peers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RankingExpr {
+    RowNumber,
+    Rank,
+    DenseRank,
+}
+
+impl RankingExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use RankingExpr::*;
+        match self {
+            RowNumber => &RowNumberEvaluator {},
+            Rank => &RankEvaluator {},
+            DenseRank => &DenseRankEvaluator {},
+        }
+    }
+}
+
+fn ranking(func: RankingExpr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Ranking(func),
+        inputs: vec![],
+    }
+}
+
+pub fn row_number() -> Expr {
+    ranking(RankingExpr::RowNumber)
+}
+
+pub fn rank() -> Expr {
+    ranking(RankingExpr::Rank)
+}
+
+pub fn dense_rank() -> Expr {
+    ranking(RankingExpr::DenseRank)
+}
@@ -0,0 +1,43 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::Expr;
+
+use super::super::FunctionEvaluator;
+
+macro_rules! ranking_evaluator {
+    ($name:ident, $fname:literal) => {
+        pub(super) struct $name {}
+
+        impl FunctionEvaluator for $name {
+            fn fn_name(&self) -> &'static str {
+                $fname
+            }
+
+            fn to_field(&self, inputs: &[Expr], _: &Schema, _: &Expr) -> DaftResult<Field> {
+                if !inputs.is_empty() {
+                    return Err(DaftError::SchemaMismatch(format!(
+                        "Expected 0 input args, got {}",
+                        inputs.len()
+                    )));
+                }
+                Ok(Field::new($fname, DataType::UInt64))
+            }
+
+            fn evaluate(&self, _: &[Series], _: &Expr) -> DaftResult<Series> {
+                Err(DaftError::ValueError(format!(
+                    "{} can only be evaluated over a window",
+                    $fname
+                )))
+            }
+        }
+    };
+}
+
+ranking_evaluator!(RowNumberEvaluator, "row_number");
+ranking_evaluator!(RankEvaluator, "rank");
+ranking_evaluator!(DenseRankEvaluator, "dense_rank");
@@ -0,0 +1,189 @@
+mod udf;
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{datatypes::DataType, series::Series};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Expr;
+
+/// Signature of a native UDF: takes the evaluated input Series and returns the result Series.
+pub type RustUDFFunction = dyn Fn(&[Series]) -> DaftResult<Series> + Send + Sync;
+
+fn registry() -> &'static RwLock<HashMap<String, Arc<RustUDFFunction>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<RustUDFFunction>>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+fn poisoned_registry<T>(_: T) -> DaftError {
+    DaftError::InternalError(
+        "The UDF registry was poisoned by a panic while it was being updated".to_string(),
+    )
+}
+
+/// Registers a native UDF under `name`, so that it can be used in expressions with [`rust_udf`].
+///
+/// UDFs are identified by name, both when comparing expressions and when (de)serializing them, so
+/// a name can only be registered once per process. Processes that deserialize plans containing the
+/// UDF must register it under the same name.
+pub fn register_udf<F>(name: &str, func: F) -> DaftResult<()>
+where
+    F: Fn(&[Series]) -> DaftResult<Series> + Send + Sync + 'static,
+{
+    let mut registry = registry().write().map_err(poisoned_registry)?;
+    if registry.contains_key(name) {
+        return Err(DaftError::ValueError(format!(
+            "A UDF named \"{name}\" is already registered"
+        )));
+    }
+    registry.insert(name.to_string(), Arc::new(func));
+    Ok(())
+}
+
+fn get_registered_udf(name: &str) -> DaftResult<Arc<RustUDFFunction>> {
+    registry()
+        .read()
+        .map_err(poisoned_registry)?
+        .get(name)
+        .cloned()
+        .ok_or_else(|| {
+            DaftError::ValueError(format!("No UDF named \"{name}\" has been registered"))
+        })
+}
+
+#[derive(Clone)]
+pub struct RustUDF {
+    name: Arc<str>,
+    func: Arc<RustUDFFunction>,
+    num_expressions: usize,
+    return_dtype: DataType,
+}
+
+/// Creates an expression that calls the native UDF registered under `name` on `expressions`,
+/// declaring that it returns a Series of `return_dtype`.
+pub fn rust_udf(name: &str, expressions: &[Expr], return_dtype: DataType) -> DaftResult<Expr> {
+    Ok(Expr::Function {
+        func: super::FunctionExpr::Rust(RustUDF {
+            name: name.into(),
+            func: get_registered_udf(name)?,
+            num_expressions: expressions.len(),
+            return_dtype,
+        }),
+        inputs: expressions.into(),
+    })
+}
+
+impl Debug for RustUDF {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RustUDF")
+            .field("name", &self.name)
+            .field("num_expressions", &self.num_expressions)
+            .field("return_dtype", &self.return_dtype)
+            .finish()
+    }
+}
+
+impl PartialEq for RustUDF {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.num_expressions == other.num_expressions
+            && self.return_dtype == other.return_dtype
+    }
+}
+
+impl Eq for RustUDF {}
+
+impl Hash for RustUDF {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.num_expressions.hash(state);
+        self.return_dtype.hash(state);
+    }
+}
+
+/// Serialized form of a [`RustUDF`]; the function itself is looked up by name on deserialization.
+#[derive(Serialize, Deserialize)]
+struct RustUDFRef {
+    name: String,
+    num_expressions: usize,
+    return_dtype: DataType,
+}
+
+impl Serialize for RustUDF {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RustUDFRef {
+            name: self.name.to_string(),
+            num_expressions: self.num_expressions,
+            return_dtype: self.return_dtype.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RustUDF {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let udf_ref = RustUDFRef::deserialize(deserializer)?;
+        let func = get_registered_udf(&udf_ref.name).map_err(de::Error::custom)?;
+        Ok(Self {
+            name: udf_ref.name.into(),
+            func,
+            num_expressions: udf_ref.num_expressions,
+            return_dtype: udf_ref.return_dtype,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, functions::FunctionEvaluator};
+    use daft_core::{
+        datatypes::{Field, Int64Array},
+        schema::Schema,
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn register_and_evaluate_udf() -> DaftResult<()> {
+        register_udf("test_to_float", |inputs: &[Series]| {
+            inputs[0].cast(&DataType::Float64)
+        })?;
+        assert!(register_udf("test_to_float", |inputs: &[Series]| Ok(inputs[0].clone())).is_err());
+        assert!(rust_udf("test_unregistered", &[col("x")], DataType::Float64).is_err());
+
+        let schema = Schema::new(vec![Field::new("x", DataType::Int64)])?;
+        let expr = rust_udf("test_to_float", &[col("x")], DataType::Float64)?;
+        assert_eq!(expr.to_field(&schema)?, Field::new("x", DataType::Float64));
+
+        let x = Int64Array::from(("x", vec![1, 2, 3])).into_series();
+        let Expr::Function { func, .. } = &expr else {
+            unreachable!()
+        };
+        let result = func.evaluate(&[x.clone()], &expr)?;
+        assert_eq!(result.field(), &Field::new("x", DataType::Float64));
+
+        // The declared return dtype is checked against the result.
+        let mislabeled = rust_udf("test_to_float", &[col("x")], DataType::Utf8)?;
+        let Expr::Function { func, .. } = &mislabeled else {
+            unreachable!()
+        };
+        assert!(func.evaluate(&[x], &mislabeled).is_err());
+
+        let roundtripped: Expr =
+            serde_json::from_str(&serde_json::to_string(&expr).unwrap()).unwrap();
+        assert_eq!(roundtripped, expr);
+        Ok(())
+    }
+}
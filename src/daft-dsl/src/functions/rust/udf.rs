@@ -0,0 +1,67 @@
+use daft_core::{datatypes::Field, schema::Schema, series::Series};
+
+use crate::Expr;
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+use super::RustUDF;
+
+impl FunctionEvaluator for RustUDF {
+    fn fn_name(&self) -> &'static str {
+        "rust_udf"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        if inputs.len() != self.num_expressions {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Number of inputs required by UDF {} does not match number of inputs provided: {}",
+                self.num_expressions,
+                inputs.len()
+            )));
+        }
+        for input in inputs {
+            input.to_field(schema)?;
+        }
+        match inputs {
+            [] => Err(DaftError::ValueError(
+                "Cannot run UDF with 0 expression arguments".into(),
+            )),
+            [first, ..] => Ok(Field::new(first.name()?, self.return_dtype.clone())),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+        if inputs.len() != self.num_expressions {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Number of inputs required by UDF {} does not match number of inputs provided: {}",
+                self.num_expressions,
+                inputs.len()
+            )));
+        }
+        let Some(first) = inputs.first() else {
+            return Err(DaftError::ValueError(
+                "Cannot run UDF with 0 expression arguments".into(),
+            ));
+        };
+        let result = (self.func)(inputs)?;
+        if result.len() != first.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected UDF \"{}\" to return a Series of length {}, but received {}",
+                self.name,
+                first.len(),
+                result.len()
+            )));
+        }
+        if *result.data_type() != self.return_dtype {
+            return Err(DaftError::TypeError(format!(
+                "Expected UDF \"{}\" to return a Series of type {}, but received {}",
+                self.name,
+                self.return_dtype,
+                result.data_type()
+            )));
+        }
+        // Rename the result after the first input, as declared in to_field.
+        Ok(result.rename(first.name()))
+    }
+}
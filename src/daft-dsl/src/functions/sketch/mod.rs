@@ -0,0 +1,31 @@
+mod percentile;
+
+use percentile::PercentileEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::{expr::Percentile, Expr};
+
+use super::FunctionEvaluator;
+
+/// Functions over the serialized sketches built by [`crate::AggExpr::ApproxSketch`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SketchExpr {
+    Percentile(Percentile),
+}
+
+impl SketchExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use SketchExpr::*;
+        match self {
+            Percentile(_) => &PercentileEvaluator {},
+        }
+    }
+}
+
+pub fn sketch_percentile(input: &Expr, percentile: f64) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Sketch(SketchExpr::Percentile(Percentile(percentile))),
+        inputs: vec![input.clone()],
+    }
+}
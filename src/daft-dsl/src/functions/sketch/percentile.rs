@@ -0,0 +1,62 @@
+use crate::Expr;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, SketchExpr};
+
+pub(super) struct PercentileEvaluator {}
+
+impl FunctionEvaluator for PercentileEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "sketch_percentile"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+
+                match input_field.dtype {
+                    DataType::Binary => Ok(Field::new(input_field.name, DataType::Float64)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a serialized sketch of type Binary, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let percentile = match expr {
+                    Expr::Function {
+                        func: FunctionExpr::Sketch(SketchExpr::Percentile(percentile)),
+                        inputs: _,
+                    } => percentile,
+                    _ => panic!("Expected Sketch Percentile Expr, got {expr}"),
+                };
+
+                Ok(input
+                    .binary()?
+                    .dd_sketch_quantile(percentile.0)?
+                    .into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -28,3 +28,35 @@ pub fn get(input: &Expr, name: &str) -> Expr {
         inputs: vec![input.clone()],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::col;
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    #[test]
+    fn check_struct_get_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Int64),
+                    Field::new("b", DataType::List(Box::new(DataType::Utf8))),
+                ]),
+            ),
+            Field::new("x", DataType::Int64),
+        ])?;
+        assert_eq!(
+            get(&col("s"), "b").to_field(&schema)?,
+            Field::new("b", DataType::List(Box::new(DataType::Utf8)))
+        );
+        assert!(get(&col("s"), "c").to_field(&schema).is_err());
+        assert!(get(&col("x"), "a").to_field(&schema).is_err());
+        Ok(())
+    }
+}
@@ -2,14 +2,23 @@ mod date;
 mod day;
 mod day_of_week;
 mod hour;
+mod minute;
 mod month;
+mod tz;
 mod year;
 
+use daft_core::DstResolution;
 use serde::{Deserialize, Serialize};
 
 use crate::functions::temporal::{
-    date::DateEvaluator, day::DayEvaluator, day_of_week::DayOfWeekEvaluator, hour::HourEvaluator,
-    month::MonthEvaluator, year::YearEvaluator,
+    date::DateEvaluator,
+    day::DayEvaluator,
+    day_of_week::DayOfWeekEvaluator,
+    hour::HourEvaluator,
+    minute::MinuteEvaluator,
+    month::MonthEvaluator,
+    tz::{TzConvertEvaluator, TzLocalizeEvaluator},
+    year::YearEvaluator,
 };
 use crate::Expr;
 
@@ -19,10 +28,13 @@ use super::FunctionEvaluator;
 pub enum TemporalExpr {
     Day,
     Hour,
+    Minute,
     Month,
     Year,
     DayOfWeek,
     Date,
+    TzLocalize(String, DstResolution, DstResolution),
+    TzConvert(String),
 }
 
 impl TemporalExpr {
@@ -32,10 +44,13 @@ impl TemporalExpr {
         match self {
             Day => &DayEvaluator {},
             Hour => &HourEvaluator {},
+            Minute => &MinuteEvaluator {},
             Month => &MonthEvaluator {},
             Year => &YearEvaluator {},
             DayOfWeek => &DayOfWeekEvaluator {},
             Date => &DateEvaluator {},
+            TzLocalize(..) => &TzLocalizeEvaluator {},
+            TzConvert(..) => &TzConvertEvaluator {},
         }
     }
 }
@@ -61,6 +76,13 @@ pub fn hour(input: &Expr) -> Expr {
     }
 }
 
+pub fn minute(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Minute),
+        inputs: vec![input.clone()],
+    }
+}
+
 pub fn month(input: &Expr) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::Temporal(TemporalExpr::Month),
@@ -81,3 +103,26 @@ pub fn day_of_week(input: &Expr) -> Expr {
         inputs: vec![input.clone()],
     }
 }
+
+pub fn tz_localize(
+    input: &Expr,
+    tz: &str,
+    ambiguous: DstResolution,
+    nonexistent: DstResolution,
+) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::TzLocalize(
+            tz.to_string(),
+            ambiguous,
+            nonexistent,
+        )),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn tz_convert(input: &Expr, tz: &str) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::TzConvert(tz.to_string())),
+        inputs: vec![input.clone()],
+    }
+}
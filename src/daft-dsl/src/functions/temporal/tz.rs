@@ -0,0 +1,128 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field, Timezone},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{functions::FunctionExpr, Expr};
+
+use super::{super::FunctionEvaluator, TemporalExpr};
+
+pub(super) struct TzLocalizeEvaluator {}
+
+impl FunctionEvaluator for TzLocalizeEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "tz_localize"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, expr: &Expr) -> DaftResult<Field> {
+        let tz = match expr {
+            Expr::Function {
+                func: FunctionExpr::Temporal(TemporalExpr::TzLocalize(tz, ..)),
+                inputs: _,
+            } => tz,
+            _ => panic!("Expected TzLocalize Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => match input.to_field(schema)? {
+                Field {
+                    name,
+                    dtype: DataType::Timestamp(timeunit, None),
+                    ..
+                } => {
+                    Timezone::parse(tz)?;
+                    Ok(Field::new(
+                        name,
+                        DataType::Timestamp(timeunit, Some(tz.clone())),
+                    ))
+                }
+                field => Err(DaftError::TypeError(format!(
+                    "Expected input to tz_localize to be a timestamp without a timezone, got {}",
+                    field.dtype
+                ))),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        match (inputs, expr) {
+            (
+                [input],
+                Expr::Function {
+                    func:
+                        FunctionExpr::Temporal(TemporalExpr::TzLocalize(tz, ambiguous, nonexistent)),
+                    inputs: _,
+                },
+            ) => input.dt_tz_localize(tz, *ambiguous, *nonexistent),
+            ([_], _) => panic!("Expected TzLocalize Expr, got {expr}"),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+pub(super) struct TzConvertEvaluator {}
+
+impl FunctionEvaluator for TzConvertEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "tz_convert"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, expr: &Expr) -> DaftResult<Field> {
+        let tz = match expr {
+            Expr::Function {
+                func: FunctionExpr::Temporal(TemporalExpr::TzConvert(tz)),
+                inputs: _,
+            } => tz,
+            _ => panic!("Expected TzConvert Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => match input.to_field(schema)? {
+                Field {
+                    name,
+                    dtype: DataType::Timestamp(timeunit, Some(_)),
+                    nullable,
+                    ..
+                } => {
+                    Timezone::parse(tz)?;
+                    Ok(
+                        Field::new(name, DataType::Timestamp(timeunit, Some(tz.clone())))
+                            .with_nullable(nullable),
+                    )
+                }
+                field => Err(DaftError::TypeError(format!(
+                    "Expected input to tz_convert to be a timestamp with a timezone, got {}",
+                    field.dtype
+                ))),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        match (inputs, expr) {
+            (
+                [input],
+                Expr::Function {
+                    func: FunctionExpr::Temporal(TemporalExpr::TzConvert(tz)),
+                    inputs: _,
+                },
+            ) => input.dt_tz_convert(tz),
+            ([_], _) => panic!("Expected TzConvert Expr, got {expr}"),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
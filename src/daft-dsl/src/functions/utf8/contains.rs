@@ -22,7 +22,7 @@ impl FunctionEvaluator for ContainsEvaluator {
             [data, pattern] => match (data.to_field(schema), pattern.to_field(schema)) {
                 (Ok(data_field), Ok(pattern_field)) => {
                     match (&data_field.dtype, &pattern_field.dtype) {
-                        (DataType::Utf8, DataType::Utf8) => {
+                        (DataType::Utf8 | DataType::Null, DataType::Utf8 | DataType::Null) => {
                             Ok(Field::new(data_field.name, DataType::Boolean))
                         }
                         _ => Err(DaftError::TypeError(format!(
@@ -0,0 +1,61 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{
+    functions::{utf8::Utf8Expr, FunctionExpr},
+    Expr,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct ExtractEvaluator {}
+
+impl FunctionEvaluator for ExtractEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "extract"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [data, pattern] => match (data.to_field(schema), pattern.to_field(schema)) {
+                (Ok(data_field), Ok(pattern_field)) => {
+                    match (&data_field.dtype, &pattern_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::Utf8))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to extract to be utf8, but received {data_field} and {pattern_field}",
+                        ))),
+                    }
+                }
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        let index = match expr {
+            Expr::Function {
+                func: FunctionExpr::Utf8(Utf8Expr::Extract(index)),
+                inputs: _,
+            } => *index,
+            _ => panic!("Expected Utf8 Extract Expr, got {expr}"),
+        };
+        match inputs {
+            [data, pattern] => data.utf8_extract(pattern, index),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
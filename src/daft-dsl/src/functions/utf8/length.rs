@@ -17,26 +17,34 @@ impl FunctionEvaluator for LengthEvaluator {
     }
 
     fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        length_to_field(self.fn_name(), inputs, schema)
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
         match inputs {
-            [data] => match data.to_field(schema) {
-                Ok(data_field) => match &data_field.dtype {
-                    DataType::Utf8 => Ok(Field::new(data_field.name, DataType::UInt64)),
-                    _ => Err(DaftError::TypeError(format!(
-                        "Expects input to length to be utf8, but received {data_field}",
-                    ))),
-                },
-                Err(e) => Err(e),
-            },
-            _ => Err(DaftError::SchemaMismatch(format!(
+            [data] => data.utf8_length(),
+            _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input args, got {}",
                 inputs.len()
             ))),
         }
     }
+}
+
+pub(super) struct LengthCharsEvaluator {}
+
+impl FunctionEvaluator for LengthCharsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "length_chars"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        length_to_field(self.fn_name(), inputs, schema)
+    }
 
     fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
         match inputs {
-            [data] => data.utf8_length(),
+            [data] => data.utf8_length_chars(),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input args, got {}",
                 inputs.len()
@@ -44,3 +52,21 @@ impl FunctionEvaluator for LengthEvaluator {
         }
     }
 }
+
+fn length_to_field(fn_name: &str, inputs: &[Expr], schema: &Schema) -> DaftResult<Field> {
+    match inputs {
+        [data] => match data.to_field(schema) {
+            Ok(data_field) => match &data_field.dtype {
+                DataType::Utf8 => Ok(Field::new(data_field.name, DataType::UInt64)),
+                _ => Err(DaftError::TypeError(format!(
+                    "Expects input to {fn_name} to be utf8, but received {data_field}",
+                ))),
+            },
+            Err(e) => Err(e),
+        },
+        _ => Err(DaftError::SchemaMismatch(format!(
+            "Expected 1 input args, got {}",
+            inputs.len()
+        ))),
+    }
+}
@@ -1,24 +1,34 @@
+mod capitalize;
 mod contains;
 mod endswith;
+mod extract;
 mod length;
 mod lower;
 mod lstrip;
+mod match_;
+mod replace;
 mod reverse;
 mod rstrip;
 mod split;
 mod startswith;
+mod substr;
 mod upper;
 
+use capitalize::CapitalizeEvaluator;
 use contains::ContainsEvaluator;
 use endswith::EndswithEvaluator;
-use length::LengthEvaluator;
+use extract::ExtractEvaluator;
+use length::{LengthCharsEvaluator, LengthEvaluator};
 use lower::LowerEvaluator;
 use lstrip::LstripEvaluator;
+use match_::MatchEvaluator;
+use replace::ReplaceEvaluator;
 use reverse::ReverseEvaluator;
 use rstrip::RstripEvaluator;
 use serde::{Deserialize, Serialize};
 use split::SplitEvaluator;
 use startswith::StartswithEvaluator;
+use substr::SubstrEvaluator;
 use upper::UpperEvaluator;
 
 use crate::Expr;
@@ -30,13 +40,22 @@ pub enum Utf8Expr {
     EndsWith,
     StartsWith,
     Contains,
-    Split,
+    /// Splits on matches of a regex pattern if set, and on a literal pattern otherwise.
+    Split(bool),
     Length,
+    LengthChars,
     Lower,
     Upper,
+    Capitalize,
     Lstrip,
     Rstrip,
     Reverse,
+    /// Takes the substring with the given start and length in characters.
+    Substr(i64, Option<usize>),
+    Match,
+    /// Extracts the capture group with the given index.
+    Extract(usize),
+    Replace,
 }
 
 impl Utf8Expr {
@@ -47,13 +66,19 @@ impl Utf8Expr {
             EndsWith => &EndswithEvaluator {},
             StartsWith => &StartswithEvaluator {},
             Contains => &ContainsEvaluator {},
-            Split => &SplitEvaluator {},
+            Split(..) => &SplitEvaluator {},
             Length => &LengthEvaluator {},
+            LengthChars => &LengthCharsEvaluator {},
             Lower => &LowerEvaluator {},
             Upper => &UpperEvaluator {},
+            Capitalize => &CapitalizeEvaluator {},
             Lstrip => &LstripEvaluator {},
             Rstrip => &RstripEvaluator {},
             Reverse => &ReverseEvaluator {},
+            Substr(..) => &SubstrEvaluator {},
+            Match => &MatchEvaluator {},
+            Extract(..) => &ExtractEvaluator {},
+            Replace => &ReplaceEvaluator {},
         }
     }
 }
@@ -79,9 +104,9 @@ pub fn contains(data: &Expr, pattern: &Expr) -> Expr {
     }
 }
 
-pub fn split(data: &Expr, pattern: &Expr) -> Expr {
+pub fn split(data: &Expr, pattern: &Expr, regex: bool) -> Expr {
     Expr::Function {
-        func: super::FunctionExpr::Utf8(Utf8Expr::Split),
+        func: super::FunctionExpr::Utf8(Utf8Expr::Split(regex)),
         inputs: vec![data.clone(), pattern.clone()],
     }
 }
@@ -93,6 +118,13 @@ pub fn length(data: &Expr) -> Expr {
     }
 }
 
+pub fn length_chars(data: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::LengthChars),
+        inputs: vec![data.clone()],
+    }
+}
+
 pub fn lower(data: &Expr) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::Utf8(Utf8Expr::Lower),
@@ -107,6 +139,13 @@ pub fn upper(data: &Expr) -> Expr {
     }
 }
 
+pub fn capitalize(data: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Capitalize),
+        inputs: vec![data.clone()],
+    }
+}
+
 pub fn lstrip(data: &Expr) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::Utf8(Utf8Expr::Lstrip),
@@ -127,3 +166,92 @@ pub fn reverse(data: &Expr) -> Expr {
         inputs: vec![data.clone()],
     }
 }
+
+/// Takes the substring of each string with the given start and length in characters.
+pub fn substr(data: &Expr, start: i64, length: Option<usize>) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Substr(start, length)),
+        inputs: vec![data.clone()],
+    }
+}
+
+/// Whether each string contains a match of the regex `pattern`.
+pub fn match_(data: &Expr, pattern: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Match),
+        inputs: vec![data.clone(), pattern.clone()],
+    }
+}
+
+/// Extracts capture group `index` of the first match of the regex `pattern`, where group 0 is the
+/// entire match.
+pub fn extract(data: &Expr, pattern: &Expr, index: usize) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Extract(index)),
+        inputs: vec![data.clone(), pattern.clone()],
+    }
+}
+
+/// Replaces all matches of the regex `pattern` with `replacement`.
+pub fn replace(data: &Expr, pattern: &Expr, replacement: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Replace),
+        inputs: vec![data.clone(), pattern.clone(), replacement.clone()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, lit};
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    #[test]
+    fn check_utf8_function_types() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("s", DataType::Utf8),
+            Field::new("x", DataType::Int64),
+        ])?;
+        let s = col("s");
+        assert_eq!(
+            contains(&s, &lit("a")).to_field(&schema)?,
+            Field::new("s", DataType::Boolean)
+        );
+        assert_eq!(
+            length(&s).to_field(&schema)?,
+            Field::new("s", DataType::UInt64)
+        );
+        assert_eq!(
+            split(&s, &lit(",")).to_field(&schema)?,
+            Field::new("s", DataType::List(Box::new(DataType::Utf8)))
+        );
+        assert_eq!(
+            match_(&s, &lit("a+")).to_field(&schema)?,
+            Field::new("s", DataType::Boolean)
+        );
+        for expr in [
+            lower(&s),
+            upper(&s),
+            capitalize(&s),
+            lstrip(&s),
+            rstrip(&s),
+            reverse(&s),
+            extract(&s, &lit("(a+)"), 1),
+            replace(&s, &lit("a+"), &lit("b")),
+        ] {
+            assert_eq!(expr.to_field(&schema)?, Field::new("s", DataType::Utf8));
+        }
+
+        let x = col("x");
+        assert!(startswith(&x, &lit("a")).to_field(&schema).is_err());
+        assert!(endswith(&s, &lit(1)).to_field(&schema).is_err());
+        assert!(length(&x).to_field(&schema).is_err());
+        assert!(upper(&x).to_field(&schema).is_err());
+        assert!(replace(&s, &lit("a"), &lit(1)).to_field(&schema).is_err());
+        Ok(())
+    }
+}
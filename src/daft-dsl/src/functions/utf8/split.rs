@@ -7,7 +7,8 @@ use daft_core::{
 
 use common_error::{DaftError, DaftResult};
 
-use super::super::FunctionEvaluator;
+use super::{super::FunctionEvaluator, Utf8Expr};
+use crate::functions::FunctionExpr;
 
 pub(super) struct SplitEvaluator {}
 
@@ -38,9 +39,16 @@ impl FunctionEvaluator for SplitEvaluator {
         }
     }
 
-    fn evaluate(&self, inputs: &[Series], _: &Expr) -> DaftResult<Series> {
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        let regex = match expr {
+            Expr::Function {
+                func: FunctionExpr::Utf8(Utf8Expr::Split(regex)),
+                inputs: _,
+            } => *regex,
+            _ => panic!("Expected Utf8 Split Expr, got {expr}"),
+        };
         match inputs {
-            [data, pattern] => data.utf8_split(pattern),
+            [data, pattern] => data.utf8_split(pattern, regex),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 2 input args, got {}",
                 inputs.len()
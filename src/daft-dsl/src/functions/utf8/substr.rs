@@ -0,0 +1,56 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::{
+    functions::{utf8::Utf8Expr, FunctionExpr},
+    Expr,
+};
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct SubstrEvaluator {}
+
+impl FunctionEvaluator for SubstrEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "substr"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(data_field.name, DataType::Utf8)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to substr to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        let (start, length) = match expr {
+            Expr::Function {
+                func: FunctionExpr::Utf8(Utf8Expr::Substr(start, length)),
+                inputs: _,
+            } => (*start, *length),
+            _ => panic!("Expected Utf8 Substr Expr, got {expr}"),
+        };
+        match inputs {
+            [data] => data.utf8_substr(start, length),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
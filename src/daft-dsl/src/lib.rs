@@ -1,5 +1,6 @@
 #![feature(let_chains)]
 mod arithmetic;
+mod display;
 mod expr;
 pub mod functions;
 mod lit;
@@ -8,14 +9,23 @@ pub mod optimization;
 mod pyobject;
 #[cfg(feature = "python")]
 pub mod python;
+mod serialization;
 mod treenode;
+mod when;
+mod window;
 pub use common_treenode;
 pub use expr::binary_op;
 pub use expr::col;
-pub use expr::{AggExpr, Expr, ExprRef, Operator};
-pub use lit::{lit, null_lit, LiteralValue};
+pub use expr::{AggExpr, Expr, ExprRef, Operator, Percentile, SketchType};
+pub use lit::{
+    decimal_lit, duration_lit, lit, lit_struct, null_lit, null_lit_with_dtype, Literal,
+    LiteralValue, TypedLiteral,
+};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+pub use serialization::EXPR_SERIALIZATION_VERSION;
+pub use when::{when, Then, When};
+pub use window::{Window, WindowSpec};
 
 #[cfg(feature = "python")]
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
@@ -25,9 +35,19 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_wrapped(wrap_pyfunction!(python::lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::date_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::time_lit))?;
+    parent.add_wrapped(wrap_pyfunction!(python::duration_lit))?;
+    parent.add_wrapped(wrap_pyfunction!(python::interval_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::timestamp_lit))?;
+    parent.add_wrapped(wrap_pyfunction!(python::decimal_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::series_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::udf))?;
+    parent.add_wrapped(wrap_pyfunction!(python::coalesce))?;
+    parent.add_wrapped(wrap_pyfunction!(python::row_number))?;
+    parent.add_wrapped(wrap_pyfunction!(python::rank))?;
+    parent.add_wrapped(wrap_pyfunction!(python::dense_rank))?;
+    parent.add_wrapped(wrap_pyfunction!(python::rand))?;
+    parent.add_wrapped(wrap_pyfunction!(python::uuid4))?;
+    parent.add_wrapped(wrap_pyfunction!(python::monotonically_increasing_id))?;
     parent.add_wrapped(wrap_pyfunction!(python::eq))?;
 
     Ok(())
@@ -3,15 +3,25 @@ use crate::expr::Expr;
 use daft_core::datatypes::logical::TimeArray;
 use daft_core::utils::display_table::display_time64;
 use daft_core::utils::hashable_float_wrapper::FloatWrapper;
-use daft_core::{array::ops::full::FullNull, datatypes::DataType};
+use daft_core::{
+    array::{
+        ops::{full::FullNull, str_to_decimal128},
+        StructArray,
+    },
+    datatypes::{DataType, Field},
+};
 use daft_core::{
     datatypes::{
-        logical::{DateArray, TimestampArray},
-        TimeUnit,
+        logical::{DateArray, Decimal128Array, DurationArray, IntervalArray, TimestampArray},
+        TimeUnit, DECIMAL128_MAX_PRECISION,
     },
     series::Series,
-    utils::display_table::{display_date32, display_series_literal, display_timestamp},
+    utils::display_table::{
+        display_date32, display_decimal128, display_interval, display_series_literal,
+        display_timestamp,
+    },
 };
+use common_error::{DaftError, DaftResult};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter, Result},
@@ -23,7 +33,7 @@ use crate::pyobject::DaftPyObject;
 
 /// Stores a literal value for queries and computations.
 /// We only need to support the limited types below since those are the types that we would get from python.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LiteralValue {
     Null,
     /// A binary true or false.
@@ -32,6 +42,14 @@ pub enum LiteralValue {
     Utf8(String),
     /// A raw binary array
     Binary(Vec<u8>),
+    /// An 8-bit signed integer number.
+    Int8(i8),
+    /// An 8-bit unsigned integer number.
+    UInt8(u8),
+    /// A 16-bit signed integer number.
+    Int16(i16),
+    /// A 16-bit unsigned integer number.
+    UInt16(u16),
     /// A 32-bit signed integer number.
     Int32(i32),
     /// A 32-bit unsigned integer number.
@@ -59,15 +77,83 @@ pub enum LiteralValue {
     Date(i32),
     /// An [`i64`] representing a time in microseconds or nanoseconds since midnight.
     Time(i64, TimeUnit),
+    /// An [`i64`] representing a measure of elapsed time in [`TimeUnit`].
+    Duration(i64, TimeUnit),
+    /// A calendar-aware interval of months, days and nanoseconds.
+    Interval(i32, i32, i64),
+    /// A 32-bit floating point number.
+    Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// An [`i128`] representing a decimal number with the provided precision and scale.
+    ///
+    /// The scale never exceeds the precision; use [`decimal_lit`] to build one with this checked.
+    Decimal128 {
+        value: i128,
+        precision: u8,
+        scale: u8,
+    },
     /// A list
     Series(Series),
+    /// A single list value, whose elements are the values of the Series, e.g. from `lit(vec![..])`.
+    List(Series),
+    /// A struct of named literal values.
+    Struct(Vec<(String, LiteralValue)>),
     /// Python object.
     #[cfg(feature = "python")]
     Python(DaftPyObject),
 }
 
+impl PartialEq for LiteralValue {
+    fn eq(&self, other: &Self) -> bool {
+        use LiteralValue::*;
+
+        match (self, other) {
+            (Null, Null) => true,
+            (Boolean(a), Boolean(b)) => a == b,
+            (Utf8(a), Utf8(b)) => a == b,
+            (Binary(a), Binary(b)) => a == b,
+            (Int8(a), Int8(b)) => a == b,
+            (UInt8(a), UInt8(b)) => a == b,
+            (Int16(a), Int16(b)) => a == b,
+            (UInt16(a), UInt16(b)) => a == b,
+            (Int32(a), Int32(b)) => a == b,
+            (UInt32(a), UInt32(b)) => a == b,
+            (Int64(a), Int64(b)) => a == b,
+            (UInt64(a), UInt64(b)) => a == b,
+            (Timestamp(a, a_tu, a_tz), Timestamp(b, b_tu, b_tz)) => {
+                a == b && a_tu == b_tu && a_tz == b_tz
+            }
+            (Date(a), Date(b)) => a == b,
+            (Time(a, a_tu), Time(b, b_tu)) => a == b && a_tu == b_tu,
+            (Duration(a, a_tu), Duration(b, b_tu)) => a == b && a_tu == b_tu,
+            (Interval(a_m, a_d, a_ns), Interval(b_m, b_d, b_ns)) => {
+                a_m == b_m && a_d == b_d && a_ns == b_ns
+            }
+            // Compare floats through the hashable newtype so that equality agrees with hashing.
+            (Float32(a), Float32(b)) => FloatWrapper(*a) == FloatWrapper(*b),
+            (Float64(a), Float64(b)) => FloatWrapper(*a) == FloatWrapper(*b),
+            (
+                Decimal128 {
+                    value: a,
+                    precision: a_p,
+                    scale: a_s,
+                },
+                Decimal128 {
+                    value: b,
+                    precision: b_p,
+                    scale: b_s,
+                },
+            ) => a == b && a_p == b_p && a_s == b_s,
+            (Series(a), Series(b)) | (List(a), List(b)) => a == b,
+            (Struct(a), Struct(b)) => a == b,
+            #[cfg(feature = "python")]
+            (Python(a), Python(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 impl Eq for LiteralValue {}
 
 impl Hash for LiteralValue {
@@ -80,6 +166,10 @@ impl Hash for LiteralValue {
             Boolean(bool) => bool.hash(state),
             Utf8(s) => s.hash(state),
             Binary(arr) => arr.hash(state),
+            Int8(n) => n.hash(state),
+            UInt8(n) => n.hash(state),
+            Int16(n) => n.hash(state),
+            UInt16(n) => n.hash(state),
             Int32(n) => n.hash(state),
             UInt32(n) => n.hash(state),
             Int64(n) => n.hash(state),
@@ -89,20 +179,40 @@ impl Hash for LiteralValue {
                 n.hash(state);
                 tu.hash(state);
             }
+            Duration(n, tu) => {
+                n.hash(state);
+                tu.hash(state);
+            }
+            Interval(months, days, ns) => {
+                months.hash(state);
+                days.hash(state);
+                ns.hash(state);
+            }
             Timestamp(n, tu, tz) => {
                 n.hash(state);
                 tu.hash(state);
                 tz.hash(state);
             }
-            // Wrap float64 in hashable newtype.
+            // Wrap floats in hashable newtype.
+            Float32(n) => FloatWrapper(*n).hash(state),
             Float64(n) => FloatWrapper(*n).hash(state),
-            Series(series) => {
+            Decimal128 {
+                value,
+                precision,
+                scale,
+            } => {
+                value.hash(state);
+                precision.hash(state);
+                scale.hash(state);
+            }
+            Series(series) | List(series) => {
                 let hash_result = series.hash(None);
                 match hash_result {
                     Ok(hash) => hash.into_iter().for_each(|i| i.hash(state)),
                     Err(_) => panic!("Cannot hash series"),
                 }
             }
+            Struct(fields) => fields.hash(state),
             #[cfg(feature = "python")]
             Python(py_obj) => py_obj.hash(state),
         }
@@ -118,15 +228,43 @@ impl Display for LiteralValue {
             Boolean(val) => write!(f, "{val}"),
             Utf8(val) => write!(f, "\"{val}\""),
             Binary(val) => write!(f, "Binary[{}]", val.len()),
+            Int8(val) => write!(f, "{val}"),
+            UInt8(val) => write!(f, "{val}"),
+            Int16(val) => write!(f, "{val}"),
+            UInt16(val) => write!(f, "{val}"),
             Int32(val) => write!(f, "{val}"),
             UInt32(val) => write!(f, "{val}"),
             Int64(val) => write!(f, "{val}"),
             UInt64(val) => write!(f, "{val}"),
             Date(val) => write!(f, "{}", display_date32(*val)),
             Time(val, tu) => write!(f, "{}", display_time64(*val, tu)),
+            Duration(val, tu) => write!(f, "{val} {tu}"),
+            Interval(months, days, ns) => write!(f, "{}", display_interval(*months, *days, *ns)),
             Timestamp(val, tu, tz) => write!(f, "{}", display_timestamp(*val, tu, tz)),
+            Float32(val) => write!(f, "{val:.1}"),
             Float64(val) => write!(f, "{val:.1}"),
-            Series(series) => write!(f, "{}", display_series_literal(series)),
+            Decimal128 {
+                value,
+                precision,
+                scale,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    display_decimal128(*value, *precision, *scale as i8)
+                )
+            }
+            Series(series) | List(series) => write!(f, "{}", display_series_literal(series)),
+            Struct(fields) => {
+                write!(f, "Struct(")?;
+                for (i, (name, val)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name}: {val}")?;
+                }
+                write!(f, ")")
+            }
             #[cfg(feature = "python")]
             Python(pyobj) => write!(f, "PyObject({})", {
                 use pyo3::prelude::*;
@@ -149,15 +287,32 @@ impl LiteralValue {
             Boolean(_) => DataType::Boolean,
             Utf8(_) => DataType::Utf8,
             Binary(_) => DataType::Binary,
+            Int8(_) => DataType::Int8,
+            UInt8(_) => DataType::UInt8,
+            Int16(_) => DataType::Int16,
+            UInt16(_) => DataType::UInt16,
             Int32(_) => DataType::Int32,
             UInt32(_) => DataType::UInt32,
             Int64(_) => DataType::Int64,
             UInt64(_) => DataType::UInt64,
             Date(_) => DataType::Date,
             Time(_, tu) => DataType::Time(*tu),
+            Duration(_, tu) => DataType::Duration(*tu),
+            Interval(..) => DataType::Interval,
             Timestamp(_, tu, tz) => DataType::Timestamp(*tu, tz.clone()),
+            Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
+            Decimal128 {
+                precision, scale, ..
+            } => DataType::Decimal128(*precision as usize, *scale as usize),
             Series(series) => series.data_type().clone(),
+            List(series) => DataType::List(Box::new(series.data_type().clone())),
+            Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, val)| Field::new(name, val.get_type()))
+                    .collect(),
+            ),
             #[cfg(feature = "python")]
             Python(_) => DataType::Python,
         }
@@ -172,6 +327,10 @@ impl LiteralValue {
             Boolean(val) => BooleanArray::from(("literal", [*val].as_slice())).into_series(),
             Utf8(val) => Utf8Array::from(("literal", [val.as_str()].as_slice())).into_series(),
             Binary(val) => BinaryArray::from(("literal", val.as_slice())).into_series(),
+            Int8(val) => Int8Array::from(("literal", [*val].as_slice())).into_series(),
+            UInt8(val) => UInt8Array::from(("literal", [*val].as_slice())).into_series(),
+            Int16(val) => Int16Array::from(("literal", [*val].as_slice())).into_series(),
+            UInt16(val) => UInt16Array::from(("literal", [*val].as_slice())).into_series(),
             Int32(val) => Int32Array::from(("literal", [*val].as_slice())).into_series(),
             UInt32(val) => UInt32Array::from(("literal", [*val].as_slice())).into_series(),
             Int64(val) => Int64Array::from(("literal", [*val].as_slice())).into_series(),
@@ -184,17 +343,228 @@ impl LiteralValue {
                 let physical = Int64Array::from(("literal", [*val].as_slice()));
                 TimeArray::new(Field::new("literal", self.get_type()), physical).into_series()
             }
+            Duration(val, ..) => {
+                let physical = Int64Array::from(("literal", [*val].as_slice()));
+                DurationArray::new(Field::new("literal", self.get_type()), physical).into_series()
+            }
+            Interval(months, days, ns) => {
+                IntervalArray::from_values("literal", std::iter::once(Some((*months, *days, *ns))))
+                    .unwrap()
+                    .into_series()
+            }
             Timestamp(val, ..) => {
                 let physical = Int64Array::from(("literal", [*val].as_slice()));
                 TimestampArray::new(Field::new("literal", self.get_type()), physical).into_series()
             }
+            Float32(val) => Float32Array::from(("literal", [*val].as_slice())).into_series(),
             Float64(val) => Float64Array::from(("literal", [*val].as_slice())).into_series(),
+            Decimal128 { value, .. } => {
+                let physical = Int128Array::from(("literal", [*value].as_slice()));
+                Decimal128Array::new(Field::new("literal", self.get_type()), physical)
+                    .into_series()
+            }
             Series(series) => series.clone().rename("literal"),
+            List(series) => series
+                .agg_list(None)
+                .expect("List literals are built from arrays that can be aggregated into a list")
+                .rename("literal"),
+            Struct(fields) => {
+                let children = fields
+                    .iter()
+                    .map(|(name, val)| val.to_series().rename(name))
+                    .collect();
+                StructArray::new(Field::new("literal", self.get_type()), children, None)
+                    .into_series()
+            }
             #[cfg(feature = "python")]
             Python(val) => PythonArray::from(("literal", vec![val.pyobject.clone()])).into_series(),
         };
         result
     }
+
+    /// Parses a string into a literal of the provided [`DataType`].
+    ///
+    /// Temporal types accept ISO 8601 formatted strings, e.g. "2023-01-01" for dates,
+    /// "2023-01-01T12:00:00" for timestamps and "09:30:00" for times. Decimals with more fractional
+    /// digits than the scale are rounded half away from zero.
+    pub fn try_from_str(s: &str, dtype: &DataType) -> DaftResult<Self> {
+        use LiteralValue::*;
+
+        let parse_err = || {
+            DaftError::ValueError(format!(
+                "Unable to parse \"{s}\" as a literal of type {dtype}"
+            ))
+        };
+
+        let value = match dtype {
+            DataType::Null => Null,
+            DataType::Boolean => match s.trim().to_lowercase().as_str() {
+                "true" => Boolean(true),
+                "false" => Boolean(false),
+                _ => return Err(parse_err()),
+            },
+            DataType::Utf8 => Utf8(s.to_string()),
+            DataType::Binary => Binary(s.as_bytes().to_vec()),
+            DataType::Int8 => Int8(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::UInt8 => UInt8(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::Int16 => Int16(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::UInt16 => UInt16(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::Int32 => Int32(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::UInt32 => UInt32(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::Int64 => Int64(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::UInt64 => UInt64(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::Float32 => Float32(s.trim().parse().map_err(|_| parse_err())?),
+            DataType::Float64 => Float64(s.trim().parse().map_err(|_| parse_err())?),
+            // Rounds extra fractional digits half away from zero, the same as casting from Utf8.
+            DataType::Decimal128(precision, scale) => Decimal128 {
+                value: str_to_decimal128(s, *precision, *scale).ok_or_else(parse_err)?,
+                precision: *precision as u8,
+                scale: *scale as u8,
+            },
+            DataType::Date => {
+                let date = chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+                    .map_err(|_| parse_err())?;
+                let epoch_date = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+                Date(date.signed_duration_since(epoch_date).num_days() as i32)
+            }
+            DataType::Time(tu) => {
+                use chrono::Timelike;
+
+                let time = chrono::NaiveTime::parse_from_str(s.trim(), "%H:%M:%S%.f")
+                    .map_err(|_| parse_err())?;
+                let seconds = time.num_seconds_from_midnight() as i64;
+                let nanos = time.nanosecond() as i64;
+                let value = match tu {
+                    TimeUnit::Seconds => seconds,
+                    TimeUnit::Milliseconds => seconds * 1_000 + nanos / 1_000_000,
+                    TimeUnit::Microseconds => seconds * 1_000_000 + nanos / 1_000,
+                    TimeUnit::Nanoseconds => seconds * 1_000_000_000 + nanos,
+                };
+                Time(value, *tu)
+            }
+            DataType::Timestamp(tu, tz) => {
+                let trimmed = s.trim();
+                // Strings with an explicit offset are converted to UTC, otherwise they are taken as is.
+                let datetime = match chrono::DateTime::parse_from_rfc3339(trimmed) {
+                    Ok(datetime) => datetime.naive_utc(),
+                    Err(_) => {
+                        use chrono::{NaiveDate, NaiveDateTime};
+
+                        NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%dT%H:%M:%S%.f")
+                            .or_else(|_| {
+                                NaiveDateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S%.f")
+                            })
+                            .or_else(|_| {
+                                NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                                    .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+                            })
+                            .map_err(|_| parse_err())?
+                    }
+                };
+                let value = naive_datetime_to_timestamp(&datetime, tu).ok_or_else(parse_err)?;
+                Timestamp(value, *tu, tz.clone())
+            }
+            DataType::Duration(tu) => Duration(s.trim().parse().map_err(|_| parse_err())?, *tu),
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "Parsing a literal from a string is not supported for type {other}"
+                )))
+            }
+        };
+        Ok(value)
+    }
+
+    /// Materializes this literal as a Series named `name` of length `len`.
+    ///
+    /// Series literals are not broadcast and must already have length `len`.
+    pub fn to_series_broadcast(&self, name: &str, len: usize) -> DaftResult<Series> {
+        let series = self.to_series().rename(name);
+        match self {
+            LiteralValue::Series(_) if series.len() != len => {
+                Err(DaftError::ValueError(format!(
+                    "Expected Series literal of length {len}, but received length {}",
+                    series.len()
+                )))
+            }
+            LiteralValue::Series(_) => Ok(series),
+            _ => series.broadcast(len),
+        }
+    }
+
+    /// Converts the value of a length-1 Series back into a literal, or None if the value is null.
+    ///
+    /// This is the inverse of [`LiteralValue::to_series`] for scalar types; nested and Python
+    /// types are not supported.
+    pub fn try_from_single_value_series(series: &Series) -> DaftResult<Option<Self>> {
+        use daft_core::datatypes::*;
+        use LiteralValue::*;
+        if series.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected Series of length 1 to convert into a literal, but received length {}",
+                series.len()
+            )));
+        }
+        let value = match series.data_type() {
+            DataType::Null => None,
+            DataType::Boolean => series.bool()?.get(0).map(Boolean),
+            DataType::Utf8 => series.utf8()?.get(0).map(|v| Utf8(v.to_string())),
+            DataType::Binary => series.binary()?.get(0).map(|v| Binary(v.to_vec())),
+            DataType::Int8 => series.i8()?.get(0).map(Int8),
+            DataType::UInt8 => series.u8()?.get(0).map(UInt8),
+            DataType::Int16 => series.i16()?.get(0).map(Int16),
+            DataType::UInt16 => series.u16()?.get(0).map(UInt16),
+            DataType::Int32 => series.i32()?.get(0).map(Int32),
+            DataType::UInt32 => series.u32()?.get(0).map(UInt32),
+            DataType::Int64 => series.i64()?.get(0).map(Int64),
+            DataType::UInt64 => series.u64()?.get(0).map(UInt64),
+            DataType::Float32 => series.f32()?.get(0).map(Float32),
+            DataType::Float64 => series.f64()?.get(0).map(Float64),
+            DataType::Date => series.downcast::<DateArray>()?.get(0).map(Date),
+            DataType::Time(tu) => series.downcast::<TimeArray>()?.get(0).map(|v| Time(v, *tu)),
+            DataType::Duration(tu) => series
+                .downcast::<DurationArray>()?
+                .get(0)
+                .map(|v| Duration(v, *tu)),
+            DataType::Interval => series
+                .downcast::<IntervalArray>()?
+                .get(0)
+                .map(|(months, days, ns)| Interval(months, days, ns)),
+            DataType::Timestamp(tu, tz) => series
+                .downcast::<TimestampArray>()?
+                .get(0)
+                .map(|v| Timestamp(v, *tu, tz.clone())),
+            DataType::Decimal128(precision, scale) => series
+                .downcast::<Decimal128Array>()?
+                .get(0)
+                .map(|value| Decimal128 {
+                    value,
+                    precision: *precision as u8,
+                    scale: *scale as u8,
+                }),
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "Cannot convert Series of type {other} into a literal"
+                )))
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// Converts a naive datetime into a count of [`TimeUnit`] since the UNIX epoch,
+/// returning `None` on overflow.
+fn naive_datetime_to_timestamp(datetime: &chrono::NaiveDateTime, tu: &TimeUnit) -> Option<i64> {
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let elapsed = datetime.signed_duration_since(epoch);
+    match tu {
+        TimeUnit::Seconds => Some(elapsed.num_seconds()),
+        TimeUnit::Milliseconds => Some(elapsed.num_milliseconds()),
+        TimeUnit::Microseconds => elapsed.num_microseconds(),
+        TimeUnit::Nanoseconds => elapsed.num_nanoseconds(),
+    }
 }
 
 pub trait Literal {
@@ -214,6 +584,31 @@ impl<'a> Literal for &'a str {
     }
 }
 
+/// A [`Literal`] whose [`DataType`] is known without needing a value.
+pub trait TypedLiteral: Literal {
+    fn literal_dtype() -> DataType;
+}
+
+/// Produces the literal for `Some` values, and a null literal of the corresponding type for `None`.
+impl<T: TypedLiteral> Literal for Option<T> {
+    fn lit(self) -> Expr {
+        match self {
+            Some(val) => val.lit(),
+            None => null_lit_with_dtype(&T::literal_dtype()),
+        }
+    }
+}
+
+macro_rules! make_typed_literal {
+    ($TYPE:ty, $DTYPE:expr) => {
+        impl TypedLiteral for $TYPE {
+            fn literal_dtype() -> DataType {
+                $DTYPE
+            }
+        }
+    };
+}
+
 macro_rules! make_literal {
     ($TYPE:ty, $SCALAR:ident) => {
         impl Literal for $TYPE {
@@ -221,6 +616,8 @@ macro_rules! make_literal {
                 Expr::Literal(LiteralValue::$SCALAR(self))
             }
         }
+
+        make_typed_literal!($TYPE, DataType::$SCALAR);
     };
 }
 
@@ -230,6 +627,38 @@ impl<'a> Literal for &'a [u8] {
     }
 }
 
+impl Literal for Vec<u8> {
+    fn lit(self) -> Expr {
+        Expr::Literal(LiteralValue::Binary(self))
+    }
+}
+
+impl Literal for chrono::NaiveDate {
+    fn lit(self) -> Expr {
+        let epoch_date = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let days_since_epoch = self.signed_duration_since(epoch_date).num_days() as i32;
+        Expr::Literal(LiteralValue::Date(days_since_epoch))
+    }
+}
+
+impl Literal for chrono::NaiveDateTime {
+    fn lit(self) -> Expr {
+        let micros = naive_datetime_to_timestamp(&self, &TimeUnit::Microseconds)
+            .expect("timestamp literal overflows i64 microseconds");
+        Expr::Literal(LiteralValue::Timestamp(micros, TimeUnit::Microseconds, None))
+    }
+}
+
+impl Literal for chrono::NaiveTime {
+    fn lit(self) -> Expr {
+        use chrono::Timelike;
+
+        let micros = self.num_seconds_from_midnight() as i64 * 1_000_000
+            + self.nanosecond() as i64 / 1_000;
+        Expr::Literal(LiteralValue::Time(micros, TimeUnit::Microseconds))
+    }
+}
+
 impl Literal for Series {
     fn lit(self) -> Expr {
         Expr::Literal(LiteralValue::Series(self))
@@ -243,17 +672,378 @@ impl Literal for pyo3::PyObject {
     }
 }
 
+macro_rules! make_series_literal {
+    ($TYPE:ty, $ARRAY:ident) => {
+        impl Literal for Vec<$TYPE> {
+            fn lit(self) -> Expr {
+                use daft_core::datatypes::$ARRAY;
+                use daft_core::series::IntoSeries;
+
+                let series = $ARRAY::from(("literal", self.as_slice())).into_series();
+                Expr::Literal(LiteralValue::List(series))
+            }
+        }
+    };
+}
+
 make_literal!(bool, Boolean);
+make_literal!(i8, Int8);
+make_literal!(u8, UInt8);
+make_literal!(i16, Int16);
+make_literal!(u16, UInt16);
 make_literal!(i32, Int32);
 make_literal!(u32, UInt32);
 make_literal!(i64, Int64);
 make_literal!(u64, UInt64);
+make_literal!(f32, Float32);
 make_literal!(f64, Float64);
 
+make_typed_literal!(String, DataType::Utf8);
+make_typed_literal!(&str, DataType::Utf8);
+make_typed_literal!(&[u8], DataType::Binary);
+make_typed_literal!(Vec<u8>, DataType::Binary);
+make_typed_literal!(chrono::NaiveDate, DataType::Date);
+make_typed_literal!(
+    chrono::NaiveDateTime,
+    DataType::Timestamp(TimeUnit::Microseconds, None)
+);
+make_typed_literal!(chrono::NaiveTime, DataType::Time(TimeUnit::Microseconds));
+
+make_series_literal!(bool, BooleanArray);
+make_series_literal!(i32, Int32Array);
+make_series_literal!(u32, UInt32Array);
+make_series_literal!(i64, Int64Array);
+make_series_literal!(u64, UInt64Array);
+make_series_literal!(f64, Float64Array);
+make_series_literal!(String, Utf8Array);
+make_series_literal!(&str, Utf8Array);
+
 pub fn lit<L: Literal>(t: L) -> Expr {
     t.lit()
 }
 
+/// Creates a microsecond duration literal, erroring if the duration overflows i64 microseconds.
+pub fn duration_lit(val: chrono::Duration) -> DaftResult<Expr> {
+    let micros = val.num_microseconds().ok_or_else(|| {
+        DaftError::ValueError(format!("Duration literal {val} overflows i64 microseconds"))
+    })?;
+    Ok(Expr::Literal(LiteralValue::Duration(
+        micros,
+        TimeUnit::Microseconds,
+    )))
+}
+
+/// Creates a decimal literal, checking that the precision is within 1..=38 and the scale does not
+/// exceed the precision.
+pub fn decimal_lit(val: i128, precision: u8, scale: u8) -> DaftResult<Expr> {
+    if precision == 0 || precision as usize > DECIMAL128_MAX_PRECISION {
+        return Err(DaftError::ValueError(format!(
+            "Decimal128 precision must be between 1 and {DECIMAL128_MAX_PRECISION}, got {precision}"
+        )));
+    }
+    if scale > precision {
+        return Err(DaftError::ValueError(format!(
+            "Decimal128 scale must not exceed the precision {precision}, got {scale}"
+        )));
+    }
+    Ok(Expr::Literal(LiteralValue::Decimal128 {
+        value: val,
+        precision,
+        scale,
+    }))
+}
+
+/// Creates a struct literal from pairs of field names and literal expressions.
+pub fn lit_struct<S: Into<String>, I: IntoIterator<Item = (S, Expr)>>(
+    fields: I,
+) -> DaftResult<Expr> {
+    let fields = fields
+        .into_iter()
+        .map(|(name, expr)| match expr {
+            Expr::Literal(val) => Ok((name.into(), val)),
+            other => Err(DaftError::ValueError(format!(
+                "Expected struct field to be a literal, but received: {other}"
+            ))),
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(Expr::Literal(LiteralValue::Struct(fields)))
+}
+
 pub fn null_lit() -> Expr {
     Expr::Literal(LiteralValue::Null)
 }
+
+/// Creates a null literal that resolves to the provided [`DataType`] during planning.
+pub fn null_lit_with_dtype(dtype: &DataType) -> Expr {
+    null_lit().cast(dtype)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal_lit, duration_lit, lit, lit_struct, null_lit_with_dtype, LiteralValue};
+    use crate::Expr;
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field, Int64Array, TimeUnit},
+        schema::Schema,
+        IntoSeries,
+    };
+
+    #[test]
+    fn binary_literal_to_series() {
+        let value = LiteralValue::Binary(b"daft".to_vec());
+        let series = value.to_series();
+        assert_eq!(series.len(), 1);
+        assert_eq!(*series.data_type(), DataType::Binary);
+        assert_eq!(series.binary().unwrap().get(0), Some(b"daft".as_slice()));
+    }
+
+    #[test]
+    fn float_literal_eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |value: &LiteralValue| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let nan = LiteralValue::Float64(f64::NAN);
+        let other_nan = LiteralValue::Float64(-f64::NAN);
+        assert_eq!(nan, other_nan);
+        assert_eq!(hash(&nan), hash(&other_nan));
+
+        let zero = LiteralValue::Float64(0.0);
+        let neg_zero = LiteralValue::Float64(-0.0);
+        assert_eq!(zero, neg_zero);
+        assert_eq!(hash(&zero), hash(&neg_zero));
+
+        assert_ne!(LiteralValue::Float64(1.0), LiteralValue::Float32(1.0));
+        assert_eq!(lit(1.5) + lit(2), lit(1.5) + lit(2));
+    }
+
+    #[test]
+    fn literal_from_str() -> DaftResult<()> {
+        assert_eq!(
+            LiteralValue::try_from_str("true", &DataType::Boolean)?,
+            LiteralValue::Boolean(true)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str("2.5", &DataType::Float64)?,
+            LiteralValue::Float64(2.5)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str("-12", &DataType::Int16)?,
+            LiteralValue::Int16(-12)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str("-1.5", &DataType::Decimal128(5, 2))?,
+            LiteralValue::Decimal128 {
+                value: -150,
+                precision: 5,
+                scale: 2
+            }
+        );
+        assert_eq!(
+            LiteralValue::try_from_str("1970-01-11", &DataType::Date)?,
+            LiteralValue::Date(10)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str("00:00:01.5", &DataType::Time(TimeUnit::Microseconds))?,
+            LiteralValue::Time(1_500_000, TimeUnit::Microseconds)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str(
+                "1970-01-01T00:00:01",
+                &DataType::Timestamp(TimeUnit::Milliseconds, None)
+            )?,
+            LiteralValue::Timestamp(1_000, TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(
+            LiteralValue::try_from_str(
+                "1970-01-01T01:00:00+01:00",
+                &DataType::Timestamp(TimeUnit::Seconds, Some("UTC".into()))
+            )?,
+            LiteralValue::Timestamp(0, TimeUnit::Seconds, Some("UTC".into()))
+        );
+        assert!(LiteralValue::try_from_str("abc", &DataType::Int64).is_err());
+        assert_eq!(
+            LiteralValue::try_from_str("-1.235", &DataType::Decimal128(5, 2))?,
+            LiteralValue::Decimal128 {
+                value: -124,
+                precision: 5,
+                scale: 2
+            }
+        );
+        assert!(LiteralValue::try_from_str("1234.5", &DataType::Decimal128(5, 2)).is_err());
+        assert_eq!(
+            LiteralValue::try_from_str("0.50", &DataType::Decimal128(2, 2))?,
+            LiteralValue::Decimal128 {
+                value: 50,
+                precision: 2,
+                scale: 2
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn typed_null_literal() -> DaftResult<()> {
+        let expr = null_lit_with_dtype(&DataType::Int64);
+        let field = expr.to_field(&Schema::empty())?;
+        assert_eq!(field, Field::new("literal", DataType::Int64));
+        Ok(())
+    }
+
+    #[test]
+    fn option_literal() -> DaftResult<()> {
+        assert_eq!(lit(Some(1i64)), lit(1i64));
+
+        let expr = lit(None::<&str>);
+        assert_eq!(expr, null_lit_with_dtype(&DataType::Utf8));
+        assert_eq!(expr.get_type(&Schema::empty())?, DataType::Utf8);
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_literal_to_series() -> DaftResult<()> {
+        let series = LiteralValue::Utf8("a".into()).to_series_broadcast("x", 3)?;
+        assert_eq!(series.name(), "x");
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.utf8()?.get(2), Some("a"));
+
+        let series = LiteralValue::Null.to_series_broadcast("x", 2)?;
+        assert_eq!(series.len(), 2);
+        assert_eq!(*series.data_type(), DataType::Null);
+
+        let value = LiteralValue::Series(Int64Array::from(("x", vec![1i64, 2])).into_series());
+        assert_eq!(value.to_series_broadcast("x", 2)?.len(), 2);
+        assert!(value.to_series_broadcast("x", 3).is_err());
+
+        // List literals are a single value, so they broadcast like any other scalar.
+        let Expr::Literal(value) = lit(vec![1i64, 2]) else {
+            panic!("expected a literal expression");
+        };
+        assert_eq!(value.to_series_broadcast("x", 3)?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn narrow_numeric_literals() {
+        assert_eq!(lit(1i8), Expr::Literal(LiteralValue::Int8(1)));
+        assert_eq!(lit(1u16), Expr::Literal(LiteralValue::UInt16(1)));
+        assert_eq!(LiteralValue::Int16(-3).get_type(), DataType::Int16);
+        assert_eq!(LiteralValue::UInt8(3).get_type(), DataType::UInt8);
+
+        let series = LiteralValue::Float32(1.5).to_series();
+        assert_eq!(*series.data_type(), DataType::Float32);
+        assert_eq!(series.f32().unwrap().get(0), Some(1.5));
+    }
+
+    #[test]
+    fn decimal_literal_to_series() {
+        let value = LiteralValue::Decimal128 {
+            value: -12345,
+            precision: 7,
+            scale: 2,
+        };
+        assert_eq!(value.get_type(), DataType::Decimal128(7, 2));
+        assert_eq!(format!("{value}"), "-123.45");
+
+        let series = value.to_series();
+        assert_eq!(*series.data_type(), DataType::Decimal128(7, 2));
+        assert_eq!(series.decimal128().unwrap().get(0), Some(-12345));
+    }
+
+    #[test]
+    fn decimal_literal_validates_precision_and_scale() -> DaftResult<()> {
+        assert_eq!(
+            decimal_lit(12345, 5, 2)?,
+            Expr::Literal(LiteralValue::Decimal128 {
+                value: 12345,
+                precision: 5,
+                scale: 2
+            })
+        );
+        assert!(decimal_lit(1, 0, 0).is_err());
+        assert!(decimal_lit(1, 39, 0).is_err());
+        assert!(decimal_lit(1, 5, 6).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn vec_literal_to_series() {
+        let Expr::Literal(value) = lit(vec![1i64, 2, 3]) else {
+            panic!("expected a literal expression");
+        };
+        assert_eq!(value.get_type(), DataType::List(Box::new(DataType::Int64)));
+
+        let series = value.to_series();
+        assert_eq!(series.len(), 1);
+        assert_eq!(*series.data_type(), value.get_type());
+        let list = series.list().unwrap().get(0).unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.i64().unwrap().get(2), Some(3));
+    }
+
+    #[test]
+    fn struct_literal_to_series() -> DaftResult<()> {
+        let Expr::Literal(value) = lit_struct([("x", lit(1)), ("y", lit("a"))])? else {
+            panic!("expected a literal expression");
+        };
+        let expected_dtype = DataType::Struct(vec![
+            Field::new("x", DataType::Int32),
+            Field::new("y", DataType::Utf8),
+        ]);
+        assert_eq!(value.get_type(), expected_dtype);
+        assert_eq!(format!("{value}"), "Struct(x: 1, y: \"a\")");
+
+        let series = value.to_series();
+        assert_eq!(series.len(), 1);
+        assert_eq!(*series.data_type(), expected_dtype);
+
+        assert!(lit_struct([("x", crate::col("x"))]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn temporal_literals_from_chrono() {
+        let date = chrono::NaiveDate::from_ymd_opt(1970, 1, 11).unwrap();
+        assert_eq!(lit(date), Expr::Literal(LiteralValue::Date(10)));
+
+        let datetime = date.and_hms_opt(0, 0, 1).unwrap();
+        let expected = LiteralValue::Timestamp(
+            (10 * 24 * 60 * 60 + 1) * 1_000_000,
+            TimeUnit::Microseconds,
+            None,
+        );
+        assert_eq!(lit(datetime), Expr::Literal(expected.clone()));
+        assert_eq!(
+            expected.get_type(),
+            DataType::Timestamp(TimeUnit::Microseconds, None)
+        );
+        assert_eq!(
+            *expected.to_series().data_type(),
+            DataType::Timestamp(TimeUnit::Microseconds, None)
+        );
+
+        let time = chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        let expected = LiteralValue::Time((9 * 60 + 30) * 60 * 1_000_000, TimeUnit::Microseconds);
+        assert_eq!(lit(time), Expr::Literal(expected.clone()));
+        assert_eq!(format!("{expected}"), "09:30:00");
+        assert_eq!(
+            *expected.to_series().data_type(),
+            DataType::Time(TimeUnit::Microseconds)
+        );
+
+        let duration = duration_lit(chrono::Duration::hours(3)).unwrap();
+        let expected = LiteralValue::Duration(3 * 60 * 60 * 1_000_000, TimeUnit::Microseconds);
+        assert_eq!(duration, Expr::Literal(expected.clone()));
+        assert_eq!(
+            *expected.to_series().data_type(),
+            DataType::Duration(TimeUnit::Microseconds)
+        );
+        assert!(duration_lit(chrono::Duration::max_value()).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use common_treenode::{Transformed, TreeNode, VisitRecursion};
 
-use crate::Operator;
+use crate::{functions::FunctionExpr, Operator};
 
 use super::expr::Expr;
 
@@ -31,10 +31,51 @@ pub fn requires_computation(e: &Expr) -> bool {
         | Expr::IsNull(..)
         | Expr::NotNull(..)
         | Expr::IsIn { .. }
-        | Expr::IfElse { .. } => true,
+        | Expr::Between { .. }
+        | Expr::IfElse { .. }
+        | Expr::Window(..) => true,
     }
 }
 
+/// Returns whether this expression evaluates anything over a window, including window functions
+/// that run over all rows on their own. Such expressions depend on every row of their partition, so
+/// operators must not be pushed down or split underneath them.
+pub fn contains_window(e: &Expr) -> bool {
+    let mut found = false;
+    e.apply(&mut |expr| {
+        if matches!(expr, Expr::Window(..)) || expr.is_window_function() {
+            found = true;
+            Ok(VisitRecursion::Stop)
+        } else {
+            Ok(VisitRecursion::Continue)
+        }
+    })
+    .expect("Error occurred when visiting for window expressions");
+    found
+}
+
+/// Returns whether evaluating this expression twice produces the same values, i.e. whether it
+/// doesn't call any non-deterministic generators like `uuid4`, so that it can be evaluated once
+/// and shared by every expression that contains it.
+pub fn is_deterministic(e: &Expr) -> bool {
+    let mut deterministic = true;
+    e.apply(&mut |expr| {
+        if let Expr::Function {
+            func: FunctionExpr::Generator(generator),
+            ..
+        } = expr
+            && !generator.is_deterministic()
+        {
+            deterministic = false;
+            Ok(VisitRecursion::Stop)
+        } else {
+            Ok(VisitRecursion::Continue)
+        }
+    })
+    .expect("Error occurred when visiting for non-deterministic expressions");
+    deterministic
+}
+
 pub fn replace_columns_with_expressions(expr: &Expr, replace_map: &HashMap<String, Expr>) -> Expr {
     expr.clone()
         .transform(&|e| {
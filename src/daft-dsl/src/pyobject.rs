@@ -77,6 +77,25 @@ impl<'de> Visitor<'de> for DaftPyObjectVisitor {
             })
         })
     }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(&v)
+    }
+
+    // Self-describing formats such as JSON serialize bytes as a sequence of integers.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            v.push(byte);
+        }
+        self.visit_bytes(&v)
+    }
 }
 
 impl<'de> Deserialize<'de> for DaftPyObject {
@@ -8,10 +8,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{functions, optimization, Expr, LiteralValue};
 use daft_core::{
+    closed_interval::ClosedInterval,
     count_mode::CountMode,
     datatypes::ImageFormat,
-    impl_bincode_py_state_serialization,
     python::{datatype::PyDataType, field::PyField, schema::PySchema},
+    DstResolution, RoundMode,
 };
 
 use common_io_config::python::IOConfig as PyIOConfig;
@@ -40,12 +41,29 @@ pub fn time_lit(item: i64, tu: PyTimeUnit) -> PyResult<PyExpr> {
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn duration_lit(val: i64, tu: PyTimeUnit) -> PyResult<PyExpr> {
+    let expr = Expr::Literal(LiteralValue::Duration(val, tu.timeunit));
+    Ok(expr.into())
+}
+
+#[pyfunction]
+pub fn interval_lit(months: i32, days: i32, nanoseconds: i64) -> PyResult<PyExpr> {
+    let expr = Expr::Literal(LiteralValue::Interval(months, days, nanoseconds));
+    Ok(expr.into())
+}
+
 #[pyfunction]
 pub fn timestamp_lit(val: i64, tu: PyTimeUnit, tz: Option<String>) -> PyResult<PyExpr> {
     let expr = Expr::Literal(LiteralValue::Timestamp(val, tu.timeunit, tz));
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn decimal_lit(val: i128, precision: u8, scale: u8) -> PyResult<PyExpr> {
+    Ok(crate::decimal_lit(val, precision, scale)?.into())
+}
+
 #[pyfunction]
 pub fn series_lit(series: PySeries) -> PyResult<PyExpr> {
     let expr = Expr::Literal(LiteralValue::Series(series.series));
@@ -119,6 +137,43 @@ pub struct PyExpr {
     pub expr: crate::Expr,
 }
 
+#[pyfunction]
+pub fn row_number() -> PyResult<PyExpr> {
+    Ok(functions::ranking::row_number().into())
+}
+
+#[pyfunction]
+pub fn rank() -> PyResult<PyExpr> {
+    Ok(functions::ranking::rank().into())
+}
+
+#[pyfunction]
+pub fn dense_rank() -> PyResult<PyExpr> {
+    Ok(functions::ranking::dense_rank().into())
+}
+
+#[pyfunction]
+pub fn rand(seed: Option<u64>) -> PyResult<PyExpr> {
+    Ok(functions::generator::rand(seed).into())
+}
+
+#[pyfunction]
+pub fn uuid4() -> PyResult<PyExpr> {
+    Ok(functions::generator::uuid4().into())
+}
+
+#[pyfunction]
+pub fn monotonically_increasing_id() -> PyResult<PyExpr> {
+    Ok(functions::generator::monotonically_increasing_id().into())
+}
+
+#[pyfunction]
+pub fn coalesce(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    use functions::null::coalesce;
+    let exprs = exprs.into_iter().map(|e| e.expr).collect::<Vec<_>>();
+    Ok(coalesce(exprs.as_slice()).into())
+}
+
 #[pyfunction]
 pub fn eq(expr1: &PyExpr, expr2: &PyExpr) -> PyResult<bool> {
     Ok(expr1.expr == expr2.expr)
@@ -160,6 +215,96 @@ impl PyExpr {
         Ok(floor(&self.expr).into())
     }
 
+    pub fn round(&self, decimals: i32, mode: RoundMode) -> PyResult<Self> {
+        use functions::numeric::round;
+        Ok(round(&self.expr, decimals, mode).into())
+    }
+
+    pub fn sqrt(&self) -> PyResult<Self> {
+        use functions::numeric::sqrt;
+        Ok(sqrt(&self.expr).into())
+    }
+
+    pub fn exp(&self) -> PyResult<Self> {
+        use functions::numeric::exp;
+        Ok(exp(&self.expr).into())
+    }
+
+    pub fn ln(&self) -> PyResult<Self> {
+        use functions::numeric::ln;
+        Ok(ln(&self.expr).into())
+    }
+
+    pub fn sin(&self) -> PyResult<Self> {
+        use functions::numeric::sin;
+        Ok(sin(&self.expr).into())
+    }
+
+    pub fn cos(&self) -> PyResult<Self> {
+        use functions::numeric::cos;
+        Ok(cos(&self.expr).into())
+    }
+
+    pub fn tan(&self) -> PyResult<Self> {
+        use functions::numeric::tan;
+        Ok(tan(&self.expr).into())
+    }
+
+    pub fn pow(&self, other: &Self) -> PyResult<Self> {
+        use functions::numeric::pow;
+        Ok(pow(&self.expr, &other.expr).into())
+    }
+
+    pub fn log(&self, other: &Self) -> PyResult<Self> {
+        use functions::numeric::log;
+        Ok(log(&self.expr, &other.expr).into())
+    }
+
+    pub fn atan2(&self, other: &Self) -> PyResult<Self> {
+        use functions::numeric::atan2;
+        Ok(atan2(&self.expr, &other.expr).into())
+    }
+
+    pub fn sign(&self) -> PyResult<Self> {
+        use functions::numeric::sign;
+        Ok(sign(&self.expr).into())
+    }
+
+    pub fn clip(&self, lower: &Self, upper: &Self) -> PyResult<Self> {
+        use functions::numeric::clip;
+        Ok(clip(&self.expr, &lower.expr, &upper.expr).into())
+    }
+
+    pub fn cumsum(&self) -> PyResult<Self> {
+        use functions::cumulative::cumsum;
+        Ok(cumsum(&self.expr).into())
+    }
+
+    pub fn cummax(&self) -> PyResult<Self> {
+        use functions::cumulative::cummax;
+        Ok(cummax(&self.expr).into())
+    }
+
+    pub fn cummin(&self) -> PyResult<Self> {
+        use functions::cumulative::cummin;
+        Ok(cummin(&self.expr).into())
+    }
+
+    pub fn cumcount(&self) -> PyResult<Self> {
+        use functions::cumulative::cumcount;
+        Ok(cumcount(&self.expr).into())
+    }
+
+    pub fn lead(&self, n: u64, default: &Self) -> PyResult<Self> {
+        use functions::offset::lead;
+        Ok(lead(&self.expr, n, &default.expr).into())
+    }
+
+    pub fn lag(&self, n: u64, default: &Self) -> PyResult<Self> {
+        use functions::offset::lag;
+        Ok(lag(&self.expr, n, &default.expr).into())
+    }
+
     pub fn if_else(&self, if_true: &Self, if_false: &Self) -> PyResult<Self> {
         Ok(self.expr.if_else(&if_true.expr, &if_false.expr).into())
     }
@@ -188,6 +333,39 @@ impl PyExpr {
         Ok(self.expr.any_value(ignore_nulls).into())
     }
 
+    pub fn first(&self) -> PyResult<Self> {
+        Ok(self.expr.first().into())
+    }
+
+    pub fn last(&self) -> PyResult<Self> {
+        Ok(self.expr.last().into())
+    }
+
+    pub fn approx_count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.approx_count_distinct().into())
+    }
+
+    pub fn count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.count_distinct().into())
+    }
+
+    pub fn approx_percentile(&self, percentile: f64) -> PyResult<Self> {
+        if !(0.0..=1.0).contains(&percentile) {
+            return Err(PyValueError::new_err(format!(
+                "Expected percentile to be between 0 and 1, but received {percentile}"
+            )));
+        }
+        Ok(self.expr.approx_percentile(percentile).into())
+    }
+
+    pub fn median(&self) -> PyResult<Self> {
+        Ok(self.expr.median().into())
+    }
+
+    pub fn str_join(&self, separator: &str) -> PyResult<Self> {
+        Ok(self.expr.str_join(separator).into())
+    }
+
     pub fn agg_list(&self) -> PyResult<Self> {
         Ok(self.expr.agg_list().into())
     }
@@ -196,6 +374,22 @@ impl PyExpr {
         Ok(self.expr.agg_concat().into())
     }
 
+    pub fn agg_filter(&self, predicate: &Self) -> PyResult<Self> {
+        Ok(self.expr.filter(&predicate.expr)?.into())
+    }
+
+    pub fn over(
+        &self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let partition_by = partition_by.into_iter().map(|e| e.expr).collect::<Vec<_>>();
+        let order_by = order_by.into_iter().map(|e| e.expr).collect::<Vec<_>>();
+        let spec = crate::Window::partition_by(&partition_by).order_by(&order_by, &descending);
+        Ok(self.expr.over(&spec).into())
+    }
+
     pub fn explode(&self) -> PyResult<Self> {
         use functions::list::explode;
         Ok(explode(&self.expr).into())
@@ -242,6 +436,30 @@ impl PyExpr {
         Ok(crate::binary_op(crate::Operator::Xor, &self.expr, &other.expr).into())
     }
 
+    pub fn bitwise_and(&self, other: &Self) -> PyResult<Self> {
+        Ok(crate::binary_op(crate::Operator::BitwiseAnd, &self.expr, &other.expr).into())
+    }
+
+    pub fn bitwise_or(&self, other: &Self) -> PyResult<Self> {
+        Ok(crate::binary_op(crate::Operator::BitwiseOr, &self.expr, &other.expr).into())
+    }
+
+    pub fn bitwise_xor(&self, other: &Self) -> PyResult<Self> {
+        Ok(crate::binary_op(crate::Operator::BitwiseXor, &self.expr, &other.expr).into())
+    }
+
+    pub fn __lshift__(&self, other: &Self) -> PyResult<Self> {
+        Ok(crate::binary_op(crate::Operator::ShiftLeft, &self.expr, &other.expr).into())
+    }
+
+    pub fn __rshift__(&self, other: &Self) -> PyResult<Self> {
+        Ok(crate::binary_op(crate::Operator::ShiftRight, &self.expr, &other.expr).into())
+    }
+
+    pub fn eq_null_safe(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.expr.eq_null_safe(&other.expr).into())
+    }
+
     pub fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<Self> {
         use crate::{binary_op, Operator};
         match op {
@@ -270,6 +488,10 @@ impl PyExpr {
         Ok(self.expr.is_in(&other.expr).into())
     }
 
+    pub fn between(&self, lower: &Self, upper: &Self, closed: ClosedInterval) -> PyResult<Self> {
+        Ok(self.expr.between(&lower.expr, &upper.expr, closed).into())
+    }
+
     pub fn name(&self) -> PyResult<&str> {
         Ok(self.expr.name()?)
     }
@@ -293,6 +515,29 @@ impl PyExpr {
         Ok(is_nan(&self.expr).into())
     }
 
+    pub fn is_inf(&self) -> PyResult<Self> {
+        use functions::float::is_inf;
+        Ok(is_inf(&self.expr).into())
+    }
+
+    pub fn not_nan(&self) -> PyResult<Self> {
+        use functions::float::not_nan;
+        Ok(not_nan(&self.expr).into())
+    }
+
+    pub fn fill_nan(&self, fill_value: &Self) -> PyResult<Self> {
+        use functions::float::fill_nan;
+        Ok(fill_nan(&self.expr, &fill_value.expr).into())
+    }
+
+    pub fn hash(&self, seed: Option<u64>) -> PyResult<Self> {
+        Ok(self.expr.hash_with_seed(seed).into())
+    }
+
+    pub fn fill_null(&self, fill_value: &Self) -> PyResult<Self> {
+        Ok(self.expr.fill_null(&fill_value.expr).into())
+    }
+
     pub fn dt_date(&self) -> PyResult<Self> {
         use functions::temporal::date;
         Ok(date(&self.expr).into())
@@ -308,6 +553,11 @@ impl PyExpr {
         Ok(hour(&self.expr).into())
     }
 
+    pub fn dt_minute(&self) -> PyResult<Self> {
+        use functions::temporal::minute;
+        Ok(minute(&self.expr).into())
+    }
+
     pub fn dt_month(&self) -> PyResult<Self> {
         use functions::temporal::month;
         Ok(month(&self.expr).into())
@@ -323,6 +573,21 @@ impl PyExpr {
         Ok(day_of_week(&self.expr).into())
     }
 
+    pub fn dt_tz_localize(
+        &self,
+        tz: &str,
+        ambiguous: DstResolution,
+        nonexistent: DstResolution,
+    ) -> PyResult<Self> {
+        use functions::temporal::tz_localize;
+        Ok(tz_localize(&self.expr, tz, ambiguous, nonexistent).into())
+    }
+
+    pub fn dt_tz_convert(&self, tz: &str) -> PyResult<Self> {
+        use functions::temporal::tz_convert;
+        Ok(tz_convert(&self.expr, tz).into())
+    }
+
     pub fn utf8_endswith(&self, pattern: &Self) -> PyResult<Self> {
         use crate::functions::utf8::endswith;
         Ok(endswith(&self.expr, &pattern.expr).into())
@@ -338,9 +603,24 @@ impl PyExpr {
         Ok(contains(&self.expr, &pattern.expr).into())
     }
 
-    pub fn utf8_split(&self, pattern: &Self) -> PyResult<Self> {
+    pub fn utf8_split(&self, pattern: &Self, regex: bool) -> PyResult<Self> {
         use crate::functions::utf8::split;
-        Ok(split(&self.expr, &pattern.expr).into())
+        Ok(split(&self.expr, &pattern.expr, regex).into())
+    }
+
+    pub fn utf8_match(&self, pattern: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::match_;
+        Ok(match_(&self.expr, &pattern.expr).into())
+    }
+
+    pub fn utf8_extract(&self, pattern: &Self, index: usize) -> PyResult<Self> {
+        use crate::functions::utf8::extract;
+        Ok(extract(&self.expr, &pattern.expr, index).into())
+    }
+
+    pub fn utf8_replace(&self, pattern: &Self, replacement: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::replace;
+        Ok(replace(&self.expr, &pattern.expr, &replacement.expr).into())
     }
 
     pub fn utf8_length(&self) -> PyResult<Self> {
@@ -348,6 +628,11 @@ impl PyExpr {
         Ok(length(&self.expr).into())
     }
 
+    pub fn utf8_length_chars(&self) -> PyResult<Self> {
+        use crate::functions::utf8::length_chars;
+        Ok(length_chars(&self.expr).into())
+    }
+
     pub fn utf8_lower(&self) -> PyResult<Self> {
         use crate::functions::utf8::lower;
         Ok(lower(&self.expr).into())
@@ -358,6 +643,11 @@ impl PyExpr {
         Ok(upper(&self.expr).into())
     }
 
+    pub fn utf8_capitalize(&self) -> PyResult<Self> {
+        use crate::functions::utf8::capitalize;
+        Ok(capitalize(&self.expr).into())
+    }
+
     pub fn utf8_lstrip(&self) -> PyResult<Self> {
         use crate::functions::utf8::lstrip;
         Ok(lstrip(&self.expr).into())
@@ -373,6 +663,11 @@ impl PyExpr {
         Ok(reverse(&self.expr).into())
     }
 
+    pub fn utf8_substr(&self, start: i64, length: Option<usize>) -> PyResult<Self> {
+        use crate::functions::utf8::substr;
+        Ok(substr(&self.expr, start, length).into())
+    }
+
     pub fn image_decode(&self) -> PyResult<Self> {
         use crate::functions::image::decode;
         Ok(decode(&self.expr).into())
@@ -418,11 +713,41 @@ impl PyExpr {
         Ok(get(&self.expr, &idx.expr, &default.expr).into())
     }
 
+    pub fn list_slice(&self, start: &Self, end: &Self) -> PyResult<Self> {
+        use crate::functions::list::slice;
+        Ok(slice(&self.expr, &start.expr, &end.expr).into())
+    }
+
+    pub fn list_contains(&self, item: &Self) -> PyResult<Self> {
+        use crate::functions::list::contains;
+        Ok(contains(&self.expr, &item.expr).into())
+    }
+
     pub fn struct_get(&self, name: &str) -> PyResult<Self> {
         use crate::functions::struct_::get;
         Ok(get(&self.expr, name).into())
     }
 
+    pub fn map_get(&self, key: &Self) -> PyResult<Self> {
+        use crate::functions::map::get;
+        Ok(get(&self.expr, &key.expr).into())
+    }
+
+    pub fn embedding_dot(&self, other: &Self) -> PyResult<Self> {
+        use crate::functions::embedding::dot;
+        Ok(dot(&self.expr, &other.expr).into())
+    }
+
+    pub fn embedding_cosine_similarity(&self, other: &Self) -> PyResult<Self> {
+        use crate::functions::embedding::cosine_similarity;
+        Ok(cosine_similarity(&self.expr, &other.expr).into())
+    }
+
+    pub fn json_query(&self, query: &str) -> PyResult<Self> {
+        use crate::functions::json::query as json_query;
+        Ok(json_query(&self.expr, query).into())
+    }
+
     pub fn partitioning_days(&self) -> PyResult<Self> {
         use crate::functions::partitioning::days;
         Ok(days(self.expr.clone()).into())
@@ -477,7 +802,22 @@ impl PyExpr {
     }
 }
 
-impl_bincode_py_state_serialization!(PyExpr);
+#[pymethods]
+impl PyExpr {
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        Ok((
+            Self::type_object(py)
+                .getattr("_from_serialized")?
+                .to_object(py),
+            (PyBytes::new(py, &self.expr.to_bincode()?).to_object(py),).to_object(py),
+        ))
+    }
+
+    #[staticmethod]
+    pub fn _from_serialized(serialized: &PyBytes) -> PyResult<Self> {
+        Ok(Expr::from_bincode(serialized.as_bytes())?.into())
+    }
+}
 
 impl From<crate::Expr> for PyExpr {
     fn from(value: crate::Expr) -> Self {
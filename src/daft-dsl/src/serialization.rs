@@ -0,0 +1,162 @@
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+
+use crate::Expr;
+
+/// Version of the serialized [`Expr`] format.
+///
+/// Bump this whenever a change to the `Expr`/`AggExpr`/`LiteralValue` hierarchy changes how
+/// existing expressions are serialized, so that payloads from a mismatched driver/worker are
+/// rejected up front instead of being decoded into the wrong expression.
+pub const EXPR_SERIALIZATION_VERSION: u32 = 2;
+
+#[derive(Serialize)]
+struct VersionedExprRef<'a> {
+    version: u32,
+    expr: &'a Expr,
+}
+
+#[derive(Deserialize)]
+struct VersionedExpr {
+    version: u32,
+    expr: Expr,
+}
+
+/// Only the leading version field, so that the version can be checked before decoding the rest.
+#[derive(Deserialize)]
+struct VersionHeader {
+    version: u32,
+}
+
+fn check_version(version: u32) -> DaftResult<()> {
+    if version != EXPR_SERIALIZATION_VERSION {
+        return Err(DaftError::ValueError(format!(
+            "Expected serialized expression of version {EXPR_SERIALIZATION_VERSION}, but received version {version}"
+        )));
+    }
+    Ok(())
+}
+
+fn bincode_error(error: bincode::Error) -> DaftError {
+    DaftError::ValueError(format!("Failed to (de)serialize expression: {error}"))
+}
+
+impl Expr {
+    fn versioned(&self) -> VersionedExprRef {
+        VersionedExprRef {
+            version: EXPR_SERIALIZATION_VERSION,
+            expr: self,
+        }
+    }
+
+    /// Serializes this expression to versioned JSON.
+    pub fn to_json(&self) -> DaftResult<String> {
+        Ok(serde_json::to_string(&self.versioned())?)
+    }
+
+    /// Deserializes an expression from JSON produced by [`Expr::to_json`].
+    pub fn from_json(json: &str) -> DaftResult<Self> {
+        check_version(serde_json::from_str::<VersionHeader>(json)?.version)?;
+        Ok(serde_json::from_str::<VersionedExpr>(json)?.expr)
+    }
+
+    /// Serializes this expression to versioned bincode.
+    pub fn to_bincode(&self) -> DaftResult<Vec<u8>> {
+        bincode::serialize(&self.versioned()).map_err(bincode_error)
+    }
+
+    /// Deserializes an expression from bincode produced by [`Expr::to_bincode`].
+    pub fn from_bincode(bytes: &[u8]) -> DaftResult<Self> {
+        // Bincode encodes struct fields in order, so the version is a prefix of the payload.
+        check_version(
+            bincode::deserialize::<VersionHeader>(bytes)
+                .map_err(bincode_error)?
+                .version,
+        )?;
+        let versioned: VersionedExpr = bincode::deserialize(bytes).map_err(bincode_error)?;
+        Ok(versioned.expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Int64Array, TimeUnit},
+        ClosedInterval, CountMode, DataType, IntoSeries,
+    };
+
+    use crate::{
+        col, decimal_lit, functions::utf8::lower, lit, lit_struct, null_lit_with_dtype, when, Expr,
+        LiteralValue, Window,
+    };
+
+    use super::{VersionedExprRef, EXPR_SERIALIZATION_VERSION};
+
+    fn exprs() -> DaftResult<Vec<Expr>> {
+        Ok(vec![
+            col("a"),
+            lit(true),
+            lit("a string"),
+            lit(vec![1u8, 2, 3]),
+            lit(1) + lit(2i64) * lit(3.5),
+            lit(1.5f32),
+            decimal_lit(12345, 10, 2)?,
+            Expr::Literal(LiteralValue::Date(19000)),
+            Expr::Literal(LiteralValue::Timestamp(
+                1_700_000_000,
+                TimeUnit::Seconds,
+                Some("UTC".to_string()),
+            )),
+            Expr::Literal(LiteralValue::Duration(5, TimeUnit::Milliseconds)),
+            Expr::Literal(LiteralValue::Interval(1, 2, 3_000_000_000)),
+            lit(Int64Array::from(("list", vec![1, 2, 3])).into_series()),
+            lit_struct([("x", lit(1)), ("y", lit("b"))])?,
+            null_lit_with_dtype(&DataType::Utf8),
+            col("a").cast(&DataType::Float64).alias("b"),
+            col("a").not().is_null().and(&col("b").not_null()),
+            col("a").is_in(&lit(Int64Array::from(("", vec![1, 2])).into_series())),
+            col("a").between(&lit(1), &lit(10), ClosedInterval::Both),
+            when(&col("a").gt(&lit(1)))
+                .then(&lit("big"))
+                .otherwise(&lit("small")),
+            lower(&col("s")),
+            col("a").sum(),
+            col("a").mean().alias("mean"),
+            col("a").count(CountMode::Valid),
+            col("a").approx_percentile(0.99),
+            col("a").agg_list(),
+            col("a")
+                .sum()
+                .over(&Window::partition_by(&[col("k")]).order_by(&[col("t")], &[true])),
+        ])
+    }
+
+    #[test]
+    fn json_roundtrip() -> DaftResult<()> {
+        for expr in exprs()? {
+            assert_eq!(Expr::from_json(&expr.to_json()?)?, expr);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bincode_roundtrip() -> DaftResult<()> {
+        for expr in exprs()? {
+            assert_eq!(Expr::from_bincode(&expr.to_bincode()?)?, expr);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn reject_mismatched_version() -> DaftResult<()> {
+        let expr = col("a") + lit(1);
+        let mismatched = VersionedExprRef {
+            version: EXPR_SERIALIZATION_VERSION + 1,
+            expr: &expr,
+        };
+        assert!(Expr::from_json(&serde_json::to_string(&mismatched)?).is_err());
+        assert!(Expr::from_bincode(&bincode::serialize(&mismatched).unwrap()).is_err());
+        Ok(())
+    }
+}
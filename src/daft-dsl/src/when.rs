@@ -0,0 +1,83 @@
+use crate::Expr;
+
+/// Builder for multi-branch conditionals, e.g.
+/// `when(&cond_a).then(&a).when(&cond_b).then(&b).otherwise(&c)`.
+///
+/// Branches are compiled into a chain of nested `Expr::IfElse` nodes, so the result type is the
+/// supertype of every `then` and `otherwise` branch, and the result name is that of the first
+/// `then` branch.
+pub struct When {
+    branches: Vec<(Expr, Expr)>,
+    condition: Expr,
+}
+
+pub struct Then {
+    branches: Vec<(Expr, Expr)>,
+}
+
+pub fn when(condition: &Expr) -> When {
+    When {
+        branches: vec![],
+        condition: condition.clone(),
+    }
+}
+
+impl When {
+    pub fn then(self, value: &Expr) -> Then {
+        let mut branches = self.branches;
+        branches.push((self.condition, value.clone()));
+        Then { branches }
+    }
+}
+
+impl Then {
+    pub fn when(self, condition: &Expr) -> When {
+        When {
+            branches: self.branches,
+            condition: condition.clone(),
+        }
+    }
+
+    pub fn otherwise(self, value: &Expr) -> Expr {
+        self.branches
+            .into_iter()
+            .rev()
+            .fold(value.clone(), |if_false, (condition, if_true)| {
+                condition.if_else(&if_true, &if_false)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, lit};
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{DataType, Field},
+        schema::Schema,
+    };
+
+    #[test]
+    fn check_when_then_otherwise() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Boolean),
+            Field::new("b", DataType::Boolean),
+            Field::new("x", DataType::Int32),
+        ])?;
+        let z = when(&col("a"))
+            .then(&col("x"))
+            .when(&col("b"))
+            .then(&lit(1i64))
+            .otherwise(&lit(0.5));
+        assert_eq!(
+            z,
+            col("a").if_else(&col("x"), &col("b").if_else(&lit(1i64), &lit(0.5)))
+        );
+        assert_eq!(z.to_field(&schema)?, Field::new("x", DataType::Float64));
+
+        let z = when(&col("x")).then(&lit(1)).otherwise(&lit(0));
+        assert!(z.to_field(&schema).is_err());
+        Ok(())
+    }
+}
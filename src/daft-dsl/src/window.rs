@@ -0,0 +1,137 @@
+use std::fmt::{Display, Formatter, Result};
+
+use daft_core::schema::Schema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Expr, ExprRef};
+
+/// Specification of the window that an aggregation is evaluated over, e.g.
+/// `col("x").sum().over(&Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]))`.
+///
+/// Rows are split into partitions by the `partition_by` expressions. Without `order_by`, every
+/// row receives the aggregate of its whole partition. With `order_by`, rows in each partition are
+/// sorted and every row receives the aggregate of the rows up to and including itself, i.e. a
+/// running aggregate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub struct WindowSpec {
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+}
+
+/// Entrypoint for building a [`WindowSpec`].
+pub struct Window;
+
+impl Window {
+    pub fn partition_by(exprs: &[Expr]) -> WindowSpec {
+        WindowSpec::default().partition_by(exprs)
+    }
+
+    pub fn order_by(exprs: &[Expr], descending: &[bool]) -> WindowSpec {
+        WindowSpec::default().order_by(exprs, descending)
+    }
+}
+
+impl WindowSpec {
+    pub fn partition_by(mut self, exprs: &[Expr]) -> Self {
+        self.partition_by = exprs.iter().map(|e| e.clone().into()).collect();
+        self
+    }
+
+    pub fn order_by(mut self, exprs: &[Expr], descending: &[bool]) -> Self {
+        self.order_by = exprs.iter().map(|e| e.clone().into()).collect();
+        self.descending = descending.to_vec();
+        self
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        self.partition_by
+            .iter()
+            .chain(self.order_by.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> String {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.semantic_id(schema).id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter())
+            .map(|(e, desc)| format!("{}:{}", e.semantic_id(schema).id, desc))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("partition_by=[{partition_by}],order_by=[{order_by}]")
+    }
+
+    /// Rebuilds this spec with new partition_by and order_by expressions, in the order returned
+    /// by [`WindowSpec::children`].
+    pub fn with_children(&self, children: Vec<ExprRef>) -> Self {
+        let (partition_by, order_by) = children.split_at(self.partition_by.len());
+        Self {
+            partition_by: partition_by.to_vec(),
+            order_by: order_by.to_vec(),
+            descending: self.descending.clone(),
+        }
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter())
+            .map(|(e, desc)| format!("{e} {}", if *desc { "desc" } else { "asc" }))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "partition_by=[{partition_by}], order_by=[{order_by}]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::col;
+    use common_error::DaftResult;
+    use daft_core::datatypes::{DataType, Field};
+
+    #[test]
+    fn check_window_type() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("k", DataType::Utf8),
+            Field::new("t", DataType::Int64),
+            Field::new("x", DataType::Int32),
+        ])?;
+        let spec = Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]);
+        let z = col("x").mean().over(&spec);
+        assert_eq!(z.to_field(&schema)?, Field::new("x", DataType::Float64));
+        let expected_children: Vec<ExprRef> =
+            vec![col("x").mean().into(), col("k").into(), col("t").into()];
+        assert_eq!(z.children(), expected_children);
+
+        assert!(col("x").over(&spec).to_field(&schema).is_err());
+        assert!(col("x")
+            .sum()
+            .over(&Window::partition_by(&[col("missing")]))
+            .to_field(&schema)
+            .is_err());
+        assert!(col("x")
+            .sum()
+            .over(&Window::order_by(&[col("t")], &[]))
+            .to_field(&schema)
+            .is_err());
+        Ok(())
+    }
+}
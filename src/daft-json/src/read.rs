@@ -149,7 +149,7 @@ fn tables_concat(mut tables: Vec<Table>) -> DaftResult<Table> {
 
     let first_schema = &first_table.schema;
     for tab in tables.iter().skip(1) {
-        if tab.schema.as_ref() != first_schema.as_ref() {
+        if !tab.schema.eq_ignoring_nullability(first_schema) {
             return Err(DaftError::SchemaMismatch(format!(
                 "Table concat requires all schemas to match, {} vs {}",
                 first_schema, tab.schema
@@ -326,7 +326,7 @@ impl MicroPartition {
     /// Create a new "loaded" MicroPartition using the materialized tables
     ///
     /// Schema invariants:
-    /// 1. `schema` must match each Table's schema exactly
+    /// 1. `schema` must match each Table's schema exactly, apart from nullability, which Tables don't infer
     /// 2. If `statistics` is provided, each Loaded column statistic must be castable to the corresponding column in the MicroPartition's schema
     pub fn new_loaded(
         schema: SchemaRef,
@@ -336,7 +336,7 @@ impl MicroPartition {
         // Check and validate invariants with asserts
         for table in tables.iter() {
             assert!(
-                table.schema == schema,
+                table.schema.eq_ignoring_nullability(&schema),
                 "Loaded MicroPartition's tables' schema must match its own schema exactly"
             );
         }
@@ -620,7 +620,7 @@ pub(crate) fn read_csv_into_micropartition(
             let unioned_schema = tables
                 .iter()
                 .map(|tbl| tbl.schema.clone())
-                .try_reduce(|s1, s2| s1.union(s2.as_ref()).map(Arc::new))?
+                .reduce(|s1, s2| Arc::new(s1.merge(s2.as_ref())))
                 .unwrap();
             let tables = tables
                 .into_iter()
@@ -669,7 +669,7 @@ pub(crate) fn read_json_into_micropartition(
             let unioned_schema = tables
                 .iter()
                 .map(|tbl| tbl.schema.clone())
-                .try_reduce(|s1, s2| s1.union(s2.as_ref()).map(Arc::new))?
+                .reduce(|s1, s2| Arc::new(s1.merge(s2.as_ref())))
                 .unwrap();
             let tables = tables
                 .into_iter()
@@ -727,7 +727,7 @@ pub(crate) fn read_parquet_into_micropartition(
         let unioned_schema = all_tables
             .iter()
             .map(|t| t.schema.clone())
-            .try_reduce(|l, r| DaftResult::Ok(l.union(&r)?.into()))?;
+            .reduce(|l, r| l.merge(&r).into());
         let full_daft_schema = unioned_schema.expect("we need at least 1 schema");
         // Hack to avoid to owned schema
         let full_daft_schema = Schema {
@@ -783,7 +783,7 @@ pub(crate) fn read_parquet_into_micropartition(
     };
 
     // Union and prune the schema using the specified `columns`
-    let unioned_schema = schemas.into_iter().try_reduce(|l, r| l.union(&r))?;
+    let unioned_schema = schemas.into_iter().reduce(|l, r| l.merge(&r));
     let full_daft_schema = unioned_schema.expect("we need at least 1 schema");
     let pruned_daft_schema = prune_fields_from_schema(full_daft_schema, columns)?;
 
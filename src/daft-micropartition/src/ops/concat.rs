@@ -19,7 +19,7 @@ impl MicroPartition {
 
         let first_schema = first_table.schema.as_ref();
         for tab in mps.iter().skip(1) {
-            if tab.schema.as_ref() != first_schema {
+            if !tab.schema.eq_ignoring_nullability(first_schema) {
                 return Err(DaftError::SchemaMismatch(format!(
                     "MicroPartition concat requires all schemas to match, {} vs {}",
                     first_schema, tab.schema
@@ -2,7 +2,7 @@ use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
 use daft_core::schema::Schema;
-use daft_dsl::Expr;
+use daft_dsl::{optimization::contains_window, Expr};
 use daft_io::IOStatsContext;
 use snafu::ResultExt;
 
@@ -34,7 +34,12 @@ impl MicroPartition {
         let io_stats = IOStatsContext::new("MicroPartition::eval_expression_list");
 
         let expected_schema = infer_schema(exprs, &self.schema)?;
-        let tables = self.tables_or_read(io_stats)?;
+        // Window expressions need to see every row of the partition at once.
+        let tables = if exprs.iter().any(contains_window) {
+            self.concat_or_get(io_stats)?
+        } else {
+            self.tables_or_read(io_stats)?
+        };
         let evaluated_tables = tables
             .iter()
             .map(|t| t.eval_expression_list(exprs))
@@ -12,9 +12,14 @@ use crate::{
 };
 use common_error::{DaftError, DaftResult};
 use common_io_config::IOConfig;
+use common_treenode::{Transformed, TreeNode};
 use daft_core::schema::Schema;
 use daft_core::schema::SchemaRef;
-use daft_dsl::Expr;
+use daft_dsl::{
+    col,
+    functions::{generator::GeneratorExpr, FunctionExpr},
+    Expr,
+};
 use daft_scan::{file_format::FileFormat, Pushdowns, ScanExternalInfo, ScanOperatorRef};
 
 #[cfg(feature = "python")]
@@ -27,6 +32,52 @@ use {
     pyo3::prelude::*,
 };
 
+/// Name of the column of ids that `monotonically_increasing_id()` expressions are replaced with.
+const MONOTONICALLY_INCREASING_ID_COLUMN: &str = "__monotonically_increasing_id__";
+
+/// The ids of `monotonically_increasing_id()` depend on the position of each partition, which
+/// expressions are evaluated without. So replace these expressions of a projection with a column of
+/// ids that a `MonotonicallyIncreasingId` node adds to the input of the projection. Seeded `rand()`
+/// expressions are given the ids too, to generate different values in every partition.
+fn add_monotonically_increasing_ids(
+    input: Arc<LogicalPlan>,
+    projection: Vec<Expr>,
+) -> DaftResult<(Arc<LogicalPlan>, Vec<Expr>)> {
+    let new_projection = projection
+        .iter()
+        .map(|e| {
+            e.clone().transform(&|e: Expr| match &e {
+                Expr::Function {
+                    func: FunctionExpr::Generator(GeneratorExpr::MonotonicallyIncreasingId),
+                    ..
+                } => Ok(Transformed::Yes(
+                    col(MONOTONICALLY_INCREASING_ID_COLUMN).alias(e.name()?),
+                )),
+                Expr::Function {
+                    func: func @ FunctionExpr::Generator(GeneratorExpr::Rand(Some(..))),
+                    inputs,
+                } if inputs.is_empty() => Ok(Transformed::Yes(
+                    Expr::Function {
+                        func: func.clone(),
+                        inputs: vec![col(MONOTONICALLY_INCREASING_ID_COLUMN)],
+                    }
+                    .alias(e.name()?),
+                )),
+                _ => Ok(Transformed::No(e)),
+            })
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    if new_projection == projection {
+        return Ok((input, projection));
+    }
+    let input: LogicalPlan = logical_ops::MonotonicallyIncreasingId::new(
+        input,
+        Some(MONOTONICALLY_INCREASING_ID_COLUMN),
+    )
+    .into();
+    Ok((input.into(), new_projection))
+}
+
 /// A logical plan builder, which simplifies constructing logical plans via
 /// a fluent interface. E.g., LogicalPlanBuilder::table_scan(..).project(..).filter(..).build().
 ///
@@ -100,14 +151,25 @@ impl LogicalPlanBuilder {
         projection: Vec<Expr>,
         resource_request: ResourceRequest,
     ) -> DaftResult<Self> {
+        let (input, projection) = add_monotonically_increasing_ids(self.plan.clone(), projection)?;
         let logical_plan: LogicalPlan =
-            logical_ops::Project::try_new(self.plan.clone(), projection, resource_request)?.into();
+            logical_ops::Project::try_new(input, projection, resource_request)?.into();
         Ok(logical_plan.into())
     }
 
     pub fn filter(&self, predicate: Expr) -> DaftResult<Self> {
+        let (input, mut predicates) =
+            add_monotonically_increasing_ids(self.plan.clone(), vec![predicate])?;
+        let predicate = predicates.remove(0);
+        if Arc::ptr_eq(&input, &self.plan) {
+            let logical_plan: LogicalPlan = logical_ops::Filter::try_new(input, predicate)?.into();
+            return Ok(logical_plan.into());
+        }
+        // Drop the column of ids again after filtering.
+        let filtered: LogicalPlan = logical_ops::Filter::try_new(input, predicate)?.into();
+        let projection = self.plan.schema().names().into_iter().map(col).collect();
         let logical_plan: LogicalPlan =
-            logical_ops::Filter::try_new(self.plan.clone(), predicate)?.into();
+            logical_ops::Project::try_new(filtered.into(), projection, Default::default())?.into();
         Ok(logical_plan.into())
     }
 
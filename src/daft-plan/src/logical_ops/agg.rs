@@ -29,6 +29,12 @@ impl Aggregate {
         aggregations: Vec<AggExpr>,
         groupby: Vec<Expr>,
     ) -> logical_plan::Result<Self> {
+        let agg_exprs = aggregations
+            .iter()
+            .map(|ae| Expr::Agg(ae.clone()))
+            .collect::<Vec<_>>();
+        super::check_no_windows(agg_exprs.iter().chain(&groupby), "aggregation")
+            .context(CreationSnafu)?;
         let output_schema = {
             let upstream_schema = input.schema();
             let fields = groupby
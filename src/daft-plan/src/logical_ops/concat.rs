@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_error::DaftError;
+use daft_core::schema::{Schema, SchemaRef};
 use snafu::ResultExt;
 
 use crate::logical_plan;
@@ -21,7 +22,7 @@ impl Concat {
     ) -> logical_plan::Result<Self> {
         let self_schema = input.schema();
         let other_schema = other.schema();
-        if self_schema != other_schema {
+        if !self_schema.eq_ignoring_nullability(&other_schema) {
             return Err(DaftError::ValueError(format!(
                 "Both DataFrames must have the same schema to concatenate them, but got: {}, {}",
                 self_schema, other_schema
@@ -30,4 +31,25 @@ impl Concat {
         }
         Ok(Self { input, other })
     }
+
+    /// The schema of the inputs, with the fields that are nullable in either input nullable.
+    pub fn schema(&self) -> SchemaRef {
+        let input_schema = self.input.schema();
+        let other_schema = self.other.schema();
+        if input_schema == other_schema {
+            return input_schema;
+        }
+        let fields = input_schema
+            .fields
+            .iter()
+            .zip(other_schema.fields.values())
+            .map(|((name, a), b)| {
+                (
+                    name.clone(),
+                    a.clone().with_nullable(a.nullable || b.nullable),
+                )
+            })
+            .collect();
+        Schema { fields }.into()
+    }
 }
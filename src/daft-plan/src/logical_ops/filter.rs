@@ -18,6 +18,7 @@ pub struct Filter {
 
 impl Filter {
     pub(crate) fn try_new(input: Arc<LogicalPlan>, predicate: Expr) -> Result<Self> {
+        super::check_no_windows([&predicate], "filter").context(CreationSnafu)?;
         let field = predicate
             .to_field(input.schema().as_ref())
             .context(CreationSnafu)?;
@@ -79,11 +79,19 @@ impl Join {
                 .collect::<common_error::DaftResult<HashSet<_>>>()
                 .context(CreationSnafu)?;
             let left_schema = &left.schema().fields;
+            // The side whose rows aren't all preserved is null in rows without a match.
+            let (left_nullable, right_nullable) = match join_type {
+                JoinType::Inner => (false, false),
+                JoinType::Left => (false, true),
+                JoinType::Right => (true, false),
+            };
             let fields = left_schema
                 .iter()
-                .map(|(_, field)| field)
-                .cloned()
+                .map(|(_, field)| field.clone().with_nullable(field.nullable || left_nullable))
                 .chain(right.schema().fields.iter().filter_map(|(rname, rfield)| {
+                    let rfield = rfield
+                        .clone()
+                        .with_nullable(rfield.nullable || right_nullable);
                     if left_join_keys.contains(rname.as_str()) {
                         right_input_mapping.insert(rname.clone(), rname.clone());
                         None
@@ -93,7 +101,7 @@ impl Join {
                         Some(rfield.rename(new_name))
                     } else {
                         right_input_mapping.insert(rname.clone(), rname.clone());
-                        Some(rfield.clone())
+                        Some(rfield)
                     }
                 }))
                 .collect::<Vec<_>>();
@@ -145,3 +153,43 @@ impl Join {
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, DataType};
+    use daft_dsl::col;
+
+    use crate::{
+        test::{dummy_scan_node, dummy_scan_operator},
+        JoinType,
+    };
+
+    #[test]
+    fn join_output_nullability() -> DaftResult<()> {
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("k", DataType::Int64).with_nullable(false),
+            Field::new("a", DataType::Int64).with_nullable(false),
+        ]));
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("k", DataType::Int64).with_nullable(false),
+            Field::new("b", DataType::Int64).with_nullable(false),
+        ]));
+        for (join_type, expected) in [
+            (JoinType::Inner, [false, false, false]),
+            (JoinType::Left, [false, false, true]),
+            (JoinType::Right, [true, true, false]),
+        ] {
+            let schema = left
+                .join(&right, vec![col("k")], vec![col("k")], join_type, None)?
+                .schema();
+            let nullable = schema
+                .fields
+                .values()
+                .map(|f| f.nullable)
+                .collect::<Vec<_>>();
+            assert_eq!(nullable, expected, "{join_type}");
+        }
+        Ok(())
+    }
+}
@@ -27,3 +27,17 @@ pub use sample::Sample;
 pub use sink::Sink;
 pub use sort::Sort;
 pub use source::Source;
+
+use common_error::{DaftError, DaftResult};
+use daft_dsl::{optimization::contains_window, Expr};
+
+/// Window expressions need all rows of a window partition to be in the same partition, which the
+/// planner only arranges for projections, so they are rejected in the expressions of other ops.
+fn check_no_windows<'a>(exprs: impl IntoIterator<Item = &'a Expr>, op: &str) -> DaftResult<()> {
+    match exprs.into_iter().find(|e| contains_window(e)) {
+        Some(e) => Err(DaftError::ValueError(format!(
+            "Window expressions are only supported in projections, but {op} received {e}"
+        ))),
+        None => Ok(()),
+    }
+}
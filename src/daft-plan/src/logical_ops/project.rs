@@ -127,9 +127,16 @@ impl Project {
                         // Mark expr as seen
                         let newly_seen = seen_subexpressions.insert(expr_id.clone());
                         if newly_seen {
-                            // If not previously seen, continue recursing down children
-                            expr.children()
-                        } else {
+                            // If not previously seen, continue recursing down children.
+                            // The aggregation of a window can't be computed on its own,
+                            // so skip over it to its inputs.
+                            match expr.as_ref() {
+                                Expr::Window(agg, spec) => {
+                                    [agg.children(), spec.children()].concat()
+                                }
+                                _ => expr.children(),
+                            }
+                        } else if optimization::is_deterministic(expr) {
                             // If previously seen, cache the expression (if it involves computation)
                             if optimization::requires_computation(expr) {
                                 subexpressions_to_cache.insert(expr_id.clone(), expr.clone());
@@ -137,6 +144,10 @@ impl Project {
                             // Stop recursing if previously seen;
                             // we only want top-level repeated subexpressions
                             vec![]
+                        } else {
+                            // Non-deterministic expressions have to be evaluated every time they
+                            // appear, but their deterministic subexpressions can still be shared.
+                            expr.children()
                         }
                     }
                 })
@@ -264,6 +275,32 @@ fn replace_column_with_semantic_id(
                     )
                 }
             }
+            Expr::Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => {
+                let expr =
+                    replace_column_with_semantic_id(expr.clone(), subexprs_to_replace, schema);
+                let lower =
+                    replace_column_with_semantic_id(lower.clone(), subexprs_to_replace, schema);
+                let upper =
+                    replace_column_with_semantic_id(upper.clone(), subexprs_to_replace, schema);
+                if expr.is_no() && lower.is_no() && upper.is_no() {
+                    Transformed::No(e)
+                } else {
+                    Transformed::Yes(
+                        Expr::Between {
+                            expr: expr.unwrap().clone(),
+                            lower: lower.unwrap().clone(),
+                            upper: upper.unwrap().clone(),
+                            closed: *closed,
+                        }
+                        .into(),
+                    )
+                }
+            }
             Expr::BinaryOp { op, left, right } => {
                 let left =
                     replace_column_with_semantic_id(left.clone(), subexprs_to_replace, schema);
@@ -333,6 +370,28 @@ fn replace_column_with_semantic_id(
                     )
                 }
             }
+            Expr::Window(child, spec) => {
+                let child =
+                    replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema);
+                let spec_children = spec
+                    .children()
+                    .into_iter()
+                    .map(|e| replace_column_with_semantic_id(e, subexprs_to_replace, schema))
+                    .collect::<Vec<_>>();
+                if child.is_no() && spec_children.iter().all(|e| e.is_no()) {
+                    Transformed::No(e)
+                } else {
+                    Transformed::Yes(
+                        Expr::Window(
+                            child.unwrap().clone(),
+                            spec.with_children(
+                                spec_children.iter().map(|t| t.unwrap().clone()).collect(),
+                            ),
+                        )
+                        .into(),
+                    )
+                }
+            }
         }
     }
 }
@@ -376,6 +435,48 @@ fn replace_column_with_semantic_id_aggexpr(
                 |_| e.clone(),
             )
         }
+        AggExpr::First(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::First, |_| e.clone())
+        }
+        AggExpr::Last(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::Last, |_| e.clone())
+        }
+        AggExpr::ApproxCountDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::ApproxCountDistinct, |_| e.clone())
+        }
+        AggExpr::CountDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::CountDistinct, |_| e.clone())
+        }
+        AggExpr::Set(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::Set, |_| e.clone())
+        }
+        AggExpr::ApproxPercentile(ref child, percentile) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::ApproxPercentile(transformed_child, percentile),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::Median(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::Median, |_| e.clone())
+        }
+        AggExpr::ApproxSketch(ref child, sketch_type) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::ApproxSketch(transformed_child, sketch_type),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::MergeSketch(ref child, sketch_type) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::MergeSketch(transformed_child, sketch_type),
+                |_| e.clone(),
+            )
+        }
         AggExpr::List(ref child) => {
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::List, |_| e.clone())
@@ -384,6 +485,12 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Concat, |_| e.clone())
         }
+        AggExpr::StrJoin(ref child, ref separator) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::StrJoin(transformed_child, separator.clone()),
+                |_| e.clone(),
+            )
+        }
         AggExpr::MapGroups { func, inputs } => {
             let transforms = inputs
                 .iter()
@@ -411,7 +518,14 @@ fn replace_column_with_semantic_id_aggexpr(
 mod tests {
     use common_error::DaftResult;
     use daft_core::{datatypes::Field, DataType};
-    use daft_dsl::{binary_op, col, lit, Operator};
+    use daft_dsl::{
+        binary_op, col,
+        functions::{
+            generator::{rand, uuid4},
+            utf8::{contains, lower, startswith},
+        },
+        lit, Operator,
+    };
 
     use crate::{
         logical_ops::Project,
@@ -502,6 +616,84 @@ mod tests {
         Ok(())
     }
 
+    /// Test that shared function calls (e.g. expensive string ops or UDFs) are evaluated once
+    /// and reused by every expression that refers to them.
+    /// e.g.
+    /// contains(lower(a), "x") as x, startswith(lower(a), "y") as y
+    /// ->
+    /// 1. contains(la, "x") as x, startswith(la, "y") as y
+    /// 2. lower(a) as la
+    #[test]
+    fn test_shared_function_subexpression() -> DaftResult<()> {
+        let source =
+            dummy_scan_node(dummy_scan_operator(vec![Field::new("a", DataType::Utf8)])).build();
+        let lowered = lower(&col("a"));
+        let expressions = vec![
+            contains(&lowered, &lit("x")).alias("x"),
+            startswith(&lowered, &lit("y")).alias("y"),
+        ];
+        let result_projection = Project::try_new(source.clone(), expressions, Default::default())?;
+
+        let lowered_colname = lowered.semantic_id(&source.schema()).id;
+        let lowered_col = col(lowered_colname.clone());
+        let expected_result_projection = vec![
+            contains(&lowered_col, &lit("x")).alias("x"),
+            startswith(&lowered_col, &lit("y")).alias("y"),
+        ];
+        assert_eq!(result_projection.projection, expected_result_projection);
+
+        let expected_subprojection = vec![lowered.alias(lowered_colname)];
+        let LogicalPlan::Project(subprojection) = result_projection.input.as_ref() else {
+            panic!()
+        };
+        assert_eq!(subprojection.projection, expected_subprojection);
+
+        Ok(())
+    }
+
+    /// Test that repeated calls to non-deterministic generators are evaluated separately,
+    /// while their deterministic subexpressions are still shared.
+    /// e.g.
+    /// uuid4() as u1, uuid4() as u2, rand()+(a+a) as x, rand()+(a+a) as y
+    /// ->
+    /// 1. uuid4() as u1, uuid4() as u2, rand()+aa as x, rand()+aa as y
+    /// 2. a+a as aa
+    #[test]
+    fn test_non_deterministic_subexpression() -> DaftResult<()> {
+        let source = dummy_scan_node(dummy_scan_operator(vec![Field::new(
+            "a",
+            DataType::Float64,
+        )]))
+        .build();
+        let a2 = binary_op(Operator::Plus, &col("a"), &col("a"));
+        let random_a2 = binary_op(Operator::Plus, &rand(None), &a2);
+        let expressions = vec![
+            uuid4().alias("u1"),
+            uuid4().alias("u2"),
+            random_a2.alias("x"),
+            random_a2.alias("y"),
+        ];
+        let result_projection = Project::try_new(source.clone(), expressions, Default::default())?;
+
+        let a2_colname = a2.semantic_id(&source.schema()).id;
+        let random_a2_col = binary_op(Operator::Plus, &rand(None), &col(a2_colname.clone()));
+        let expected_result_projection = vec![
+            uuid4().alias("u1"),
+            uuid4().alias("u2"),
+            random_a2_col.alias("x"),
+            random_a2_col.alias("y"),
+        ];
+        assert_eq!(result_projection.projection, expected_result_projection);
+
+        let expected_subprojection = vec![a2.alias(a2_colname)];
+        let LogicalPlan::Project(subprojection) = result_projection.input.as_ref() else {
+            panic!()
+        };
+        assert_eq!(subprojection.projection, expected_subprojection);
+
+        Ok(())
+    }
+
     /// Test that common leaf expressions are not factored out
     /// (since this would not save computation and only introduces another materialization)
     /// e.g.
@@ -31,6 +31,7 @@ impl Sort {
             ))
             .context(CreationSnafu);
         }
+        super::check_no_windows(&sort_by, "sort").context(CreationSnafu)?;
         let upstream_schema = input.schema();
         let sort_by_resolved_schema = {
             let sort_by_fields = sort_by
@@ -7,7 +7,7 @@ use crate::LogicalPlan;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        ApplyOrder, DropRepartition, OptimizerRule, PushDownFilter, PushDownLimit,
+        ApplyOrder, DropRepartition, FoldConstants, OptimizerRule, PushDownFilter, PushDownLimit,
         PushDownProjection, Transformed,
     },
 };
@@ -131,6 +131,7 @@ impl Optimizer {
         // Default rule batches.
         let rule_batches: Vec<RuleBatch> = vec![RuleBatch::new(
             vec![
+                Box::new(FoldConstants::new()),
                 Box::new(DropRepartition::new()),
                 Box::new(PushDownFilter::new()),
                 Box::new(PushDownProjection::new()),
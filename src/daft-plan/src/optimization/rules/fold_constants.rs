@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed as TransformedExpr, TreeNode};
+use daft_dsl::{functions::FunctionExpr, null_lit_with_dtype, Expr, LiteralValue};
+use daft_table::Table;
+
+use crate::{
+    logical_ops::{Filter, Project},
+    LogicalPlan,
+};
+
+use super::{ApplyOrder, OptimizerRule, Transformed};
+
+/// Optimization rule for evaluating expression subtrees that only depend on literals.
+///
+/// e.g. `col("a") + lit(2) * lit(3)` -> `col("a") + lit(6)`
+///
+/// Subtrees are evaluated with the same kernels that would run during execution, so folding doesn't
+/// change results; subtrees that fail to evaluate are left as-is to fail at execution time instead.
+#[derive(Default, Debug)]
+pub struct FoldConstants {}
+
+impl FoldConstants {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for FoldConstants {
+    fn apply_order(&self) -> ApplyOrder {
+        ApplyOrder::TopDown
+    }
+
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        match plan.as_ref() {
+            LogicalPlan::Project(project) => {
+                let projection = project
+                    .projection
+                    .iter()
+                    .map(fold_constants)
+                    .collect::<DaftResult<Vec<_>>>()?;
+                if projection == project.projection {
+                    return Ok(Transformed::No(plan));
+                }
+                let new_plan: LogicalPlan = Project::try_new(
+                    project.input.clone(),
+                    projection,
+                    project.resource_request.clone(),
+                )?
+                .into();
+                Ok(Transformed::Yes(new_plan.into()))
+            }
+            LogicalPlan::Filter(filter) => {
+                let predicate = fold_constants(&filter.predicate)?;
+                if predicate == filter.predicate {
+                    return Ok(Transformed::No(plan));
+                }
+                let new_plan: LogicalPlan =
+                    Filter::try_new(filter.input.clone(), predicate)?.into();
+                Ok(Transformed::Yes(new_plan.into()))
+            }
+            _ => Ok(Transformed::No(plan)),
+        }
+    }
+}
+
+/// Whether this expression is already as folded as it can get: a literal, or a typed null.
+fn is_constant(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(_) => true,
+        Expr::Cast(child, _) => matches!(child.as_ref(), Expr::Literal(LiteralValue::Null)),
+        _ => false,
+    }
+}
+
+/// Whether this expression can be evaluated during planning if all of its children are constant.
+fn is_foldable(expr: &Expr) -> bool {
+    match expr {
+        Expr::BinaryOp { .. }
+        | Expr::Cast(..)
+        | Expr::Not(..)
+        | Expr::IsNull(..)
+        | Expr::NotNull(..)
+        | Expr::IsIn(..)
+        | Expr::Between { .. }
+        | Expr::IfElse { .. } => true,
+        // UDFs may be non-deterministic or have side effects, and URL functions perform IO.
+        // Window functions depend on all the rows they run over, and generators on the row count.
+        Expr::Function { func, .. } => match func {
+            #[cfg(feature = "python")]
+            FunctionExpr::Python(..) => false,
+            FunctionExpr::Rust(..)
+            | FunctionExpr::Uri(..)
+            | FunctionExpr::Cumulative(..)
+            | FunctionExpr::Ranking(..)
+            | FunctionExpr::Offset(..)
+            | FunctionExpr::Generator(..) => false,
+            _ => true,
+        },
+        Expr::Column(..)
+        | Expr::Literal(..)
+        | Expr::Alias(..)
+        | Expr::Agg(..)
+        | Expr::Window(..) => false,
+    }
+}
+
+/// Evaluates a foldable expression with constant children into a constant, or None if it can't be
+/// evaluated or its result can't be represented as a literal.
+fn try_evaluate(expr: &Expr) -> Option<Expr> {
+    let name = expr.name().ok()?;
+    let result = Table::empty(None)
+        .and_then(|table| table.eval_expression_list(&[expr.clone()]))
+        .ok()?;
+    let series = result.get_column_by_index(0).ok()?;
+    if series.len() != 1 {
+        return None;
+    }
+    let folded = match LiteralValue::try_from_single_value_series(series).ok()? {
+        Some(value) => Expr::Literal(value),
+        None => null_lit_with_dtype(series.data_type()),
+    };
+    // Keep the name of the original expression, e.g. when folding a struct field access.
+    if folded.name().ok()? == name {
+        Some(folded)
+    } else {
+        Some(folded.alias(name))
+    }
+}
+
+pub(crate) fn fold_constants(expr: &Expr) -> DaftResult<Expr> {
+    expr.clone().transform_up(&|e: Expr| {
+        if is_constant(&e) || !is_foldable(&e) || !e.children().iter().all(|c| is_constant(c)) {
+            return Ok(TransformedExpr::No(e));
+        }
+        Ok(match try_evaluate(&e) {
+            Some(folded) => TransformedExpr::Yes(folded),
+            None => TransformedExpr::No(e),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Field, DataType};
+    use daft_dsl::{col, lit, null_lit_with_dtype};
+    use std::sync::Arc;
+
+    use crate::{
+        optimization::{rules::FoldConstants, test::assert_optimized_plan_with_rules_eq},
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    /// Helper that creates an optimizer with the FoldConstants rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(plan, expected, vec![Box::new(FoldConstants::new())])
+    }
+
+    /// Tests that literal-only subtrees of projections and filters are folded.
+    #[test]
+    fn fold_literal_subtrees() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node(scan_op.clone())
+            .filter(col("a").gt(&(lit(2) + lit(3) * lit(4))))?
+            .project(
+                vec![
+                    col("a") + lit(1).cast(&DataType::Int64),
+                    (lit(1) + null_lit_with_dtype(&DataType::Int32)).alias("b"),
+                    lit(1).gt(&lit(2)).not().alias("c"),
+                ],
+                Default::default(),
+            )?
+            .build();
+        let expected = dummy_scan_node(scan_op)
+            .filter(col("a").gt(&lit(14)))?
+            .project(
+                vec![
+                    col("a") + lit(1i64),
+                    null_lit_with_dtype(&DataType::Int32).alias("b"),
+                    lit(true).alias("c"),
+                ],
+                Default::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that plans without literal-only subtrees are left untouched.
+    #[test]
+    fn no_fold_without_literal_subtrees() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node(scan_op)
+            .project(
+                vec![col("a") + lit(1), col("a").alias("b")],
+                Default::default(),
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 mod drop_repartition;
+mod fold_constants;
 mod push_down_filter;
 mod push_down_limit;
 mod push_down_projection;
 mod rule;
 
 pub use drop_repartition::DropRepartition;
+pub use fold_constants::FoldConstants;
 pub use push_down_filter::PushDownFilter;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
@@ -8,7 +8,8 @@ use daft_dsl::{
     col,
     functions::FunctionExpr,
     optimization::{
-        conjuct, get_required_columns, replace_columns_with_expressions, split_conjuction,
+        conjuct, contains_window, get_required_columns, replace_columns_with_expressions,
+        split_conjuction,
     },
     Expr,
 };
@@ -99,6 +100,10 @@ impl OptimizerRule for PushDownFilter {
                                     has_udf = true;
                                     Ok(VisitRecursion::Stop)
                                 },
+                                Expr::Function{func: FunctionExpr::Rust(..), .. } => {
+                                    has_udf = true;
+                                    Ok(VisitRecursion::Stop)
+                                },
                                 Expr::Function{func: FunctionExpr::Uri(..), .. } => {
                                     has_udf = true;
                                     Ok(VisitRecursion::Stop)
@@ -134,6 +139,12 @@ impl OptimizerRule for PushDownFilter {
                 // don't involve compute.
                 //
                 // Filter-Projection --> {Filter-}Projection-Filter
+                //
+                // Window expressions depend on every row of their partition, so filtering their
+                // input would change their results.
+                if child_project.projection.iter().any(contains_window) {
+                    return Ok(Transformed::No(plan));
+                }
                 let predicates = split_conjuction(&filter.predicate);
                 let projection_input_mapping = child_project
                     .projection
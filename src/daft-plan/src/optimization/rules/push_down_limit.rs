@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
+use daft_dsl::optimization::contains_window;
 
 use crate::{
     logical_ops::{Limit as LogicalLimit, Source},
@@ -37,6 +38,14 @@ impl OptimizerRule for PushDownLimit {
                     // Naive commuting with unary ops.
                     //
                     // Limit-UnaryOp -> UnaryOp-Limit
+                    //
+                    // Projections with window expressions depend on every row of their input, so
+                    // the limit can't be applied before them.
+                    LogicalPlan::Project(project)
+                        if project.projection.iter().any(contains_window) =>
+                    {
+                        Ok(Transformed::No(plan))
+                    }
                     LogicalPlan::Repartition(_) | LogicalPlan::Project(_) => {
                         let new_limit = plan
                             .with_new_children(&[input.children()[0].clone()])
@@ -177,6 +177,29 @@ impl Project {
                 )?;
                 Ok(Expr::IsIn(newchild.into(), newitems.into()))
             }
+            Expr::Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => {
+                let newexpr =
+                    Self::translate_partition_spec_expr(expr.as_ref(), old_colname_to_new_colname)?;
+                let newlower = Self::translate_partition_spec_expr(
+                    lower.as_ref(),
+                    old_colname_to_new_colname,
+                )?;
+                let newupper = Self::translate_partition_spec_expr(
+                    upper.as_ref(),
+                    old_colname_to_new_colname,
+                )?;
+                Ok(Expr::Between {
+                    expr: newexpr.into(),
+                    lower: newlower.into(),
+                    upper: newupper.into(),
+                    closed: *closed,
+                })
+            }
             Expr::IfElse {
                 if_true,
                 if_false,
@@ -200,8 +223,8 @@ impl Project {
                     predicate: newpred.into(),
                 })
             }
-            // Cannot have agg exprs in partition specs.
-            Expr::Agg(_) => Err(()),
+            // Cannot have agg or window exprs in partition specs.
+            Expr::Agg(_) | Expr::Window(..) => Err(()),
         }
     }
 
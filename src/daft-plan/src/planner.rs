@@ -6,9 +6,11 @@ use std::{
 };
 
 use common_daft_config::DaftExecutionConfig;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
+use common_treenode::{TreeNode, VisitRecursion};
 use daft_core::count_mode::CountMode;
 use daft_core::DataType;
+use daft_dsl::optimization::contains_window;
 use daft_dsl::Expr;
 use daft_scan::ScanExternalInfo;
 
@@ -30,6 +32,32 @@ use crate::{physical_ops::*, JoinStrategy, PartitionSpec};
 #[cfg(feature = "python")]
 use crate::physical_ops::InMemoryScan;
 
+/// Returns the partition_by keys shared by every window expression in the projection, or None if
+/// the windows are partitioned differently or over the whole input.
+fn window_partition_by(projection: &[Expr]) -> Option<Vec<Expr>> {
+    let mut partition_bys = vec![];
+    for e in projection {
+        e.apply(&mut |expr| {
+            if let Expr::Window(_, spec) = expr {
+                partition_bys.push(spec.partition_by.clone());
+                // Window functions under a window only run over the window partitions.
+                return Ok(VisitRecursion::Skip);
+            }
+            if expr.is_window_function() {
+                // Window functions outside of a window run over the whole input.
+                partition_bys.push(vec![]);
+            }
+            Ok(VisitRecursion::Continue)
+        })
+        .expect("Error occurred when visiting for window expressions");
+    }
+    let first = partition_bys.first()?;
+    if first.is_empty() || partition_bys.iter().any(|p| p != first) {
+        return None;
+    }
+    Some(first.iter().map(|e| e.as_ref().clone()).collect())
+}
+
 /// Translate a logical plan to a physical plan.
 pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftResult<PhysicalPlan> {
     match logical_plan {
@@ -102,6 +130,29 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
             ..
         }) => {
             let input_physical = plan(input, cfg)?;
+            let num_input_partitions = input_physical.partition_spec().num_partitions;
+            // Window expressions need all rows of a window partition to be in the same partition, so
+            // hash partition by the window partition keys, or gather everything into one partition.
+            let input_physical =
+                if num_input_partitions > 1 && projection.iter().any(contains_window) {
+                    match window_partition_by(projection) {
+                        Some(partition_by) => {
+                            let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                                input_physical.into(),
+                                num_input_partitions,
+                                partition_by,
+                            ));
+                            PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()))
+                        }
+                        None => PhysicalPlan::Coalesce(Coalesce::new(
+                            input_physical.into(),
+                            num_input_partitions,
+                            1,
+                        )),
+                    }
+                } else {
+                    input_physical
+                };
             let partition_spec = input_physical.partition_spec().clone();
             Ok(PhysicalPlan::Project(Project::try_new(
                 input_physical.into(),
@@ -283,8 +334,13 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
             input,
             ..
         }) => {
+            use daft_dsl::functions::{
+                list::{count_distinct as list_count_distinct, median as list_median},
+                sketch::sketch_percentile,
+            };
             use daft_dsl::AggExpr::{self, *};
             use daft_dsl::Expr::Column;
+            use daft_dsl::SketchType;
             let input_plan = plan(input, cfg.clone())?;
 
             let num_input_partitions = input_plan.partition_spec().num_partitions;
@@ -413,6 +469,157 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                                         Column(any_id.clone()).alias(any_of_any_id.clone()).into(),
                                         *ignore_nulls,
                                     ));
+                                final_exprs.push(Column(any_of_any_id.clone()).alias(output_name));
+                            }
+                            First(e) => {
+                                // Partitions are merged in order, so the first value of the first stage
+                                // results is the first value overall.
+                                let first_id = agg_expr.semantic_id(&schema).id;
+                                let first_of_first_id = First(Column(first_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(first_id.clone())
+                                    .or_insert(First(e.alias(first_id.clone()).clone().into()));
+                                second_stage_aggs
+                                    .entry(first_of_first_id.clone())
+                                    .or_insert(First(
+                                        Column(first_id.clone())
+                                            .alias(first_of_first_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs
+                                    .push(Column(first_of_first_id.clone()).alias(output_name));
+                            }
+                            Last(e) => {
+                                let last_id = agg_expr.semantic_id(&schema).id;
+                                let last_of_last_id =
+                                    Last(Column(last_id.clone()).into()).semantic_id(&schema).id;
+                                first_stage_aggs
+                                    .entry(last_id.clone())
+                                    .or_insert(Last(e.alias(last_id.clone()).clone().into()));
+                                second_stage_aggs
+                                    .entry(last_of_last_id.clone())
+                                    .or_insert(Last(
+                                        Column(last_id.clone())
+                                            .alias(last_of_last_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs
+                                    .push(Column(last_of_last_id.clone()).alias(output_name));
+                            }
+                            ApproxCountDistinct(e) => {
+                                let sketch_id = ApproxSketch(e.clone(), SketchType::HyperLogLog)
+                                    .semantic_id(&schema)
+                                    .id;
+                                let merge_sketch_id = MergeSketch(
+                                    Column(sketch_id.clone()).into(),
+                                    SketchType::HyperLogLog,
+                                )
+                                .semantic_id(&schema)
+                                .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                        SketchType::HyperLogLog,
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    MergeSketch(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                        SketchType::HyperLogLog,
+                                    ),
+                                );
+                                final_exprs
+                                    .push(Column(merge_sketch_id.clone()).alias(output_name));
+                            }
+                            ApproxPercentile(e, percentile) => {
+                                let sketch_id = ApproxSketch(e.clone(), SketchType::DDSketch)
+                                    .semantic_id(&schema)
+                                    .id;
+                                let merge_sketch_id = MergeSketch(
+                                    Column(sketch_id.clone()).into(),
+                                    SketchType::DDSketch,
+                                )
+                                .semantic_id(&schema)
+                                .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone().into(),
+                                        SketchType::DDSketch,
+                                    ));
+                                second_stage_aggs.entry(merge_sketch_id.clone()).or_insert(
+                                    MergeSketch(
+                                        Column(sketch_id.clone())
+                                            .alias(merge_sketch_id.clone())
+                                            .into(),
+                                        SketchType::DDSketch,
+                                    ),
+                                );
+                                final_exprs.push(
+                                    sketch_percentile(
+                                        &Column(merge_sketch_id.clone()),
+                                        percentile.0,
+                                    )
+                                    .alias(output_name),
+                                );
+                            }
+                            Median(e) => {
+                                // An exact median needs every value of a group, so gather them into lists
+                                // and compute the median over the concatenated list of each group.
+                                let list_id = List(e.clone()).semantic_id(&schema).id;
+                                let concat_of_list_id = Concat(Column(list_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(list_id.clone())
+                                    .or_insert(List(e.alias(list_id.clone()).clone().into()));
+                                second_stage_aggs
+                                    .entry(concat_of_list_id.clone())
+                                    .or_insert(Concat(
+                                        Column(list_id.clone())
+                                            .alias(concat_of_list_id.clone())
+                                            .into(),
+                                    ));
+                                final_exprs.push(
+                                    list_median(&Column(concat_of_list_id.clone()))
+                                        .alias(output_name),
+                                );
+                            }
+                            CountDistinct(e) => {
+                                // The distinct values of each group are gathered into sets, which are then
+                                // concatenated across partitions and deduplicated again for the final count.
+                                let set_id = Set(e.clone()).semantic_id(&schema).id;
+                                let concat_of_set_id = Concat(Column(set_id.clone()).into())
+                                    .semantic_id(&schema)
+                                    .id;
+                                first_stage_aggs
+                                    .entry(set_id.clone())
+                                    .or_insert(Set(e.alias(set_id.clone()).clone().into()));
+                                second_stage_aggs.entry(concat_of_set_id.clone()).or_insert(
+                                    Concat(
+                                        Column(set_id.clone())
+                                            .alias(concat_of_set_id.clone())
+                                            .into(),
+                                    ),
+                                );
+                                final_exprs.push(
+                                    list_count_distinct(&Column(concat_of_set_id.clone()))
+                                        .alias(output_name),
+                                );
+                            }
+                            ApproxSketch(..) | MergeSketch(..) => {
+                                return Err(DaftError::ValueError(format!(
+                                    "Sketch aggregations are only used internally to plan approximate aggregations, got {agg_expr}"
+                                )));
+                            }
+                            Set(..) => {
+                                return Err(DaftError::ValueError(format!(
+                                    "Set aggregations are only used internally to plan distinct counts, got {agg_expr}"
+                                )));
                             }
                             List(e) => {
                                 let list_id = agg_expr.semantic_id(&schema).id;
@@ -450,6 +657,29 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                                 final_exprs
                                     .push(Column(concat_of_concat_id.clone()).alias(output_name));
                             }
+                            StrJoin(e, separator) => {
+                                // Groups without strings are null in the first stage, so joining the partial
+                                // results again doesn't introduce extra separators.
+                                let join_id = agg_expr.semantic_id(&schema).id;
+                                let join_of_join_id =
+                                    StrJoin(Column(join_id.clone()).into(), separator.clone())
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs.entry(join_id.clone()).or_insert(StrJoin(
+                                    e.alias(join_id.clone()).clone().into(),
+                                    separator.clone(),
+                                ));
+                                second_stage_aggs.entry(join_of_join_id.clone()).or_insert(
+                                    StrJoin(
+                                        Column(join_id.clone())
+                                            .alias(join_of_join_id.clone())
+                                            .into(),
+                                        separator.clone(),
+                                    ),
+                                );
+                                final_exprs
+                                    .push(Column(join_of_join_id.clone()).alias(output_name));
+                            }
                             MapGroups { func, inputs } => {
                                 let func_id = agg_expr.semantic_id(&schema).id;
                                 // No first stage aggregation for MapGroups, do all the work in the second stage.
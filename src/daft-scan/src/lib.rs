@@ -332,7 +332,7 @@ impl ScanTask {
                 ffc2: sc2.file_format_config.clone(),
             });
         }
-        if sc1.schema != sc2.schema {
+        if !sc1.schema.eq_ignoring_nullability(&sc2.schema) {
             return Err(Error::DifferingSchemasInScanTaskMerge {
                 s1: sc1.schema.clone(),
                 s2: sc2.schema.clone(),
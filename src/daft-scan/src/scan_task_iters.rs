@@ -61,7 +61,7 @@ impl MergeByFileSize {
             .expect("accumulator should be populated");
         let child_matches_accumulator = other.partition_spec() == accumulator.partition_spec()
             && other.file_format_config == accumulator.file_format_config
-            && other.schema == accumulator.schema
+            && other.schema.eq_ignoring_nullability(&accumulator.schema)
             && other.storage_config == accumulator.storage_config
             && other.pushdowns == accumulator.pushdowns;
 
@@ -17,7 +17,7 @@ impl PartitionSpec {
 impl PartialEq for PartitionSpec {
     fn eq(&self, other: &Self) -> bool {
         // If the names of fields or types of fields don't match, return False
-        if self.keys.schema != other.keys.schema {
+        if !self.keys.schema.eq_ignoring_nullability(&other.keys.schema) {
             return false;
         }
 
@@ -16,8 +16,8 @@ use daft_core::datatypes::{BooleanArray, DataType, Field, UInt64Array};
 use daft_core::schema::{Schema, SchemaRef};
 use daft_core::series::{IntoSeries, Series};
 
-use daft_dsl::functions::FunctionEvaluator;
-use daft_dsl::{col, null_lit, AggExpr, Expr};
+use daft_dsl::functions::{FunctionEvaluator, FunctionExpr};
+use daft_dsl::{col, null_lit_with_dtype, AggExpr, Expr, SketchType};
 #[cfg(feature = "python")]
 pub mod ffi;
 mod ops;
@@ -43,7 +43,7 @@ impl Table {
         let mut num_rows = 1;
 
         for (field, series) in schema.fields.values().zip(columns.iter()) {
-            if field != series.field() {
+            if !field.eq_ignoring_nullability(series.field()) {
                 return Err(DaftError::SchemaMismatch(format!("While building a Table, we found that the Schema Field and the Series Field  did not match. schema field: {field} vs series field: {}", series.field())));
             }
             if (series.len() != 1) && (series.len() != num_rows) {
@@ -248,6 +248,16 @@ impl Table {
             )));
         }
 
+        // A mask of length 1 keeps or drops all rows.
+        if mask.len() != self.len() && mask.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Expected a mask of length 1 or {} to filter a Table of length {}, but received a mask of length {}",
+                self.len(),
+                self.len(),
+                mask.len()
+            )));
+        }
+
         let mask = mask.downcast::<BooleanArray>().unwrap();
         let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.filter(mask)).collect();
         Ok(Table {
@@ -257,7 +267,22 @@ impl Table {
     }
 
     pub fn take(&self, idx: &Series) -> DaftResult<Self> {
-        let new_series: DaftResult<Vec<_>> = self.columns.iter().map(|s| s.take(idx)).collect();
+        // All columns have the same length, so the indices only need to be checked once.
+        let mut columns = self.columns.iter();
+        let new_series = match columns.next() {
+            Some(first) => std::iter::once(first.take(idx))
+                .chain(columns.map(|s| s.take_unchecked(idx)))
+                .collect::<DaftResult<Vec<_>>>()?,
+            None => vec![],
+        };
+        Ok(Table::new(self.schema.clone(), new_series).unwrap())
+    }
+
+    /// Like [`Table::take`], but skips checking that the indices are in bounds. See
+    /// [`Series::take_unchecked`].
+    pub fn take_unchecked(&self, idx: &Series) -> DaftResult<Self> {
+        let new_series: DaftResult<Vec<_>> =
+            self.columns.iter().map(|s| s.take_unchecked(idx)).collect();
         Ok(Table::new(self.schema.clone(), new_series?).unwrap())
     }
 
@@ -274,7 +299,7 @@ impl Table {
 
         let first_schema = first_table.schema.as_ref();
         for tab in tables.iter().skip(1).map(|t| t.as_ref()) {
-            if tab.schema.as_ref() != first_schema {
+            if !tab.schema.eq_ignoring_nullability(first_schema) {
                 return Err(DaftError::SchemaMismatch(format!(
                     "Table concat requires all schemas to match, {} vs {}",
                     first_schema, tab.schema
@@ -328,8 +353,35 @@ impl Table {
             AnyValue(expr, ignore_nulls) => {
                 Series::any_value(&self.eval_expression(expr)?, groups, *ignore_nulls)
             }
+            First(expr) => Series::first(&self.eval_expression(expr)?, groups),
+            Last(expr) => Series::last(&self.eval_expression(expr)?, groups),
+            ApproxCountDistinct(expr) => {
+                Series::approx_count_distinct(&self.eval_expression(expr)?, groups)
+            }
+            CountDistinct(expr) => Series::count_distinct(&self.eval_expression(expr)?, groups),
+            Set(expr) => Series::agg_set(&self.eval_expression(expr)?, groups),
+            ApproxPercentile(expr, percentile) => {
+                Series::approx_percentile(&self.eval_expression(expr)?, groups, percentile.0)
+            }
+            Median(expr) => Series::median(&self.eval_expression(expr)?, groups),
+            ApproxSketch(expr, SketchType::HyperLogLog) => {
+                Series::hll_sketch(&self.eval_expression(expr)?, groups)
+            }
+            ApproxSketch(expr, SketchType::DDSketch) => {
+                Series::dd_sketch(&self.eval_expression(expr)?, groups)
+            }
+            MergeSketch(expr, SketchType::HyperLogLog) => {
+                let merged = Series::hll_merge(&self.eval_expression(expr)?, groups)?;
+                Ok(merged.binary()?.hll_cardinality()?.into_series())
+            }
+            MergeSketch(expr, SketchType::DDSketch) => {
+                Series::dd_merge(&self.eval_expression(expr)?, groups)
+            }
             List(expr) => Series::agg_list(&self.eval_expression(expr)?, groups),
             Concat(expr) => Series::agg_concat(&self.eval_expression(expr)?, groups),
+            StrJoin(expr, separator) => {
+                Series::str_join(&self.eval_expression(expr)?, groups, separator)
+            }
             MapGroups { .. } => Err(DaftError::ValueError(
                 "MapGroups not supported via aggregation, use map_groups instead".to_string(),
             )),
@@ -350,6 +402,16 @@ impl Table {
             IsIn(child, items) => self
                 .eval_expression(child)?
                 .is_in(&self.eval_expression(items)?),
+            Between {
+                expr,
+                lower,
+                upper,
+                closed,
+            } => self.eval_expression(expr)?.between(
+                &self.eval_expression(lower)?,
+                &self.eval_expression(upper)?,
+                *closed,
+            ),
             BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(left)?;
                 let rhs = self.eval_expression(right)?;
@@ -365,14 +427,25 @@ impl Table {
                     LtEq => Ok(lhs.lte(&rhs)?.into_series()),
                     Eq => Ok(lhs.equal(&rhs)?.into_series()),
                     NotEq => Ok(lhs.not_equal(&rhs)?.into_series()),
+                    EqNullSafe => Ok(lhs.eq_null_safe(&rhs)?.into_series()),
                     GtEq => Ok(lhs.gte(&rhs)?.into_series()),
                     Gt => Ok(lhs.gt(&rhs)?.into_series()),
                     And => Ok(lhs.and(&rhs)?.into_series()),
                     Or => Ok(lhs.or(&rhs)?.into_series()),
                     Xor => Ok(lhs.xor(&rhs)?.into_series()),
+                    BitwiseAnd => lhs.bitwise_and(&rhs),
+                    BitwiseOr => lhs.bitwise_or(&rhs),
+                    BitwiseXor => lhs.bitwise_xor(&rhs),
+                    ShiftLeft => lhs.shift_left(&rhs),
+                    ShiftRight => lhs.shift_right(&rhs),
                     _ => panic!("{op:?} not supported"),
                 }
             }
+            // Generators have no inputs to take the length from, so generate a value per row.
+            Function {
+                func: FunctionExpr::Generator(generator),
+                inputs,
+            } if inputs.is_empty() => generator.generate(self.len(), &expected_field.name),
             Function { func, inputs } => {
                 let evaluated_inputs = inputs
                     .iter()
@@ -391,6 +464,7 @@ impl Table {
                 let predicate_series = self.eval_expression(predicate)?;
                 Ok(if_true_series.if_else(&if_false_series, &predicate_series)?)
             }
+            Window(child, spec) => self.eval_window_expression(child, spec),
         }?;
         if expected_field.name != series.field().name {
             return Err(DaftError::ComputeError(format!(
@@ -402,6 +476,14 @@ impl Table {
         if expected_field.dtype != series.field().dtype {
             panic!("Mismatch of expected expression data type and data type from computed series, {} vs {}", expected_field.dtype, series.field().dtype);
         }
+        // Drop all-valid bitmaps of provably non-null results, so that downstream kernels can skip
+        // validity handling.
+        if !expected_field.nullable
+            && !series.data_type().is_python()
+            && series.validity().map_or(false, |v| v.unset_bits() == 0)
+        {
+            return series.with_validity(None);
+        }
         Ok(series)
     }
 
@@ -444,7 +526,7 @@ impl Table {
                     col(name.clone()).cast(&field.dtype)
                 } else {
                     // For any fields in schema that are not in self.schema, create all-null arrays
-                    null_lit().alias(name.clone()).cast(&field.dtype)
+                    null_lit_with_dtype(&field.dtype).alias(name.clone())
                 }
             })
             .collect();
@@ -564,10 +646,18 @@ mod test {
 
     use crate::Table;
     use common_error::DaftResult;
-    use daft_core::datatypes::{DataType, Float64Array, Int64Array};
+    use daft_core::array::ops::as_arrow::AsArrow;
+    use daft_core::datatypes::{DataType, Float64Array, Int32Array, Int64Array, Utf8Array};
+    use daft_core::kernels::hashing::hash_xxh64;
     use daft_core::schema::Schema;
     use daft_core::series::IntoSeries;
-    use daft_dsl::col;
+    use daft_dsl::functions::cumulative::{cumcount, cummin, cumsum};
+    use daft_dsl::functions::generator::{monotonically_increasing_id, rand, uuid4};
+    use daft_dsl::functions::hash::hash_columns;
+    use daft_dsl::functions::null::coalesce;
+    use daft_dsl::functions::offset::{lag, lead};
+    use daft_dsl::functions::ranking::{dense_rank, rank, row_number};
+    use daft_dsl::{col, lit, null_lit, Expr, Window};
     #[test]
     fn add_int_and_float_expression() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
@@ -589,4 +679,233 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn hash_expression() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 1])).into_series();
+        let b = Utf8Array::from(("b", vec!["x", "y", "x"].as_slice())).into_series();
+        let schema = Schema::new(vec![a.field().clone(), b.field().clone()])?;
+        let table = Table::new(schema, vec![a.clone(), b.clone()])?;
+
+        let result = table.eval_expression(&col("a").hash_with_seed(None))?;
+        assert_eq!(*result.data_type(), DataType::UInt64);
+        assert_eq!(result.name(), "a");
+        let a_hashes = hash_xxh64(a.to_arrow().as_ref(), None)?;
+        assert_eq!(result.u64()?.as_arrow(), &a_hashes);
+
+        let seeded = table.eval_expression(&col("a").hash_with_seed(Some(42)))?;
+        assert_ne!(seeded.u64()?, result.u64()?);
+
+        let hashes = table.eval_expression(&hash_columns(&[col("a"), col("b")], None))?;
+        let expected = hash_xxh64(b.to_arrow().as_ref(), Some(&a_hashes))?;
+        let hashes = hashes.u64()?.as_arrow();
+        assert_eq!(hashes, &expected);
+        assert_eq!(hashes.value(0), hashes.value(2));
+        assert_ne!(hashes.value(0), hashes.value(1));
+
+        // Literals are broadcast to the length of the other inputs.
+        let with_literal = table.eval_expression(&hash_columns(&[col("a"), lit(1)], None))?;
+        assert_eq!(with_literal.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn fill_null_and_coalesce_expression() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3]))
+            .with_validity_slice(&[true, false, false])?
+            .into_series();
+        let b = Float64Array::from(("b", vec![1., 2., 3.]))
+            .with_validity_slice(&[false, true, false])?
+            .into_series();
+        let schema = Schema::new(vec![a.field().clone(), b.field().clone()])?;
+        let table = Table::new(schema, vec![a, b])?;
+
+        let result = table.eval_expression(&col("a").fill_null(&lit(0)))?;
+        assert_eq!(*result.data_type(), DataType::Int64);
+        let values: Vec<_> = result
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect();
+        assert_eq!(values[..], [Some(1), Some(0), Some(0)]);
+
+        let result = table.eval_expression(&coalesce(&[col("a"), col("b"), lit(0)]))?;
+        assert_eq!(*result.data_type(), DataType::Float64);
+        assert_eq!(result.name(), "a");
+        let values: Vec<_> = result
+            .f64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect();
+        assert_eq!(values[..], [Some(1.), Some(2.), Some(0.)]);
+        Ok(())
+    }
+
+    #[test]
+    fn window_expression() -> DaftResult<()> {
+        let k = Utf8Array::from(("k", ["a", "b", "a", "a"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![4, 1, 2, 3])).into_series();
+        let v = Int64Array::from(("v", vec![1, 2, 3, 4])).into_series();
+        let table = Table::from_columns(vec![k, t, v])?;
+
+        let partition_sum = col("v").sum().over(&Window::partition_by(&[col("k")]));
+        let result = table.eval_expression(&partition_sum)?;
+        assert_eq!(result.name(), "v");
+        let values: Vec<_> = result.i64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [8, 2, 8, 8]);
+
+        let running_sum = col("v")
+            .sum()
+            .over(&Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]));
+        let result = table.eval_expression(&running_sum)?;
+        let values: Vec<_> = result.i64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [8, 2, 3, 7]);
+
+        let global_running_max = col("v").max().over(&Window::order_by(&[col("t")], &[true]));
+        let result = table.eval_expression(&global_running_max)?;
+        let values: Vec<_> = result.i64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [1, 4, 4, 4]);
+
+        assert!(table
+            .eval_expression(&col("v").over(&Window::partition_by(&[col("k")])))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cumulative_expression() -> DaftResult<()> {
+        let k = Utf8Array::from(("k", ["a", "b", "a", "a"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![4, 1, 2, 3])).into_series();
+        let v = Int32Array::from(("v", vec![1, 2, 3, 4])).into_series();
+        let table = Table::from_columns(vec![k, t, v])?;
+
+        // On their own, cumulative functions run over the table in its current order.
+        let result = table.eval_expression(&cumsum(&col("v")))?;
+        assert_eq!(*result.data_type(), DataType::Int64);
+        let values: Vec<_> = result.i64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [1, 3, 6, 10]);
+
+        // Over a window, they restart in every partition and follow the window's order.
+        let spec = Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]);
+        let result = table.eval_expression(&cumsum(&col("v")).over(&spec))?;
+        let values: Vec<_> = result.i64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [8, 2, 3, 7]);
+
+        let result = table.eval_expression(&cummin(&col("v")).over(&spec))?;
+        let values: Vec<_> = result.i32()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [1, 2, 3, 3]);
+
+        let result = table.eval_expression(&cumcount(&col("v")).over(&spec))?;
+        let values: Vec<_> = result.u64()?.as_arrow().values_iter().copied().collect();
+        assert_eq!(values[..], [3, 1, 1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn ranking_expression() -> DaftResult<()> {
+        let k = Utf8Array::from(("k", ["a", "a", "b", "a", "a"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![2, 1, 5, 2, 3])).into_series();
+        let table = Table::from_columns(vec![k, t])?;
+
+        let spec = Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]);
+        let ranks = |e: Expr| -> DaftResult<Vec<u64>> {
+            let result = table.eval_expression(&e.over(&spec))?;
+            Ok(result.u64()?.as_arrow().values_iter().copied().collect())
+        };
+        let row_numbers = ranks(row_number())?;
+        assert_eq!(row_numbers[1..3], [1, 1]);
+        assert_eq!(row_numbers[4], 4);
+        assert_eq!(ranks(rank())?, [2, 1, 1, 2, 4]);
+        assert_eq!(ranks(dense_rank())?, [2, 1, 1, 2, 3]);
+
+        assert_eq!(table.eval_expression(&rank().over(&spec))?.name(), "rank");
+        assert!(table.eval_expression(&rank()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn offset_expression() -> DaftResult<()> {
+        let k = Utf8Array::from(("k", ["a", "b", "a", "a"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![4, 1, 2, 3])).into_series();
+        let v = Int64Array::from(("v", vec![1, 2, 3, 4])).into_series();
+        let table = Table::from_columns(vec![k, t, v])?;
+        let values = |e: Expr| -> DaftResult<Vec<Option<i64>>> {
+            let result = table.eval_expression(&e)?;
+            assert_eq!(result.name(), "v");
+            Ok(result
+                .i64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+
+        assert_eq!(
+            values(lag(&col("v"), 1, &null_lit()))?,
+            [None, Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            values(lead(&col("v"), 2, &lit(0)))?,
+            [Some(3), Some(4), Some(0), Some(0)]
+        );
+        assert_eq!(
+            values(lead(&col("v"), 10, &col("t")))?,
+            [Some(4), Some(1), Some(2), Some(3)]
+        );
+
+        let spec = Window::partition_by(&[col("k")]).order_by(&[col("t")], &[false]);
+        assert_eq!(
+            values(lag(&col("v"), 1, &null_lit()).over(&spec))?,
+            [Some(4), None, None, Some(3)]
+        );
+        assert_eq!(
+            values(lead(&col("v"), 1, &col("t")).over(&spec))?,
+            [Some(4), Some(1), Some(4), Some(1)]
+        );
+        Ok(())
+    }
+    #[test]
+    fn generator_expression() -> DaftResult<()> {
+        let v = Int64Array::from(("v", vec![1, 2, 3])).into_series();
+        let table = Table::from_columns(vec![v])?;
+
+        let result = table.eval_expression(&(rand(Some(7)) * lit(10.)))?;
+        assert_eq!(result.name(), "rand");
+        assert_eq!(result.len(), 3);
+        // Seeded values are the same every time.
+        assert_eq!(table.eval_expression(&(rand(Some(7)) * lit(10.)))?, result);
+
+        let result = table.eval_expression(&uuid4().alias("id"))?;
+        assert_eq!(*result.data_type(), DataType::Utf8);
+        assert_eq!(result.len(), 3);
+
+        // Ids depend on the position of the table's partition, which only the plan knows about.
+        assert!(table
+            .eval_expression(&monotonically_increasing_id())
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn make_groups_with_null_keys() -> DaftResult<()> {
+        use daft_core::array::ops::IntoGroups;
+
+        let a = Int64Array::from(("a", vec![1, 0, 1, 0, 1]))
+            .with_validity_slice(&[true, false, true, false, true])?
+            .into_series();
+        let b = Utf8Array::from(("b", vec!["x", "y", "y", "y", "x"].as_slice())).into_series();
+        let schema = Schema::new(vec![a.field().clone(), b.field().clone()])?;
+
+        // Groups are in order of first occurrence, and null keys form their own group.
+        let (key_indices, group_indices) = Table::new(schema, vec![a.clone(), b])?.make_groups()?;
+        assert_eq!(key_indices, vec![0, 1, 2]);
+        assert_eq!(group_indices, vec![vec![0, 4], vec![1, 3], vec![2]]);
+
+        let (key_indices, group_indices) = a.make_groups()?;
+        assert_eq!(key_indices, vec![0, 1]);
+        assert_eq!(group_indices, vec![vec![0, 2, 4], vec![1, 3]]);
+        Ok(())
+    }
 }
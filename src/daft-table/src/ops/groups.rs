@@ -20,23 +20,23 @@ impl Table {
         //
         // e.g. given a table [B, B, A, B, C, C]
         // returns: (
-        //      [2, 0, 4]  <-- indices of A, B, and C
-        //      [[2], [0, 1, 3], [4, 5]]  <--- indices of all A, all B, all C
+        //      [0, 2, 4]  <-- indices of B, A, and C, in order of first occurrence
+        //      [[0, 1, 3], [2], [4, 5]]  <--- indices of all B, all A, all C
         // )
+        //
+        // Rows with null keys are grouped together like any other key.
 
         if self.num_columns() == 1 {
             return self.columns.first().unwrap().make_groups();
         }
 
         let probe_table = self.to_probe_hash_table()?;
-        let mut key_indices: Vec<u64> = Vec::with_capacity(probe_table.len());
-        let mut values_indices: Vec<Vec<u64>> = Vec::with_capacity(probe_table.len());
-
-        for (idx_hash, val_idx) in probe_table.into_iter() {
-            key_indices.push(idx_hash.idx);
-            values_indices.push(val_idx);
-        }
-        Ok((key_indices, values_indices))
+        let mut groups = probe_table
+            .into_iter()
+            .map(|(idx_hash, val_idx)| (idx_hash.idx, val_idx))
+            .collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|(key_idx, _)| *key_idx);
+        Ok(groups.into_iter().unzip())
     }
 
     fn sort_grouper(&self) -> DaftResult<(Vec<u64>, Vec<UInt64Array>)> {
@@ -54,8 +54,11 @@ impl Table {
         // )
 
         // Begin by doing the argsort.
-        let argsort_series =
-            Series::argsort_multikey(self.columns.as_slice(), &vec![false; self.columns.len()])?;
+        let argsort_series = Series::argsort_multikey(
+            self.columns.as_slice(),
+            &vec![false; self.columns.len()],
+            &vec![false; self.columns.len()],
+        )?;
         let argsort_array = argsort_series.downcast::<UInt64Array>()?;
 
         // The result indices.
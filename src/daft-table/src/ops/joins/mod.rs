@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use daft_core::{schema::Schema, utils::supertype::try_get_supertype, Series};
+use daft_core::{datatypes::supertype::try_get_supertype, schema::Schema, Series};
 
 use common_error::{DaftError, DaftResult};
 use daft_dsl::Expr;
@@ -170,7 +170,7 @@ impl Table {
 
         let mut join_series = self
             .get_columns(ltable.column_names().as_slice())?
-            .take(&lidx)?
+            .take_unchecked(&lidx)?
             .columns;
         drop(ltable);
         drop(rtable);
@@ -187,7 +187,7 @@ impl Table {
                 continue;
             } else {
                 join_fields.push(field.clone());
-                join_series.push(self.get_column(&field.name)?.take(&lidx)?);
+                join_series.push(self.get_column(&field.name)?.take_unchecked(&lidx)?);
                 names_so_far.insert(field.name.clone());
             }
         }
@@ -224,7 +224,7 @@ impl Table {
                 right
                     .get_column(&field.name)?
                     .rename(curr_name.clone())
-                    .take(&ridx)?,
+                    .take_unchecked(&ridx)?,
             );
             names_so_far.insert(curr_name.clone());
         }
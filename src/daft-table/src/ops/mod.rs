@@ -6,5 +6,7 @@ mod joins;
 mod partition;
 mod search_sorted;
 mod sort;
+mod value_counts;
+mod window;
 
 pub use joins::infer_join_schema;
@@ -1,6 +1,8 @@
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    array::DataArray, datatypes::UInt64Array, kernels::search_sorted::search_sorted_multi_array,
+    array::DataArray,
+    datatypes::UInt64Array,
+    kernels::search_sorted::{search_sorted_multi_array, SearchSortedSide},
     series::Series,
 };
 
@@ -8,7 +10,7 @@ use crate::Table;
 
 impl Table {
     pub fn search_sorted(&self, keys: &Self, descending: &[bool]) -> DaftResult<UInt64Array> {
-        if self.schema != keys.schema {
+        if !self.schema.eq_ignoring_nullability(&keys.schema) {
             return Err(DaftError::SchemaMismatch(format!(
                 "Schema Mismatch in search_sorted: data: {} vs keys: {}",
                 self.schema, keys.schema
@@ -19,9 +21,11 @@ impl Table {
         }
 
         if self.num_columns() == 1 {
-            return self
-                .get_column_by_index(0)?
-                .search_sorted(keys.get_column_by_index(0)?, *descending.first().unwrap());
+            return self.get_column_by_index(0)?.search_sorted(
+                keys.get_column_by_index(0)?,
+                *descending.first().unwrap(),
+                SearchSortedSide::Right,
+            );
         }
         unsafe {
             multicol_search_sorted(self.columns.as_slice(), keys.columns.as_slice(), descending)
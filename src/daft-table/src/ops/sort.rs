@@ -6,7 +6,7 @@ use daft_dsl::Expr;
 impl Table {
     pub fn sort(&self, sort_keys: &[Expr], descending: &[bool]) -> DaftResult<Table> {
         let argsort = self.argsort(sort_keys, descending)?;
-        self.take(&argsort)
+        self.take_unchecked(&argsort)
     }
 
     pub fn argsort(&self, sort_keys: &[Expr], descending: &[bool]) -> DaftResult<Series> {
@@ -19,10 +19,10 @@ impl Table {
         }
         if sort_keys.len() == 1 {
             self.eval_expression(sort_keys.get(0).unwrap())?
-                .argsort(*descending.first().unwrap())
+                .argsort(*descending.first().unwrap(), *descending.first().unwrap())
         } else {
             let expr_result = self.eval_expression_list(sort_keys)?;
-            Series::argsort_multikey(expr_result.columns.as_slice(), descending)
+            Series::argsort_multikey(expr_result.columns.as_slice(), descending, descending)
         }
     }
 }
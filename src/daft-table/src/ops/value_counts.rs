@@ -0,0 +1,33 @@
+use common_error::DaftResult;
+use daft_core::{array::ops::IntoGroups, datatypes::UInt64Array, series::IntoSeries, Series};
+
+use crate::Table;
+
+impl Table {
+    /// Counts the occurrences of each distinct value of `series`, returning a table of the values
+    /// and a `count` column, sorted by descending count. Values with equal counts are in order of
+    /// first occurrence, and nulls are counted as a single value.
+    ///
+    /// If `series` is itself named `count`, the count column is named `count_count` instead so the
+    /// column names stay unique.
+    pub fn value_counts(series: &Series) -> DaftResult<Table> {
+        let (value_indices, groups) = series.make_groups()?;
+        let values = series.take(&UInt64Array::from(("", value_indices)).into_series())?;
+        let count_name = if series.name() == "count" {
+            "count_count"
+        } else {
+            "count"
+        };
+        let counts = UInt64Array::from((
+            count_name,
+            groups
+                .iter()
+                .map(|group| group.len() as u64)
+                .collect::<Vec<_>>(),
+        ))
+        .into_series();
+
+        let order = counts.argsort(true, false)?;
+        Self::from_columns(vec![values.take(&order)?, counts.take(&order)?])
+    }
+}
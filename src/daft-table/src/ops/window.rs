@@ -0,0 +1,314 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{
+        arrow2::comparison::build_multi_array_is_equal, as_arrow::AsArrow, GroupIndices, IntoGroups,
+    },
+    count_mode::CountMode,
+    datatypes::{DataType, Float64Array, UInt64Array},
+    fill_null_strategy::FillNullStrategy,
+    kernels::search_sorted::build_compare_with_null_placement,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ranking::RankingExpr, FunctionExpr},
+    AggExpr, Expr, WindowSpec,
+};
+
+use crate::Table;
+
+impl Table {
+    pub(crate) fn eval_window_expression(
+        &self,
+        expr: &Expr,
+        spec: &WindowSpec,
+    ) -> DaftResult<Series> {
+        let agg_expr = match expr {
+            Expr::Agg(agg_expr) => Some(agg_expr),
+            _ if expr.is_window_function() => None,
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected an aggregation or a window function to evaluate over a window, but received {expr}"
+                )))
+            }
+        };
+
+        // Indices of the rows in each window partition.
+        let partitions: GroupIndices = if spec.partition_by.is_empty() {
+            vec![(0..self.len() as u64).collect()]
+        } else {
+            let partition_by = spec
+                .partition_by
+                .iter()
+                .map(|e| e.as_ref().clone())
+                .collect::<Vec<_>>();
+            self.eval_expression_list(&partition_by)?.make_groups()?.1
+        };
+        let mut row_to_partition = vec![0u64; self.len()];
+        for (partition_idx, rows) in partitions.iter().enumerate() {
+            for row in rows {
+                row_to_partition[*row as usize] = partition_idx as u64;
+            }
+        }
+
+        // Unordered windows aggregate over the whole partition, and broadcast the result to every row.
+        if let Some(agg_expr) = agg_expr && spec.order_by.is_empty() {
+            let aggregated = self.eval_agg_expression(agg_expr, Some(&partitions))?;
+            return aggregated.take(&UInt64Array::from(("", row_to_partition)).into_series());
+        }
+
+        // Ordered windows and window functions run over the rows of each partition in order.
+        // Traversing the rows in sorted order yields each partition's rows already sorted.
+        let sorted_partitions = if spec.order_by.is_empty() {
+            partitions
+        } else {
+            let order_by = spec
+                .order_by
+                .iter()
+                .map(|e| e.as_ref().clone())
+                .collect::<Vec<_>>();
+            let argsort = self.argsort(&order_by, &spec.descending)?;
+            let mut sorted_partitions = vec![vec![]; partitions.len()];
+            for row in argsort.u64()?.as_arrow().values_iter() {
+                sorted_partitions[row_to_partition[*row as usize] as usize].push(*row);
+            }
+            sorted_partitions
+        };
+
+        let agg_expr = match (agg_expr, expr) {
+            (Some(agg_expr), _) => agg_expr,
+            (
+                None,
+                Expr::Function {
+                    func: FunctionExpr::Ranking(ranking),
+                    ..
+                },
+            ) => return self.eval_ranking_expression(ranking, expr, spec, &sorted_partitions),
+            (None, _) => return self.eval_partitioned_function(expr, &sorted_partitions),
+        };
+
+        // Like SQL's default RANGE frame, the frame of each row extends to its last peer, so that
+        // rows with equal order_by values receive the same aggregate.
+        let running = self.eval_running_aggregation(agg_expr, &sorted_partitions)?;
+        let is_peer = self.build_is_peer(spec)?;
+        let mut frame_ends = vec![0u64; self.len()];
+        for rows in sorted_partitions.iter() {
+            let mut end = rows.len();
+            for i in (0..rows.len()).rev() {
+                if i + 1 < rows.len() && !is_peer(rows[i] as usize, rows[i + 1] as usize) {
+                    end = i + 1;
+                }
+                frame_ends[rows[i] as usize] = rows[end - 1];
+            }
+        }
+        running.take(&UInt64Array::from(("", frame_ends)).into_series())
+    }
+
+    /// Builds a comparator of whether two rows are peers, i.e. their order_by values are equal,
+    /// including nulls.
+    fn build_is_peer(
+        &self,
+        spec: &WindowSpec,
+    ) -> DaftResult<Box<dyn Fn(usize, usize) -> bool + Send + Sync>> {
+        let order_by = spec
+            .order_by
+            .iter()
+            .map(|e| e.as_ref().clone())
+            .collect::<Vec<_>>();
+        let keys = self.eval_expression_list(&order_by)?;
+        build_multi_array_is_equal(&keys.columns, &keys.columns, true, true)
+    }
+
+    /// Aggregates the rows of each window partition, whose rows are given in window order, up to
+    /// and including each row, regardless of its peers. The aggregations are computed incrementally while traversing the
+    /// partitions, so this is linear in the number of rows.
+    fn eval_running_aggregation(
+        &self,
+        agg_expr: &AggExpr,
+        sorted_partitions: &GroupIndices,
+    ) -> DaftResult<Series> {
+        use AggExpr::*;
+        let field = agg_expr.to_field(&self.schema)?;
+        let input = match agg_expr {
+            Count(e, _)
+            | Sum(e)
+            | Mean(e)
+            | Min(e)
+            | Max(e)
+            | AnyValue(e, _)
+            | First(e)
+            | Last(e) => self.eval_expression(e)?,
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Aggregation {agg_expr} is not supported over an ordered window"
+                )))
+            }
+        };
+        let validity = input.validity().filter(|_| input.null_count() > 0);
+        let is_valid = |row: u64| validity.map_or(true, |v| v.get_bit(row as usize));
+
+        match agg_expr {
+            Count(_, mode) => {
+                let mut counts = vec![0u64; self.len()];
+                for rows in sorted_partitions.iter() {
+                    let mut count = 0;
+                    for row in rows {
+                        count += match mode {
+                            CountMode::All => 1,
+                            CountMode::Valid => is_valid(*row) as u64,
+                            CountMode::Null => !is_valid(*row) as u64,
+                        };
+                        counts[*row as usize] = count;
+                    }
+                }
+                Ok(UInt64Array::from((field.name.as_str(), counts)).into_series())
+            }
+            // These pick a single row of the frame, which is tracked as it grows.
+            Min(..) | Max(..) | First(..) | Last(..) | AnyValue(..) => {
+                let comparator = match agg_expr {
+                    Min(..) | Max(..) => {
+                        let keys = match input.data_type() {
+                            DataType::Categorical => input.cast(&DataType::Utf8)?,
+                            _ => input.as_physical()?,
+                        }
+                        .to_arrow();
+                        Some(build_compare_with_null_placement(
+                            keys.as_ref(),
+                            keys.as_ref(),
+                            matches!(agg_expr, Max(..)),
+                            false,
+                        )?)
+                    }
+                    _ => None,
+                };
+                let mut picked = vec![None; self.len()];
+                for rows in sorted_partitions.iter() {
+                    let mut current: Option<u64> = None;
+                    for row in rows {
+                        current = match agg_expr {
+                            First(..) | AnyValue(_, false) => current.or(Some(*row)),
+                            Last(..) => Some(*row),
+                            _ if !is_valid(*row) => current,
+                            AnyValue(_, true) => current.or(Some(*row)),
+                            _ => match (current, &comparator) {
+                                (Some(current), Some(comparator))
+                                    if comparator(current as usize, *row as usize).is_le() =>
+                                {
+                                    Some(current)
+                                }
+                                _ => Some(*row),
+                            },
+                        };
+                        picked[*row as usize] = current;
+                    }
+                }
+                input.take(&UInt64Array::from_iter("", picked.into_iter()).into_series())
+            }
+            // Sums are accumulated over the rows in window order, and restart at every partition.
+            Sum(..) | Mean(..) => {
+                let values = match (agg_expr, input.data_type()) {
+                    (Sum(..), _) => input.cast(&field.dtype)?.as_physical()?,
+                    (_, DataType::Duration(..)) => input.as_physical()?.cast(&DataType::Float64)?,
+                    _ => input.cast(&DataType::Float64)?,
+                };
+                let order = sorted_partitions
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                let ordered = values.take(&UInt64Array::from(("", order.clone())).into_series())?;
+                let mut sums = Vec::with_capacity(sorted_partitions.len());
+                let mut start = 0;
+                for rows in sorted_partitions.iter().filter(|rows| !rows.is_empty()) {
+                    let partition = ordered.slice(start, start + rows.len())?;
+                    sums.push(
+                        partition
+                            .cumsum()?
+                            .fill_null_with_strategy(FillNullStrategy::Forward)?,
+                    );
+                    start += rows.len();
+                }
+                if sums.is_empty() {
+                    return Ok(Series::empty(&field.name, &field.dtype));
+                }
+                let mut running = Series::concat(&sums.iter().collect::<Vec<_>>())?;
+                if let Mean(..) = agg_expr {
+                    let mut counts = Vec::with_capacity(order.len());
+                    for rows in sorted_partitions.iter() {
+                        let mut count = 0.0;
+                        for row in rows {
+                            count += is_valid(*row) as u8 as f64;
+                            counts.push(count);
+                        }
+                    }
+                    let counts = Float64Array::from(("", counts)).into_series();
+                    running = (&running / &counts)?;
+                }
+                let mut row_to_position = vec![0u64; self.len()];
+                for (position, row) in order.iter().enumerate() {
+                    row_to_position[*row as usize] = position as u64;
+                }
+                running
+                    .take(&UInt64Array::from(("", row_to_position)).into_series())?
+                    .cast(&field.dtype.to_physical())?
+                    .cast(&field.dtype)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Runs a cumulative or offset function over each window partition, whose rows are given in
+    /// window order, so that the function restarts at the beginning of every partition.
+    fn eval_partitioned_function(
+        &self,
+        expr: &Expr,
+        sorted_partitions: &GroupIndices,
+    ) -> DaftResult<Series> {
+        if sorted_partitions.is_empty() {
+            return self.eval_expression(expr);
+        }
+        let mut results = Vec::with_capacity(sorted_partitions.len());
+        let mut row_to_result = vec![0u64; self.len()];
+        let mut offset = 0;
+        for rows in sorted_partitions.iter() {
+            for (i, row) in rows.iter().enumerate() {
+                row_to_result[*row as usize] = (offset + i) as u64;
+            }
+            offset += rows.len();
+            let partition = self.take(&UInt64Array::from(("", rows.clone())).into_series())?;
+            results.push(partition.eval_expression(expr)?);
+        }
+        let results = Series::concat(&results.iter().collect::<Vec<_>>())?;
+        results.take(&UInt64Array::from(("", row_to_result)).into_series())
+    }
+
+    /// Numbers the rows of each window partition, whose rows are given in window order, numbering
+    /// peers alike for the ranks.
+    fn eval_ranking_expression(
+        &self,
+        ranking: &RankingExpr,
+        expr: &Expr,
+        spec: &WindowSpec,
+        sorted_partitions: &GroupIndices,
+    ) -> DaftResult<Series> {
+        let is_peer = self.build_is_peer(spec)?;
+
+        let mut ranks = vec![0u64; self.len()];
+        for rows in sorted_partitions.iter() {
+            let mut rank = 0;
+            let mut dense_rank = 0;
+            for (i, row) in rows.iter().enumerate() {
+                let row_number = i as u64 + 1;
+                if i == 0 || !is_peer(rows[i - 1] as usize, *row as usize) {
+                    rank = row_number;
+                    dense_rank += 1;
+                }
+                ranks[*row as usize] = match ranking {
+                    RankingExpr::RowNumber => row_number,
+                    RankingExpr::Rank => rank,
+                    RankingExpr::DenseRank => dense_rank,
+                };
+            }
+        }
+        Ok(UInt64Array::from((expr.name()?, ranks)).into_series())
+    }
+}
@@ -352,6 +352,11 @@ impl PyTable {
         py.allow_threads(|| Ok(Table::concat(tables.as_slice())?.into()))
     }
 
+    #[staticmethod]
+    pub fn value_counts(py: Python, series: &PySeries) -> PyResult<Self> {
+        py.allow_threads(|| Ok(Table::value_counts(&series.series)?.into()))
+    }
+
     pub fn slice(&self, start: i64, end: i64) -> PyResult<Self> {
         if start < 0 {
             return Err(PyValueError::new_err(format!(
@@ -47,6 +47,176 @@ def test_agg_global(make_df, repartition_nparts):
     assert set(res_list[0]) == set(exp_list[0])
 
 
+@pytest.mark.parametrize(
+    ["dtype", "sum_dtype"],
+    [
+        (DataType.int8(), DataType.int64()),
+        (DataType.int16(), DataType.int64()),
+        (DataType.uint8(), DataType.uint64()),
+        (DataType.uint16(), DataType.uint64()),
+        (DataType.float32(), DataType.float32()),
+    ],
+)
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_global_narrow_numerics(make_df, repartition_nparts, dtype, sum_dtype):
+    daft_df = make_df({"values": [100, None, 120]}, repartition=repartition_nparts)
+    daft_df = daft_df.select(col("values").cast(dtype)).agg(
+        [
+            (col("values").alias("sum"), "sum"),
+            (col("values").alias("mean"), "mean"),
+            (col("values").alias("min"), "min"),
+            (col("values").alias("max"), "max"),
+        ]
+    )
+
+    # Sums are widened so that they don't overflow, while min and max keep the narrow type.
+    schema = daft_df.schema()
+    assert schema["sum"].dtype == sum_dtype
+    assert schema["mean"].dtype == DataType.float64()
+    assert schema["min"].dtype == dtype
+    assert schema["max"].dtype == dtype
+    assert daft_df.to_pydict() == {"sum": [220], "mean": [110.0], "min": [100], "max": [120]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_approx_count_distinct(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2],
+            "values": [1, 2, 1, None, 3, 3],
+        },
+        repartition=repartition_nparts,
+    )
+    global_df = daft_df.agg([(col("values"), "approx_count_distinct")])
+    assert global_df.to_pydict() == {"values": [3]}
+
+    grouped_df = daft_df.groupby("group").agg([(col("values"), "approx_count_distinct")]).sort("group")
+    assert grouped_df.to_pydict() == {"group": [1, 2], "values": [2, 1]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_count_distinct(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3],
+            "values": ["a", "b", "a", None, "c", "c", None],
+        },
+        repartition=repartition_nparts,
+    )
+    global_df = daft_df.agg([(col("values"), "count_distinct")])
+    assert global_df.to_pydict() == {"values": [3]}
+
+    grouped_df = daft_df.groupby("group").agg([(col("values"), "count_distinct")]).sort("group")
+    assert grouped_df.to_pydict() == {"group": [1, 2, 3], "values": [2, 1, 0]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_first_last(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 2, 1, 2, 1, 2],
+            "ts": [5, 2, 1, 8, 3, 4],
+            "values": ["e", "b", None, "h", "c", None],
+        },
+        repartition=repartition_nparts,
+    )
+    sorted_df = daft_df.sort("ts")
+    grouped_df = sorted_df.groupby("group").agg([(col("values").alias("first"), "first"), (col("values"), "last")])
+    assert grouped_df.sort("group").to_pydict() == {"group": [1, 2], "first": [None, "b"], "values": ["e", "h"]}
+
+    global_df = sorted_df.agg([(col("values").alias("first"), "first"), (col("values"), "last")])
+    assert global_df.to_pydict() == {"first": [None], "values": ["h"]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_filtered_aggs(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2],
+            "x": [1, 2, None, 4, 5, 6],
+            "y": [1, -1, 1, None, -1, -1],
+        },
+        repartition=repartition_nparts,
+    )
+    positive = col("y") > 0
+    grouped_df = daft_df.groupby("group").agg(
+        [
+            (col("x").alias("sum"), "sum", positive),
+            (col("x").alias("mean"), "mean", positive),
+            (col("x").alias("count"), "count", positive),
+            (col("x").alias("count_distinct"), "count_distinct", positive),
+            (col("x").alias("max"), "max", positive),
+        ]
+    )
+    assert grouped_df.sort("group").to_pydict() == {
+        "group": [1, 2],
+        "sum": [1, None],
+        "mean": [1.0, None],
+        "count": [1, 0],
+        "count_distinct": [1, 0],
+        "max": [1, None],
+    }
+
+    global_df = daft_df.agg([(col("x"), "sum", positive), (col("y").alias("num_rows"), "count", col("x") > 1)])
+    assert global_df.to_pydict() == {"x": [1], "num_rows": [3]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_str_join(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 3],
+            "values": ["a", None, "b", None, None, "c"],
+        },
+        repartition=repartition_nparts,
+    )
+    # Partitions can be joined in any order, so compare the joined pieces.
+    global_df = daft_df.agg_str_join(", ", "values")
+    [joined] = global_df.to_pydict()["values"]
+    assert sorted(joined.split(", ")) == ["a", "b", "c"]
+
+    grouped = daft_df.groupby("group").agg_str_join("-").sort("group").to_pydict()
+    assert grouped["group"] == [1, 2, 3]
+    assert sorted(grouped["values"][0].split("-")) == ["a", "b"]
+    assert grouped["values"][1:] == [None, "c"]
+
+
+def test_filtered_aggs_not_supported():
+    with pytest.raises(ValueError, match="skip nulls"):
+        col("x")._agg_list().filter(col("y") > 0)
+    with pytest.raises(ValueError, match="Expected an aggregation"):
+        col("x").filter(col("y") > 0)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_any_value(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 2, 2],
+            "values": [1, 1, None, 3],
+        },
+        repartition=repartition_nparts,
+    )
+    grouped_df = daft_df.groupby("group").any_value("values").sort("group")
+    assert grouped_df.to_pydict()["values"][0] == 1
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_median(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 2],
+            "values": [3, 1, None, 4, 1, 2, 10],
+        },
+        repartition=repartition_nparts,
+    )
+    global_df = daft_df.agg([(col("values"), "median")])
+    assert global_df.to_pydict() == {"values": [2.5]}
+
+    grouped_df = daft_df.groupby("group").agg([(col("values"), "median")]).sort("group")
+    assert grouped_df.to_pydict() == {"group": [1, 2], "values": [2.0, 3.0]}
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
 def test_agg_global_all_null(make_df, repartition_nparts):
     daft_df = make_df(
@@ -4,6 +4,7 @@ import decimal
 import tempfile
 
 import pyarrow as pa
+import pytest
 
 import daft
 
@@ -45,3 +46,91 @@ def test_python_decimal() -> None:
 
     res = df.to_pydict()["decimal128"]
     assert str(res) == str(python_decimals)
+
+
+def _decimal_df() -> daft.DataFrame:
+    return daft.from_arrow(
+        pa.table(
+            {
+                "a": pa.array([decimal.Decimal("1.25"), decimal.Decimal("-3.10"), None], pa.decimal128(5, 2)),
+                "b": pa.array(
+                    [decimal.Decimal("0.125"), decimal.Decimal("2.000"), decimal.Decimal("1.000")], pa.decimal128(6, 3)
+                ),
+            }
+        )
+    )
+
+
+def test_decimal_arithmetic() -> None:
+    df = _decimal_df().select(
+        (daft.col("a") + daft.col("b")).alias("add"),
+        (daft.col("a") - daft.col("b")).alias("sub"),
+        (daft.col("a") * daft.col("b")).alias("mul"),
+        (daft.col("a") * 2).alias("mul_int"),
+        (daft.col("a") / daft.col("b")).alias("div"),
+    )
+
+    schema = df.schema()
+    assert schema["add"].dtype == daft.DataType.decimal128(7, 3)
+    assert schema["sub"].dtype == daft.DataType.decimal128(7, 3)
+    assert schema["mul"].dtype == daft.DataType.decimal128(11, 5)
+    assert schema["mul_int"].dtype == daft.DataType.decimal128(15, 2)
+    assert schema["div"].dtype == daft.DataType.float64()
+    assert df.to_pydict() == {
+        "add": [decimal.Decimal("1.375"), decimal.Decimal("-1.100"), None],
+        "sub": [decimal.Decimal("1.125"), decimal.Decimal("-5.100"), None],
+        "mul": [decimal.Decimal("0.15625"), decimal.Decimal("-6.20000"), None],
+        "mul_int": [decimal.Decimal("2.50"), decimal.Decimal("-6.20"), None],
+        "div": [10.0, -1.55, None],
+    }
+
+
+def test_decimal_arithmetic_overflow_is_null() -> None:
+    df = daft.from_arrow(pa.table({"a": pa.array([decimal.Decimal("9" * 38)], pa.decimal128(38, 0))}))
+
+    assert df.select(daft.col("a") + 1).to_pydict() == {"a": [None]}
+
+
+def test_decimal_comparison() -> None:
+    df = _decimal_df().select(
+        (daft.col("a") > daft.col("b")).alias("gt_decimal"),
+        (daft.col("a") >= 1).alias("ge_int"),
+        (daft.col("a") == 1.25).alias("eq_float"),
+    )
+
+    assert df.to_pydict() == {
+        "gt_decimal": [True, False, None],
+        "ge_int": [True, False, None],
+        "eq_float": [True, False, None],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+def test_decimal_aggs(repartition_nparts) -> None:
+    df = _decimal_df().repartition(repartition_nparts)
+
+    result = df.agg([(daft.col("a").alias("sum"), "sum"), (daft.col("a").alias("mean"), "mean")]).collect()
+
+    assert result.schema()["sum"].dtype == daft.DataType.decimal128(38, 2)
+    assert result.schema()["mean"].dtype == daft.DataType.float64()
+    res = result.to_pydict()
+    assert res["sum"] == [decimal.Decimal("-1.85")]
+    assert res["mean"] == [pytest.approx(-0.925)]
+
+
+def test_decimal_casts() -> None:
+    df = _decimal_df().select(
+        daft.col("a").cast(daft.DataType.string()).alias("str"),
+        daft.col("a").cast(daft.DataType.float64()).alias("float"),
+    )
+    assert df.to_pydict() == {"str": ["1.25", "-3.10", None], "float": [1.25, -3.1, None]}
+
+    df = daft.from_pydict({"s": ["1.005", " -2 ", "abc", None], "f": [2.5, -1.0, 0.0, None]})
+    df = df.select(
+        daft.col("s").cast(daft.DataType.decimal128(5, 2)),
+        daft.col("f").cast(daft.DataType.decimal128(5, 2)),
+    )
+    assert df.to_pydict() == {
+        "s": [decimal.Decimal("1.01"), decimal.Decimal("-2.00"), None, None],
+        "f": [decimal.Decimal("2.50"), decimal.Decimal("-1.00"), decimal.Decimal("0.00"), None],
+    }
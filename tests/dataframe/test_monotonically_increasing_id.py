@@ -3,6 +3,7 @@ from __future__ import annotations
 import pytest
 
 from daft.datatype import DataType
+from daft.expressions import col, monotonically_increasing_id
 
 
 def test_monotonically_increasing_id_single_partition(make_df) -> None:
@@ -74,3 +75,19 @@ def test_monotonically_increasing_id_custom_col_name(make_df) -> None:
     assert set(df.column_names) == {"custom_id", "a"}
     assert df.schema()["custom_id"].dtype == DataType.uint64()
     assert df.to_pydict() == {"custom_id": [0, 1, 2, 3, 4], "a": [1, 2, 3, 4, 5]}
+
+
+def test_monotonically_increasing_id_expression(make_df) -> None:
+    data = {"a": [1, 2, 3, 4]}
+    df = make_df(data).into_partitions(2).with_column("id", monotonically_increasing_id()).collect()
+
+    assert df.column_names == ["a", "id"]
+    assert df.schema()["id"].dtype == DataType.uint64()
+    assert df.to_pydict() == {"a": [1, 2, 3, 4], "id": [0, 1, 1 << 36, (1 << 36) + 1]}
+
+
+def test_monotonically_increasing_id_expression_in_computation(make_df) -> None:
+    data = {"a": [1, 2, 3]}
+    df = make_df(data).select((monotonically_increasing_id() + col("a")).alias("b"), monotonically_increasing_id())
+
+    assert df.to_pydict() == {"b": [1, 3, 5], "monotonically_increasing_id": [0, 1, 2]}
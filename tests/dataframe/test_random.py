@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import re
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col, rand, uuid4
+
+
+def test_rand(make_df) -> None:
+    df = make_df({"a": list(range(100))}).with_column("r", rand()).collect()
+
+    assert df.schema()["r"].dtype == DataType.float64()
+    values = df.to_pydict()["r"]
+    assert len(values) == 100
+    assert all(0 <= v < 1 for v in values)
+    assert len(set(values)) > 1
+
+
+def test_rand_with_seed(make_df) -> None:
+    df = make_df({"a": list(range(10))})
+
+    first = df.select(rand(seed=42)).to_pydict()
+    second = df.select(rand(seed=42)).to_pydict()
+    assert first == second
+    assert df.select(rand(seed=7)).to_pydict() != first
+
+
+def test_rand_with_seed_differs_across_partitions(make_df) -> None:
+    df = make_df({"a": [0, 0, 1, 1]}).into_partitions(2)
+
+    values = df.with_column("r", rand(seed=42)).to_pydict()["r"]
+    assert len(set(values)) == 4
+    assert df.with_column("r", rand(seed=42)).to_pydict()["r"] == values
+
+    filtered = df.where(rand(seed=42) < 2.0).to_pydict()
+    assert filtered == {"a": [0, 0, 1, 1]}
+
+
+def test_rand_in_filter(make_df) -> None:
+    df = make_df({"a": list(range(100))}).where(rand(seed=0) < col("a").cast(DataType.float64()) / 100).collect()
+
+    assert 0 < len(df) < 100
+
+
+def test_rand_negative_seed() -> None:
+    with pytest.raises(ValueError, match="non-negative"):
+        rand(seed=-1)
+
+
+def test_uuid4(make_df) -> None:
+    df = make_df({"a": list(range(100))}).into_partitions(3).with_column("id", uuid4()).collect()
+
+    assert df.schema()["id"].dtype == DataType.string()
+    ids = df.to_pydict()["id"]
+    assert len(set(ids)) == 100
+    pattern = re.compile(r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$")
+    assert all(pattern.match(i) for i in ids)
@@ -312,13 +312,15 @@ def test_repr_empty_struct():
 """
     assert df.schema()._truncated_table_string() == expected_schema_truncated_repr
 
-    expected_schema_repr = """╭──────────────────────┬──────────────────────────────────╮
-│ Column Name          ┆ Type                             │
-╞══════════════════════╪══════════════════════════════════╡
-│ empty_structs        ┆ Struct[]                         │
-├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
-│ nested_empty_structs ┆ Struct[a: Struct[], b: Struct[]] │
-╰──────────────────────┴──────────────────────────────────╯
+    expected_schema_repr = """╭──────────────────────┬────────────────╮
+│ Column Name          ┆ Type           │
+╞══════════════════════╪════════════════╡
+│ empty_structs        ┆ Struct[]       │
+├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+│ nested_empty_structs ┆ Struct         │
+│                      ┆ ├─ a: Struct[] │
+│                      ┆ └─ b: Struct[] │
+╰──────────────────────┴────────────────╯
 """
     assert repr(df.schema()) == expected_schema_repr
 
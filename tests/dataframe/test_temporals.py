@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import tempfile
-from datetime import datetime, timedelta, timezone
+from datetime import date, datetime, timedelta, timezone
 
 import pyarrow as pa
 import pytest
@@ -41,6 +41,53 @@ def test_temporal_arithmetic() -> None:
     assert result["add_dur"] == [timedelta(days=2), timedelta(microseconds=2)]
 
 
+def test_date_arithmetic() -> None:
+    df = daft.from_pydict(
+        {
+            "date": [date(2023, 1, 31), date(2024, 2, 28), None],
+            "other": [date(2023, 1, 1), date(2024, 3, 1), date(2024, 3, 1)],
+            "duration": [timedelta(days=1), timedelta(days=2), timedelta(days=1)],
+        }
+    )
+
+    df = df.select(
+        (df["date"] + df["duration"]).alias("ladd"),
+        (df["duration"] + df["date"]).alias("radd"),
+        (df["date"] - df["duration"]).alias("sub"),
+        (df["date"] - df["other"]).alias("diff"),
+    ).collect()
+
+    assert df.schema()["ladd"].dtype == daft.DataType.date()
+    assert df.schema()["sub"].dtype == daft.DataType.date()
+    assert df.schema()["diff"].dtype == daft.DataType.duration(daft.TimeUnit.s())
+
+    result = df.to_pydict()
+    assert result["ladd"] == [date(2023, 2, 1), date(2024, 3, 1), None]
+    assert result["radd"] == result["ladd"]
+    assert result["sub"] == [date(2023, 1, 30), date(2024, 2, 26), None]
+    assert result["diff"] == [timedelta(days=30), timedelta(days=-2), None]
+
+
+def test_date_arithmetic_with_sub_day_duration() -> None:
+    df = daft.from_pydict({"date": [date(2023, 1, 31)], "duration": [timedelta(days=1, hours=12)]})
+    with pytest.raises(ValueError, match="whole days"):
+        df.select(df["date"] + df["duration"]).collect()
+    with pytest.raises(ValueError, match="whole days"):
+        df.select(df["date"] - df["duration"]).collect()
+
+
+def test_timestamp_arithmetic_mismatch_timezone() -> None:
+    pa_table = pa.Table.from_pydict(
+        {
+            "naive": pa.array([1], pa.timestamp("us")),
+            "utc": pa.array([1], pa.timestamp("us", "UTC")),
+        }
+    )
+    df = daft.from_arrow(pa_table)
+    with pytest.raises(ValueError):
+        df.select(df["naive"] - df["utc"]).collect()
+
+
 @pytest.mark.parametrize("format", ["csv", "parquet"])
 @pytest.mark.parametrize("use_native_downloader", [True, False])
 def test_temporal_file_roundtrip(format, use_native_downloader) -> None:
@@ -143,6 +190,106 @@ def test_python_duration() -> None:
     assert res == duration
 
 
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+def test_duration_aggs(repartition_nparts) -> None:
+    df = daft.from_pydict(
+        {
+            "group": [1, 1, 1, 2, 2],
+            "latency": [
+                timedelta(seconds=1),
+                timedelta(seconds=2),
+                None,
+                timedelta(milliseconds=5),
+                timedelta(milliseconds=6),
+            ],
+        }
+    ).repartition(repartition_nparts)
+
+    df = (
+        df.groupby("group")
+        .agg([(df["latency"].alias("total"), "sum"), (df["latency"].alias("avg"), "mean")])
+        .sort("group")
+    )
+    assert df.schema()["total"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    assert df.schema()["avg"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+
+    # Means are truncated to whole time units.
+    result = df.to_pydict()
+    assert result["total"] == [timedelta(seconds=3), timedelta(milliseconds=11)]
+    assert result["avg"] == [timedelta(milliseconds=1500), timedelta(microseconds=5500)]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 3])
+def test_duration_mean_of_all_null_group(repartition_nparts) -> None:
+    df = daft.from_pydict(
+        {
+            "group": [1, 1, 2, 2],
+            "latency": [timedelta(seconds=1), timedelta(seconds=2), None, None],
+        }
+    ).repartition(repartition_nparts, "group")
+
+    df = df.groupby("group").agg([(df["latency"].alias("avg"), "mean")]).sort("group")
+    assert df.to_pydict() == {"group": [1, 2], "avg": [timedelta(milliseconds=1500), None]}
+
+
+def test_duration_division_and_integer_cast() -> None:
+    df = daft.from_pydict({"duration": [timedelta(seconds=3), timedelta(microseconds=-3), None]})
+
+    df = df.select(
+        (df["duration"] / 2).alias("halved"),
+        df["duration"].cast(daft.DataType.int64()).alias("micros"),
+        df["duration"].cast(daft.DataType.int32()).alias("micros_i32"),
+    ).collect()
+
+    assert df.schema()["halved"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    result = df.to_pydict()
+    assert result["halved"] == [timedelta(milliseconds=1500), timedelta(microseconds=-1), None]
+    assert result["micros"] == [3_000_000, -3, None]
+    assert result["micros_i32"] == result["micros"]
+
+    df = daft.from_pydict({"duration": [timedelta(seconds=1), None], "divisor": [0, 0]})
+    result = df.select((df["duration"] / df["divisor"]).alias("by_zero"), (df["duration"] / 0).alias("by_lit_zero"))
+    assert result.to_pydict() == {"by_zero": [None, None], "by_lit_zero": [None, None]}
+
+
+def test_interval_arithmetic() -> None:
+    df = daft.from_pydict(
+        {
+            "date": [date(2024, 1, 31), date(2023, 3, 31), None],
+            "timestamp": [datetime(2024, 1, 31, 12), datetime(2023, 3, 31, 0, 30), None],
+        }
+    )
+
+    df = df.select(
+        (df["date"] + daft.interval(months=1)).alias("date_plus"),
+        (df["date"] - daft.interval(years=1, days=1)).alias("date_minus"),
+        (daft.interval(weeks=1) + df["date"]).alias("date_rplus"),
+        (df["timestamp"] + daft.interval(months=1, hours=1)).alias("ts_plus"),
+        (df["timestamp"] - daft.interval(months=1, minutes=45)).alias("ts_minus"),
+    ).collect()
+
+    assert df.schema()["date_plus"].dtype == daft.DataType.date()
+    assert df.schema()["ts_plus"].dtype == daft.DataType.timestamp(daft.TimeUnit.us())
+    assert df.to_pydict() == {
+        "date_plus": [date(2024, 2, 29), date(2023, 4, 30), None],
+        "date_minus": [date(2023, 1, 30), date(2022, 3, 30), None],
+        "date_rplus": [date(2024, 2, 7), date(2023, 4, 7), None],
+        "ts_plus": [datetime(2024, 2, 29, 13), datetime(2023, 4, 30, 1, 30), None],
+        "ts_minus": [datetime(2023, 12, 31, 11, 15), datetime(2023, 2, 27, 23, 45), None],
+    }
+
+
+def test_interval_arrow_roundtrip() -> None:
+    arrow_intervals = pa.array(
+        [pa.MonthDayNano([1, 2, 3_000_000_000]), None], type=pa.month_day_nano_interval()
+    )
+    df = daft.from_arrow(pa.table({"interval": arrow_intervals}))
+
+    assert df.schema()["interval"].dtype == daft.DataType.interval()
+    assert df.to_arrow()["interval"].combine_chunks() == arrow_intervals
+    assert df.select(df["interval"].cast(daft.DataType.string())).to_pydict() == {"interval": ["P1M2DT3S", None]}
+
+
 @pytest.mark.parametrize(
     "timeunit",
     ["s", "ms", "us", "ns"],
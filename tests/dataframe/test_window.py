@@ -0,0 +1,263 @@
+from __future__ import annotations
+
+import sqlite3
+
+import pytest
+
+from daft import Window, col
+from daft.expressions import dense_rank, rank, row_number
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_partition_aggregation(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "group": [1, 2, 1, 1, 2],
+            "values": [1, 2, 3, None, 5],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.with_column("total", col("values")._sum().over(Window.partition_by("group"))).sort("id")
+    assert daft_df.to_pydict()["total"] == [4, 7, 4, 4, 7]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_running_aggregation(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "group": [1, 2, 1, 1, 2],
+            "values": [1, 2, 3, 4, 5],
+        },
+        repartition=repartition_nparts,
+    )
+    running_sum = col("values")._sum().over(Window.partition_by("group").order_by("id", desc=True))
+    running_max = col("values").alias("max")._max().over(Window.order_by("id"))
+    daft_df = daft_df.select(col("id"), running_sum, running_max).sort("id")
+    assert daft_df.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "values": [8, 7, 7, 4, 5],
+        "max": [1, 2, 3, 4, 5],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_running_aggregation_with_nulls(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "group": [1, 1, 1, 2, 2],
+            "values": [None, 4, 2, 6, None],
+            "names": ["b", None, "a", "c", "d"],
+        },
+        repartition=repartition_nparts,
+    )
+    window = Window.partition_by("group").order_by("id")
+    daft_df = daft_df.select(
+        col("id"),
+        col("values").alias("sum")._sum().over(window),
+        col("values").alias("mean")._mean().over(window),
+        col("values").alias("count")._count().over(window),
+        col("names").alias("min")._min().over(window),
+        col("names").alias("max")._max().over(window),
+    ).sort("id")
+    assert daft_df.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "sum": [None, 4, 6, 6, 6],
+        "mean": [None, 4.0, 3.0, 6.0, 6.0],
+        "count": [0, 1, 2, 1, 1],
+        "min": ["b", "b", "a", "c", "c"],
+        "max": ["b", "b", "b", "c", "d"],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize("desc", [False, True])
+def test_window_running_aggregation_with_ties_matches_sql(make_df, repartition_nparts, desc):
+    data = {
+        "id": list(range(8)),
+        "group": ["a", "b", "a", "a", "b", "a", "b", "a"],
+        "t": [1, 1, 2, 1, 3, 2, 3, 3],
+        "values": [1, 2, 3, 4, 5, 6, 7, 8],
+    }
+    daft_df = make_df(data, repartition=repartition_nparts)
+    window = Window.partition_by("group").order_by("t", desc=desc)
+    daft_df = daft_df.select(
+        col("id"),
+        col("values").alias("sum")._sum().over(window),
+        col("values").alias("count")._count().over(window),
+        col("values").alias("max")._max().over(window),
+    ).sort("id")
+
+    direction = "DESC" if desc else "ASC"
+    conn = sqlite3.connect(":memory:")
+    conn.execute("CREATE TABLE t (id INTEGER, grp TEXT, t INTEGER, v INTEGER)")
+    conn.executemany("INSERT INTO t VALUES (?, ?, ?, ?)", zip(data["id"], data["group"], data["t"], data["values"]))
+    rows = conn.execute(
+        f"""
+        SELECT
+            SUM(v) OVER (PARTITION BY grp ORDER BY t {direction}),
+            COUNT(v) OVER (PARTITION BY grp ORDER BY t {direction}),
+            MAX(v) OVER (PARTITION BY grp ORDER BY t {direction})
+        FROM t ORDER BY id
+        """
+    ).fetchall()
+    assert daft_df.to_pydict() == {
+        "id": data["id"],
+        "sum": [r[0] for r in rows],
+        "count": [r[1] for r in rows],
+        "max": [r[2] for r in rows],
+    }
+
+
+def test_window_running_unsupported_aggregation(make_df):
+    daft_df = make_df({"id": [0, 1], "values": [1, 2]})
+    with pytest.raises(ValueError):
+        daft_df.select(col("values")._agg_list().over(Window.order_by("id"))).collect()
+
+
+def test_window_over_non_aggregation(make_df):
+    daft_df = make_df({"group": [1, 2], "values": [1, 2]})
+    with pytest.raises(ValueError):
+        daft_df.with_column("total", col("values").over(Window.partition_by("group")))
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_cumulative_over_window(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "group": [1, 2, 1, 1, 2],
+            "values": [3, 2, None, 1, 5],
+        },
+        repartition=repartition_nparts,
+    )
+    window = Window.partition_by("group").order_by("id")
+    daft_df = daft_df.select(
+        col("id"),
+        col("values").alias("sum").cumsum().over(window),
+        col("values").alias("max").cummax().over(window),
+        col("values").alias("min").cummin().over(window),
+        col("values").alias("count").cumcount().over(window),
+    ).sort("id")
+    assert daft_df.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "sum": [3, 2, None, 4, 7],
+        "max": [3, 2, None, 3, 5],
+        "min": [3, 2, None, 1, 2],
+        "count": [1, 1, 1, 2, 2],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_cumulative_over_all_rows(make_df, repartition_nparts):
+    daft_df = make_df({"id": [3, 1, 0, 2], "values": [4.0, 2.0, 1.0, 3.0]}, repartition=repartition_nparts)
+    daft_df = daft_df.sort("id").with_column("sum", col("values").cumsum())
+    assert daft_df.to_pydict() == {"id": [0, 1, 2, 3], "values": [1.0, 2.0, 3.0, 4.0], "sum": [1.0, 3.0, 6.0, 10.0]}
+
+
+def test_cumulative_non_numeric(make_df):
+    daft_df = make_df({"values": ["a", "b"]})
+    with pytest.raises(ValueError):
+        daft_df.with_column("sum", col("values").cumsum())
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize("desc", [False, True])
+def test_ranking_matches_sql(make_df, repartition_nparts, desc):
+    data = {
+        "id": list(range(10)),
+        "group": ["a", "b", "a", "a", "b", "a", "b", "a", "b", "b"],
+        "t": [3, 1, 1, 3, 2, 2, 2, 3, 1, 5],
+    }
+    daft_df = make_df(data, repartition=repartition_nparts)
+    window = Window.partition_by("group").order_by("t", desc=desc)
+    # Order ties by id for row_number, so that the numbering is deterministic.
+    tiebroken_window = Window.partition_by("group").order_by("t", "id", desc=[desc, False])
+    daft_df = daft_df.select(
+        col("id"),
+        row_number().over(tiebroken_window),
+        rank().over(window),
+        dense_rank().over(window),
+    ).sort("id")
+
+    direction = "DESC" if desc else "ASC"
+    conn = sqlite3.connect(":memory:")
+    conn.execute("CREATE TABLE t (id INTEGER, grp TEXT, t INTEGER)")
+    conn.executemany("INSERT INTO t VALUES (?, ?, ?)", zip(data["id"], data["group"], data["t"]))
+    rows = conn.execute(
+        f"""
+        SELECT
+            ROW_NUMBER() OVER (PARTITION BY grp ORDER BY t {direction}, id ASC),
+            RANK() OVER (PARTITION BY grp ORDER BY t {direction}),
+            DENSE_RANK() OVER (PARTITION BY grp ORDER BY t {direction})
+        FROM t ORDER BY id
+        """
+    ).fetchall()
+    assert daft_df.to_pydict() == {
+        "id": data["id"],
+        "row_number": [r[0] for r in rows],
+        "rank": [r[1] for r in rows],
+        "dense_rank": [r[2] for r in rows],
+    }
+
+
+def test_ranking_without_order_by(make_df):
+    daft_df = make_df({"group": [1, 2, 1], "t": [1, 2, 3]})
+    daft_df = daft_df.select(rank().over(Window.partition_by("group")), dense_rank().over(Window.partition_by("group")))
+    # Without an ordering, all rows of a partition are peers.
+    assert daft_df.to_pydict() == {"rank": [1, 1, 1], "dense_rank": [1, 1, 1]}
+
+
+def test_ranking_without_window(make_df):
+    daft_df = make_df({"t": [1, 2, 3]})
+    with pytest.raises(ValueError):
+        daft_df.select(rank()).collect()
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_lead_lag_over_window(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "group": [1, 2, 1, 1, 2],
+            "values": [1.0, 2.0, 4.0, 7.0, 3.0],
+        },
+        repartition=repartition_nparts,
+    )
+    window = Window.partition_by("group").order_by("id")
+    daft_df = daft_df.select(
+        col("id"),
+        (col("values") - col("values").lag().over(window)).alias("delta"),
+        col("values").alias("next").lead(1, 0).over(window),
+        col("values").alias("prev2").lag(2, col("id")).over(window),
+    ).sort("id")
+    assert daft_df.to_pydict() == {
+        "id": [0, 1, 2, 3, 4],
+        "delta": [None, None, 3.0, 3.0, 1.0],
+        "next": [4.0, 3.0, 7.0, 0.0, 0.0],
+        "prev2": [0.0, 1.0, 2.0, 1.0, 4.0],
+    }
+
+
+def test_lead_lag_over_all_rows(make_df):
+    daft_df = make_df({"id": [2, 0, 1], "values": ["c", "a", "b"]})
+    daft_df = daft_df.sort("id").select(col("values").lag().alias("prev"), col("values").lead().alias("next"))
+    assert daft_df.to_pydict() == {"prev": [None, "a", "b"], "next": ["b", "c", None]}
+
+
+def test_lead_lag_negative_offset():
+    with pytest.raises(ValueError):
+        col("values").lead(-1)
+
+
+def test_window_outside_projection(make_df):
+    daft_df = make_df({"id": [0, 1, 2], "values": [1, 2, 3]})
+    running_sum = col("values")._sum().over(Window.order_by("id"))
+    with pytest.raises(ValueError):
+        daft_df.where(running_sum > 1)
+    with pytest.raises(ValueError):
+        daft_df.sort(running_sum)
+    with pytest.raises(ValueError):
+        daft_df.agg([(col("values").cumsum().over(Window.order_by("id")), "sum")])
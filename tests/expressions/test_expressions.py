@@ -166,6 +166,27 @@ def test_float_is_nan() -> None:
     assert output == "is_nan(col(a))"
 
 
+def test_float_fill_nan() -> None:
+    table = MicroPartition.from_pydict({"a": [1.0, float("nan"), None]})
+    result = table.eval_expression_list([col("a").float.fill_nan(0.0), col("a").float.is_inf().alias("inf")])
+    assert result.to_pydict() == {"a": [1.0, 0.0, None], "inf": [False, False, None]}
+
+
+def test_clip() -> None:
+    table = MicroPartition.from_pydict({"a": [1, 5, 10, None], "hi": [3, 3, None, 3]})
+    result = table.eval_expression_list(
+        [col("a").clip(2, col("hi")), col("a").clip(upper=4).alias("upper"), col("a").sign().alias("sign")]
+    )
+    assert result.to_pydict() == {"a": [2, 3, 10, None], "upper": [1, 4, 4, None], "sign": [1, 1, 1, None]}
+
+
+def test_eq_null_safe() -> None:
+    table = MicroPartition.from_pydict({"a": [1, None, None, 2], "b": [1, None, 3, None]})
+    result = table.eval_expression_list([col("a").eq_null_safe(col("b")), (col("a") == col("b")).alias("eq")])
+    assert result.to_pydict() == {"a": [True, True, False, False], "eq": [True, None, None, None]}
+    assert repr(col("a").eq_null_safe(col("b"))) == "col(a) <=> col(b)"
+
+
 def test_date_lit_post_epoch() -> None:
     d = lit(date(2022, 1, 1))
     output = repr(d)
@@ -200,3 +221,14 @@ def test_repr_series_lit() -> None:
     s = lit(Series.from_pylist([1, 2, 3]))
     output = repr(s)
     assert output == "lit([1, 2, 3])"
+
+
+def test_hash_expression() -> None:
+    table = MicroPartition.from_pydict({"a": [1, 2, 1, None]})
+    result = table.eval_expression_list([col("a").hash(), col("a").hash(seed=42).alias("seeded")])
+    assert result.schema()["a"].dtype == DataType.uint64()
+
+    hashes = result.get_column("a").to_pylist()
+    assert hashes[0] == hashes[2]
+    assert hashes[0] != hashes[1]
+    assert result.get_column("seeded").to_pylist() != hashes
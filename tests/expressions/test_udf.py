@@ -48,6 +48,29 @@ def test_class_udf():
     assert result.to_pydict() == {"a": ["foofoo", "barbar", "bazbaz"]}
 
 
+def test_udf_batch_size():
+    table = MicroPartition.from_pydict({"a": list(range(10)), "b": list(range(10, 20))})
+    num_inits = []
+    batch_lengths = []
+
+    @udf(return_dtype=DataType.int64(), batch_size=4)
+    class AddColumns:
+        def __init__(self):
+            num_inits.append(1)
+
+        def __call__(self, a, b):
+            batch_lengths.append(len(a))
+            return [x + y for x, y in zip(a.to_pylist(), b.to_pylist())]
+
+    result = table.eval_expression_list([AddColumns(col("a"), col("b"))])
+    assert result.to_pydict() == {"a": [x + x + 10 for x in range(10)]}
+    assert batch_lengths == [4, 4, 2]
+    assert len(num_inits) == 1
+
+    with pytest.raises(ValueError):
+        udf(return_dtype=DataType.int64(), batch_size=0)(lambda x: x)
+
+
 def test_udf_kwargs():
     table = MicroPartition.from_pydict({"a": ["foo", "bar", "baz"]})
 
@@ -168,3 +191,20 @@ def test_udf_repr():
         pass
 
     assert single_arg_udf(col("x"), "y", z=20).__repr__() == "@udf[single_arg_udf](col('x'), 'y', z=20)"
+
+
+def test_udf_pickle_roundtrip():
+    table = MicroPartition.from_pydict({"a": ["foo", "bar", "baz"]})
+
+    @udf(return_dtype=DataType.string())
+    def repeat_n(data, n):
+        return Series.from_pylist([d.as_py() * n for d in data.to_arrow()])
+
+    import pickle
+
+    expr = repeat_n(col("a"), 2).alias("b")
+    roundtripped = pickle.loads(pickle.dumps(expr))
+    assert expr_structurally_equal(roundtripped, expr)
+
+    result = table.eval_expression_list([roundtripped])
+    assert result.to_pydict() == {"b": ["foofoo", "barbar", "bazbaz"]}
@@ -5,7 +5,8 @@ import operator as ops
 import pytest
 
 from daft.datatype import DataType
-from daft.expressions import col
+from daft.expressions import ExpressionsProjection, col
+from daft.table import MicroPartition
 from tests.expressions.typing.conftest import (
     assert_typing_resolve_vs_runtime_behavior,
     has_supertype,
@@ -21,6 +22,10 @@ def plus_type_validation(lhs: DataType, rhs: DataType) -> bool:
         if not (is_numeric(arg) or (arg == DataType.string()) or (arg == DataType.bool()) or (arg == DataType.null())):
             return False
 
+    # Strings are only added to strings, other types have to be explicitly cast first
+    if DataType.string() in (lhs, rhs):
+        return all(arg in (DataType.string(), DataType.null()) for arg in (lhs, rhs))
+
     return has_supertype(lhs, rhs)
 
 
@@ -39,6 +44,14 @@ def test_plus(binary_data_fixture):
     )
 
 
+def test_plus_error_points_to_expression():
+    table = MicroPartition.from_pydict({"s": ["a", "b"], "x": [1, 2]})
+    projection = ExpressionsProjection([((col("s") + col("x")) * 2).alias("result")])
+    with pytest.raises(ValueError, match="explicitly cast") as exc_info:
+        projection.resolve_schema(table.schema())
+    assert "in expression: col(s) + col(x)" in str(exc_info.value)
+
+
 def binary_numeric_arithmetic_type_validation(lhs: DataType, rhs: DataType) -> bool:
     """Checks whether these input types are resolvable for arithmetic operations"""
     # (numeric <op> numeric = numeric)
@@ -92,3 +105,35 @@ def test_floor(unary_data_fixture):
         run_kernel=lambda: arg.floor(),
         resolvable=is_numeric(arg.datatype()),
     )
+
+
+def test_round(unary_data_fixture):
+    arg = unary_data_fixture
+    assert_typing_resolve_vs_runtime_behavior(
+        data=(unary_data_fixture,),
+        expr=col(arg.name()).round(1),
+        run_kernel=lambda: arg.round(1),
+        resolvable=is_numeric(arg.datatype()),
+    )
+
+
+@pytest.mark.parametrize("fn_name", ["sqrt", "exp", "ln", "sin", "cos", "tan"])
+def test_unary_math(unary_data_fixture, fn_name):
+    arg = unary_data_fixture
+    assert_typing_resolve_vs_runtime_behavior(
+        data=(unary_data_fixture,),
+        expr=getattr(col(arg.name()), fn_name)(),
+        run_kernel=lambda: getattr(arg, fn_name)(),
+        resolvable=is_numeric(arg.datatype()),
+    )
+
+
+@pytest.mark.parametrize("fn_name", ["pow", "log", "atan2"])
+def test_binary_math(binary_data_fixture, fn_name):
+    lhs, rhs = binary_data_fixture
+    assert_typing_resolve_vs_runtime_behavior(
+        data=binary_data_fixture,
+        expr=getattr(col(lhs.name()), fn_name)(col(rhs.name())),
+        run_kernel=lambda: getattr(lhs, fn_name)(rhs),
+        resolvable=is_numeric(lhs.datatype()) and is_numeric(rhs.datatype()),
+    )
@@ -31,7 +31,9 @@ def test_str_compares(binary_data_fixture, op, request):
         data=binary_data_fixture,
         expr=op(col(lhs.name()), col(rhs.name())),
         run_kernel=lambda: op(lhs, rhs),
-        resolvable=(lhs.datatype() == DataType.string()) and (rhs.datatype() == DataType.string()),
+        # Nulls of the Null type propagate like null strings.
+        resolvable=(lhs.datatype() in (DataType.string(), DataType.null()))
+        and (rhs.datatype() in (DataType.string(), DataType.null())),
     )
 
 
@@ -45,6 +47,16 @@ def test_str_length():
     )
 
 
+def test_str_length_chars():
+    s = Series.from_arrow(pa.array(["1", "é", "3"]), name="arg")
+    assert_typing_resolve_vs_runtime_behavior(
+        data=[s],
+        expr=col(s.name()).str.length_chars(),
+        run_kernel=s.str.length_chars,
+        resolvable=True,
+    )
+
+
 def test_str_lower():
     s = Series.from_arrow(pa.array(["Foo", "BarBaz", "QUUX"]), name="arg")
     assert_typing_resolve_vs_runtime_behavior(
@@ -55,6 +67,16 @@ def test_str_lower():
     )
 
 
+def test_str_capitalize():
+    s = Series.from_arrow(pa.array(["foo", "BarBaz", "quux"]), name="arg")
+    assert_typing_resolve_vs_runtime_behavior(
+        data=[s],
+        expr=col(s.name()).str.capitalize(),
+        run_kernel=s.str.capitalize,
+        resolvable=True,
+    )
+
+
 def test_str_upper():
     s = Series.from_arrow(pa.array(["Foo", "BarBaz", "quux"]), name="arg")
     assert_typing_resolve_vs_runtime_behavior(
@@ -93,3 +115,13 @@ def test_str_reverse():
         run_kernel=s.str.reverse,
         resolvable=True,
     )
+
+
+def test_str_substr():
+    s = Series.from_arrow(pa.array(["abc", "déf", None, ""]), name="arg")
+    assert_typing_resolve_vs_runtime_behavior(
+        data=[s],
+        expr=col(s.name()).str.substr(1, 1),
+        run_kernel=lambda: s.str.substr(1, 1),
+        resolvable=True,
+    )
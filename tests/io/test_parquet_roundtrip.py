@@ -21,6 +21,11 @@ PYARROW_GE_8_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeri
     ["data", "pa_type", "expected_dtype"],
     [
         ([1, 2, None], pa.int64(), DataType.int64()),
+        ([1, -2, None], pa.int8(), DataType.int8()),
+        ([1, -2, None], pa.int16(), DataType.int16()),
+        ([1, 255, None], pa.uint8(), DataType.uint8()),
+        ([1, 65535, None], pa.uint16(), DataType.uint16()),
+        ([1.5, -2.5, None], pa.float32(), DataType.float32()),
         (["a", "b", None], pa.large_string(), DataType.string()),
         ([True, False, None], pa.bool_(), DataType.bool()),
         ([b"a", b"b", None], pa.large_binary(), DataType.binary()),
@@ -51,6 +56,11 @@ PYARROW_GE_8_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeri
         # TODO: Crashes when parsing fixed size lists
         # ([[1, 2, 3], [4, 5, 6], None], pa.list_(pa.int64(), list_size=3), DataType.fixed_size_list(DataType.int64(), 3)),
         ([{"bar": 1}, {"bar": None}, None], pa.struct({"bar": pa.int64()}), DataType.struct({"bar": DataType.int64()})),
+        (
+            [[("a", 1), ("b", None)], [], None],
+            pa.map_(pa.large_string(), pa.int64()),
+            DataType.map(DataType.string(), DataType.int64()),
+        ),
     ],
 )
 def test_roundtrip_simple_arrow_types(tmp_path, data, pa_type, expected_dtype):
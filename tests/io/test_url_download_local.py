@@ -56,3 +56,17 @@ def test_url_download_local_no_read_permissions(local_image_data_fixture, tmpdir
 
     with pytest.raises(PermissionError):
         df.collect()
+
+
+@pytest.mark.integration()
+def test_url_download_local_missing_null(local_image_data_fixture, image_data):
+    data = {"urls": local_image_data_fixture + ["/missing/path/x.jpeg"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null"))
+    assert df.to_pydict() == {**data, "data": [image_data for _ in range(len(local_image_data_fixture))] + [None]}
+
+
+def test_url_download_invalid_on_error():
+    df = daft.from_pydict({"urls": ["/missing/path/x.jpeg"]})
+    with pytest.raises(ValueError):
+        df["urls"].url.download(on_error="ignore")
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import itertools
 import operator
+from datetime import timedelta
 
 import pyarrow as pa
 import pytest
@@ -142,7 +143,8 @@ def test_add_for_int_and_string_array(l_dtype, r_dtype) -> None:
     l = Series.from_arrow(l_arrow.cast(l_dtype), name="left")
     r = Series.from_arrow(r_arrow.cast(r_dtype), name="right")
 
-    add = l + r
+    # Non-string operands must be cast to strings explicitly to be concatenated.
+    add = l.cast(DataType.string()) + r
     assert add.name() == l.name()
     assert add.to_pylist() == ["11", "24", "31", None, None, None]
 
@@ -157,7 +159,8 @@ def test_add_for_int_and_string_left_scalar(l_dtype, r_dtype) -> None:
     l = Series.from_arrow(l_arrow.cast(l_dtype), name="left")
     r = Series.from_arrow(r_arrow.cast(r_dtype), name="right")
 
-    add = l + r
+    # Non-string operands must be cast to strings explicitly to be concatenated.
+    add = l.cast(DataType.string()) + r
     assert add.name() == l.name()
     assert add.to_pylist() == ["11", "14", "11", "15", None, None]
 
@@ -172,7 +175,8 @@ def test_add_for_int_and_string_right_scalar(l_dtype, r_dtype) -> None:
     l = Series.from_arrow(l_arrow.cast(l_dtype), name="left")
     r = Series.from_arrow(r_arrow.cast(r_dtype), name="right")
 
-    add = l + r
+    # Non-string operands must be cast to strings explicitly to be concatenated.
+    add = l.cast(DataType.string()) + r
     assert add.name() == l.name()
     assert add.to_pylist() == ["11", "21", "31", None, "51", None]
 
@@ -187,11 +191,47 @@ def test_add_for_int_and_string_null_scalar(l_dtype, r_dtype) -> None:
     l = Series.from_arrow(l_arrow.cast(l_dtype), name="left")
     r = Series.from_arrow(r_arrow.cast(r_dtype), name="right")
 
-    add = l + r
+    # Non-string operands must be cast to strings explicitly to be concatenated.
+    add = l.cast(DataType.string()) + r
     assert add.name() == l.name()
     assert add.to_pylist() == [None, None, None, None, None, None]
 
 
+@pytest.mark.parametrize("l_dtype", arrow_int_types + arrow_float_types)
+def test_add_for_non_string_and_string_raises(l_dtype) -> None:
+    l = Series.from_arrow(pa.array([1, 2]).cast(l_dtype), name="left")
+    r = Series.from_arrow(pa.array(["a", "b"]), name="right")
+
+    with pytest.raises(ValueError, match="explicitly cast"):
+        l + r
+    with pytest.raises(ValueError, match="explicitly cast"):
+        r + l
+
+
+@pytest.mark.parametrize("l_dtype, r_dtype", itertools.product(arrow_int_types + arrow_float_types, repeat=2))
+def test_division_by_zero_is_null(l_dtype, r_dtype) -> None:
+    l = Series.from_arrow(pa.array([6, 0, 7, None]).cast(l_dtype), name="left")
+    r = Series.from_arrow(pa.array([3, 0, 0, 0]).cast(r_dtype), name="right")
+
+    assert (l / r).to_pylist() == [2.0, None, None, None]
+    assert (l % r).to_pylist() == [0, None, None, None]
+
+    zero = Series.from_arrow(pa.array([0]).cast(r_dtype))
+    assert (l / zero).to_pylist() == [None, None, None, None]
+    assert (l % zero).to_pylist() == [None, None, None, None]
+
+    one = Series.from_arrow(pa.array([1]).cast(l_dtype))
+    assert (one / r).to_pylist() == [1 / 3, None, None, None]
+
+
+def test_duration_division_by_zero_is_null() -> None:
+    l = Series.from_pylist([timedelta(seconds=6), timedelta(seconds=7), None], name="left")
+    r = Series.from_pylist([3, 0, 0], name="right")
+
+    assert (l / r).to_pylist() == [timedelta(seconds=2), None, None]
+    assert (l / Series.from_pylist([0])).to_pylist() == [None, None, None]
+
+
 def test_comparisons_bad_right_value() -> None:
     l_arrow = pa.array([1, 2, 3, None, 5, None])
 
@@ -169,6 +169,28 @@ def test_series_cast_python_to_fixed_size_list(dtype) -> None:
     )
 
 
+def test_series_cast_list_to_fixed_size_list_and_back() -> None:
+    data = [[1, 2, 3], None, [4, 5, 6]]
+    s = Series.from_pylist(data)
+
+    target_dtype = DataType.fixed_size_list(DataType.int64(), 3)
+    t = s.cast(target_dtype)
+    assert t.datatype() == target_dtype
+    assert t.to_pylist() == data
+
+    back = t.cast(DataType.list(DataType.int64()))
+    assert back.datatype() == DataType.list(DataType.int64())
+    assert back.list.lengths().to_pylist() == [3, None, 3]
+    assert back.to_pylist() == data
+
+
+def test_series_cast_list_to_fixed_size_list_wrong_size() -> None:
+    s = Series.from_pylist([[1, 2, 3], [4, 5]])
+
+    with pytest.raises(ValueError, match="not all elements have sizes: 3"):
+        s.cast(DataType.fixed_size_list(DataType.int64(), 3))
+
+
 @pytest.mark.parametrize("dtype", ARROW_FLOAT_TYPES + ARROW_INT_TYPES)
 def test_series_cast_python_to_embedding(dtype) -> None:
     data = [[1, 2, 3], np.arange(3), ["1", "2", "3"], [1, "2", 3.0], pd.Series([1.1, 2, 3]), (1, 2, 3), None]
@@ -762,6 +784,30 @@ def test_series_cast_struct_add_col() -> None:
     assert casted.to_pylist() == [{**x, "baz": None} for x in data.to_pylist()]
 
 
+def test_cast_date_to_string_and_back():
+    from datetime import date
+
+    input = Series.from_pylist([date(2022, 1, 6), None, date(1969, 12, 31)])
+    casted = input.cast(DataType.string())
+    assert casted.to_pylist() == ["2022-01-06", None, "1969-12-31"]
+
+    back = casted.cast(DataType.date())
+    assert back.datatype() == DataType.date()
+    assert back.to_pylist() == input.to_pylist()
+
+
+@pytest.mark.parametrize("timeunit", ["us", "ns"])
+def test_cast_string_to_time(timeunit):
+    from datetime import time
+
+    input = Series.from_pylist(["09:30:00", "23:59:59.5", " 00:01 ", "not a time", None])
+    casted = input.cast(DataType.time(timeunit))
+    assert casted.datatype() == DataType.time(timeunit)
+    assert casted.to_pylist() == [time(9, 30), time(23, 59, 59, 500000), time(0, 1), None, None]
+
+    assert casted.cast(DataType.string()).cast(DataType.time(timeunit)).to_pylist() == casted.to_pylist()
+
+
 def test_cast_date_to_timestamp():
     from datetime import date, datetime
 
@@ -791,3 +837,65 @@ def test_cast_timestamp_to_time_unsupported_timeunit(timeunit):
     input = Series.from_pylist([datetime(2022, 1, 6, 12, 34, 56, 78)])
     with pytest.raises(ValueError):
         input.cast(DataType.time(timeunit))
+
+
+@pytest.mark.parametrize(
+    "data, dtype",
+    [
+        (["1", "x", None], DataType.int64()),
+        (["2023-01-01", "not a date", None], DataType.date()),
+        ([1, 300, None], DataType.uint8()),
+        ([1, -1, None], DataType.uint64()),
+        ([1.0, float("nan"), None], DataType.int32()),
+        ([1.0, 1e20, None], DataType.int64()),
+    ],
+)
+def test_strict_cast_raises_on_failure(data, dtype):
+    s = Series.from_pylist(data)
+    with pytest.raises(ValueError, match="Cannot cast value"):
+        s.cast(dtype, strict=True)
+    # The lenient cast still succeeds.
+    assert len(s.cast(dtype)) == len(data)
+
+
+def test_strict_cast_succeeds():
+    s = Series.from_pylist(["1", None, "-3"])
+    assert s.cast(DataType.int8(), strict=True).to_pylist() == [1, None, -3]
+    assert s.cast(DataType.int8()).to_pylist() == [1, None, -3]
+
+    assert Series.from_pylist(["1", "x"]).cast(DataType.int64()).to_pylist() == [1, None]
+    assert Series.from_pylist([1.5, None]).cast(DataType.int64(), strict=True).to_pylist() == [1, None]
+
+
+def test_strict_cast_null_series():
+    s = Series.from_pylist([None, None])
+    assert s.cast(DataType.int64(), strict=True).to_pylist() == [None, None]
+
+
+@pytest.mark.parametrize(
+    "data, source_dtype, dtype",
+    [
+        ([["1", "x"], None], None, DataType.list(DataType.int64())),
+        (
+            [[1, 300], [2, 3]],
+            DataType.fixed_size_list(DataType.int64(), 2),
+            DataType.fixed_size_list(DataType.uint8(), 2),
+        ),
+        ([{"a": "1"}, {"a": "x"}], None, DataType.struct({"a": DataType.int64()})),
+    ],
+)
+def test_strict_cast_nested_raises_on_failure(data, source_dtype, dtype):
+    s = Series.from_pylist(data)
+    if source_dtype is not None:
+        s = s.cast(source_dtype)
+    with pytest.raises(ValueError, match="Cannot cast value"):
+        s.cast(dtype, strict=True)
+
+
+def test_strict_cast_nested_succeeds():
+    s = Series.from_pylist([["1", None], None, []])
+    assert s.cast(DataType.list(DataType.int8()), strict=True).to_pylist() == [[1, None], None, []]
+
+    s = Series.from_pylist([{"a": "1", "b": 2}, None])
+    result = s.cast(DataType.struct({"a": DataType.int64(), "c": DataType.string()}), strict=True)
+    assert result.to_pylist() == [{"a": 1, "c": None}, None]
@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft.daft import JoinType
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.series import Series
+from daft.table import MicroPartition
+
+
+def test_categorical_from_arrow_dictionary():
+    arrow_arr = pa.array(["b", "a", None, "b"]).dictionary_encode()
+    s = Series.from_arrow(arrow_arr)
+    assert s.datatype() == DataType.categorical()
+    assert s.to_pylist() == ["b", "a", None, "b"]
+
+    roundtrip = s.to_arrow()
+    assert pa.types.is_dictionary(roundtrip.type)
+    assert roundtrip.to_pylist() == ["b", "a", None, "b"]
+
+
+def test_categorical_from_arrow_dictionary_with_duplicate_values():
+    arrow_arr = pa.DictionaryArray.from_arrays(pa.array([0, 1, 2, None]), pa.array(["a", "b", "a"]))
+    s = Series.from_arrow(arrow_arr)
+    assert s.to_pylist() == ["a", "b", "a", None]
+    assert (s == Series.from_pylist(["a", "a", "a", "a"])).to_pylist() == [True, False, True, None]
+
+
+def test_categorical_cast_round_trip():
+    s = Series.from_pylist(["x", None, "y", "x"])
+    categorical = s.cast(DataType.categorical())
+    assert categorical.datatype() == DataType.categorical()
+    assert categorical.to_pylist() == ["x", None, "y", "x"]
+
+    back = categorical.cast(DataType.string())
+    assert back.datatype() == DataType.string()
+    assert back.to_pylist() == ["x", None, "y", "x"]
+
+
+@pytest.mark.parametrize(
+    "op, expected",
+    [
+        ("__eq__", [True, False, False, None]),
+        ("__ne__", [False, True, True, None]),
+        ("__lt__", [False, True, False, None]),
+        ("__le__", [True, True, False, None]),
+        ("__gt__", [False, False, True, None]),
+        ("__ge__", [True, False, True, None]),
+    ],
+)
+def test_categorical_comparisons(op, expected):
+    lhs = Series.from_pylist(["b", "a", "c", None]).cast(DataType.categorical())
+    strings = Series.from_pylist(["b", "b", "b", "b"])
+    assert getattr(lhs, op)(strings).to_pylist() == expected
+    # Against a categorical with a different dictionary.
+    assert getattr(lhs, op)(strings.cast(DataType.categorical())).to_pylist() == expected
+
+
+def test_categorical_sort():
+    s = Series.from_pylist(["b", None, "c", "a"]).cast(DataType.categorical())
+    assert s.sort().to_pylist() == ["a", "b", "c", None]
+    assert s.sort(descending=True).to_pylist() == [None, "c", "b", "a"]
+
+
+def test_categorical_hash_matches_strings():
+    s = Series.from_pylist(["b", "a", "b"])
+    assert s.cast(DataType.categorical()).hash().to_pylist() == s.hash().to_pylist()
+
+
+def test_categorical_groupby():
+    mp = MicroPartition.from_pydict({"k": ["b", "a", None, "b", "a"], "v": [1, 2, 3, 4, 5]})
+    mp = mp.eval_expression_list([col("k").cast(DataType.categorical()), col("v")])
+    result = mp.agg([col("v")._sum()], group_by=[col("k")]).to_pydict()
+    assert sorted(zip(result["k"], result["v"]), key=str) == [("a", 7), ("b", 5), (None, 3)]
+
+
+@pytest.mark.parametrize("join_impl", ["hash_join", "sort_merge_join"])
+@pytest.mark.parametrize("right_dtype", [DataType.string(), DataType.categorical()])
+def test_categorical_join(join_impl, right_dtype):
+    left = MicroPartition.from_pydict({"x": ["a", "b", "c", None], "x_ind": [0, 1, 2, 3]})
+    left = left.eval_expression_list([col("x").cast(DataType.categorical()), col("x_ind")])
+    right = MicroPartition.from_pydict({"y": ["c", "a", "d", None], "y_ind": [0, 1, 2, 3]})
+    right = right.eval_expression_list([col("y").cast(right_dtype), col("y_ind")])
+
+    result = getattr(left, join_impl)(right, left_on=[col("x")], right_on=[col("y")], how=JoinType.Inner)
+    pairs = list(zip(result.get_column("x_ind").to_pylist(), result.get_column("y_ind").to_pylist()))
+    assert sorted(pairs) == [(0, 1), (2, 0)]
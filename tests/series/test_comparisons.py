@@ -694,8 +694,8 @@ def test_compare_timestamps_no_tz(tu1, tu2):
 
 def test_compare_timestamps_no_tz_date():
     tz1 = Series.from_pylist([datetime(2022, 1, 1)])
-    Series.from_pylist([date(2022, 1, 1)])
-    assert (tz1 == tz1).to_pylist() == [True]
+    tz2 = Series.from_pylist([date(2022, 1, 1)])
+    assert (tz1 == tz2).to_pylist() == [True]
 
 
 def test_compare_timestamps_one_tz():
@@ -742,5 +742,38 @@ def test_compare_timestamps_diff_tz(tu1, tu2):
     utc = datetime(2022, 1, 1, tzinfo=pytz.utc)
     eastern = utc.astimezone(pytz.timezone("US/Eastern"))
     tz1 = Series.from_pylist([utc]).cast(DataType.timestamp(tu1, "UTC"))
-    tz2 = Series.from_pylist([eastern]).cast(DataType.timestamp(tu1, "US/Eastern"))
+    tz2 = Series.from_pylist([eastern]).cast(DataType.timestamp(tu2, "US/Eastern"))
     assert (tz1 == tz2).to_pylist() == [True]
+    # Timezones only affect how instants are displayed, so the same instant compares equal.
+    assert (tz1 < tz2).to_pylist() == [False]
+
+
+@pytest.mark.parametrize("l_dtype, r_dtype", itertools.product(arrow_int_types + arrow_float_types, repeat=2))
+def test_eq_null_safe(l_dtype, r_dtype):
+    left = Series.from_arrow(pa.array([1, 2, None, None], type=l_dtype))
+    right = Series.from_arrow(pa.array([1, 3, None, 4], type=r_dtype))
+    assert left.eq_null_safe(right).to_pylist() == [True, False, True, False]
+    assert right.eq_null_safe(left).to_pylist() == [True, False, True, False]
+
+
+def test_eq_null_safe_scalar():
+    left = Series.from_pylist(["a", None, "b"])
+    assert left.eq_null_safe(Series.from_pylist(["a"])).to_pylist() == [True, False, False]
+    null_scalar = Series.from_pylist([None]).cast(DataType.string())
+    assert left.eq_null_safe(null_scalar).to_pylist() == [False, True, False]
+    assert null_scalar.eq_null_safe(left).to_pylist() == [False, True, False]
+    assert Series.from_pylist([None, None]).eq_null_safe(Series.from_pylist([None, None])).to_pylist() == [True, True]
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        [[1, 2], [3]],
+        [{"a": 1}, {"a": 2}],
+    ],
+)
+@pytest.mark.parametrize("op", [operator.eq, operator.ne, operator.lt, operator.le, operator.gt, operator.ge])
+def test_comparisons_nested_types_raise(data, op):
+    s = Series.from_pylist(data)
+    with pytest.raises(ValueError, match="Comparisons are not supported"):
+        op(s, s)
@@ -219,8 +219,39 @@ def test_series_concat_bad_input() -> None:
         Series.concat([])
 
 
+def test_series_concat_dtype_promotion() -> None:
+    mix_types_series = [Series.from_pylist([1, 2, 3]), Series.from_pylist([1.0, None]), Series.from_pylist([None])]
+
+    concatted = Series.concat(mix_types_series)
+    assert concatted.datatype() == DataType.float64()
+    assert concatted.to_pylist() == [1.0, 2.0, 3.0, 1.0, None, None]
+
+
 def test_series_concat_dtype_mismatch() -> None:
-    mix_types_series = [Series.from_pylist([1, 2, 3]), Series.from_pylist([1.0, 2.0, 3.0])]
+    mix_types_series = [Series.from_pylist([1, 2, 3]), Series.from_pylist([b"a", b"b"])]
 
-    with pytest.raises(ValueError, match="concat requires all data types to match"):
+    with pytest.raises(ValueError, match="concat requires all data types to have a supertype"):
         Series.concat(mix_types_series)
+
+
+def test_series_concat_merges_validity() -> None:
+    series = [Series.from_pylist(["a", "b"]), Series.from_pylist([None, "c"]), Series.from_pylist(["d"])]
+
+    concatted = Series.concat(series)
+    assert concatted.datatype() == DataType.string()
+    assert concatted.to_pylist() == ["a", "b", None, "c", "d"]
+
+
+def test_series_concat_nested_dtype_promotion() -> None:
+    lists = [
+        Series.from_arrow(pa.array([[1], None], type=pa.list_(pa.int32()))),
+        Series.from_arrow(pa.array([[2, 3]], type=pa.list_(pa.int64()))),
+    ]
+    concatted = Series.concat(lists)
+    assert concatted.datatype() == DataType.list(DataType.int64())
+    assert concatted.to_pylist() == [[1], None, [2, 3]]
+
+    structs = [Series.from_pylist([{"a": 1}]), Series.from_pylist([{"a": 2.5, "b": "x"}])]
+    concatted = Series.concat(structs)
+    assert concatted.datatype() == DataType.struct({"a": DataType.float64(), "b": DataType.string()})
+    assert concatted.to_pylist() == [{"a": 1.0, "b": None}, {"a": 2.5, "b": "x"}]
@@ -4,6 +4,7 @@ import copy
 
 import numpy as np
 import pandas as pd
+import pytest
 
 from daft.datatype import DaftExtension, DataType
 from daft.series import Series
@@ -30,3 +31,19 @@ def test_embedding_arrow_round_trip():
     t_copy = copy.deepcopy(t)
     assert t_copy.datatype() == t.datatype()
     np.testing.assert_equal(t_copy.to_pylist(), t.to_pylist())
+
+
+def test_embedding_to_numpy_is_zero_copy():
+    s = Series.from_pylist([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]).cast(DataType.embedding(DataType.float32(), 2))
+
+    arr = s.slice(1, 3).to_numpy()
+
+    assert arr.dtype == np.float32
+    np.testing.assert_equal(arr, np.array([[3.0, 4.0], [5.0, 6.0]], dtype=np.float32))
+    assert not arr.flags.owndata
+
+
+def test_embedding_to_numpy_with_nulls():
+    s = Series.from_pylist([[1.0, 2.0], None]).cast(DataType.embedding(DataType.float32(), 2))
+    with pytest.raises(ValueError, match="nulls"):
+        s.to_numpy()
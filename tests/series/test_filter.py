@@ -115,6 +115,38 @@ def test_series_filter_on_struct_array() -> None:
     assert result.to_pylist() == expected
 
 
+def test_series_filter_on_nested_array() -> None:
+    dtype = pa.list_(pa.struct({"a": pa.int64(), "b": pa.list_(pa.string())}))
+    data = pa.array(
+        [[{"a": 1, "b": ["x"]}], None, [], [{"a": None, "b": None}, {"a": 2, "b": []}], [{"a": 3, "b": ["y", None]}]],
+        type=dtype,
+    )
+
+    s = Series.from_arrow(data)
+    pymask = [True, True, None, True, False]
+    mask = Series.from_pylist(pymask)
+
+    result = s.filter(mask)
+
+    assert s.datatype() == result.datatype()
+    expected = [val for val, keep in zip(s.to_pylist(), pymask) if keep]
+    assert result.to_pylist() == expected
+
+
+def test_series_filter_on_map_array() -> None:
+    data = pa.array([[("a", 1)], None, [("b", 2), ("c", None)], []], type=pa.map_(pa.string(), pa.int64()))
+
+    s = Series.from_arrow(data)
+    pymask = [None, True, True, False]
+    mask = Series.from_pylist(pymask)
+
+    result = s.filter(mask)
+
+    assert s.datatype() == result.datatype()
+    expected = [val for val, keep in zip(s.to_arrow().to_pylist(), pymask) if keep]
+    assert result.to_arrow().to_pylist() == expected
+
+
 @pytest.mark.skipif(
     get_context().runner_config.name == "ray",
     reason="pyarrow extension types aren't supported on Ray clusters.",
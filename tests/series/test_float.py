@@ -28,3 +28,34 @@ def test_float_is_nan_all_null() -> None:
     s = Series.from_arrow(pa.array([None, None, None]))
     result = s.float.is_nan()
     assert result.to_pylist() == [None, None, None]
+
+
+def test_float_is_inf() -> None:
+    s = Series.from_arrow(pa.array([1.0, None, float("inf"), float("-inf"), np.nan]))
+    result = s.float.is_inf()
+    assert result.to_pylist() == [False, None, True, True, False]
+
+
+def test_float_not_nan() -> None:
+    s = Series.from_arrow(pa.array([1.0, None, np.nan, float("inf")], type=pa.float32()))
+    result = s.float.not_nan()
+    assert result.to_pylist() == [True, None, False, True]
+
+
+def test_float_not_nan_all_null() -> None:
+    s = Series.from_arrow(pa.array([None, None]))
+    result = s.float.not_nan()
+    assert result.to_pylist() == [None, None]
+
+
+def test_float_fill_nan() -> None:
+    s = Series.from_arrow(pa.array([1.0, None, np.nan, 3.0]))
+    result = s.float.fill_nan(Series.from_pylist([0.0]))
+    assert result.to_pylist() == [1.0, None, 0.0, 3.0]
+
+
+def test_float_fill_nan_keeps_dtype() -> None:
+    s = Series.from_arrow(pa.array([np.nan, 2.0], type=pa.float32()))
+    result = s.float.fill_nan(Series.from_pylist([1]))
+    assert result.datatype() == s.datatype()
+    assert result.to_pylist() == [1.0, 2.0]
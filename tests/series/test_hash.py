@@ -4,6 +4,7 @@ import decimal
 from datetime import date, datetime, time
 
 import numpy as np
+import pyarrow as pa
 import pytest
 import pytz
 import xxhash
@@ -120,6 +121,42 @@ def test_hash_int_array_with_bad_length():
         arr.hash(bad_seed)
 
 
+def test_hash_nulls_chain_seed():
+    seed = Series.from_pylist([1, 2]).cast(DataType.uint64())
+    arr = Series.from_pylist([None, None]).cast(DataType.int64())
+    assert arr.hash(seed).to_pylist() == [xxhash.xxh3_64_intdigest(b"", 1), xxhash.xxh3_64_intdigest(b"", 2)]
+
+
+def test_hash_struct_array_chains_fields():
+    arr = Series.from_pylist([{"a": 1, "b": "x"}, {"a": 2, "b": None}, None])
+    a = Series.from_pylist([1, 2, None])
+    b = Series.from_pylist(["x", None, None])
+    hashed = arr.hash().to_pylist()
+    assert hashed[:2] == b.hash(a.hash()).to_pylist()[:2]
+    assert hashed[2] == xxhash.xxh3_64_intdigest(b"")
+
+
+@pytest.mark.parametrize("to_fixed_size", [False, True])
+def test_hash_list_array(to_fixed_size):
+    arr = Series.from_pylist([[1, 2], [2, 1], None, [1, 2], [1, None]])
+    if to_fixed_size:
+        arr = arr.cast(DataType.fixed_size_list(DataType.int64(), 2))
+    hashed = arr.hash().to_pylist()
+    assert hashed[0] == hashed[3]
+    assert len(set(hashed)) == 4
+    assert hashed[2] == xxhash.xxh3_64_intdigest(b"")
+
+    seed = Series.from_pylist([0, 1, 2, 3, 4]).cast(DataType.uint64())
+    seeded = arr.hash(seed).to_pylist()
+    assert seeded[0] != seeded[3]
+
+
+def test_hash_empty_list_differs_from_null():
+    arr = Series.from_arrow(pa.array([[], None], type=pa.list_(pa.int64())))
+    empty, null = arr.hash().to_pylist()
+    assert empty != null
+
+
 @pytest.mark.parametrize(
     "dtype",
     [
@@ -521,3 +521,32 @@ def test_series_if_else_wrong_types() -> None:
 
     with pytest.raises(ValueError):
         predicate_series.if_else(if_true_series, object())
+
+
+@pytest.mark.parametrize("predicate_length", [1, 3])
+@pytest.mark.parametrize("if_true_length", [1, 3])
+@pytest.mark.parametrize("if_false_length", [1, 3])
+@pytest.mark.parametrize("predicate_value", [True, False, None])
+def test_series_if_else_broadcast_matrix(predicate_length, if_true_length, if_false_length, predicate_value) -> None:
+    predicate_series = Series.from_arrow(pa.array([predicate_value] * predicate_length, type=pa.bool_()))
+    if_true_series = Series.from_arrow(pa.array([[1]] * if_true_length, type=pa.list_(pa.int64())))
+    if_false_series = Series.from_arrow(pa.array([[0, 0]] * if_false_length, type=pa.list_(pa.int64())))
+    result = predicate_series.if_else(if_true_series, if_false_series)
+
+    expected_length = max(predicate_length, if_true_length, if_false_length)
+    expected_value = {True: [1], False: [0, 0], None: None}[predicate_value]
+    assert result.datatype() == DataType.list(DataType.int64())
+    assert result.to_pylist() == [expected_value] * expected_length
+
+
+def test_series_if_else_null_predicate_type() -> None:
+    predicate_series = Series.from_arrow(pa.array([None], type=pa.null()))
+    result = predicate_series.if_else(Series.from_pylist([1, 2]), Series.from_pylist([3, 4]))
+    assert result.datatype() == DataType.int64()
+    assert result.to_pylist() == [None, None]
+
+
+def test_series_if_else_length_mismatch() -> None:
+    predicate_series = Series.from_arrow(pa.array([True, False, None]))
+    with pytest.raises(ValueError, match="of length 1 or of the same length"):
+        predicate_series.if_else(Series.from_pylist([1, 2]), Series.from_pylist([3]))
@@ -709,3 +709,33 @@ def test_bad_cast_fixed_shape_image():
 
     with pytest.raises(ValueError, match="Expected Numpy array to be of type: UInt8"):
         s.cast(target_dtype)
+
+
+@pytest.mark.parametrize("fixed_shape", [False, True])
+def test_image_cast_binary_roundtrip(fixed_shape):
+    shape = (2, 2, 3)
+    data = [np.arange(12, dtype=np.uint8).reshape(shape), np.arange(12, 24, dtype=np.uint8).reshape(shape), None]
+    dtype = DataType.image("RGB", 2, 2) if fixed_shape else DataType.image("RGB")
+    s = Series.from_pylist(data, pyobj="force").cast(dtype)
+
+    encoded = s.cast(DataType.binary())
+    assert encoded.datatype() == DataType.binary()
+    # Images are encoded as PNG.
+    assert encoded.to_pylist()[0][:8] == b"\x89PNG\r\n\x1a\n"
+    assert encoded.to_pylist()[2] is None
+
+    decoded = encoded.cast(dtype)
+    assert decoded.datatype() == dtype
+    np.testing.assert_equal(decoded.to_pylist(), data)
+
+
+def test_image_cast_binary_to_mode():
+    arr = np.arange(12, dtype=np.uint8).reshape((2, 2, 3))
+    encoded = Series.from_pylist([arr], pyobj="force").cast(DataType.image("RGB")).cast(DataType.binary())
+
+    decoded = encoded.cast(DataType.image("RGBA"))
+    assert decoded.datatype() == DataType.image("RGBA")
+    out = decoded.to_pylist()[0]
+    assert out.shape == (2, 2, 4)
+    np.testing.assert_equal(out[..., :3], arr)
+    np.testing.assert_equal(out[..., 3], 255)
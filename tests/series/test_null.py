@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft.datatype import DataType
+from daft.series import Series
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        pa.array([1, None, 3, None]),
+        pa.array(["a", None, "c", None]),
+        pa.array([[1], None, [], None]),
+        pa.array([{"a": 1}, None, {"a": None}, None]),
+    ],
+)
+def test_series_null_count(data) -> None:
+    s = Series.from_arrow(data)
+    assert s.null_count() == 2
+    assert s.is_null().to_pylist() == [False, True, False, True]
+    assert s.not_null().to_pylist() == [True, False, True, False]
+
+    assert s.slice(0, 1).null_count() == 0
+    assert s.slice(1, 4).null_count() == 2
+
+
+def test_series_null_count_without_nulls() -> None:
+    s = Series.from_pylist([1, 2, 3])
+    assert s.null_count() == 0
+    assert s.is_null().to_pylist() == [False, False, False]
+
+
+def test_series_null_count_null_type() -> None:
+    s = Series.from_pylist([None, None, None])
+    assert s.datatype() == DataType.null()
+    assert s.null_count() == 3
+    assert s.is_null().to_pylist() == [True, True, True]
+
+
+@pytest.mark.parametrize(
+    "strategy, expected",
+    [
+        ("forward", [None, 1, 1, 3, 3]),
+        ("backward", [1, 1, 3, 3, None]),
+        ("min", [1, 1, 1, 3, 1]),
+        ("max", [3, 1, 3, 3, 3]),
+        ("mean", [2.0, 1.0, 2.0, 3.0, 2.0]),
+    ],
+)
+def test_series_fill_null_with_strategy(strategy, expected) -> None:
+    s = Series.from_pylist([None, 1, None, 3, None])
+    assert s.fill_null(strategy=strategy).to_pylist() == expected
+
+
+def test_series_fill_null_with_value() -> None:
+    s = Series.from_pylist([None, 1, None])
+    assert s.fill_null(0).to_pylist() == [0, 1, 0]
+    assert s.fill_null(Series.from_pylist([4, 5, 6])).to_pylist() == [4, 1, 6]
+
+
+def test_series_fill_null_with_strategy_without_nulls() -> None:
+    s = Series.from_pylist([1, 2])
+    assert s.fill_null(strategy="forward").to_pylist() == [1, 2]
+    assert s.fill_null(strategy="mean").datatype() == DataType.float64()
+
+
+def test_series_fill_null_bad_arguments() -> None:
+    s = Series.from_pylist([None, 1])
+    with pytest.raises(ValueError, match="not both"):
+        s.fill_null(0, strategy="forward")
+    with pytest.raises(ValueError):
+        s.fill_null(strategy="sideways")
@@ -1,5 +1,8 @@
 from __future__ import annotations
 
+import decimal
+import math
+
 import pyarrow as pa
 import pytest
 
@@ -33,3 +36,144 @@ def test_table_abs_bad_input() -> None:
 
     with pytest.raises(ValueError, match="abs not implemented"):
         abs(series)
+
+
+@pytest.mark.parametrize("dtype", [pa.int8(), pa.int16(), pa.int32(), pa.int64()])
+def test_series_numeric_abs_overflow(dtype) -> None:
+    min_value = -(2 ** (dtype.bit_width - 1))
+    s = Series.from_arrow(pa.array([1, min_value, None], dtype))
+    with pytest.raises(ValueError, match="abs overflowed"):
+        s.abs()
+    assert s.filter(Series.from_pylist([True, False, True])).abs().to_pylist() == [1, None]
+
+
+@pytest.mark.parametrize("dtype", ARROW_INT_TYPES + ARROW_FLOAT_TYPES)
+def test_series_numeric_sign(dtype) -> None:
+    if pa.types.is_unsigned_integer(dtype):
+        pydata, expected = [0, 3, None], [0, 1, None]
+    else:
+        pydata, expected = [-5, 0, 3, None], [-1, 0, 1, None]
+    s = Series.from_arrow(pa.array(pydata, dtype))
+    result = s.sign()
+    assert result.datatype() == s.datatype()
+    assert result.to_pylist() == expected
+
+
+def test_series_float_sign_special_values() -> None:
+    result = Series.from_pylist([-0.0, float("nan"), float("-inf")]).sign().to_pylist()
+    assert math.copysign(1, result[0]) == -1
+    assert math.isnan(result[1])
+    assert result[2] == -1.0
+
+
+@pytest.mark.parametrize("dtype", ARROW_INT_TYPES + ARROW_FLOAT_TYPES)
+def test_series_numeric_clip(dtype) -> None:
+    s = Series.from_arrow(pa.array([1, 5, 10, None], dtype))
+    result = s.clip(Series.from_pylist([2]), Series.from_pylist([8]))
+    assert result.datatype() == s.datatype()
+    assert result.to_pylist() == [2, 5, 8, None]
+    assert s.clip(Series.from_pylist([None]), Series.from_pylist([8])).to_pylist() == [1, 5, 8, None]
+    lower = Series.from_pylist([0, 6, None, 0])
+    assert s.clip(lower, Series.from_pylist([None])).to_pylist() == [1, 6, 10, None]
+
+
+def test_series_clip_int_with_float_bounds() -> None:
+    s = Series.from_pylist([1, 2, 3, 4], name="x").cast(DataType.int8())
+    result = s.clip(Series.from_pylist([1.5]), Series.from_pylist([3.5]))
+    assert result.datatype() == DataType.int8()
+    assert result.to_pylist() == [2, 2, 3, 3]
+
+
+def test_series_clip_unrepresentable_bounds() -> None:
+    s = Series.from_pylist([1, 2, 3], name="x").cast(DataType.int8())
+    with pytest.raises(ValueError):
+        s.clip(Series.from_pylist([1000]), Series.from_pylist([None]))
+    with pytest.raises(ValueError):
+        s.clip(Series.from_pylist([None]), Series.from_pylist([-1000]))
+    with pytest.raises(ValueError):
+        s.clip(Series.from_pylist([float("nan")]), Series.from_pylist([None]))
+
+
+def test_series_clip_bad_input() -> None:
+    with pytest.raises(ValueError, match="clip not implemented for Utf8"):
+        Series.from_pylist(["a"]).clip(Series.from_pylist([1]), Series.from_pylist([2]))
+    with pytest.raises(ValueError, match="Expected clip bounds to be numeric"):
+        Series.from_pylist([1]).clip(Series.from_pylist(["a"]), Series.from_pylist([2]))
+
+
+@pytest.mark.parametrize("dtype", ARROW_FLOAT_TYPES)
+def test_series_numeric_round(dtype) -> None:
+    s = Series.from_arrow(pa.array([1.25, 2.5, -2.5, None, 15.0], dtype))
+
+    assert s.round().datatype() == DataType.from_arrow_type(dtype)
+    assert s.round().to_pylist() == [1.0, 3.0, -3.0, None, 15.0]
+    assert s.round(mode="half_even").to_pylist() == [1.0, 2.0, -2.0, None, 15.0]
+    assert s.round(1).to_pylist()[:3] == pytest.approx([1.3, 2.5, -2.5])
+    assert s.round(1, mode="half_even").to_pylist()[:3] == pytest.approx([1.2, 2.5, -2.5])
+    assert s.round(-1).to_pylist() == [0.0, 0.0, -0.0, None, 20.0]
+
+
+@pytest.mark.parametrize("dtype", ARROW_INT_TYPES)
+def test_series_numeric_round_int(dtype) -> None:
+    s = Series.from_arrow(pa.array([1, 2, 3], dtype))
+    assert s.round(2).to_pylist() == [1, 2, 3]
+    with pytest.raises(ValueError, match="round with -1 decimals not implemented"):
+        s.round(-1)
+
+
+def test_series_decimal_rounding() -> None:
+    data = pa.array([decimal.Decimal("1.25"), decimal.Decimal("-1.35"), None], pa.decimal128(10, 2))
+    s = Series.from_arrow(data)
+
+    rounded = s.round(1)
+    assert rounded.datatype() == s.datatype()
+    assert rounded.to_pylist() == [decimal.Decimal("1.30"), decimal.Decimal("-1.40"), None]
+    assert s.round(1, mode="half_even").to_pylist() == [decimal.Decimal("1.20"), decimal.Decimal("-1.40"), None]
+    assert s.ceil().to_pylist() == [decimal.Decimal("2.00"), decimal.Decimal("-1.00"), None]
+    assert s.floor().to_pylist() == [decimal.Decimal("1.00"), decimal.Decimal("-2.00"), None]
+
+
+def test_series_round_bad_mode() -> None:
+    s = Series.from_pylist([1.5])
+    with pytest.raises(ValueError, match="Round mode"):
+        s.round(mode="up")
+
+
+@pytest.mark.parametrize(
+    ["fn_name", "pyfn"],
+    [
+        ("sqrt", math.sqrt),
+        ("exp", math.exp),
+        ("ln", math.log),
+        ("sin", math.sin),
+        ("cos", math.cos),
+        ("tan", math.tan),
+    ],
+)
+@pytest.mark.parametrize("dtype", ARROW_INT_TYPES + ARROW_FLOAT_TYPES)
+def test_series_unary_math(fn_name, pyfn, dtype) -> None:
+    s = Series.from_arrow(pa.array([1, 2, None, 4], dtype))
+    result = getattr(s, fn_name)()
+    if dtype in (pa.int8(), pa.int16(), pa.uint8(), pa.uint16(), pa.float32()):
+        expected_type = DataType.float32()
+    else:
+        expected_type = DataType.float64()
+    assert result.datatype() == expected_type
+    values = result.to_pylist()
+    assert values[2] is None
+    assert [values[0], values[1], values[3]] == pytest.approx([pyfn(1), pyfn(2), pyfn(4)], rel=1e-6)
+
+
+def test_series_binary_math() -> None:
+    s = Series.from_pylist([1.0, 8.0, 100.0, None])
+    assert s.pow(Series.from_pylist([2.0])).to_pylist() == [1.0, 64.0, 10000.0, None]
+    assert (s ** Series.from_pylist([0.5, 1.0, 0.5, 1.0])).to_pylist() == [1.0, 8.0, 10.0, None]
+    assert s.log(Series.from_pylist([10])).to_pylist()[:3] == pytest.approx([0.0, math.log10(8.0), 2.0])
+    y = Series.from_pylist([1.0, -1.0])
+    assert y.atan2(Series.from_pylist([-1.0])).to_pylist() == pytest.approx([3 * math.pi / 4, -3 * math.pi / 4])
+
+
+def test_series_math_bad_type() -> None:
+    s = Series.from_pylist(["a"])
+    with pytest.raises(ValueError, match="sqrt not implemented for Utf8"):
+        s.sqrt()
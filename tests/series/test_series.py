@@ -31,6 +31,37 @@ def test_series_arrow_chunked_array_round_trip() -> None:
     assert arrow.combine_chunks() == back_to_arrow
 
 
+@pytest.mark.parametrize(
+    "arrow",
+    [
+        pa.array([{"a": [1, None], "b": "x"}, None], type=pa.struct([("a", pa.list_(pa.int8())), ("b", pa.string())])),
+        pa.array([[("k", 1.5)], None, []], type=pa.map_(pa.string(), pa.float64())),
+        pa.array(["1.25", None], type=pa.string()).cast(pa.decimal128(6, 2)),
+        pa.array([1, None, 3], type=pa.timestamp("ms", tz="Asia/Singapore")),
+        pa.array([[date(2000, 1, 1)], None], type=pa.large_list(pa.date32())),
+    ],
+)
+def test_series_arrow_nested_round_trip(arrow) -> None:
+    s = Series.from_arrow(arrow)
+    assert s.datatype() != DataType.python()
+    assert s.to_arrow().to_pylist() == arrow.to_pylist()
+
+
+@pytest.mark.parametrize(
+    "arrow, expected_dtype",
+    [
+        (pa.array([1.5, None], type=pa.float16()), DataType.float32()),
+        (pa.array([86_400_000, None], type=pa.date64()), DataType.timestamp("ms")),
+        (pa.array([1_000, None], type=pa.time32("ms")), DataType.time("us")),
+        (pa.array([[1_000], None], type=pa.list_(pa.time32("s"))), DataType.list(DataType.time("us"))),
+    ],
+)
+def test_series_from_arrow_widened_types(arrow, expected_dtype) -> None:
+    s = Series.from_arrow(arrow)
+    assert s.datatype() == expected_dtype
+    assert s.to_arrow().to_pylist() == arrow.cast(expected_dtype.to_arrow_dtype()).to_pylist()
+
+
 @pytest.mark.parametrize("pyobj", ["allow", "disallow", "force"])
 def test_series_pylist_round_trip(pyobj) -> None:
     data = [1, 2, 3, 4, None]
@@ -184,6 +215,16 @@ def test_series_bincode_serdes_on_complex_types() -> None:
     assert all(np.all(l == r) for l, r in zip(s.to_pylist(), copied_s.to_pylist()))
 
 
+def test_series_bincode_serdes_on_python_objects() -> None:
+    s = Series.from_pylist([{"a": 1}, None, object], pyobj="force")
+    serialized = s._debug_bincode_serialize()
+    copied_s = Series._debug_bincode_deserialize(serialized)
+
+    assert s.name() == copied_s.name()
+    assert copied_s.datatype() == DataType.python()
+    assert copied_s.to_pylist() == [{"a": 1}, None, object]
+
+
 def test_series_bincode_serdes_on_null_types() -> None:
     s = Series.from_pylist([None, None, None])
     serialized = s._debug_bincode_serialize()
@@ -192,3 +233,46 @@ def test_series_bincode_serdes_on_null_types() -> None:
     assert s.name() == copied_s.name()
     assert s.datatype() == copied_s.datatype()
     assert s.to_pylist() == copied_s.to_pylist()
+
+
+@pytest.mark.parametrize(
+    "data, expected",
+    [
+        ([3, 1, None, 3, 2, None, 1], [3, 1, None, 2]),
+        (["b", "a", "b", None, "c"], ["b", "a", None, "c"]),
+        ([True, None, True, False], [True, None, False]),
+        ([None, None], [None]),
+        ([], []),
+    ],
+)
+def test_series_unique(data, expected) -> None:
+    s = Series.from_pylist(data)
+    unique = s.unique()
+    assert unique.datatype() == s.datatype()
+    assert unique.to_pylist() == expected
+
+
+def test_series_unique_categorical() -> None:
+    s = Series.from_pylist(["b", "a", "b", None]).cast(DataType.categorical())
+    unique = s.unique()
+    assert unique.datatype() == DataType.categorical()
+    assert unique.to_pylist() == ["b", "a", None]
+
+
+def test_series_value_counts() -> None:
+    s = Series.from_pylist(["b", "a", None, "b", "c", None, "b"], name="x")
+    counts = s.value_counts()
+    assert counts.column_names() == ["x", "count"]
+    assert counts.get_column("count").datatype() == DataType.uint64()
+    # Ties are kept in order of first occurrence.
+    assert counts.to_pydict() == {"x": ["b", None, "a", "c"], "count": [3, 2, 1, 1]}
+
+
+def test_series_value_counts_empty() -> None:
+    counts = Series.from_pylist([], name="x").cast(DataType.int64()).value_counts()
+    assert counts.to_pydict() == {"x": [], "count": []}
+
+
+def test_series_value_counts_named_count() -> None:
+    counts = Series.from_pylist([1, 2, 1], name="count").value_counts()
+    assert counts.to_pydict() == {"count": [1, 2], "count_count": [2, 1]}
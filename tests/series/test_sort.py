@@ -211,3 +211,46 @@ def test_series_boolean_sorting() -> None:
     taken = s.take(s_argsorted)
     assert len(taken) == len(s)
     assert taken.to_pylist() == sorted_order[::-1]
+
+
+def test_series_struct_sorting() -> None:
+    data = pa.array(
+        [{"a": 2, "b": "x"}, None, {"a": 1, "b": "y"}, {"a": 2, "b": "w"}, {"a": None, "b": "z"}],
+        type=pa.struct({"a": pa.int64(), "b": pa.string()}),
+    )
+    sorted_order = [{"a": 1, "b": "y"}, {"a": 2, "b": "w"}, {"a": 2, "b": "x"}, {"a": None, "b": "z"}, None]
+    s = Series.from_arrow(data)
+    s_sorted = s.sort()
+    assert len(s_sorted) == len(s)
+    assert s_sorted.datatype() == s.datatype()
+    assert s_sorted.to_pylist() == sorted_order
+
+    ## Descending
+    s_sorted = s.sort(descending=True)
+    assert len(s_sorted) == len(s)
+    assert s_sorted.datatype() == s.datatype()
+    assert s_sorted.to_pylist() == sorted_order[::-1]
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        pa.array([3, None, 1, 3, None, 2]),
+        pa.array([3.0, None, 1.0, 3.0, None, 2.0]),
+        pa.array(["c", None, "a", "c", None, "b"]),
+        pa.array([True, None, False, True, None, False]),
+    ],
+)
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_first", [False, True])
+def test_series_sorting_null_placement(data, descending, nulls_first) -> None:
+    s = Series.from_arrow(data)
+    values = data.to_pylist()
+
+    # The sort is stable, so ties and nulls keep their original order.
+    valid = sorted((i for i, v in enumerate(values) if v is not None), key=lambda i: values[i], reverse=descending)
+    nulls = [i for i, v in enumerate(values) if v is None]
+    expected = nulls + valid if nulls_first else valid + nulls
+
+    assert s.argsort(descending=descending, nulls_first=nulls_first).to_pylist() == expected
+    assert s.sort(descending=descending, nulls_first=nulls_first).to_pylist() == [values[i] for i in expected]
@@ -204,3 +204,19 @@ def test_series_deeply_nested_take() -> None:
     original_data = s.to_pylist()
     expected = [original_data[1]]
     assert result.to_pylist() == expected
+
+
+@pytest.mark.parametrize("pyidx, idx_dtype", [([0, 6], pa.int64()), ([-1], pa.int64()), ([2**63], pa.uint64())])
+def test_series_take_out_of_bounds(pyidx, idx_dtype) -> None:
+    s = Series.from_pylist([[1], [2, 3], None, [], [4], None])
+    idx = Series.from_arrow(pa.array(pyidx, type=idx_dtype))
+
+    with pytest.raises(ValueError, match="index out of bounds"):
+        s.take(idx)
+
+
+def test_series_take_bad_indices() -> None:
+    s = Series.from_pylist([1, 2, 3])
+
+    with pytest.raises(ValueError, match="Expected integer indices"):
+        s.take(Series.from_pylist([0.0]))
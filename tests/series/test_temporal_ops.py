@@ -125,6 +125,25 @@ def test_series_timestamp_hour() -> None:
     assert input == days.to_pylist()
 
 
+def test_series_timestamp_minute() -> None:
+    from datetime import datetime
+
+    def ts_maker(mi):
+        if mi is None:
+            return None
+        return datetime(2023, 1, 26, 1, mi, 1)
+
+    input = [1, 5, 14, None, 59, None, 0]
+
+    input_ts = list(map(ts_maker, input))
+    s = Series.from_pylist(input_ts).cast(DataType.timestamp(TimeUnit.ms()))
+    minutes = s.dt.minute()
+
+    assert minutes.datatype() == DataType.uint32()
+
+    assert input == minutes.to_pylist()
+
+
 @pytest.mark.parametrize("tz", [None, "UTC", "+08:00", "Asia/Singapore"])
 def test_series_timestamp_month_operation(tz) -> None:
     from datetime import datetime
@@ -163,3 +182,39 @@ def test_series_timestamp_year_operation(tz) -> None:
     assert years.datatype() == DataType.int32()
 
     assert input == years.to_pylist()
+
+
+def test_series_timestamp_tz_localize_dst() -> None:
+    from datetime import datetime
+
+    # Clocks in America/New_York are turned forward at 2023-03-12T02:00 and back at 2023-11-05T02:00.
+    s = Series.from_pylist([datetime(2023, 3, 12, 2, 30), datetime(2023, 11, 5, 1, 30), None]).cast(
+        DataType.timestamp(TimeUnit.s())
+    )
+    with pytest.raises(ValueError):
+        s.dt.tz_localize("America/New_York")
+
+    earliest = s.dt.tz_localize("America/New_York", ambiguous="earliest", nonexistent="earliest")
+    assert earliest.datatype() == DataType.timestamp(TimeUnit.s(), timezone="America/New_York")
+    expected = [datetime(2023, 3, 12, 7, 0), datetime(2023, 11, 5, 5, 30), None]
+    assert earliest.dt.tz_convert("UTC").cast(DataType.timestamp(TimeUnit.s())).to_pylist() == expected
+
+    latest = s.dt.tz_localize("America/New_York", ambiguous="latest", nonexistent="null")
+    expected = [None, datetime(2023, 11, 5, 6, 30), None]
+    assert latest.dt.tz_convert("UTC").cast(DataType.timestamp(TimeUnit.s())).to_pylist() == expected
+
+
+def test_series_timestamp_tz_convert() -> None:
+    from datetime import datetime
+
+    s = Series.from_pylist([datetime(2023, 6, 1, 12, 0)]).cast(DataType.timestamp(TimeUnit.ms()))
+    berlin = s.dt.tz_localize("Europe/Berlin")
+    singapore = berlin.dt.tz_convert("Asia/Singapore")
+    assert singapore.datatype() == DataType.timestamp(TimeUnit.ms(), timezone="Asia/Singapore")
+    assert singapore.dt.hour().to_pylist() == [18]
+    assert (singapore == berlin).to_pylist() == [True]
+
+    with pytest.raises(ValueError):
+        s.dt.tz_convert("UTC")
+    with pytest.raises(ValueError):
+        berlin.dt.tz_convert("Not/A_Timezone")
@@ -3,7 +3,7 @@ from __future__ import annotations
 import pyarrow as pa
 import pytest
 
-from daft import Series
+from daft import DataType, Series
 
 
 @pytest.mark.parametrize(
@@ -48,6 +48,22 @@ def test_series_utf8_compare_pattern_broadcast_null(funcname, data) -> None:
     s = Series.from_arrow(pa.array(["x_foo", "y_foo", "z_bar"]))
     pattern = Series.from_arrow(pa.array([None], type=pa.string()))
     result = getattr(s.str, funcname)(pattern)
+    assert result.datatype() == DataType.bool()
+    assert result.to_pylist() == [None, None, None]
+
+
+@pytest.mark.parametrize("funcname", ["endswith", "startswith", "contains"])
+@pytest.mark.parametrize("pattern_length", [1, 3])
+def test_series_utf8_compare_null_type(funcname, pattern_length) -> None:
+    s = Series.from_arrow(pa.array(["x_foo", None, "z_bar"]))
+    null_pattern = Series.from_arrow(pa.array([None] * pattern_length, type=pa.null()))
+    result = getattr(s.str, funcname)(null_pattern)
+    assert result.datatype() == DataType.bool()
+    assert result.to_pylist() == [None, None, None]
+
+    null_data = Series.from_arrow(pa.array([None] * 3, type=pa.null()))
+    result = getattr(null_data.str, funcname)(Series.from_arrow(pa.array(["foo"] * pattern_length)))
+    assert result.datatype() == DataType.bool()
     assert result.to_pylist() == [None, None, None]
 
 
@@ -66,6 +82,7 @@ def test_series_utf8_compare_data_broadcast_null(funcname) -> None:
     s = Series.from_arrow(pa.array([None], type=pa.string()))
     pattern = Series.from_arrow(pa.array(["foo", "bar", "baz"]))
     result = getattr(s.str, funcname)(pattern)
+    assert result.datatype() == DataType.bool()
     assert result.to_pylist() == [None, None, None]
 
 
@@ -200,12 +217,92 @@ def test_series_utf8_split_invalid_inputs(patterns) -> None:
         s.str.split(patterns)
 
 
+def test_series_utf8_split_empty_strings() -> None:
+    s = Series.from_arrow(pa.array(["", ",", "a,"]))
+    patterns = Series.from_arrow(pa.array([","]))
+    assert s.str.split(patterns).to_pylist() == [[""], ["", ""], ["a", ""]]
+    assert s.str.split(patterns, regex=True).to_pylist() == [[""], ["", ""], ["a", ""]]
+
+
+@pytest.mark.parametrize(
+    ["data", "patterns", "expected"],
+    [
+        # Broadcasted pattern.
+        (["a1b22c", "333", "abc", None], [r"\d+"], [["a", "b", "c"], ["", ""], ["abc"], None]),
+        # Pattern per string, with nulls.
+        (["a b", "a,b", "a;b"], [r"\s", None, "[,;]"], [["a", "b"], None, ["a", "b"]]),
+        # Broadcasted data.
+        (["a1b"], [r"\d", "[ab]"], [["a", "b"], ["", "1", ""]]),
+    ],
+)
+def test_series_utf8_split_regex(data, patterns, expected) -> None:
+    s = Series.from_arrow(pa.array(data, type=pa.string()))
+    patterns = Series.from_arrow(pa.array(patterns, type=pa.string()))
+    result = s.str.split(patterns, regex=True)
+    assert result.datatype() == DataType.list(DataType.string())
+    assert result.to_pylist() == expected
+
+
+def test_series_utf8_split_regex_invalid_pattern() -> None:
+    s = Series.from_arrow(pa.array(["a,b"]))
+    with pytest.raises(ValueError):
+        s.str.split(Series.from_arrow(pa.array(["("])), regex=True)
+
+
+@pytest.mark.parametrize(
+    ["data", "other", "expected"],
+    [
+        (["a", None, "c"], ["x", "y", None], ["ax", None, None]),
+        # Broadcasted other.
+        (["a", None, "c"], ["x"], ["ax", None, "cx"]),
+        # Broadcasted data.
+        (["a"], ["x", None, ""], ["ax", None, "a"]),
+    ],
+)
+def test_series_utf8_concat(data, other, expected) -> None:
+    s = Series.from_arrow(pa.array(data, type=pa.string()))
+    other = Series.from_arrow(pa.array(other, type=pa.string()))
+    assert s.str.concat(other).to_pylist() == expected
+
+
+@pytest.mark.parametrize(
+    ["start", "length", "expected"],
+    [
+        (1, 3, ["ell", "aft", "", None, "éll", "本語"]),
+        (0, None, ["hello", "daft", "", None, "héllo", "日本語"]),
+        (-2, None, ["lo", "ft", "", None, "lo", "本語"]),
+        (-10, 2, ["he", "da", "", None, "hé", "日本"]),
+        (10, None, ["", "", "", None, "", ""]),
+        (2, 0, ["", "", "", None, "", ""]),
+    ],
+)
+def test_series_utf8_substr(start, length, expected) -> None:
+    s = Series.from_arrow(pa.array(["hello", "daft", "", None, "héllo", "日本語"]))
+    assert s.str.substr(start, length).to_pylist() == expected
+    # The ASCII strings on their own take the byte-offset fast path.
+    ascii = Series.from_arrow(pa.array(["hello", "daft", "", None]))
+    assert ascii.str.substr(start, length).to_pylist() == expected[:4]
+
+
+def test_series_utf8_substr_negative_length() -> None:
+    s = Series.from_arrow(pa.array(["hello"]))
+    with pytest.raises(ValueError):
+        s.str.substr(0, -1)
+
+
 def test_series_utf8_length() -> None:
     s = Series.from_arrow(pa.array(["foo", "barbaz", "quux"]))
     result = s.str.length()
     assert result.to_pylist() == [3, 6, 4]
 
 
+def test_series_utf8_length_non_ascii() -> None:
+    s = Series.from_arrow(pa.array(["héllo", "日本", "", None]))
+    assert s.str.length().to_pylist() == [6, 6, 0, None]
+    assert s.str.length_chars().to_pylist() == [5, 2, 0, None]
+    assert s.str.length_chars().datatype() == DataType.uint64()
+
+
 def test_series_utf8_length_with_nulls() -> None:
     s = Series.from_arrow(pa.array(["foo", None, "barbaz", "quux"]))
     result = s.str.length()
@@ -264,6 +361,28 @@ def test_series_utf8_upper(data, expected) -> None:
     assert result.to_pylist() == expected
 
 
+def test_series_utf8_case_conversion_non_ascii() -> None:
+    s = Series.from_arrow(pa.array(["ÉCLAIR", "straße", "ΟΔΟΣ", None]))
+    assert s.str.lower().to_pylist() == ["éclair", "straße", "οδος", None]
+    assert s.str.upper().to_pylist() == ["ÉCLAIR", "STRASSE", "ΟΔΟΣ", None]
+
+
+@pytest.mark.parametrize(
+    ["data", "expected"],
+    [
+        (["hello WORLD", "a", "", None], ["Hello world", "A", "", None]),
+        (["éCLAIR", "1st", " lead"], ["Éclair", "1st", " lead"]),
+        # Digraphs and ligatures are titlecased rather than uppercased
+        (["ǆEMAL", "ßa", "ﬁRE", "ΟΔΟΣ"], ["ǅemal", "Ssa", "Fire", "Οδος"]),
+        ([None] * 4, [None] * 4),
+    ],
+)
+def test_series_utf8_capitalize(data, expected) -> None:
+    s = Series.from_arrow(pa.array(data))
+    result = s.str.capitalize()
+    assert result.to_pylist() == expected
+
+
 @pytest.mark.parametrize(
     ["data", "expected"],
     [
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+EMBEDDING_DTYPE = DataType.embedding(DataType.float32(), 2)
+
+
+def _embedding_table(a, b) -> MicroPartition:
+    table = MicroPartition.from_pydict({"a": a, "b": b})
+    return table.eval_expression_list([col("a").cast(EMBEDDING_DTYPE), col("b").cast(EMBEDDING_DTYPE)])
+
+
+def test_embedding_dot():
+    table = _embedding_table([[1.0, 2.0], [3.0, 4.0], None, [1.0, 0.0]], [[3.0, 4.0], [1.0, 1.0], [1.0, 1.0], None])
+
+    result = table.eval_expression_list([col("a").embedding.dot(col("b"))])
+
+    assert result.get_column("a").datatype() == DataType.float64()
+    assert result.to_pydict() == {"a": [11.0, 7.0, None, None]}
+
+
+def test_embedding_dot_size_mismatch():
+    table = MicroPartition.from_pydict({"a": [[1.0, 2.0]], "b": [[1.0, 2.0, 3.0]]})
+    table = table.eval_expression_list(
+        [col("a").cast(EMBEDDING_DTYPE), col("b").cast(DataType.embedding(DataType.float32(), 3))]
+    )
+
+    with pytest.raises(ValueError, match="embeddings of the same size"):
+        table.eval_expression_list([col("a").embedding.dot(col("b"))])
+
+
+def test_embedding_cosine_similarity():
+    table = _embedding_table([[1.0, 0.0], [1.0, 1.0], [0.0, 0.0]], [[2.0, 0.0], [-1.0, -1.0], [1.0, 1.0]])
+
+    result = table.eval_expression_list([col("a").embedding.cosine_similarity(col("b"))]).to_pydict()["a"]
+
+    assert result[0] == pytest.approx(1.0)
+    assert result[1] == pytest.approx(-1.0)
+    assert math.isnan(result[2])
+
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_map_get():
+    data = pa.array(
+        [[("foo", 1), ("bar", 2)], [("foo", 3), ("foo", 4)], [], None],
+        type=pa.map_(pa.string(), pa.int64()),
+    )
+    table = MicroPartition.from_arrow(pa.table({"map_col": data}))
+    assert table.schema()["map_col"].dtype == DataType.map(DataType.string(), DataType.int64())
+
+    result = table.eval_expression_list([col("map_col").map.get("foo")])
+
+    assert result.to_pydict() == {"map_col": [1, 4, None, None]}
+
+
+def test_map_get_per_row_keys():
+    data = pa.array([[("foo", 1), ("bar", 2)], [("foo", 3)], [("bar", 5)]], type=pa.map_(pa.string(), pa.int64()))
+    table = MicroPartition.from_arrow(pa.table({"map_col": data, "key": ["bar", "bar", "bar"]}))
+
+    result = table.eval_expression_list([col("map_col").map.get(col("key"))])
+
+    assert result.to_pydict() == {"map_col": [2, None, 5]}
+
+
+def test_map_arrow_roundtrip():
+    data = pa.array([[(1, "a"), (2, None)], [], None], type=pa.map_(pa.int32(), pa.large_string()))
+    table = MicroPartition.from_arrow(pa.table({"map_col": data}))
+
+    assert table.to_arrow()["map_col"].combine_chunks().to_pylist() == data.to_pylist()
+
+
+def test_map_get_bad_input():
+    table = MicroPartition.from_pydict({"col": [1, 2, 3]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").map.get("foo")])
@@ -145,6 +145,21 @@ def test_parquet_read_data(data, expected_data_series, use_native_downloader):
         assert table.to_arrow() == expected.to_arrow(), f"Expected:\n{expected}\n\nReceived:\n{table}"
 
 
+@pytest.mark.parametrize("use_native_downloader", [True, False])
+def test_parquet_read_required_columns(use_native_downloader):
+    arrow_schema = pa.schema([pa.field("id", pa.int64(), nullable=False), pa.field("data", pa.string())])
+    with _parquet_write_helper(
+        pa.Table.from_pydict({"id": [1, 2, 3], "data": ["a", None, "c"]}, schema=arrow_schema)
+    ) as f:
+        storage_config = storage_config_from_use_native_downloader(use_native_downloader)
+        schema = schema_inference.from_parquet(f, storage_config=storage_config)
+        table = table_io.read_parquet(f, schema, storage_config=storage_config)
+        assert table.to_pydict() == {"id": [1, 2, 3], "data": ["a", None, "c"]}
+
+        df = daft.read_parquet(f, use_native_downloader=use_native_downloader)
+        assert df.concat(df).to_pydict() == {"id": [1, 2, 3] * 2, "data": ["a", None, "c"] * 2}
+
+
 @pytest.mark.parametrize("row_group_size", [None, 1, 3])
 @pytest.mark.parametrize("use_native_downloader", [True, False])
 def test_parquet_read_data_limit_rows(row_group_size, use_native_downloader):
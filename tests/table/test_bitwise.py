@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_bitwise_ops() -> None:
+    table = MicroPartition.from_pydict({"a": [0b1100, 0b1010, None], "b": [0b1010, 0b0110, 1]})
+    result = table.eval_expression_list(
+        [
+            col("a").bitwise_and(col("b")).alias("and"),
+            col("a").bitwise_or(col("b")).alias("or"),
+            col("a").bitwise_xor(col("b")).alias("xor"),
+            col("a").bitwise_and(0b1000).alias("and_scalar"),
+        ]
+    )
+    assert result.get_column("and").datatype() == DataType.int64()
+    assert result.to_pydict() == {
+        "and": [0b1000, 0b0010, None],
+        "or": [0b1110, 0b1110, None],
+        "xor": [0b0110, 0b1100, None],
+        "and_scalar": [0b1000, 0b1000, None],
+    }
+
+
+def test_bitwise_ops_are_distinct_from_logical_ops() -> None:
+    table = MicroPartition.from_pydict({"a": [1], "b": [True]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("a") & col("a")])
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("b").bitwise_and(col("b"))])
+
+
+def test_bit_shifts() -> None:
+    table = MicroPartition.from_arrow(
+        pa.table(
+            {
+                "a": pa.array([1, -8, None], pa.int8()),
+                "n": [1, 2, 3],
+            }
+        )
+    )
+    result = table.eval_expression_list(
+        [
+            (col("a") << col("n")).alias("left"),
+            (col("a") >> col("n")).alias("right"),
+            (col("a") << 1).alias("left_scalar"),
+        ]
+    )
+    assert result.get_column("left").datatype() == DataType.int8()
+    assert result.to_pydict() == {
+        "left": [2, -32, None],
+        "right": [0, -2, None],
+        "left_scalar": [2, -16, None],
+    }
+
+
+def test_bit_shift_out_of_range() -> None:
+    table = MicroPartition.from_arrow(pa.table({"a": pa.array([1], pa.int8())}))
+    with pytest.raises(ValueError, match="Shift amount"):
+        table.eval_expression_list([col("a") << 8])
+
+
+def test_bitwise_ops_type_errors() -> None:
+    table = MicroPartition.from_pydict({"a": [1], "f": [1.0], "s": ["x"]})
+    for expr in [col("a").bitwise_and(col("f")), col("a").bitwise_or(col("s")), col("f") << col("a")]:
+        with pytest.raises(ValueError):
+            table.eval_expression_list([expr])
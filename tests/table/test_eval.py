@@ -202,3 +202,9 @@ def test_table_floor_bad_input() -> None:
 
     with pytest.raises(ValueError, match="Expected input to floor to be numeric"):
         table.eval_expression_list([col("a").floor()])
+
+
+@pytest.mark.parametrize("strategy", ["forward", "backward", "min", "max", "mean"])
+def test_table_fill_null_with_strategy_unsupported(strategy) -> None:
+    with pytest.raises(ValueError, match="only supported on Series"):
+        col("a").fill_null(strategy=strategy)
@@ -89,6 +89,12 @@ def test_table_filter_bad_expression() -> None:
         daft_table.filter(exprs)
 
 
+def test_table_filter_drops_null_predicates() -> None:
+    daft_table = MicroPartition.from_pydict({"a": [1, None, 3, 4], "b": [[1], [2], None, [4, 5]]})
+    new_table = daft_table.filter([col("a") > 2])
+    assert new_table.to_pydict() == {"a": [3, 4], "b": [None, [4, 5]]}
+
+
 def test_table_filter_with_dates() -> None:
     from datetime import date
 
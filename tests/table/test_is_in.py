@@ -146,6 +146,14 @@ def test_table_expr_is_in_different_input_types(input, items, expected) -> None:
     assert pydict["input"] == expected
 
 
+def test_table_expr_is_in_nan_matches_nothing() -> None:
+    daft_table = MicroPartition.from_pydict({"input": [float("nan"), 0.0, -0.0, 1.0]})
+    daft_table = daft_table.eval_expression_list([col("input").is_in([float("nan"), 0.0])])
+    pydict = daft_table.to_pydict()
+
+    assert pydict["input"] == [False, True, True, False]
+
+
 def test_table_expr_is_in_with_another_df_column() -> None:
     daft_table = MicroPartition.from_pydict({"input": [1, 2, 3, 4], "items": [3, 4, 5, 6]})
     daft_table = daft_table.eval_expression_list([col("input").is_in(col("items"))])
@@ -0,0 +1,36 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+PAYLOADS = [
+    '{"user": {"name": "a", "tags": ["x", "y"]}, "n": 1}',
+    '{"user": {"name": "b", "tags": []}, "n": null}',
+    "not json",
+    None,
+]
+
+
+@pytest.mark.parametrize(
+    ["query", "expected"],
+    [
+        ("$.user.name", ["a", "b", None, None]),
+        ("$.user.tags[0]", ["x", None, None, None]),
+        ("$.user.tags[-1]", ["y", None, None, None]),
+        ('$["user"]["tags"]', ['["x","y"]', "[]", None, None]),
+        ("$.n", ["1", None, None, None]),
+        ("$.missing", [None, None, None, None]),
+    ],
+)
+def test_json_query(query, expected) -> None:
+    table = MicroPartition.from_pydict({"col": PAYLOADS})
+    result = table.eval_expression_list([col("col").json.query(query)])
+    assert result.to_pydict() == {"col": expected}
+
+
+def test_json_query_invalid_query() -> None:
+    table = MicroPartition.from_pydict({"col": PAYLOADS})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").json.query("user.name")])
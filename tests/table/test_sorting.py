@@ -405,3 +405,18 @@ def test_string_table_sorting():
         "firstname": ["alice", "alice", "bob", "bob", "eve", None, None],
         "lastname": ["a", "a", "a", None, "a", "bond", None],
     }
+
+
+def test_table_multiple_col_sorting_struct_and_temporal():
+    daft_table = MicroPartition.from_pydict(
+        {
+            "s": [{"x": 1, "y": "b"}, {"x": 0, "y": "z"}, None, {"x": 1, "y": "a"}, {"x": 1, "y": "b"}],
+            "ts": pa.array([4, 3, 2, 1, 0], type=pa.timestamp("us", tz="UTC")),
+            "id": [0, 1, 2, 3, 4],
+        }
+    )
+    sorted_table = daft_table.sort([col("s"), col("ts")], descending=[False, True])
+    assert sorted_table.get_column("id").to_pylist() == [1, 3, 0, 4, 2]
+
+    sorted_table = daft_table.sort([col("s"), col("ts")], descending=[True, False])
+    assert sorted_table.get_column("id").to_pylist() == [2, 4, 0, 3, 1]
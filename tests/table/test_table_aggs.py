@@ -8,6 +8,7 @@ import pyarrow as pa
 import pytest
 
 from daft import DataType, col, utils
+from daft.daft import CountMode
 from daft.logical.schema import Schema
 from daft.series import Series
 from daft.table import MicroPartition
@@ -103,6 +104,20 @@ def test_table_count_pyobject() -> None:
     assert res == [3]
 
 
+def test_table_count_filtered() -> None:
+    daft_table = MicroPartition.from_pydict({"input": [1, None, 3, None, None], "keep": [True, True, False, None, True]})
+    keep = col("keep")
+    daft_table = daft_table.eval_expression_list(
+        [
+            col("input").alias("valid")._count(CountMode.Valid).filter(keep),
+            col("input").alias("all")._count(CountMode.All).filter(keep),
+            col("input").alias("null")._count(CountMode.Null).filter(keep),
+        ]
+    )
+
+    assert daft_table.to_pydict() == {"valid": [1], "all": [3], "null": [2]}
+
+
 test_table_minmax_numerics_cases = [
     ([], {"min": [None], "max": [None]}),
     ([None], {"min": [None], "max": [None]}),
@@ -283,6 +298,28 @@ test_table_any_value_cases = [
 ]
 
 
+@pytest.mark.parametrize(
+    "values",
+    [[1, 2, 1, None, 2], [1.5, 2.5, 1.5, None, 2.5], [True, False, True, None, False], [b"a", b"b", b"a", None, b"b"]],
+)
+def test_table_count_distinct(values) -> None:
+    daft_table = MicroPartition.from_pydict({"a": values, "b": ["x", "x", "y", "y", "y"]})
+    assert daft_table.agg([col("a")._count_distinct()]).to_pydict() == {"a": [2]}
+    grouped = daft_table.agg([col("a")._count_distinct()], group_by=[col("b")]).sort([col("b")])
+    assert grouped.to_pydict() == {"b": ["x", "y"], "a": [2, 2]}
+
+
+def test_table_count_distinct_nulltype() -> None:
+    daft_table = MicroPartition.from_pydict({"a": Series.from_pylist([None, None])})
+    assert daft_table.agg([col("a")._count_distinct()]).to_pydict() == {"a": [0]}
+
+
+def test_table_count_distinct_badtype() -> None:
+    daft_table = MicroPartition.from_pydict({"a": [[1], [2]]})
+    with pytest.raises(ValueError, match="count_distinct not implemented"):
+        daft_table.agg([col("a")._count_distinct()])
+
+
 @pytest.mark.parametrize("case,expected_nulls,expected_no_nulls", test_table_any_value_cases)
 def test_table_any_value(case, expected_nulls, expected_no_nulls):
     daft_table = MicroPartition.from_pydict(case)
@@ -746,6 +783,39 @@ def test_grouped_concat_aggs_pyobj() -> None:
     }
 
 
+def test_concat_aggs_utf8() -> None:
+    table = MicroPartition.from_pydict({"input": ["a", None, "b", None, "c", ""], "groups": [1, 1, 1, 2, 3, 3]})
+
+    concatted = table.agg([col("input").alias("concat")._agg_concat()])
+    assert concatted.get_column("concat").datatype() == DataType.string()
+    assert concatted.to_pydict() == {"concat": ["abc"]}
+
+    # Nulls are skipped, so only groups that are all null are null.
+    concatted = table.agg([col("input").alias("concat")._agg_concat()], group_by=[col("groups")]).sort([col("groups")])
+    assert concatted.to_pydict() == {"groups": [1, 2, 3], "concat": ["ab", None, "c"]}
+
+
+def test_str_join_aggs() -> None:
+    table = MicroPartition.from_pydict({"input": ["a", None, "b", None, "c", ""], "groups": [1, 1, 1, 2, 3, 3]})
+
+    joined = table.agg([col("input").alias("joined").str_join(", ")])
+    assert joined.get_column("joined").datatype() == DataType.string()
+    assert joined.to_pydict() == {"joined": ["a, b, c, "]}
+
+    # Nulls are skipped, so only groups that are all null are null.
+    joined = table.agg([col("input").alias("joined").str_join("-")], group_by=[col("groups")]).sort([col("groups")])
+    assert joined.to_pydict() == {"groups": [1, 2, 3], "joined": ["a-b", None, "c-"]}
+
+    joined = table.agg([col("input").alias("joined").str_join("-").filter(col("groups") != 1)])
+    assert joined.to_pydict() == {"joined": ["c-"]}
+
+
+def test_str_join_aggs_non_utf8() -> None:
+    table = MicroPartition.from_pydict({"input": [1, 2, 3]})
+    with pytest.raises(ValueError):
+        table.agg([col("input").str_join(",")])
+
+
 def test_concat_aggs_empty() -> None:
 
     daft_table = MicroPartition.from_pydict({"col_A": [], "col_B": []})
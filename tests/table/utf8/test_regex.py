@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+LOGS = ["GET /index.html 200", "POST /login 403", None, "no request here"]
+PATTERN = r"^(\w+) (\S+) (\d+)$"
+
+
+def test_utf8_match() -> None:
+    table = MicroPartition.from_pydict({"col": LOGS})
+    result = table.eval_expression_list([col("col").str.match(PATTERN)])
+    assert result.to_pydict() == {"col": [True, True, None, False]}
+
+
+@pytest.mark.parametrize(
+    ["index", "expected"],
+    [
+        (0, ["GET /index.html 200", "POST /login 403", None, None]),
+        (1, ["GET", "POST", None, None]),
+        (3, ["200", "403", None, None]),
+    ],
+)
+def test_utf8_extract(index, expected) -> None:
+    table = MicroPartition.from_pydict({"col": LOGS})
+    result = table.eval_expression_list([col("col").str.extract(PATTERN, index=index)])
+    assert result.to_pydict() == {"col": expected}
+
+
+def test_utf8_replace() -> None:
+    table = MicroPartition.from_pydict({"col": LOGS})
+    result = table.eval_expression_list([col("col").str.replace(PATTERN, "$3 $1")])
+    assert result.to_pydict() == {"col": ["200 GET", "403 POST", None, "no request here"]}
+
+
+def test_utf8_regex_per_row_patterns() -> None:
+    table = MicroPartition.from_pydict({"col": ["a1", "b2", "c3"], "pattern": [r"\d", r"[a-z]", r"x"]})
+    result = table.eval_expression_list([col("col").str.match(col("pattern"))])
+    assert result.to_pydict() == {"col": [True, True, False]}
+
+
+def test_utf8_invalid_regex() -> None:
+    table = MicroPartition.from_pydict({"col": ["a"]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").str.match("(")])
@@ -20,6 +20,11 @@ from daft.table import MicroPartition
             ["a,b,c", "d,e", "f", "g,h"],
             [["a", "b", "c"], ["d", "e"], ["f"], ["g", "h"]],
         ),
+        (
+            col("col").str.split(r"[,;]", regex=True),
+            ["a,b;c", "d;e", "f", ""],
+            [["a", "b", "c"], ["d", "e"], ["f"], [""]],
+        ),
     ],
 )
 def test_series_utf8_split_broadcast_pattern(expr, data, expected) -> None: